@@ -0,0 +1,50 @@
+//! Compares `util::build_url` against building the same URL with `Url::parse_with_params`,
+//! which is how URLs used to be built.
+//!
+//! Run with `cargo bench --bench build_url`.
+
+use std::hint::black_box;
+use std::time::{Instant, SystemTime};
+
+use url::Url;
+use webweg::util;
+
+const BASE: &str = "https://act.ucsd.edu/webreg2/svc/wradapter/secure/search-load-group-data?";
+const PARAMS: [(&str, &str); 3] = [
+    ("subjcode", "CSE"),
+    ("crsecode", "100"),
+    ("termcode", "FA23"),
+];
+const ITERATIONS: u32 = 200_000;
+
+fn bench(name: &str, mut f: impl FnMut() -> Url) {
+    // Warm up, so the buffer and any lazily-initialized state are ready.
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f());
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+
+    let per_iter = start.elapsed() / ITERATIONS;
+    println!("{name:<20} {:>8} ns/iter", per_iter.as_nanos());
+}
+
+fn main() {
+    bench("parse_with_params", || {
+        let epoch = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+            .to_string();
+        let mut url = Url::parse_with_params(BASE, black_box(&PARAMS)).unwrap();
+        url.query_pairs_mut().append_pair("_", epoch.as_str());
+        url
+    });
+
+    bench("util::build_url", || {
+        util::build_url(BASE, black_box(&PARAMS), Some(SystemTime::now())).unwrap()
+    });
+}
@@ -0,0 +1,46 @@
+//! Compares `ww_parser::parse_raw_meetings`, which deserializes each meeting through the
+//! borrowed `RawWebRegMeetingRef`, against deserializing each meeting from an intermediate
+//! `serde_json::Value`, which is how course info used to be parsed.
+//!
+//! Run with `cargo bench --bench parse_course_info`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use serde_json::Value;
+use webweg::raw_types::RawWebRegMeeting;
+use webweg::ww_parser;
+
+const COURSE_INFO: &str = include_str!("../tests/json/courseinfo4.json");
+const ITERATIONS: u32 = 20_000;
+
+fn bench(name: &str, mut f: impl FnMut() -> Vec<RawWebRegMeeting>) {
+    // Warm up, so the allocator and any lazily-initialized state are ready.
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f());
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+
+    let per_iter = start.elapsed() / ITERATIONS;
+    println!("{name:<28} {:>8} ns/iter", per_iter.as_nanos());
+}
+
+fn main() {
+    bench("serde_json::Value", || {
+        serde_json::from_str::<Vec<Value>>(black_box(COURSE_INFO))
+            .unwrap()
+            .into_iter()
+            .filter_map(|v| serde_json::from_value::<RawWebRegMeeting>(v).ok())
+            .collect()
+    });
+
+    bench("ww_parser::parse_raw_meetings", || {
+        ww_parser::parse_raw_meetings(black_box(COURSE_INFO))
+            .unwrap()
+            .0
+    });
+}
@@ -16,7 +16,7 @@ async fn main() {
         let cloned = wrapper.clone();
         tasks.push(tokio::spawn(async move {
             cloned.set_cookies(format!("pretend I have cookies for {}", term));
-            let data = cloned.req(term).parsed().get_course_info("CSE", "100").await;
+            let data = cloned.req(term).parsed().get_course_info(("CSE", "100")).await;
             println!("{data:?}");
         }));
     }
@@ -0,0 +1,1290 @@
+//! A blocking wrapper, for scripts and command line tools that don't want to start a `tokio`
+//! runtime or write `async` code.
+//!
+//! Requests are sent with a `reqwest::blocking` client, and the wrapper waits (e.g., between
+//! retries, or to stay under the rate limit) by putting the current thread to sleep, so no
+//! runtime is needed. Everything else (pacing, rate limiting, quotas, retries, and parsing) is
+//! shared with the asynchronous wrapper, so every option on `WebRegWrapperBuilder` (other than
+//! the ones for its own client) works the same way here (see `WebRegWrapper::from_builder`).
+//!
+//! Like `reqwest::blocking`, the blocking wrapper must **not** be used from within an
+//! asynchronous runtime (e.g., inside a `#[tokio::main]` function).
+//!
+//! Every method on `WebRegWrapper` and on the parsed `WrapperTermRequest` is mirrored, except:
+//! - `WebRegWrapper::req_many`, and the raw requester (`WrapperTermRequestBuilder::raw`).
+//! - The request builder's per-request overrides (e.g., `try_override_cookies`); requests
+//!   always use the wrapper's settings.
+//! - `WrapperTermRequest::watch_seat_counts`, which returns a `Stream`, and the `SeatWatcher`,
+//!   which needs to be spawned on a runtime.
+//!
+//! Everything but the last of these can still be used through `inner` and `block_on`.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::io::Read;
+use std::time::Duration;
+
+use futures::executor;
+use futures::future::BoxFuture;
+use reqwest::blocking::Client;
+use reqwest::Method;
+
+use crate::types;
+use crate::types::{
+    AccountSnapshot, ActionLog, BuilderProblem, BulkPlanReport, CatalogProgress,
+    CompatibilityReport, CourseCode, CourseRangeScan, CourseSection, Courses, EnrollFromPlanReport,
+    EnrollOutcome, Events, FamilyAvailability, FinalExam, HealthReport, HttpBackend,
+    HttpBackendError, HttpRequest, HttpResponse, LinkedAddResult, ObservedErrors, PingResponse,
+    PlanMatrix, PlanOutcome, PlanReconciliation, PlanUpsertOutcome, PrerequisiteInfo, Schedule,
+    SearchResult, SearchResultItem, SeatCount, SeatStrategy, SectionFamily, SessionStatus,
+    SwapOutcome, Term, TermInfo, ValidationOutcome, WaitlistDropReport, WrapperStats,
+};
+use crate::wrapper::input_types::{
+    AddType, AutoEnrollOptions, EnrollFromPlanOptions, EnrollWaitAdd, EventAdd, ExplicitAddType,
+    GradeOption, PlanAdd, SearchType, SectionLookup, WaitlistDropPolicy,
+};
+use crate::wrapper::requester_term;
+use crate::wrapper::wrapper_builder::WebRegWrapperBuilder;
+
+/// A blocking wrapper for WebReg. This mirrors the asynchronous `WebRegWrapper`, with every
+/// method blocking the current thread until the request is complete.
+///
+/// # Panics
+/// Like `reqwest::blocking::Client`, creating, using, or dropping this wrapper from within an
+/// asynchronous runtime may panic.
+pub struct WebRegWrapper {
+    inner: crate::wrapper::WebRegWrapper,
+}
+
+impl WebRegWrapper {
+    /// Creates a new blocking wrapper with the specified `Client` and session cookies. A
+    /// default timeout and user agent will be provided. To override these, use `from_builder`.
+    ///
+    /// # Parameters
+    /// - `client`: The `reqwest::blocking` client. Note that the timeout set on the `Client`
+    ///   will be ignored in favor of the wrapper's timeout.
+    /// - `cookies`: The cookies from your session of WebReg.
+    ///
+    /// # Returns
+    /// The new blocking wrapper.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::blocking::Client;
+    /// use webweg::blocking::WebRegWrapper;
+    ///
+    /// let wrapper = WebRegWrapper::new(Client::new(), "jlinksessionidx=abc; itscookie=def");
+    /// assert!(wrapper.is_valid());
+    /// ```
+    pub fn new(client: Client, cookies: impl Into<String>) -> Self {
+        let inner = crate::wrapper::WebRegWrapper::builder()
+            .with_cookies(cookies)
+            .into_blocking(Some(client))
+            .assemble()
+            .unwrap_or_else(|p| panic!("{p}"));
+        Self { inner }
+    }
+
+    /// Creates a new blocking wrapper from a builder, so that every option on the builder can
+    /// be used. Requests are sent with the builder's backend if it was given one (e.g., a
+    /// `MockWebReg`), and with a default `reqwest::blocking` client otherwise. The builder's
+    /// options for its own `reqwest` client (e.g., `with_client`) are ignored.
+    ///
+    /// # Parameters
+    /// - `builder`: The builder.
+    ///
+    /// # Returns
+    /// The new blocking wrapper, or every problem that was found with the builder's options
+    /// (see `WebRegWrapperBuilder::build_wrapper`).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use webweg::blocking;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// let builder = WebRegWrapper::builder()
+    ///     .with_cookies("jlinksessionidx=abc; itscookie=def")
+    ///     .with_default_timeout(Duration::from_secs(10));
+    ///
+    /// let wrapper = blocking::WebRegWrapper::from_builder(builder).unwrap();
+    /// ```
+    pub fn from_builder(builder: WebRegWrapperBuilder) -> Result<Self, Vec<BuilderProblem>> {
+        builder
+            .into_blocking(None)
+            .build_wrapper()
+            .map(|inner| Self { inner })
+    }
+
+    /// Gets a reference to the underlying asynchronous wrapper. Its requests are still sent
+    /// with the blocking client, so they should only be run with `block_on`.
+    ///
+    /// # Returns
+    /// The asynchronous wrapper.
+    pub fn inner(&self) -> &crate::wrapper::WebRegWrapper {
+        &self.inner
+    }
+
+    /// Runs the given future to completion on the current thread. This can be used to call
+    /// any asynchronous method that the blocking wrapper doesn't mirror.
+    ///
+    /// # Parameters
+    /// - `future`: The future to run.
+    ///
+    /// # Returns
+    /// The output of the future.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::blocking::Client;
+    /// use webweg::blocking::WebRegWrapper;
+    ///
+    /// let wrapper = WebRegWrapper::new(Client::new(), "jlinksessionidx=abc; itscookie=def");
+    /// let courses = wrapper
+    ///     .block_on(wrapper.inner().req("FA23").raw().get_course_info(("CSE", "100")))
+    ///     .unwrap();
+    /// ```
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        executor::block_on(future)
+    }
+
+    /// Sets the cookies to the new, specified cookies.
+    ///
+    /// # Parameters
+    /// - `new_cookies`: The new cookies.
+    #[cfg(not(feature = "multi"))]
+    pub fn set_cookies(&mut self, new_cookies: impl Into<String>) {
+        self.inner.set_cookies(new_cookies);
+    }
+
+    /// Sets the cookies to the new, specified cookies.
+    ///
+    /// # Parameters
+    /// - `new_cookies`: The new cookies.
+    #[cfg(feature = "multi")]
+    pub fn set_cookies(&self, new_cookies: impl Into<String>) {
+        self.inner.set_cookies(new_cookies);
+    }
+
+    /// Checks if the current WebReg instance is valid.
+    ///
+    /// # Returns
+    /// `true` if the instance is valid and `false` otherwise.
+    pub fn is_valid(&self) -> bool {
+        self.block_on(self.inner.is_valid())
+    }
+
+    /// Checks the status of the current WebReg session.
+    ///
+    /// # Returns
+    /// The session status.
+    pub fn get_session_status(&self) -> types::Result<SessionStatus> {
+        self.block_on(self.inner.get_session_status())
+    }
+
+    /// Gets the name of the owner associated with this account.
+    ///
+    /// # Returns
+    /// The name of the person.
+    pub fn get_account_name(&self) -> types::Result<String> {
+        self.block_on(self.inner.get_account_name())
+    }
+
+    /// Associates every term with your session.
+    ///
+    /// # Returns
+    /// Nothing, or an error if a term could not be associated.
+    pub fn register_all_terms(&self) -> types::Result<()> {
+        self.block_on(self.inner.register_all_terms())
+    }
+
+    /// Gets all terms available on WebReg.
+    ///
+    /// # Returns
+    /// The terms.
+    pub fn get_all_terms(&self) -> types::Result<Vec<Term>> {
+        self.block_on(self.inner.get_all_terms())
+    }
+
+    /// Gets all terms available on WebReg, including every field that WebReg gives for each
+    /// term.
+    ///
+    /// # Returns
+    /// The terms.
+    pub fn get_all_term_info(&self) -> types::Result<Vec<TermInfo>> {
+        self.block_on(self.inner.get_all_term_info())
+    }
+
+    /// Gets all terms available on WebReg, along with each term's enrollment periods. **This
+    /// associates every term with your session.**
+    ///
+    /// # Parameters
+    /// - `concurrency`: The maximum number of terms to request at once.
+    ///
+    /// # Returns
+    /// The terms.
+    pub fn get_all_term_details(&self, concurrency: usize) -> types::Result<Vec<TermInfo>> {
+        self.block_on(self.inner.get_all_term_details(concurrency))
+    }
+
+    /// Gets all terms available on WebReg, as returned by WebReg.
+    ///
+    /// # Returns
+    /// The raw JSON response.
+    pub fn get_all_terms_raw(&self) -> types::Result<String> {
+        self.block_on(self.inner.get_all_terms_raw())
+    }
+
+    /// Associates the term with your session.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    ///
+    /// # Returns
+    /// Nothing, or an error if the term could not be associated.
+    pub fn associate_term(&self, term: impl AsRef<str>) -> types::Result<()> {
+        self.block_on(self.inner.associate_term(term))
+    }
+
+    /// Associates the term with your session, and then checks that the term can actually be
+    /// used.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    ///
+    /// # Returns
+    /// Nothing, or an error if the term could not be associated or used.
+    pub fn associate_term_verified(&self, term: impl AsRef<str>) -> types::Result<()> {
+        self.block_on(self.inner.associate_term_verified(term))
+    }
+
+    /// Gets the terms that have been associated through this wrapper.
+    ///
+    /// # Returns
+    /// The associated terms.
+    pub fn get_associated_terms(&self) -> Vec<String> {
+        self.inner.get_associated_terms()
+    }
+
+    /// Checks whether a term has been associated through this wrapper.
+    ///
+    /// # Parameters
+    /// - `term`: The term.
+    ///
+    /// # Returns
+    /// Whether the term has been associated.
+    pub fn is_term_associated(&self, term: impl AsRef<str>) -> bool {
+        self.inner.is_term_associated(term)
+    }
+
+    /// Checks every associated term with WebReg.
+    ///
+    /// # Returns
+    /// The terms that are still associated, sorted.
+    pub fn verify_associated_terms(&self) -> Vec<String> {
+        self.block_on(self.inner.verify_associated_terms())
+    }
+
+    /// Checks that the server is responding.
+    ///
+    /// # Returns
+    /// `true` if the server responded and your session is valid.
+    pub fn ping_server(&self) -> bool {
+        self.block_on(self.inner.ping_server())
+    }
+
+    /// Pings the WebReg server, returning everything that WebReg gave back.
+    ///
+    /// # Returns
+    /// The ping response.
+    pub fn ping_details(&self) -> types::Result<PingResponse> {
+        self.block_on(self.inner.ping_details())
+    }
+
+    /// Runs a few cheap checks to see whether the wrapper can currently talk to WebReg.
+    ///
+    /// # Parameters
+    /// - `term`: The term that your service uses.
+    ///
+    /// # Returns
+    /// The health report.
+    pub fn health_check(&self, term: impl AsRef<str>) -> HealthReport {
+        self.block_on(self.inner.health_check(term))
+    }
+
+    /// Checks whether WebReg's responses can still be understood by this library.
+    ///
+    /// # Parameters
+    /// - `term`: The term to check with.
+    ///
+    /// # Returns
+    /// The compatibility report.
+    pub fn probe_api_compatibility(&self, term: impl AsRef<str>) -> CompatibilityReport {
+        self.block_on(self.inner.probe_api_compatibility(term))
+    }
+
+    /// Gets statistics for all requests that have been made through this wrapper.
+    ///
+    /// # Returns
+    /// The statistics.
+    pub fn stats(&self) -> WrapperStats {
+        self.inner.stats()
+    }
+
+    /// Clears all statistics that have been collected by this wrapper so far.
+    pub fn reset_stats(&self) {
+        self.inner.reset_stats();
+    }
+
+    /// Clears this wrapper's cached subject and department lists.
+    pub fn clear_catalog_cache(&self) {
+        self.inner.clear_catalog_cache();
+    }
+
+    /// Gets all unique error reasons that WebReg has given for requests made through this
+    /// wrapper.
+    ///
+    /// # Returns
+    /// The observed errors.
+    pub fn observed_errors(&self) -> ObservedErrors {
+        self.inner.observed_errors()
+    }
+
+    /// Gets every mutating request made through this wrapper.
+    ///
+    /// # Returns
+    /// The action log.
+    pub fn action_log(&self) -> ActionLog {
+        self.inner.action_log()
+    }
+
+    /// Clears every mutating request that has been recorded by this wrapper so far.
+    pub fn clear_action_log(&self) {
+        self.inner.clear_action_log();
+    }
+
+    /// Creates a request for the given term, using the wrapper's default settings.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    ///
+    /// # Returns
+    /// The blocking requester.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::blocking::Client;
+    /// use webweg::blocking::WebRegWrapper;
+    ///
+    /// let wrapper = WebRegWrapper::new(Client::new(), "jlinksessionidx=abc; itscookie=def");
+    /// wrapper.associate_term("FA23").unwrap();
+    /// let courses = wrapper.req("FA23").get_course_info(("CSE", "100")).unwrap();
+    /// println!("{}", courses.len());
+    /// ```
+    pub fn req<'a>(&'a self, term: &'a str) -> WrapperTermRequest<'a> {
+        WrapperTermRequest {
+            inner: self.inner.req(term).parsed(),
+        }
+    }
+}
+
+/// A blocking requester for a particular term. This mirrors the asynchronous
+/// `WrapperTermRequest` (see the module documentation for what isn't mirrored); see there for
+/// more details on each method.
+pub struct WrapperTermRequest<'a> {
+    inner: requester_term::WrapperTermRequest<'a>,
+}
+
+impl<'a> WrapperTermRequest<'a> {
+    /// Gets a reference to the underlying asynchronous requester.
+    ///
+    /// # Returns
+    /// The asynchronous requester.
+    pub fn inner(&self) -> &requester_term::WrapperTermRequest<'a> {
+        &self.inner
+    }
+
+    /// Gets the seat strategy that the wrapper uses to decide whether a section has seats.
+    ///
+    /// # Returns
+    /// The seat strategy.
+    pub fn seat_strategy(&self) -> &'a dyn SeatStrategy {
+        self.inner.seat_strategy()
+    }
+
+    /// Gets the prerequisites for a particular course.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `("CSE", "100")`).
+    ///
+    /// # Returns
+    /// The prerequisites.
+    pub fn get_prerequisites(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> types::Result<PrerequisiteInfo> {
+        executor::block_on(self.inner.get_prerequisites(course))
+    }
+
+    /// Gets your current schedule.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The schedule.
+    pub fn get_schedule(&self, schedule_name: Option<&str>) -> types::Result<Schedule> {
+        executor::block_on(self.inner.get_schedule(schedule_name))
+    }
+
+    /// Gets a printable version of your schedule, as a standalone HTML page.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The HTML page.
+    pub fn get_printable_schedule(&self, schedule_name: Option<&str>) -> types::Result<String> {
+        executor::block_on(self.inner.get_printable_schedule(schedule_name))
+    }
+
+    /// Gets the final exams for every section in your schedule.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The final exams.
+    pub fn get_final_exams(&self, schedule_name: Option<&str>) -> types::Result<Vec<FinalExam>> {
+        executor::block_on(self.inner.get_final_exams(schedule_name))
+    }
+
+    /// Gets enrollment counts for a particular course.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `("CSE", "100")`).
+    ///
+    /// # Returns
+    /// The sections of the course, with only the enrollment counts filled out.
+    pub fn get_enrollment_count(&self, course: impl Into<CourseCode>) -> types::Result<Courses> {
+        executor::block_on(self.inner.get_enrollment_count(course))
+    }
+
+    /// Gets course information for a particular course.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `("CSE", "100")`).
+    ///
+    /// # Returns
+    /// The sections of the course.
+    pub fn get_course_info(&self, course: impl Into<CourseCode>) -> types::Result<Courses> {
+        executor::block_on(self.inner.get_course_info(course))
+    }
+
+    /// Gets information about a particular section, given only its section ID.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID. For example, `079911`.
+    ///
+    /// # Returns
+    /// The section.
+    pub fn get_section_info(&self, section_id: impl AsRef<str>) -> types::Result<CourseSection> {
+        executor::block_on(self.inner.get_section_info(section_id))
+    }
+
+    /// Gets course information for a particular course, grouped by section family.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `("CSE", "100")`).
+    ///
+    /// # Returns
+    /// The section families of the course.
+    pub fn get_course_structure(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> types::Result<Vec<SectionFamily>> {
+        executor::block_on(self.inner.get_course_structure(course))
+    }
+
+    /// Probes every course number in a range for a subject.
+    ///
+    /// # Parameters
+    /// - `subject_code`: The subject code (e.g., `CSE`).
+    /// - `course_nums`: The course numbers to probe (e.g., `1..=199`).
+    ///
+    /// # Returns
+    /// The courses that were found, along with any course numbers that couldn't be probed.
+    pub fn scan_course_range(
+        &self,
+        subject_code: impl AsRef<str>,
+        course_nums: impl IntoIterator<Item = u32>,
+    ) -> types::Result<CourseRangeScan> {
+        executor::block_on(self.inner.scan_course_range(subject_code, course_nums))
+    }
+
+    /// Gets the seat counts for every section of a particular course.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `("CSE", "100")`).
+    ///
+    /// # Returns
+    /// The seat counts.
+    pub fn get_seat_counts(&self, course: impl Into<CourseCode>) -> types::Result<Vec<SeatCount>> {
+        executor::block_on(self.inner.get_seat_counts(course))
+    }
+
+    /// Gets the combined seat counts for each section family of a course.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `("CSE", "100")`).
+    ///
+    /// # Returns
+    /// The seat counts for each section family.
+    pub fn get_family_availability(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> types::Result<Vec<FamilyAvailability>> {
+        executor::block_on(self.inner.get_family_availability(course))
+    }
+
+    /// Gets a list of all departments that are offering courses for the given term.
+    ///
+    /// # Returns
+    /// The department codes.
+    pub fn get_department_codes(&self) -> types::Result<Vec<String>> {
+        executor::block_on(self.inner.get_department_codes())
+    }
+
+    /// Gets a list of all subjects that have at least one course offered for the given term.
+    ///
+    /// # Returns
+    /// The subject codes.
+    pub fn get_subject_codes(&self) -> types::Result<Vec<String>> {
+        executor::block_on(self.inner.get_subject_codes())
+    }
+
+    /// Gets a list of all departments, using the list cached by this wrapper if possible.
+    ///
+    /// # Returns
+    /// The department codes.
+    pub fn get_departments_wrapper_cached(&self) -> types::Result<Vec<String>> {
+        executor::block_on(self.inner.get_departments_wrapper_cached())
+    }
+
+    /// Gets a list of all subjects, using the list cached by this wrapper if possible.
+    ///
+    /// # Returns
+    /// The subject codes.
+    pub fn get_subjects_wrapper_cached(&self) -> types::Result<Vec<String>> {
+        executor::block_on(self.inner.get_subjects_wrapper_cached())
+    }
+
+    /// Searches for courses.
+    ///
+    /// # Parameters
+    /// - `filter_by`: The search to make.
+    ///
+    /// # Returns
+    /// The search results.
+    pub fn search_courses(&self, filter_by: SearchType) -> types::Result<SearchResult> {
+        executor::block_on(self.inner.search_courses(filter_by))
+    }
+
+    /// Searches for courses, and then gets the sections of every course that was found.
+    ///
+    /// # Parameters
+    /// - `filter_by`: The search to make.
+    /// - `concurrency`: The maximum number of course information requests to have in progress.
+    ///
+    /// # Returns
+    /// Each search result, along with its sections.
+    pub fn search_courses_detailed(
+        &self,
+        filter_by: SearchType,
+        concurrency: usize,
+    ) -> types::Result<Vec<(SearchResultItem, Courses)>> {
+        executor::block_on(self.inner.search_courses_detailed(filter_by, concurrency))
+    }
+
+    /// Gets every section offered this term.
+    ///
+    /// # Parameters
+    /// - `concurrency`: The maximum number of courses to get at once.
+    /// - `on_progress`: Called after each department is finished.
+    ///
+    /// # Returns
+    /// Every section.
+    pub fn get_all_courses(
+        &self,
+        concurrency: usize,
+        on_progress: impl FnMut(&CatalogProgress),
+    ) -> types::Result<Courses> {
+        executor::block_on(self.inner.get_all_courses(concurrency, on_progress))
+    }
+
+    /// Gets a list of all course notes for one or more subjects.
+    ///
+    /// # Parameters
+    /// - `subj`: The subject codes.
+    ///
+    /// # Returns
+    /// A map from each course to its note.
+    pub fn get_course_notes<T: AsRef<str>>(
+        &self,
+        subj: &[T],
+    ) -> types::Result<HashMap<String, String>> {
+        executor::block_on(self.inner.get_course_notes(subj))
+    }
+
+    /// Gets a list of all notes for all sections in a course.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `("CSE", "100")`).
+    ///
+    /// # Returns
+    /// A map from each section family (e.g., `A`) to its note.
+    pub fn get_section_notes_by_course(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> types::Result<HashMap<String, String>> {
+        executor::block_on(self.inner.get_section_notes_by_course(course))
+    }
+
+    /// Gets all of your events.
+    ///
+    /// # Returns
+    /// The events.
+    pub fn get_events(&self) -> types::Result<Events> {
+        executor::block_on(self.inner.get_events())
+    }
+
+    /// Gets the names of all of your schedules.
+    ///
+    /// # Returns
+    /// The schedule names.
+    pub fn get_schedule_list(&self) -> types::Result<Vec<String>> {
+        executor::block_on(self.inner.get_schedule_list())
+    }
+
+    /// Gets a snapshot of your account for this term.
+    ///
+    /// # Returns
+    /// The snapshot.
+    pub fn export_account_snapshot(&self) -> types::Result<AccountSnapshot> {
+        executor::block_on(self.inner.export_account_snapshot())
+    }
+
+    /// Compares the sections planned in one of your schedules against what you are actually
+    /// enrolled in.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The reconciliation.
+    pub fn reconcile_plan(&self, schedule_name: Option<&str>) -> types::Result<PlanReconciliation> {
+        executor::block_on(self.inner.reconcile_plan(schedule_name))
+    }
+
+    /// Tries to enroll in every section planned in one of your schedules.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    /// - `options`: The options.
+    ///
+    /// # Returns
+    /// What happened to each planned section.
+    pub fn enroll_from_plan(
+        &self,
+        schedule_name: Option<&str>,
+        options: EnrollFromPlanOptions,
+    ) -> types::Result<EnrollFromPlanReport> {
+        executor::block_on(self.inner.enroll_from_plan(schedule_name, options))
+    }
+
+    /// Enrolls in, or waitlists, a section, handling everything that usually needs to be done
+    /// by hand.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    /// - `options`: How to add the section.
+    ///
+    /// # Returns
+    /// The outcome.
+    pub fn auto_enroll(
+        &self,
+        section_id: impl AsRef<str>,
+        options: AutoEnrollOptions,
+    ) -> types::Result<EnrollOutcome> {
+        executor::block_on(self.inner.auto_enroll(section_id, options))
+    }
+
+    /// Sends an email to yourself.
+    ///
+    /// # Parameters
+    /// - `email_content`: The email to send.
+    ///
+    /// # Returns
+    /// Nothing, or an error if the email could not be sent.
+    pub fn send_email_to_self(&self, email_content: &str) -> types::Result<()> {
+        executor::block_on(self.inner.send_email_to_self(email_content))
+    }
+
+    /// Changes the grading option for a section.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    /// - `new_grade_opt`: The new grading option.
+    ///
+    /// # Returns
+    /// `true` if the grading option was changed.
+    pub fn change_grading_option(
+        &self,
+        section_id: &str,
+        new_grade_opt: GradeOption,
+    ) -> types::Result<bool> {
+        executor::block_on(self.inner.change_grading_option(section_id, new_grade_opt))
+    }
+
+    /// Checks whether a section can be switched to the given grading option.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    /// - `new_grade_opt`: The grading option.
+    ///
+    /// # Returns
+    /// Nothing, or an error if the grading option can't be changed.
+    pub fn validate_grading_option(
+        &self,
+        section_id: &str,
+        new_grade_opt: GradeOption,
+    ) -> types::Result<()> {
+        executor::block_on(
+            self.inner
+                .validate_grading_option(section_id, new_grade_opt),
+        )
+    }
+
+    /// Checks whether a section can be planned, without planning it.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the section that you want to plan.
+    ///
+    /// # Returns
+    /// The outcome of the validation.
+    pub fn validate_add_to_plan(
+        &self,
+        plan_options: &PlanAdd<'_>,
+    ) -> types::Result<ValidationOutcome> {
+        executor::block_on(self.inner.validate_add_to_plan(plan_options))
+    }
+
+    /// Plans a section.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the section that you want to plan.
+    /// - `validate`: Whether to validate the plan beforehand.
+    ///
+    /// # Returns
+    /// `true` if the section was planned.
+    pub fn add_to_plan(&self, plan_options: PlanAdd<'_>, validate: bool) -> types::Result<bool> {
+        executor::block_on(self.inner.add_to_plan(plan_options, validate))
+    }
+
+    /// Plans a section, validating beforehand if the wrapper is set to do so.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the section that you want to plan.
+    ///
+    /// # Returns
+    /// `true` if the section was planned.
+    pub fn add_to_plan_default(&self, plan_options: PlanAdd<'_>) -> types::Result<bool> {
+        executor::block_on(self.inner.add_to_plan_default(plan_options))
+    }
+
+    /// Changes the unit count and grading option of a section that is already planned.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the planned section.
+    ///
+    /// # Returns
+    /// `true` if the plan was changed.
+    pub fn edit_plan(&self, plan_options: PlanAdd<'_>) -> types::Result<bool> {
+        executor::block_on(self.inner.edit_plan(plan_options))
+    }
+
+    /// Plans a section or, if it's already planned, updates its unit count and grading option.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the section that you want to plan.
+    /// - `validate`: Whether to validate the plan beforehand.
+    ///
+    /// # Returns
+    /// Whether the section was planned or updated.
+    pub fn upsert_plan(
+        &self,
+        plan_options: PlanAdd<'_>,
+        validate: bool,
+    ) -> types::Result<PlanUpsertOutcome> {
+        executor::block_on(self.inner.upsert_plan(plan_options, validate))
+    }
+
+    /// Plans several sections at once.
+    ///
+    /// # Parameters
+    /// - `plans`: Information for the sections that you want to plan.
+    /// - `concurrency`: The maximum number of sections to plan at once.
+    ///
+    /// # Returns
+    /// What happened to each section.
+    pub fn add_many_to_plan(&self, plans: Vec<PlanAdd<'_>>, concurrency: usize) -> BulkPlanReport {
+        executor::block_on(self.inner.add_many_to_plan(plans, concurrency))
+    }
+
+    /// Removes several sections from a schedule at once.
+    ///
+    /// # Parameters
+    /// - `section_ids`: The section IDs.
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    /// - `concurrency`: The maximum number of sections to remove at once.
+    ///
+    /// # Returns
+    /// What happened to each section.
+    pub fn remove_many_from_plan(
+        &self,
+        section_ids: Vec<&str>,
+        schedule_name: Option<&str>,
+        concurrency: usize,
+    ) -> BulkPlanReport {
+        executor::block_on(self.inner.remove_many_from_plan(
+            section_ids,
+            schedule_name,
+            concurrency,
+        ))
+    }
+
+    /// Plans the same sections into several schedules.
+    ///
+    /// # Parameters
+    /// - `plan`: The sections to plan.
+    /// - `schedules`: The schedules to plan the sections into.
+    /// - `concurrency`: The maximum number of schedules to work on at once.
+    ///
+    /// # Returns
+    /// What happened to each section in each schedule.
+    pub fn apply_plan_to_schedules(
+        &self,
+        plan: &[PlanAdd<'_>],
+        schedules: &[&str],
+        concurrency: usize,
+    ) -> PlanMatrix {
+        executor::block_on(
+            self.inner
+                .apply_plan_to_schedules(plan, schedules, concurrency),
+        )
+    }
+
+    /// Plans a section, but only if it doesn't conflict with anything in the target schedule.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the section that you want to plan.
+    /// - `validate`: Whether to validate the plan beforehand.
+    ///
+    /// # Returns
+    /// Whether the section was planned, or what it conflicts with.
+    pub fn plan_if_no_conflict(
+        &self,
+        plan_options: PlanAdd<'_>,
+        validate: bool,
+    ) -> types::Result<PlanOutcome> {
+        executor::block_on(self.inner.plan_if_no_conflict(plan_options, validate))
+    }
+
+    /// Removes a section from a schedule.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// `true` if the section was removed.
+    pub fn remove_from_plan(
+        &self,
+        section_id: impl AsRef<str>,
+        schedule_name: Option<&str>,
+    ) -> types::Result<bool> {
+        executor::block_on(self.inner.remove_from_plan(section_id, schedule_name))
+    }
+
+    /// Checks whether a section can be added, without adding it.
+    ///
+    /// # Parameters
+    /// - `add_type`: The add type.
+    /// - `enroll_options`: Information for the section that you want to add.
+    ///
+    /// # Returns
+    /// The outcome of the validation.
+    pub fn validate_add_section(
+        &self,
+        add_type: AddType,
+        enroll_options: &EnrollWaitAdd<'_>,
+    ) -> types::Result<ValidationOutcome> {
+        executor::block_on(self.inner.validate_add_section(add_type, enroll_options))
+    }
+
+    /// Checks whether you can enroll in, or waitlist, a section.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// The add type.
+    pub fn get_add_type(&self, section_id: &str) -> types::Result<ExplicitAddType> {
+        executor::block_on(self.inner.get_add_type(section_id))
+    }
+
+    /// Looks up the section ID of a section by its course and section code.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `("CSE", "100")`).
+    /// - `section_code`: The section code (e.g., `A01`).
+    ///
+    /// # Returns
+    /// The section ID.
+    pub fn resolve_section_id(
+        &self,
+        course: impl Into<CourseCode>,
+        section_code: impl AsRef<str>,
+    ) -> types::Result<String> {
+        executor::block_on(self.inner.resolve_section_id(course, section_code))
+    }
+
+    /// Turns a section lookup into an `EnrollWaitAdd` object by looking up the section's ID.
+    ///
+    /// # Parameters
+    /// - `lookup`: The section lookup.
+    ///
+    /// # Returns
+    /// The `EnrollWaitAdd` object.
+    pub fn resolve_enroll_wait_add<'b>(
+        &self,
+        lookup: SectionLookup<'b>,
+    ) -> types::Result<EnrollWaitAdd<'b>> {
+        executor::block_on(self.inner.resolve_enroll_wait_add(lookup))
+    }
+
+    /// Enrolls in, or waitlists, a section.
+    ///
+    /// # Parameters
+    /// - `add_type`: The add type.
+    /// - `enroll_options`: Information for the section that you want to add.
+    /// - `validate`: Whether to validate the add beforehand.
+    ///
+    /// # Returns
+    /// `true` if the section was added.
+    pub fn add_section(
+        &self,
+        add_type: AddType,
+        enroll_options: EnrollWaitAdd<'_>,
+        validate: bool,
+    ) -> types::Result<bool> {
+        executor::block_on(self.inner.add_section(add_type, enroll_options, validate))
+    }
+
+    /// Enrolls in, or waitlists, a section, validating beforehand if the wrapper is set to do
+    /// so.
+    ///
+    /// # Parameters
+    /// - `add_type`: The add type.
+    /// - `enroll_options`: Information for the section that you want to add.
+    ///
+    /// # Returns
+    /// `true` if the section was added.
+    pub fn add_section_default(
+        &self,
+        add_type: AddType,
+        enroll_options: EnrollWaitAdd<'_>,
+    ) -> types::Result<bool> {
+        executor::block_on(self.inner.add_section_default(add_type, enroll_options))
+    }
+
+    /// Enrolls in, or waitlists, several sections that must be taken together.
+    ///
+    /// # Parameters
+    /// - `sections`: The sections to add, along with how each section should be added.
+    /// - `validate`: Whether to validate each add beforehand.
+    ///
+    /// # Returns
+    /// What happened to the sections.
+    pub fn add_linked_sections(
+        &self,
+        sections: Vec<(AddType, EnrollWaitAdd<'_>)>,
+        validate: bool,
+    ) -> LinkedAddResult {
+        executor::block_on(self.inner.add_linked_sections(sections, validate))
+    }
+
+    /// Drops a section.
+    ///
+    /// # Parameters
+    /// - `prev_enroll_status`: Whether you're enrolled in or waitlisted for the section.
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// `true` if the section was dropped.
+    pub fn drop_section(
+        &self,
+        prev_enroll_status: ExplicitAddType,
+        section_id: impl AsRef<str>,
+    ) -> types::Result<bool> {
+        executor::block_on(self.inner.drop_section(prev_enroll_status, section_id))
+    }
+
+    /// Swaps a section that you are enrolled in for another one.
+    ///
+    /// # Parameters
+    /// - `old_section_id`: The section ID of the section that you want to drop.
+    /// - `new_enroll_options`: Information for the section that you want to enroll in.
+    ///
+    /// # Returns
+    /// The outcome of the swap.
+    pub fn swap_section(
+        &self,
+        old_section_id: &str,
+        new_enroll_options: EnrollWaitAdd<'_>,
+    ) -> types::Result<SwapOutcome> {
+        executor::block_on(self.inner.swap_section(old_section_id, new_enroll_options))
+    }
+
+    /// Drops the waitlisted sections that a policy says you no longer need.
+    ///
+    /// # Parameters
+    /// - `policy`: The policy.
+    ///
+    /// # Returns
+    /// What happened to each waitlisted section.
+    pub fn apply_drop_policy(
+        &self,
+        policy: &WaitlistDropPolicy,
+    ) -> types::Result<WaitlistDropReport> {
+        executor::block_on(self.inner.apply_drop_policy(policy))
+    }
+
+    /// Renames a schedule.
+    ///
+    /// # Parameters
+    /// - `old_name`: The name of the old schedule.
+    /// - `new_name`: The new name.
+    ///
+    /// # Returns
+    /// `true` if the schedule was renamed.
+    pub fn rename_schedule(
+        &self,
+        old_name: impl AsRef<str>,
+        new_name: impl AsRef<str>,
+    ) -> types::Result<bool> {
+        executor::block_on(self.inner.rename_schedule(old_name, new_name))
+    }
+
+    /// Removes a schedule.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The name of the schedule to delete.
+    ///
+    /// # Returns
+    /// `true` if the schedule was removed.
+    pub fn remove_schedule(&self, schedule_name: impl AsRef<str>) -> types::Result<bool> {
+        executor::block_on(self.inner.remove_schedule(schedule_name))
+    }
+
+    /// Makes sure that a schedule with the given name exists, creating it if needed.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The name of the schedule.
+    /// - `seed`: The section to plan if the schedule needs to be created.
+    /// - `validate`: Whether to validate the plan beforehand.
+    ///
+    /// # Returns
+    /// `true` if the schedule was created.
+    pub fn ensure_schedule_exists(
+        &self,
+        schedule_name: impl AsRef<str>,
+        seed: PlanAdd<'_>,
+        validate: bool,
+    ) -> types::Result<bool> {
+        executor::block_on(
+            self.inner
+                .ensure_schedule_exists(schedule_name, seed, validate),
+        )
+    }
+
+    /// Adds an event to your WebReg calendar, or edits an existing event.
+    ///
+    /// # Parameters
+    /// - `event_info`: The details of the event.
+    /// - `event_timestamp`: The timestamp of the event to edit, or `None` to add a new event.
+    ///
+    /// # Returns
+    /// `true` if the event was added or edited.
+    pub fn add_or_edit_event<'b>(
+        &self,
+        event_info: EventAdd<'_>,
+        event_timestamp: impl Into<Option<&'b str>>,
+    ) -> types::Result<bool> {
+        executor::block_on(self.inner.add_or_edit_event(event_info, event_timestamp))
+    }
+
+    /// Removes an event from your WebReg calendar.
+    ///
+    /// # Parameters
+    /// - `event_timestamp`: The timestamp of the event to remove.
+    ///
+    /// # Returns
+    /// `true` if the event was removed.
+    pub fn remove_event(&self, event_timestamp: impl AsRef<str>) -> types::Result<bool> {
+        executor::block_on(self.inner.remove_event(event_timestamp))
+    }
+
+    /// Exports all of your events so that they can be added again later.
+    ///
+    /// # Returns
+    /// The events.
+    pub fn export_events(&self) -> types::Result<Vec<EventAdd<'static>>> {
+        executor::block_on(self.inner.export_events())
+    }
+
+    /// Imports the given events, skipping any event that you already have.
+    ///
+    /// # Parameters
+    /// - `events`: The events to add.
+    ///
+    /// # Returns
+    /// The number of events that were added.
+    pub fn import_events(&self, events: Vec<EventAdd<'_>>) -> types::Result<usize> {
+        executor::block_on(self.inner.import_events(events))
+    }
+
+    /// Associates the term of this requester with your session.
+    ///
+    /// # Returns
+    /// Nothing, or an error if the term could not be associated.
+    pub fn associate_term(&self) -> types::Result<()> {
+        executor::block_on(self.inner.associate_term())
+    }
+}
+
+/// Sends the wrapper's requests with a `reqwest::blocking` client. The request is sent as soon
+/// as the returned future is polled, blocking the thread until the whole response is read.
+pub(crate) struct ClientBackend {
+    client: Client,
+    max_response_size: Option<u64>,
+}
+
+impl ClientBackend {
+    /// Creates a new backend.
+    ///
+    /// # Parameters
+    /// - `client`: The client to send requests with.
+    /// - `max_response_size`: The largest response, in bytes, that the wrapper will accept, if
+    ///   there's a limit. At most one more byte than this is read.
+    ///
+    /// # Returns
+    /// The backend.
+    pub fn new(client: Client, max_response_size: Option<u64>) -> Self {
+        Self {
+            client,
+            max_response_size,
+        }
+    }
+
+    /// Sends a request, reading the whole response.
+    ///
+    /// # Parameters
+    /// - `request`: The request.
+    ///
+    /// # Returns
+    /// The response, or the error that occurred while sending the request.
+    fn send_blocking(&self, request: HttpRequest) -> Result<HttpResponse, HttpBackendError> {
+        let method = Method::from_bytes(request.method.as_bytes())
+            .map_err(|e| HttpBackendError::Other(e.to_string()))?;
+        let mut builder = self.client.request(method, request.url.as_str());
+        for (name, value) in &request.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let res = builder.send().map_err(to_backend_error)?;
+        let status = res.status().as_u16();
+        let url = res.url().to_string();
+        let headers = res
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect();
+
+        // Reading one byte past the limit is enough for the wrapper to know that the response
+        // is too large.
+        let limit = self
+            .max_response_size
+            .map_or(u64::MAX, |limit| limit.saturating_add(1));
+        let mut body = vec![];
+        res.take(limit)
+            .read_to_end(&mut body)
+            .map_err(|e| HttpBackendError::Other(e.to_string()))?;
+
+        Ok(HttpResponse {
+            status,
+            url,
+            headers,
+            body,
+        })
+    }
+}
+
+impl HttpBackend for ClientBackend {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, HttpBackendError>> {
+        Box::pin(async move { self.send_blocking(request) })
+    }
+}
+
+/// Converts an error from the blocking client into a backend error, so that timeouts and
+/// connection errors are still retried.
+///
+/// # Parameters
+/// - `err`: The error.
+///
+/// # Returns
+/// The backend error.
+fn to_backend_error(err: reqwest::Error) -> HttpBackendError {
+    if err.is_timeout() {
+        HttpBackendError::Timeout(err.to_string())
+    } else if err.is_connect() {
+        HttpBackendError::Connect(err.to_string())
+    } else {
+        HttpBackendError::Other(err.to_string())
+    }
+}
+
+/// Waits by putting the current thread to sleep, since the blocking wrapper isn't driven by a
+/// `tokio` runtime.
+///
+/// # Parameters
+/// - `duration`: How long to wait.
+///
+/// # Returns
+/// The future that finishes once the time has passed.
+pub(crate) fn thread_sleep(duration: Duration) -> BoxFuture<'static, ()> {
+    Box::pin(async move { std::thread::sleep(duration) })
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 pub(crate) const MY_USER_AGENT: &str =
     "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, \
 like Gecko) Chrome/97.0.4692.71 Safari/537.36";
@@ -6,6 +8,15 @@ pub(crate) const STATUS_ENROLL: &str = "EN";
 pub(crate) const STATUS_WAITLIST: &str = "WT";
 pub(crate) const STATUS_PLANNED: &str = "PL";
 
+/// How long subject and department lists are cached for, by default.
+pub(crate) const DEFAULT_CATALOG_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How many mutating requests the action log keeps, by default.
+pub(crate) const DEFAULT_ACTION_LOG_CAPACITY: usize = 1000;
+
+/// How long a schedule fetched by one of the mutation helpers can be reused by the others.
+pub(crate) const SCHEDULE_CACHE_TTL: Duration = Duration::from_secs(5);
+
 /// The default schedule name.
 pub(crate) const DEFAULT_SCHEDULE_NAME: &str = "My Schedule";
 
@@ -80,3 +91,5 @@ pub(crate) const SECTION_TEXT: &str =
 pub(crate) const TERM_LIST: &str = "https://act.ucsd.edu/webreg2/svc/wradapter/get-term?";
 
 pub(crate) const VERIFY_FAIL_ERR: &str = "[{\"VERIFY\":\"FAIL\"}]";
+pub(crate) const VERIFY_FAIL_MSG: &str =
+    "Verification error: register your term using the `associate_term` function.";
@@ -1,4 +1,8 @@
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod constants;
+pub mod notes;
+pub mod offline;
 pub mod raw_types;
 pub mod types;
 pub mod util;
@@ -0,0 +1,187 @@
+//! Parsing for the free-text notes that WebReg attaches to sections (e.g., the notes from
+//! `WrapperTermRequest::get_section_notes_by_course`).
+//!
+//! Section notes are written by each department, so there's no fixed format. The parser here
+//! recognizes the facts that show up most often, and keeps everything else as text.
+
+use serde::{Deserialize, Serialize};
+
+/// A single fact from a section's notes.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub enum SectionNote {
+    /// The section is cross-listed with other courses (e.g., `COGS 118A`). This may be empty
+    /// if the note didn't name the courses in a recognizable way.
+    CrossListed(Vec<String>),
+    /// You need the department's approval to enroll.
+    DepartmentApproval,
+    /// You need the instructor's approval to enroll.
+    InstructorApproval,
+    /// Some of the section's seats are reserved for a group of students.
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    SeatsReserved {
+        /// The number of reserved seats, if the note gave one.
+        count: Option<i64>,
+        /// Who the seats are reserved for (e.g., `Data Science majors`).
+        reserved_for: String,
+    },
+    /// Enrollment is restricted to a group of students (e.g., `seniors`).
+    RestrictedTo(String),
+    /// Anything that wasn't recognized, as it appeared in the note.
+    Text(String),
+}
+
+/// Parses a section's notes into structured facts. The notes are split into sentences, and
+/// each sentence that isn't recognized is kept as `SectionNote::Text` (consecutive sentences
+/// that aren't recognized are kept together).
+///
+/// # Parameters
+/// - `text`: The notes.
+///
+/// # Returns
+/// The facts, in the order that they appeared in the notes.
+///
+/// # Example
+/// ```rust
+/// use webweg::notes::{parse_section_notes, SectionNote};
+///
+/// let notes = parse_section_notes(
+///     "Cross-listed with COGS 118A. 20 seats reserved for Data Science majors. Bring a laptop.",
+/// );
+///
+/// assert_eq!(
+///     vec![
+///         SectionNote::CrossListed(vec!["COGS 118A".to_string()]),
+///         SectionNote::SeatsReserved {
+///             count: Some(20),
+///             reserved_for: "Data Science majors".to_string(),
+///         },
+///         SectionNote::Text("Bring a laptop.".to_string()),
+///     ],
+///     notes
+/// );
+/// ```
+pub fn parse_section_notes(text: &str) -> Vec<SectionNote> {
+    let mut notes: Vec<SectionNote> = vec![];
+    for sentence in split_sentences(text) {
+        let note = parse_sentence(sentence);
+        match (notes.last_mut(), note) {
+            (Some(SectionNote::Text(prev)), SectionNote::Text(curr)) => {
+                prev.push(' ');
+                prev.push_str(&curr);
+            }
+            (_, note) => notes.push(note),
+        }
+    }
+
+    notes
+}
+
+/// Splits the notes into sentences, collapsing any extra whitespace (WebReg pads each line of
+/// a note with spaces). A sentence ends with `.`, `!`, `?`, or `;` followed by whitespace, so
+/// course numbers like `1.5` aren't split.
+fn split_sentences(text: &str) -> Vec<&str> {
+    let text = text.trim();
+    let mut sentences = vec![];
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let at_break = matches!(c, '.' | '!' | '?' | ';')
+            && chars.peek().is_none_or(|(_, next)| next.is_whitespace());
+        if at_break {
+            let end = i + c.len_utf8();
+            sentences.push(text[start..end].trim());
+            start = end;
+        }
+    }
+
+    sentences.push(text[start..].trim());
+    sentences.retain(|s| !s.is_empty());
+    sentences
+}
+
+/// Parses a single sentence.
+fn parse_sentence(sentence: &str) -> SectionNote {
+    let collapsed = sentence.split_whitespace().collect::<Vec<_>>().join(" ");
+    let lower = collapsed.to_ascii_lowercase();
+
+    if let Some(pos) = ["cross-listed", "cross listed", "crosslisted"]
+        .iter()
+        .find_map(|p| lower.find(p))
+    {
+        return SectionNote::CrossListed(find_courses(&collapsed[pos..]));
+    }
+
+    if let Some(pos) = lower.find("reserved for") {
+        let count = collapsed[..pos]
+            .split(|c: char| !c.is_ascii_digit())
+            .filter_map(|n| n.parse::<i64>().ok())
+            .next_back();
+        return SectionNote::SeatsReserved {
+            count,
+            reserved_for: clean_tail(&collapsed[pos + "reserved for".len()..]),
+        };
+    }
+
+    if [
+        "department approval",
+        "department stamp",
+        "approval of the department",
+    ]
+    .iter()
+    .any(|p| lower.contains(p))
+    {
+        return SectionNote::DepartmentApproval;
+    }
+
+    if [
+        "instructor approval",
+        "instructor consent",
+        "consent of instructor",
+        "consent of the instructor",
+        "approval of the instructor",
+    ]
+    .iter()
+    .any(|p| lower.contains(p))
+    {
+        return SectionNote::InstructorApproval;
+    }
+
+    if let Some(pos) = lower.find("restricted to") {
+        return SectionNote::RestrictedTo(clean_tail(&collapsed[pos + "restricted to".len()..]));
+    }
+
+    SectionNote::Text(collapsed)
+}
+
+/// Finds every course (e.g., `COGS 118A`) in the text. A course is an uppercase subject code
+/// followed by a course number that starts with a digit.
+fn find_courses(text: &str) -> Vec<String> {
+    let words: Vec<&str> = text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let mut courses: Vec<String> = vec![];
+    for pair in words.windows(2) {
+        let (subj, num) = (pair[0], pair[1]);
+        let is_subj = (2..=5).contains(&subj.len()) && subj.chars().all(|c| c.is_ascii_uppercase());
+        let is_num = num.starts_with(|c: char| c.is_ascii_digit());
+        if is_subj && is_num {
+            let course = format!("{subj} {}", num.to_uppercase());
+            if !courses.contains(&course) {
+                courses.push(course);
+            }
+        }
+    }
+
+    courses
+}
+
+/// Trims whitespace and any trailing punctuation from the rest of a sentence.
+fn clean_tail(text: &str) -> String {
+    text.trim()
+        .trim_end_matches(|c: char| c.is_ascii_punctuation())
+        .trim()
+        .to_string()
+}
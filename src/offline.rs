@@ -0,0 +1,98 @@
+//! Parsing for saved WebReg responses, without making any requests.
+//!
+//! Each function here takes the JSON text exactly as WebReg returned it (e.g., a response that
+//! was saved to a file by a scraper, or a fixture in a test) and gives back the same parsed
+//! type as the corresponding `WrapperTermRequest` method. The functions in `ww_parser` do the
+//! actual parsing, but take the raw types instead of text; this module is the place to start
+//! if you only have the text.
+
+use crate::raw_types::{RawEvent, RawPrerequisite, RawScheduledMeeting, RawWebRegSearchResultItem};
+use crate::types;
+use crate::types::{Courses, Events, PrerequisiteInfo, Schedule, SearchResult};
+use crate::ww_parser;
+
+/// Parses a saved response from the course information endpoint (see
+/// `WrapperTermRequest::get_course_info`). Meetings that are malformed are skipped, like they
+/// are when making the request.
+///
+/// # Parameters
+/// - `json`: The response.
+/// - `subj_num`: The course that the response is for (e.g., `CSE 100`).
+///
+/// # Returns
+/// The sections of the course, or an error if the response couldn't be parsed.
+///
+/// # Example
+/// ```rust,no_run
+/// use webweg::offline;
+///
+/// let json = std::fs::read_to_string("cse100.json").unwrap();
+/// let sections = offline::parse_course_info(&json, "CSE 100").unwrap();
+/// println!("{}", sections.len());
+/// ```
+pub fn parse_course_info(json: &str, subj_num: &str) -> types::Result<Courses> {
+    let (meetings, _) = ww_parser::parse_raw_meetings(json)?;
+    ww_parser::parse_course_info(meetings, subj_num.to_owned())
+}
+
+/// Parses a saved response from the course information endpoint into enrollment counts (see
+/// `WrapperTermRequest::get_enrollment_count`).
+///
+/// # Parameters
+/// - `json`: The response.
+/// - `subj_num`: The course that the response is for (e.g., `CSE 100`).
+///
+/// # Returns
+/// The sections of the course, with only the enrollment counts filled out, or an error if the
+/// response couldn't be parsed.
+pub fn parse_enrollment_count(json: &str, subj_num: &str) -> types::Result<Courses> {
+    let (meetings, _) = ww_parser::parse_raw_meetings(json)?;
+    ww_parser::parse_enrollment_count(meetings, subj_num.to_owned())
+}
+
+/// Parses a saved response from the schedule endpoint (see `WrapperTermRequest::get_schedule`).
+///
+/// # Parameters
+/// - `json`: The response.
+///
+/// # Returns
+/// The schedule, or an error if the response couldn't be parsed.
+pub fn parse_schedule(json: &str) -> types::Result<Schedule> {
+    ww_parser::parse_schedule(serde_json::from_str::<Vec<RawScheduledMeeting>>(json)?)
+}
+
+/// Parses a saved response from the prerequisites endpoint (see
+/// `WrapperTermRequest::get_prerequisites`).
+///
+/// # Parameters
+/// - `json`: The response.
+///
+/// # Returns
+/// The prerequisites, or an error if the response couldn't be parsed.
+pub fn parse_prerequisites(json: &str) -> types::Result<PrerequisiteInfo> {
+    ww_parser::parse_prerequisites(serde_json::from_str::<Vec<RawPrerequisite>>(json)?)
+}
+
+/// Parses a saved response from the events endpoint (see `WrapperTermRequest::get_events`).
+///
+/// # Parameters
+/// - `json`: The response.
+///
+/// # Returns
+/// The events, or an error if the response couldn't be parsed.
+pub fn parse_events(json: &str) -> types::Result<Events> {
+    ww_parser::parse_get_events(serde_json::from_str::<Vec<RawEvent>>(json)?)
+}
+
+/// Parses a saved response from the search endpoint (see `WrapperTermRequest::search_courses`).
+///
+/// # Parameters
+/// - `json`: The response.
+///
+/// # Returns
+/// The search results, or an error if the response couldn't be parsed.
+pub fn parse_search_results(json: &str) -> types::Result<SearchResult> {
+    Ok(ww_parser::parse_search_results(serde_json::from_str::<
+        Vec<RawWebRegSearchResultItem>,
+    >(json)?))
+}
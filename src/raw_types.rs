@@ -1,27 +1,97 @@
-use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+// WebReg pads many of its strings with spaces (e.g., building codes are usually padded to a
+// fixed width). Rather than trimming these wherever they're used, the string fields of the raw
+// types are trimmed when they're deserialized, with the exception of the `TEXT` fields (which
+// are pieces of a longer text, so the spaces matter) and the event timestamp (which is sent back
+// to WebReg as-is).
+
+/// Deserializes a string, removing any leading and trailing whitespace. If the string can be
+/// borrowed from the input (i.e., it has no escape sequences), the trimmed string is borrowed
+/// rather than copied.
+///
+/// # Parameters
+/// - `deserializer`: The deserializer.
+///
+/// # Returns
+/// The trimmed string, or an error if the value isn't a string.
+fn trimmed_cow<'de, D>(deserializer: D) -> Result<Cow<'de, str>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct TrimmedVisitor;
+
+    impl<'de> Visitor<'de> for TrimmedVisitor {
+        type Value = Cow<'de, str>;
+
+        fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+            Ok(Cow::Borrowed(v.trim()))
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(v.trim().to_string()))
+        }
+
+        fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+            let t = v.trim();
+            Ok(Cow::Owned(if t.len() == v.len() {
+                v
+            } else {
+                t.to_string()
+            }))
+        }
+    }
+
+    deserializer.deserialize_str(TrimmedVisitor)
+}
+
+/// Deserializes a string, removing any leading and trailing whitespace.
+///
+/// # Parameters
+/// - `deserializer`: The deserializer.
+///
+/// # Returns
+/// The trimmed string, or an error if the value isn't a string.
+fn trimmed<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    trimmed_cow(deserializer).map(Cow::into_owned)
+}
 
 /// One possible result you can get by searching for a particular course.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RawWebRegSearchResultItem {
     /// The maximum number of units you can get.
     #[serde(rename = "UNIT_TO")]
-    max_units: f32,
+    pub max_units: f32,
 
     /// The subject code. For example, `CSE` or `MATH` are both possible option.
-    #[serde(rename = "SUBJ_CODE")]
+    #[serde(rename = "SUBJ_CODE", deserialize_with = "trimmed")]
     pub subj_code: String,
 
     /// The course title. For example, `Abstract Algebra II`.
-    #[serde(rename = "CRSE_TITLE")]
+    #[serde(rename = "CRSE_TITLE", deserialize_with = "trimmed")]
     pub course_title: String,
 
     /// The minimum number of units you can get.
     #[serde(rename = "UNIT_FROM")]
-    min_units: f32,
+    pub min_units: f32,
+
+    /// The step between the allowed unit counts, if WebReg gives one. For example, if a course
+    /// can be taken for 2 to 4 units in steps of 2, this would be `2`.
+    #[serde(rename = "UNIT_INC", default)]
+    pub unit_increment: Option<f32>,
 
     /// The course code. For example, `100B`.
-    #[serde(rename = "CRSE_CODE")]
+    #[serde(rename = "CRSE_CODE", deserialize_with = "trimmed")]
     pub course_code: String,
 }
 
@@ -63,7 +133,7 @@ pub struct RawWebRegMeeting {
     pub enrolled_count: i64,
 
     /// The section ID. Each section has a unique number identifier.
-    #[serde(rename = "SECTION_NUMBER")]
+    #[serde(rename = "SECTION_NUMBER", deserialize_with = "trimmed")]
     pub section_id: String,
 
     /// The number of students currently on the waitlist.
@@ -71,7 +141,7 @@ pub struct RawWebRegMeeting {
     pub count_on_waitlist: i64,
 
     /// The room code. For example, if the meeting is in CENTR 119, then this would be `119`.
-    #[serde(rename = "ROOM_CODE")]
+    #[serde(rename = "ROOM_CODE", deserialize_with = "trimmed")]
     pub room_code: String,
 
     /// The minute part of the meeting start time. For example, if this meeting starts at 11:00 AM,
@@ -92,31 +162,30 @@ pub struct RawWebRegMeeting {
     /// - `5`: Friday
     ///
     /// For example, if a class is meeting MWF, this would be `135`.
-    #[serde(rename = "DAY_CODE")]
+    #[serde(rename = "DAY_CODE", deserialize_with = "trimmed")]
     pub day_code: String,
 
     /// The instructor(s).
-    #[serde(rename = "PERSON_FULL_NAME")]
+    #[serde(rename = "PERSON_FULL_NAME", deserialize_with = "trimmed")]
     pub person_full_name: String,
 
-    /// Special meeting type, if any. If this is a normal meeting, this will be a string with a
-    /// two spaces.
-    #[serde(rename = "FK_SPM_SPCL_MTG_CD")]
+    /// Special meeting type, if any. If this is a normal meeting, this will be empty.
+    #[serde(rename = "FK_SPM_SPCL_MTG_CD", deserialize_with = "trimmed")]
     pub special_meeting: String,
 
     /// The building code. For example, if the meeting will take place at Center Hall, this would
     /// be `CENTR`.
-    #[serde(rename = "BLDG_CODE")]
+    #[serde(rename = "BLDG_CODE", deserialize_with = "trimmed")]
     pub bldg_code: String,
 
     /// The meeting type. See https://registrar.ucsd.edu/StudentLink/instr_codes.html. Note that
     /// this will improperly record final exams, midterms, and other special events as lectures.
     /// So, you need to check `special_meeting` also.
-    #[serde(rename = "FK_CDI_INSTR_TYPE")]
+    #[serde(rename = "FK_CDI_INSTR_TYPE", deserialize_with = "trimmed")]
     pub meeting_type: String,
 
     /// The section code. For example, this could be `A00` or `B01`.
-    #[serde(rename = "SECT_CODE")]
+    #[serde(rename = "SECT_CODE", deserialize_with = "trimmed")]
     pub sect_code: String,
 
     /// The number of available seats.
@@ -126,25 +195,25 @@ pub struct RawWebRegMeeting {
     /// The date that this meeting starts. Note that this (`start_date`) and `section_start_date`
     /// will have different dates if the meeting that this `WebRegEvent` represents is a one-day
     /// event (e.g. final exam).
-    #[serde(rename = "START_DATE")]
+    #[serde(rename = "START_DATE", deserialize_with = "trimmed")]
     pub start_date: String,
 
     /// The date that this section officially starts.
-    #[serde(rename = "SECTION_START_DATE")]
+    #[serde(rename = "SECTION_START_DATE", deserialize_with = "trimmed")]
     pub section_start_date: String,
 
     /// How this particular entry is displayed. From my understanding, it looks like:
     /// - `AC`: A section that can be enrolled or planned.
     /// - `NC`: A section that cannot be enrolled or planned (see CSE 8A Discussions).
     /// - `CA`: Canceled.
-    #[serde(rename = "FK_SST_SCTN_STATCD")]
+    #[serde(rename = "FK_SST_SCTN_STATCD", deserialize_with = "trimmed")]
     pub display_type: String,
 
     /// No idea what this does, but I'm assuming this tells you if the section
     /// is visible on WebReg.
-    /// - `" "` (an empty space) or `"Y"` if it is visible, and
+    /// - `""` (WebReg gives an empty space, which is trimmed) or `"Y"` if it is visible, and
     /// - `"N"` if it is not visible.
-    #[serde(rename = "PRINT_FLAG")]
+    #[serde(rename = "PRINT_FLAG", deserialize_with = "trimmed")]
     pub print_flag: String,
 }
 
@@ -156,10 +225,167 @@ impl RawWebRegMeeting {
     /// # Returns
     /// `true` if the meeting is visible on WebReg, and `false` otherwise.
     pub fn is_visible(&self) -> bool {
-        self.print_flag.as_str() == "Y" || self.print_flag == " "
+        self.print_flag == "Y" || self.print_flag.is_empty()
+    }
+}
+
+/// A meeting that borrows its strings from the response that it was deserialized from,
+/// wherever possible. This is used as an intermediate step when parsing large responses, so
+/// that each string is only copied once (after trimming) when it's turned into a
+/// `RawWebRegMeeting` with `into_owned`.
+///
+/// See `RawWebRegMeeting` for what each field means.
+#[derive(Debug, Deserialize)]
+pub struct RawWebRegMeetingRef<'a> {
+    /// The hour part of the end time.
+    #[serde(rename = "END_HH_TIME")]
+    pub end_time_hr: i16,
+
+    /// The minutes part of the end time.
+    #[serde(rename = "END_MM_TIME")]
+    pub end_time_min: i16,
+
+    /// The section capacity.
+    #[serde(rename = "SCTN_CPCTY_QTY")]
+    pub section_capacity: i64,
+
+    /// The number of students enrolled in this section.
+    #[serde(rename = "SCTN_ENRLT_QTY")]
+    pub enrolled_count: i64,
+
+    /// The section ID.
+    #[serde(borrow, rename = "SECTION_NUMBER", deserialize_with = "trimmed_cow")]
+    pub section_id: Cow<'a, str>,
+
+    /// The number of students currently on the waitlist.
+    #[serde(rename = "COUNT_ON_WAITLIST")]
+    pub count_on_waitlist: i64,
+
+    /// The room code.
+    #[serde(borrow, rename = "ROOM_CODE", deserialize_with = "trimmed_cow")]
+    pub room_code: Cow<'a, str>,
+
+    /// The minute part of the meeting start time.
+    #[serde(rename = "BEGIN_MM_TIME")]
+    pub start_time_min: i16,
+
+    /// The hours part of the start time.
+    #[serde(rename = "BEGIN_HH_TIME")]
+    pub start_time_hr: i16,
+
+    /// The days that this meeting will take place.
+    #[serde(borrow, rename = "DAY_CODE", deserialize_with = "trimmed_cow")]
+    pub day_code: Cow<'a, str>,
+
+    /// The instructor(s).
+    #[serde(borrow, rename = "PERSON_FULL_NAME", deserialize_with = "trimmed_cow")]
+    pub person_full_name: Cow<'a, str>,
+
+    /// Special meeting type, if any.
+    #[serde(
+        borrow,
+        rename = "FK_SPM_SPCL_MTG_CD",
+        deserialize_with = "trimmed_cow"
+    )]
+    pub special_meeting: Cow<'a, str>,
+
+    /// The building code.
+    #[serde(borrow, rename = "BLDG_CODE", deserialize_with = "trimmed_cow")]
+    pub bldg_code: Cow<'a, str>,
+
+    /// The meeting type.
+    #[serde(borrow, rename = "FK_CDI_INSTR_TYPE", deserialize_with = "trimmed_cow")]
+    pub meeting_type: Cow<'a, str>,
+
+    /// The section code.
+    #[serde(borrow, rename = "SECT_CODE", deserialize_with = "trimmed_cow")]
+    pub sect_code: Cow<'a, str>,
+
+    /// The number of available seats.
+    #[serde(rename = "AVAIL_SEAT")]
+    pub avail_seat: i64,
+
+    /// The date that this meeting starts.
+    #[serde(borrow, rename = "START_DATE", deserialize_with = "trimmed_cow")]
+    pub start_date: Cow<'a, str>,
+
+    /// The date that this section officially starts.
+    #[serde(
+        borrow,
+        rename = "SECTION_START_DATE",
+        deserialize_with = "trimmed_cow"
+    )]
+    pub section_start_date: Cow<'a, str>,
+
+    /// How this particular entry is displayed.
+    #[serde(
+        borrow,
+        rename = "FK_SST_SCTN_STATCD",
+        deserialize_with = "trimmed_cow"
+    )]
+    pub display_type: Cow<'a, str>,
+
+    /// Whether the section is visible on WebReg.
+    #[serde(borrow, rename = "PRINT_FLAG", deserialize_with = "trimmed_cow")]
+    pub print_flag: Cow<'a, str>,
+}
+
+impl RawWebRegMeetingRef<'_> {
+    /// Copies any borrowed strings, turning this into a `RawWebRegMeeting`.
+    ///
+    /// # Returns
+    /// The meeting.
+    pub fn into_owned(self) -> RawWebRegMeeting {
+        RawWebRegMeeting {
+            end_time_hr: self.end_time_hr,
+            end_time_min: self.end_time_min,
+            section_capacity: self.section_capacity,
+            enrolled_count: self.enrolled_count,
+            section_id: self.section_id.into_owned(),
+            count_on_waitlist: self.count_on_waitlist,
+            room_code: self.room_code.into_owned(),
+            start_time_min: self.start_time_min,
+            start_time_hr: self.start_time_hr,
+            day_code: self.day_code.into_owned(),
+            person_full_name: self.person_full_name.into_owned(),
+            special_meeting: self.special_meeting.into_owned(),
+            bldg_code: self.bldg_code.into_owned(),
+            meeting_type: self.meeting_type.into_owned(),
+            sect_code: self.sect_code.into_owned(),
+            avail_seat: self.avail_seat,
+            start_date: self.start_date.into_owned(),
+            section_start_date: self.section_start_date.into_owned(),
+            display_type: self.display_type.into_owned(),
+            print_flag: self.print_flag.into_owned(),
+        }
     }
 }
 
+/// A meeting, but with only the fields needed to get the seat counts. This is much cheaper to
+/// deserialize than `RawWebRegMeeting`, which matters if you're polling many courses.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RawSeatCount {
+    /// The section code. For example, this could be `A00` or `B01`.
+    #[serde(rename = "SECT_CODE", deserialize_with = "trimmed")]
+    pub sect_code: String,
+
+    /// The number of students enrolled in this section.
+    #[serde(rename = "SCTN_ENRLT_QTY")]
+    pub enrolled_count: i64,
+
+    /// The number of available seats.
+    #[serde(rename = "AVAIL_SEAT")]
+    pub avail_seat: i64,
+
+    /// The number of students currently on the waitlist.
+    #[serde(rename = "COUNT_ON_WAITLIST")]
+    pub count_on_waitlist: i64,
+
+    /// How this particular entry is displayed. See `RawWebRegMeeting::display_type`.
+    #[serde(rename = "FK_SST_SCTN_STATCD", deserialize_with = "trimmed")]
+    pub display_type: String,
+}
+
 /// A meeting that you have enrolled in. Note that this doesn't represent a class by itself, but
 /// rather a "piece" of that class. For example, one `ScheduledMeeting` can represent a discussion
 /// while another can represent a lecture. Additionally, each `ScheduledMeeting` can only represent
@@ -195,63 +421,62 @@ pub struct RawScheduledMeeting {
     pub end_time_min: i16,
 
     /// The subject code. For example, `CSE` or `MATH` are both possible option.
-    #[serde(rename = "SUBJ_CODE")]
+    #[serde(rename = "SUBJ_CODE", deserialize_with = "trimmed")]
     pub subj_code: String,
 
     /// The room code. For example, if the meeting is in CENTR 119, then this would be `119`.
-    #[serde(rename = "ROOM_CODE")]
+    #[serde(rename = "ROOM_CODE", deserialize_with = "trimmed")]
     pub room_code: String,
 
     /// The course title. For example, `Abstract Algebra II`.
-    #[serde(rename = "CRSE_TITLE")]
+    #[serde(rename = "CRSE_TITLE", deserialize_with = "trimmed")]
     pub course_title: String,
 
     /// The grading option. Some common options are `P/NP` or `L`, the former being pass/no pass
     /// and the latter being letter.
-    #[serde(rename = "GRADE_OPTION")]
+    #[serde(rename = "GRADE_OPTION", deserialize_with = "trimmed")]
     pub grade_option: String,
 
     /// The day that this meeting starts. For lectures, this will usually be the first day of the
     /// quarter; for midterms and finals, these will be given different dates.
-    #[serde(rename = "START_DATE")]
+    #[serde(rename = "START_DATE", deserialize_with = "trimmed")]
     pub start_date: String,
 
     /// The course code. For example, `100B`.
-    #[serde(rename = "CRSE_CODE")]
+    #[serde(rename = "CRSE_CODE", deserialize_with = "trimmed")]
     pub course_code: String,
 
     /// The day code. Unlike in `WebRegMeeting`, this stores at most 1 number.
-    #[serde(rename = "DAY_CODE")]
+    #[serde(rename = "DAY_CODE", deserialize_with = "trimmed")]
     pub day_code: String,
 
     /// The professor teaching this course.
-    #[serde(rename = "PERSON_FULL_NAME")]
+    #[serde(rename = "PERSON_FULL_NAME", deserialize_with = "trimmed")]
     pub person_full_name: String,
 
-    /// Special meeting type, if any. If this is a normal meeting, this will be a string with a
-    /// two spaces. Note that
-    #[serde(rename = "FK_SPM_SPCL_MTG_CD")]
+    /// Special meeting type, if any. If this is a normal meeting, this will be empty.
+    #[serde(rename = "FK_SPM_SPCL_MTG_CD", deserialize_with = "trimmed")]
     pub special_meeting: String,
 
     /// The meeting type. See https://registrar.ucsd.edu/StudentLink/instr_codes.html. Note that
     /// this will properly show the event type.
-    #[serde(rename = "FK_CDI_INSTR_TYPE")]
+    #[serde(rename = "FK_CDI_INSTR_TYPE", deserialize_with = "trimmed")]
     pub meeting_type: String,
 
     /// The building code. For example, if the meeting will take place at Center Hall, this would
     /// be `CENTR`.
-    #[serde(rename = "BLDG_CODE")]
+    #[serde(rename = "BLDG_CODE", deserialize_with = "trimmed")]
     pub bldg_code: String,
 
     /// The current enrollment status. This can be one of:
     /// - `EN`: Enrolled
     /// - `WT`: Waitlisted
     /// - `PL`: Planned
-    #[serde(rename = "ENROLL_STATUS")]
+    #[serde(rename = "ENROLL_STATUS", deserialize_with = "trimmed")]
     pub enroll_status: String,
 
     /// The section code. For example, this could be `A00` or `B01`.
-    #[serde(rename = "SECT_CODE")]
+    #[serde(rename = "SECT_CODE", deserialize_with = "trimmed")]
     pub sect_code: String,
 
     /// The maximum number of students that can enroll in this section. Note that this is an
@@ -271,8 +496,13 @@ pub struct RawScheduledMeeting {
 
     /// Your waitlist position. This will either be an empty string if there is no waitlist,
     /// or your waitlist position if you are on the waitlist.
-    #[serde(rename = "WT_POS")]
+    #[serde(rename = "WT_POS", deserialize_with = "trimmed")]
     pub waitlist_pos: String,
+
+    /// Whether the grading option can be changed. WebReg gives `+` if it can, and a blank
+    /// string if the course only offers one grading option.
+    #[serde(rename = "GRADE_OPTN_CD_PLUS", default)]
+    pub grade_option_plus: Option<String>,
 }
 
 /// An enum that represents a prerequisite type. Generally, WebReg displays prerequisites as either
@@ -300,52 +530,52 @@ pub enum RawPrerequisite {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RawTestPrerequisite {
     /// The name of the test/exam.
-    #[serde(rename = "TEST_TITLE")]
+    #[serde(rename = "TEST_TITLE", deserialize_with = "trimmed")]
     pub test_title: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RawCoursePrerequisite {
     /// The subject code. For example, `CSE` or `MATH` are both possible option.
-    #[serde(rename = "SUBJECT_CODE")]
+    #[serde(rename = "SUBJECT_CODE", deserialize_with = "trimmed")]
     pub subject_code: String,
 
     /// The group that this prerequisite is in. For example, if there are two prerequisites
     /// with ID 1, then this means you just need ONE of those two prerequisites.
-    #[serde(rename = "PREREQ_SEQ_ID")]
+    #[serde(rename = "PREREQ_SEQ_ID", deserialize_with = "trimmed")]
     pub prereq_seq_id: String,
 
     /// The name of the course.
-    #[serde(rename = "CRSE_TITLE")]
+    #[serde(rename = "CRSE_TITLE", deserialize_with = "trimmed")]
     pub course_title: String,
 
     /// The course code. For example, `100A` is a possible option.
-    #[serde(rename = "COURSE_CODE")]
+    #[serde(rename = "COURSE_CODE", deserialize_with = "trimmed")]
     pub course_code: String,
 
     // This always seem to be 450 or 600 or some multiple of 50.
-    #[serde(rename = "GRADE_SEQ_ID")]
+    #[serde(rename = "GRADE_SEQ_ID", deserialize_with = "trimmed")]
     pub grade_seq_id: String,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct RawEvent {
     /// The location of the event.
-    #[serde(rename = "LOCATION")]
+    #[serde(rename = "LOCATION", deserialize_with = "trimmed")]
     pub location: String,
 
     /// The start time. Guaranteed to be length 4, where the first
     /// two characters is the hour and the last two are minutes.
-    #[serde(rename = "START_TIME")]
+    #[serde(rename = "START_TIME", deserialize_with = "trimmed")]
     pub start_time: String,
 
     /// The end time. Guaranteed to be length 4, where the first
     /// two characters is the hour and the last two are minutes.
-    #[serde(rename = "END_TIME")]
+    #[serde(rename = "END_TIME", deserialize_with = "trimmed")]
     pub end_time: String,
 
     /// A description of the event. AKA the name of the event.
-    #[serde(rename = "DESCRIPTION")]
+    #[serde(rename = "DESCRIPTION", deserialize_with = "trimmed")]
     pub description: String,
 
     /// The days that this event will occur, represented as a binary
@@ -359,7 +589,7 @@ pub struct RawEvent {
     /// ```
     /// So, for example, if we have `1010111`, then this means that Monday,
     /// Wednesday, Friday, Saturday, and Sunday are selected.
-    #[serde(rename = "DAYS")]
+    #[serde(rename = "DAYS", deserialize_with = "trimmed")]
     pub days: String,
 
     /// The timestamp, representing when the event was created. Use this
@@ -378,37 +608,40 @@ pub struct RawEvent {
 pub struct RawSubjectElement {
     /// The subject description. For example,
     /// `Mathematics`.
-    #[serde(rename = "LONG_DESC")]
+    #[serde(rename = "LONG_DESC", deserialize_with = "trimmed")]
     pub long_desc: String,
 
     /// The subject code. For example, `MATH`.
-    #[serde(rename = "SUBJECT_CODE")]
+    #[serde(rename = "SUBJECT_CODE", deserialize_with = "trimmed")]
     pub subject_code: String,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct RawDepartmentElement {
     /// The department code. For example, `MATH`.
-    #[serde(rename = "DEP_CODE")]
+    #[serde(rename = "DEP_CODE", deserialize_with = "trimmed")]
     pub dep_code: String,
 
     /// The department description. For example,
     /// `Mathematics`.
-    #[serde(rename = "DEP_DESC")]
+    #[serde(rename = "DEP_DESC", deserialize_with = "trimmed")]
     pub dep_desc: String,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct RawTermListItem {
     /// The term description (e.g., Fall 2023).
-    #[serde(rename = "termDesc")]
+    #[serde(rename = "termDesc", deserialize_with = "trimmed")]
     pub term_desc: String,
     /// The sequence ID.
     #[serde(rename = "seqId")]
     pub seq_id: i64,
     /// The term code (e.g., FA23).
-    #[serde(rename = "termCode")]
+    #[serde(rename = "termCode", deserialize_with = "trimmed")]
     pub term_code: String,
+    /// Any other fields that WebReg gives.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -417,14 +650,14 @@ pub struct RawCourseTextItem {
     #[serde(rename = "TEXT")]
     pub text: String,
     /// The course code, where the subject and number is separated by a colon (e.g., `CSE:100`).
-    #[serde(rename = "SUBJCRSE")]
+    #[serde(rename = "SUBJCRSE", deserialize_with = "trimmed")]
     pub subj_crse: String,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct RawSectionTextItem {
     /// The course section number (e.g., `123456`).
-    #[serde(rename = "SECTNUM")]
+    #[serde(rename = "SECTNUM", deserialize_with = "trimmed")]
     pub sectnum: String,
 
     /// This partitioning of the subject text information.
@@ -1,8 +1,18 @@
+use std::cmp::max;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
-use serde::Serialize;
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::constants::VERIFY_FAIL_MSG;
+use crate::util;
+use crate::wrapper::input_types::{DropRule, GradeOption};
+
 /// The generic type is the return value. Otherwise, regardless of request type,
 /// we're just returning the error string if there is an error.
 pub type Result<T, E = WrapperError> = std::result::Result<T, E>;
@@ -22,8 +32,85 @@ pub type Events = Vec<Event>;
 /// The type that will be used to represent hours and minutes.
 pub type TimeType = u32;
 
-/// Represents a single search result item from WebReg.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+/// All unique error reasons that WebReg has given, grouped by endpoint. The key is the
+/// endpoint name (e.g., `add-enroll`), and the value is a map from each reason to the number
+/// of times that reason was seen.
+pub type ObservedErrors = HashMap<String, HashMap<String, u64>>;
+
+/// A course, identified by its subject code and course number (e.g., `CSE 100`). Every
+/// method that needs a course takes `impl Into<CourseCode>`, so you can pass a `CourseCode`, or
+/// a `(subject, number)` tuple like `("CSE", "100")`. Both parts are trimmed and converted to
+/// uppercase.
+///
+/// A course can also be parsed from a string, which is tolerant of case, spacing, and
+/// separators (see `util::parse_course_identifier`).
+///
+/// # Example
+/// ```rust
+/// use webweg::types::CourseCode;
+///
+/// let course: CourseCode = "math-20d".parse().unwrap();
+/// assert_eq!("MATH", course.subject);
+/// assert_eq!("20D", course.number);
+/// assert_eq!("MATH 20D", course.to_string());
+/// assert_eq!(course, CourseCode::from(("Math", " 20d ")));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct CourseCode {
+    /// The subject code. For example, `MATH` for `MATH 100B`.
+    pub subject: String,
+    /// The course number. For example, `100B` for `MATH 100B`.
+    pub number: String,
+}
+
+impl CourseCode {
+    /// Creates a course from its subject code and course number.
+    ///
+    /// # Parameters
+    /// - `subject`: The subject code (e.g., `CSE`).
+    /// - `number`: The course number (e.g., `100`).
+    ///
+    /// # Returns
+    /// The course, with both parts trimmed and in uppercase.
+    pub fn new(subject: impl AsRef<str>, number: impl AsRef<str>) -> Self {
+        Self {
+            subject: subject.as_ref().trim().to_uppercase(),
+            number: number.as_ref().trim().to_uppercase(),
+        }
+    }
+}
+
+impl<S: AsRef<str>, N: AsRef<str>> From<(S, N)> for CourseCode {
+    fn from((subject, number): (S, N)) -> Self {
+        Self::new(subject, number)
+    }
+}
+
+impl From<&CourseCode> for CourseCode {
+    fn from(course: &CourseCode) -> Self {
+        course.clone()
+    }
+}
+
+impl std::str::FromStr for CourseCode {
+    type Err = WrapperError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (subject, number) = util::parse_course_identifier(s)?;
+        Ok(Self { subject, number })
+    }
+}
+
+impl Display for CourseCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.subject, self.number)
+    }
+}
+
+/// Represents a single search result item from WebReg. All strings are trimmed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
 pub struct SearchResultItem {
     /// The subject code. For example, `CSE` or `MATH` are both possible option.
     pub subj_code: String,
@@ -31,6 +118,58 @@ pub struct SearchResultItem {
     pub course_code: String,
     /// The course title. For example, `Abstract Algebra II`.
     pub course_title: String,
+    /// The minimum number of units that the course can be taken for.
+    #[serde(default)]
+    pub min_units: f32,
+    /// The maximum number of units that the course can be taken for. For most courses, this
+    /// is the same as `min_units`.
+    #[serde(default)]
+    pub max_units: f32,
+    /// The step between the allowed unit counts, if WebReg gave one. If this is `None`, any
+    /// whole number of units between `min_units` and `max_units` is allowed.
+    #[serde(default)]
+    pub unit_increment: Option<f32>,
+}
+
+impl SearchResultItem {
+    /// Gets every unit count that the course can be taken for.
+    ///
+    /// # Returns
+    /// The allowed unit counts, from smallest to largest.
+    pub fn unit_options(&self) -> Vec<f32> {
+        let step = match self.unit_increment {
+            Some(step) if step > 0.0 => step,
+            _ => 1.0,
+        };
+
+        let mut options = vec![];
+        let mut units = self.min_units;
+        // Allow for a bit of floating-point error when stepping by fractional units.
+        while units <= self.max_units + 1e-3 {
+            options.push(units);
+            units += step;
+        }
+
+        if options.is_empty() {
+            options.push(self.min_units);
+        }
+
+        options
+    }
+
+    /// Checks whether the course can be taken for the given number of units. This can be
+    /// used to check a `PlanAdd` or `EnrollWaitAdd` before sending it to WebReg.
+    ///
+    /// # Parameters
+    /// - `units`: The number of units.
+    ///
+    /// # Returns
+    /// `true` if the course can be taken for that many units, and `false` otherwise.
+    pub fn allows_units(&self, units: f32) -> bool {
+        self.unit_options()
+            .iter()
+            .any(|option| (option - units).abs() < 1e-3)
+    }
 }
 
 impl Display for SearchResultItem {
@@ -43,8 +182,231 @@ impl Display for SearchResultItem {
     }
 }
 
+/// An in-memory index of courses, which can be used to look up courses by their titles or
+/// course codes without making any requests. This is meant for interactive tools (e.g.,
+/// autocomplete), where the courses come from a scrape of a term's search results that was
+/// made ahead of time.
+///
+/// Lookups are done word-by-word and are forgiving: each word in the query can be the start of
+/// a word in the course (e.g., `alg` for `Algebra`), or be off by a typo or two for longer
+/// words. Queries that look like course codes (e.g., `cse10` or `CSE 100`) are matched against
+/// the course codes directly.
+#[derive(Debug, Clone, Default)]
+pub struct CourseIndex {
+    /// The courses, with no duplicates.
+    courses: Vec<SearchResultItem>,
+    /// Each course's subject and course code, lowercase and without any whitespace (e.g.,
+    /// `cse100`).
+    codes: Vec<String>,
+    /// Each lowercase word (from the titles, subject codes, and course codes), mapped to the
+    /// courses that contain it.
+    words: BTreeMap<String, Vec<usize>>,
+}
+
+impl CourseIndex {
+    /// Builds an index from the given courses. If the same course appears more than once, only
+    /// the first one is kept.
+    ///
+    /// # Parameters
+    /// - `courses`: The courses (e.g., from `search_courses`).
+    ///
+    /// # Returns
+    /// The index.
+    pub fn build(courses: &[SearchResultItem]) -> Self {
+        let mut index = Self::default();
+        for course in courses {
+            let code = Self::compact(&format!("{}{}", course.subj_code, course.course_code));
+            if index.codes.contains(&code) {
+                continue;
+            }
+
+            let pos = index.courses.len();
+            let words = Self::words(&course.course_title)
+                .chain(Self::words(&course.subj_code))
+                .chain(Self::words(&course.course_code));
+            for word in words {
+                let entry = index.words.entry(word).or_default();
+                if entry.last() != Some(&pos) {
+                    entry.push(pos);
+                }
+            }
+
+            index.codes.push(code);
+            index.courses.push(course.clone());
+        }
+
+        index
+    }
+
+    /// Gets the number of courses in the index.
+    ///
+    /// # Returns
+    /// The number of courses.
+    pub fn len(&self) -> usize {
+        self.courses.len()
+    }
+
+    /// Checks whether the index has no courses.
+    ///
+    /// # Returns
+    /// `true` if there are no courses.
+    pub fn is_empty(&self) -> bool {
+        self.courses.is_empty()
+    }
+
+    /// Looks up the courses that best match the query. A course matches if its code starts
+    /// with the query (ignoring case and whitespace), or if every word in the query matches a
+    /// word in the course. Exact word matches rank above prefix matches, which rank above
+    /// matches with typos.
+    ///
+    /// # Parameters
+    /// - `query`: The query (e.g., `data struct` or `cse 10`).
+    /// - `limit`: The maximum number of courses to return.
+    ///
+    /// # Returns
+    /// The matching courses, best match first. Courses that match equally well are sorted by
+    /// subject and course code.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&SearchResultItem> {
+        let mut scores: HashMap<usize, u32> = HashMap::new();
+
+        let code = Self::compact(query);
+        if !code.is_empty() {
+            for (pos, c) in self.codes.iter().enumerate() {
+                if c.starts_with(&code) {
+                    *scores.entry(pos).or_default() += if *c == code { 20 } else { 10 };
+                }
+            }
+        }
+
+        let tokens: Vec<String> = Self::words(query).collect();
+        if !tokens.is_empty() {
+            let mut word_scores: Option<HashMap<usize, u32>> = None;
+            for token in &tokens {
+                let token_scores = self.score_token(token);
+                word_scores = Some(match word_scores {
+                    None => token_scores,
+                    Some(prev) => prev
+                        .into_iter()
+                        .filter_map(|(pos, score)| token_scores.get(&pos).map(|s| (pos, score + s)))
+                        .collect(),
+                });
+            }
+
+            for (pos, score) in word_scores.unwrap_or_default() {
+                *scores.entry(pos).or_default() += score;
+            }
+        }
+
+        let mut results: Vec<(usize, u32)> = scores.into_iter().collect();
+        results.sort_by(|(a_pos, a_score), (b_pos, b_score)| {
+            let (a, b) = (&self.courses[*a_pos], &self.courses[*b_pos]);
+            b_score
+                .cmp(a_score)
+                .then_with(|| a.subj_code.cmp(&b.subj_code))
+                .then_with(|| {
+                    util::get_formatted_course_num(&a.course_code)
+                        .cmp(&util::get_formatted_course_num(&b.course_code))
+                })
+        });
+
+        results
+            .into_iter()
+            .take(limit)
+            .map(|(pos, _)| &self.courses[pos])
+            .collect()
+    }
+
+    /// Scores every course against one word of the query, keeping the best score for each
+    /// course.
+    ///
+    /// # Parameters
+    /// - `token`: The lowercase word.
+    ///
+    /// # Returns
+    /// The score of each course that the word matches.
+    fn score_token(&self, token: &str) -> HashMap<usize, u32> {
+        // Allow more typos in longer words, since short words are usually prefixes that are
+        // still being typed.
+        let max_typos = match token.chars().count() {
+            0..=3 => 0,
+            4..=6 => 1,
+            _ => 2,
+        };
+
+        let mut scores: HashMap<usize, u32> = HashMap::new();
+        for (word, positions) in &self.words {
+            let score = if word == token {
+                3
+            } else if word.starts_with(token) {
+                2
+            } else if max_typos > 0 && Self::is_within_typos(token, word, max_typos) {
+                1
+            } else {
+                continue;
+            };
+
+            for pos in positions {
+                let best = scores.entry(*pos).or_default();
+                *best = (*best).max(score);
+            }
+        }
+
+        scores
+    }
+
+    /// Checks whether the word, or the start of the word, is within the given number of
+    /// edits (insertions, deletions, or substitutions) of the token.
+    ///
+    /// # Parameters
+    /// - `token`: The word from the query.
+    /// - `word`: The word from the index.
+    /// - `max_typos`: The maximum number of edits.
+    ///
+    /// # Returns
+    /// `true` if the word is close enough.
+    fn is_within_typos(token: &str, word: &str, max_typos: usize) -> bool {
+        let token: Vec<char> = token.chars().collect();
+        let word: Vec<char> = word.chars().collect();
+        if word.len() + max_typos < token.len() {
+            return false;
+        }
+
+        // The usual edit distance table, except that the distance to any prefix of the word
+        // counts, so that partially typed words can still match.
+        let mut prev: Vec<usize> = (0..=word.len()).collect();
+        for (i, tc) in token.iter().enumerate() {
+            let mut curr = vec![i + 1; word.len() + 1];
+            for (j, wc) in word.iter().enumerate() {
+                let cost = usize::from(tc != wc);
+                curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+            }
+            prev = curr;
+        }
+
+        prev.into_iter().min().is_some_and(|d| d <= max_typos)
+    }
+
+    /// Splits the text into lowercase words, ignoring punctuation.
+    fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+    }
+
+    /// Lowercases the text and removes anything that isn't a letter or digit.
+    fn compact(text: &str) -> String {
+        text.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+}
+
 /// A section, which consists of a lecture, usually a discussion, and usually a final.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+///
+/// All strings that come from WebReg (e.g., section codes and instructor names) are trimmed.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
 pub struct CourseSection {
     /// The subject, course ID. For example, `CSE 100`.
     pub subj_course_id: String,
@@ -52,11 +414,36 @@ pub struct CourseSection {
     pub section_id: String,
     /// The section code. For example, `B01`.
     pub section_code: String,
-    /// All instructors (i.e., all of the instructors that appear in the `meetings`).
+    /// All instructors (i.e., all of the instructors that appear in the `meetings`). This is
+    /// sorted and has no duplicates.
+    ///
+    /// Use `section_instructors` to get the instructors that teach the section as a whole, and
+    /// `Meeting::additional_instructors` to get the instructors that are only assigned to
+    /// one meeting (e.g., a TA leading a discussion).
     pub all_instructors: Vec<String>,
     /// The number of available seats. For example, suppose a section had 30 seats
     /// total and there are 5 people enrolled. Then, this will be `25`.
+    ///
+    /// This is never negative, even if the section is over-enrolled. See
+    /// `available_seats_raw` for the number that WebReg actually gave.
     pub available_seats: i64,
+    /// The number of available seats exactly as WebReg gave it. This can be negative if
+    /// more students are enrolled than there are seats (e.g., if a section's capacity was
+    /// lowered after students enrolled).
+    pub available_seats_raw: i64,
+    /// Extra information about the instructors, keyed by the instructor's name as it appears
+    /// in `all_instructors`. This is empty unless filled in by `util::enrich_sections`.
+    #[serde(default)]
+    pub instructor_info: BTreeMap<String, InstructorInfo>,
+    /// Any notes that WebReg has for this section (e.g., special enrollment instructions).
+    /// This is empty unless the requester was built with `should_include_section_notes`.
+    #[serde(default)]
+    pub section_notes: Vec<String>,
+    /// Whether the section's waitlist is open. WebReg doesn't include the waitlist's capacity
+    /// in its course data, so this is only `false` if WebReg rejected an attempt (made through
+    /// the same wrapper) to join the waitlist because it was full.
+    #[serde(default = "default_waitlist_open")]
+    pub waitlist_open: bool,
     /// The number of students enrolled in this section. For example, suppose a
     /// section had 30 seats total and there are 5 people enrolled. Then, this will
     /// be `5`.
@@ -71,7 +458,25 @@ pub struct CourseSection {
     pub is_visible: bool,
 }
 
+/// The default value for `CourseSection::waitlist_open`, for data serialized before the field
+/// existed.
+///
+/// # Returns
+/// `true`.
+fn default_waitlist_open() -> bool {
+    true
+}
+
 impl CourseSection {
+    /// Gets the section-level instructors, i.e., the instructors that are assigned to every
+    /// meeting that has an instructor. Usually, these are the lecture's instructors.
+    ///
+    /// # Returns
+    /// The section-level instructors, sorted.
+    pub fn section_instructors(&self) -> Vec<String> {
+        util::get_section_instructors(&self.meetings)
+    }
+
     /// Checks if this section has any seats left.
     ///
     /// This function should be used because, sometimes, WebReg will say that
@@ -81,7 +486,197 @@ impl CourseSection {
     /// # Returns
     /// `true` if there are seats and `false` otherwise.
     pub fn has_seats(&self) -> bool {
-        self.available_seats > 0 && self.waitlist_ct == 0
+        DefaultSeatStrategy.has_seats(self)
+    }
+
+    /// Gets the number of students that this section is over-enrolled by.
+    ///
+    /// # Returns
+    /// The number of students over the section's capacity, or `0` if the section isn't
+    /// over-enrolled.
+    pub fn over_enrolled_by(&self) -> i64 {
+        max(-self.available_seats_raw, 0)
+    }
+
+    /// Checks if this section has any seats left, using the given strategy to decide.
+    ///
+    /// # Parameters
+    /// - `strategy`: The strategy to use.
+    ///
+    /// # Returns
+    /// `true` if there are seats and `false` otherwise.
+    pub fn has_seats_with(&self, strategy: &dyn SeatStrategy) -> bool {
+        strategy.has_seats(self)
+    }
+
+    /// Gets a fingerprint of the parts of this section that rarely change: the section's
+    /// course, ID, and code, its capacity and visibility, its instructors, and its meetings.
+    /// Seat, enrollment, and waitlist counts aren't included, so the fingerprint only changes
+    /// when something like a meeting time or an instructor changes.
+    ///
+    /// The fingerprint doesn't depend on the order of the meetings or instructors, and is the
+    /// same across runs, platforms, and versions of this library, so it can be stored and
+    /// compared later. It isn't a cryptographic hash.
+    ///
+    /// # Returns
+    /// The fingerprint.
+    pub fn fingerprint(&self) -> u64 {
+        let mut canonical = [self.clone()];
+        util::canonicalize_sections(&mut canonical);
+        let [section] = canonical;
+
+        let mut parts = vec![
+            section.subj_course_id,
+            section.section_id,
+            section.section_code,
+            section.total_seats.to_string(),
+            section.is_visible.to_string(),
+            section.all_instructors.join("|"),
+        ];
+        for m in section.meetings {
+            let days = match m.meeting_days {
+                MeetingDay::Repeated(days) => days.join(""),
+                MeetingDay::OneTime(date) => date,
+                MeetingDay::None => String::new(),
+            };
+            parts.push(format!(
+                "{}|{days}|{:02}{:02}-{:02}{:02}|{} {}|{}",
+                m.meeting_type,
+                m.start_hr,
+                m.start_min,
+                m.end_hr,
+                m.end_min,
+                m.building,
+                m.room,
+                m.instructors.join("|")
+            ));
+        }
+
+        // 64-bit FNV-1a, which (unlike the standard library's hasher) is guaranteed to give
+        // the same result everywhere.
+        parts
+            .join("\u{1f}")
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+                (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+            })
+    }
+
+    /// Gets the section family that this section belongs to. For example, sections `A01` and
+    /// `A02` both belong to the `A` family, and share the `A00` lecture.
+    ///
+    /// # Returns
+    /// The section family, or `None` if this section doesn't belong to a family (e.g., section
+    /// `001`, which is only made up of itself).
+    pub fn family(&self) -> Option<char> {
+        self.section_code
+            .chars()
+            .next()
+            .filter(|c| c.is_ascii_alphabetic())
+    }
+
+    /// Checks whether this section is co-scheduled (linked) with a section from a different
+    /// course. Two sections are considered co-scheduled if they share at least one repeated
+    /// meeting at the same time and in the same room (e.g., a course that is cross-listed under
+    /// two course numbers, or a lab that is held together with another course's lecture).
+    ///
+    /// WebReg doesn't say which sections are linked, so this is only a best guess based on
+    /// the meetings.
+    ///
+    /// # Parameters
+    /// - `other`: The other section.
+    ///
+    /// # Returns
+    /// `true` if the two sections are co-scheduled and `false` otherwise.
+    pub fn is_coscheduled_with(&self, other: &CourseSection) -> bool {
+        if self.subj_course_id == other.subj_course_id {
+            return false;
+        }
+
+        self.meetings.iter().any(|a| {
+            matches!(a.meeting_days, MeetingDay::Repeated(_))
+                && !a.building.is_empty()
+                && a.building != "TBA"
+                && other.meetings.iter().any(|b| {
+                    a.meeting_days == b.meeting_days
+                        && a.start_hr == b.start_hr
+                        && a.start_min == b.start_min
+                        && a.end_hr == b.end_hr
+                        && a.end_min == b.end_min
+                        && a.building == b.building
+                        && a.room == b.room
+                })
+        })
+    }
+}
+
+/// Extra information about an instructor from an external source (e.g., a campus directory,
+/// or a course evaluation site).
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct InstructorInfo {
+    /// The instructor's email address, if known.
+    pub email: Option<String>,
+    /// A link to more information about the instructor, if any.
+    pub url: Option<String>,
+    /// Any other information, such as ratings. Since every source rates instructors
+    /// differently, the keys and values are up to the source (e.g., `"cape_recommend"` might
+    /// map to `"87.5%"`).
+    pub attributes: BTreeMap<String, String>,
+}
+
+/// A source of extra information about instructors. You can implement this trait to attach
+/// information from your own source (e.g., CAPE, RateMyProfessors, or a campus directory)
+/// to sections using `util::enrich_sections`.
+pub trait InstructorInfoProvider {
+    /// Looks up an instructor.
+    ///
+    /// # Parameters
+    /// - `normalized_name`: The instructor's name, normalized with
+    ///   `util::normalize_instructor_name` (e.g., `john smith`). Normalize the names in your own
+    ///   data the same way so that they match.
+    ///
+    /// # Returns
+    /// The information about the instructor, or `None` if the instructor isn't known.
+    fn lookup(&self, normalized_name: &str) -> Option<InstructorInfo>;
+}
+
+/// A strategy for deciding whether a section has seats that you can enroll in.
+///
+/// Not every course behaves the same way. For example, some courses will let you enroll
+/// even if there's a waitlist (e.g., when seats open up after people drop). You can implement
+/// this trait to decide for yourself.
+pub trait SeatStrategy: Send + Sync {
+    /// Checks whether the given section has seats that you can enroll in.
+    ///
+    /// # Parameters
+    /// - `section`: The section.
+    ///
+    /// # Returns
+    /// `true` if there are seats and `false` otherwise.
+    fn has_seats(&self, section: &CourseSection) -> bool;
+}
+
+/// The default seat strategy. A section is considered to have seats if there are available
+/// seats and nobody is on the waitlist. This is because WebReg will sometimes say that there
+/// are seats available when, in reality, the seats are reserved for people on the waitlist.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultSeatStrategy;
+
+impl SeatStrategy for DefaultSeatStrategy {
+    fn has_seats(&self, section: &CourseSection) -> bool {
+        section.available_seats > 0 && section.waitlist_ct == 0
+    }
+}
+
+/// A seat strategy where a section is considered to have seats if there are any available
+/// seats, regardless of whether there's a waitlist.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AvailableSeatStrategy;
+
+impl SeatStrategy for AvailableSeatStrategy {
+    fn has_seats(&self, section: &CourseSection) -> bool {
+        section.available_seats > 0
     }
 }
 
@@ -107,14 +702,271 @@ impl Display for CourseSection {
     }
 }
 
-/// A meeting. Usually represents a lecture, final exam, discussion, and more.
+/// The seat counts for a section. This is a lighter version of `CourseSection` for when you
+/// only care about how many seats are left (e.g., when polling many courses).
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct SeatCount {
+    /// The section code. For example, `A01`.
+    pub section_code: String,
+    /// The number of students enrolled in this section.
+    pub enrolled_ct: i64,
+    /// The number of available seats. This is never negative; see
+    /// `CourseSection::available_seats`.
+    pub available_seats: i64,
+    /// The number of students on the waitlist.
+    pub waitlist_ct: i64,
+}
+
+impl SeatCount {
+    /// Checks if this section has any seats left, using the default seat strategy. This uses
+    /// the same logic as `CourseSection::has_seats`.
+    ///
+    /// # Returns
+    /// `true` if there are seats and `false` otherwise.
+    pub fn has_seats(&self) -> bool {
+        self.has_seats_with(&DefaultSeatStrategy)
+    }
+
+    /// Checks if this section has any seats left, using the given strategy to decide. Use
+    /// `WrapperTermRequest::seat_strategy` to get the wrapper's strategy, so that this agrees
+    /// with `CourseSection::has_seats_with` for the same section.
+    ///
+    /// The strategy is given a `CourseSection` with only the section code and the seat,
+    /// enrollment, and waitlist counts filled in; the section's capacity is assumed to be the
+    /// number of enrolled students plus the number of available seats.
+    ///
+    /// # Parameters
+    /// - `strategy`: The strategy to use.
+    ///
+    /// # Returns
+    /// `true` if there are seats and `false` otherwise.
+    pub fn has_seats_with(&self, strategy: &dyn SeatStrategy) -> bool {
+        strategy.has_seats(&CourseSection {
+            subj_course_id: String::new(),
+            section_id: String::new(),
+            section_code: self.section_code.clone(),
+            all_instructors: vec![],
+            available_seats: self.available_seats,
+            available_seats_raw: self.available_seats,
+            instructor_info: BTreeMap::new(),
+            section_notes: vec![],
+            waitlist_open: default_waitlist_open(),
+            enrolled_ct: self.enrolled_ct,
+            total_seats: self.enrolled_ct + self.available_seats,
+            waitlist_ct: self.waitlist_ct,
+            meetings: vec![],
+            is_visible: true,
+        })
+    }
+}
+
+/// A change in a section's availability, as seen by a
+/// [`SeatWatcher`](crate::wrapper::seat_watcher::SeatWatcher).
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all_fields = "camelCase"))]
+pub enum SeatEvent {
+    /// The section now has seats (according to the wrapper's seat strategy). This is also
+    /// sent the first time a section is checked, if it has seats.
+    Opened {
+        /// The section ID.
+        section_id: String,
+        /// The number of available seats.
+        available_seats: i64,
+    },
+    /// The section no longer has seats.
+    Closed {
+        /// The section ID.
+        section_id: String,
+    },
+    /// The number of students on the section's waitlist changed.
+    WaitlistChanged {
+        /// The section ID.
+        section_id: String,
+        /// The previous number of students on the waitlist.
+        old: i64,
+        /// The current number of students on the waitlist.
+        new: i64,
+    },
+    /// Checking a course failed. The watcher keeps going.
+    PollFailed {
+        /// The course (e.g., `CSE 100`).
+        course: String,
+        /// The error that occurred.
+        error: String,
+    },
+}
+
+impl SeatEvent {
+    /// Gets the events describing how a section changed between two checks.
+    ///
+    /// # Parameters
+    /// - `previous`: The section as of the previous check, or `None` if this is the first
+    ///   check.
+    /// - `current`: The section as of the current check.
+    /// - `strategy`: The strategy used to decide whether a section has seats.
+    ///
+    /// # Returns
+    /// The events, which may be empty.
+    pub fn changes(
+        previous: Option<&CourseSection>,
+        current: &CourseSection,
+        strategy: &dyn SeatStrategy,
+    ) -> Vec<SeatEvent> {
+        let section_id = &current.section_id;
+        let now_open = strategy.has_seats(current);
+        let mut events = vec![];
+        match previous {
+            None if now_open => events.push(SeatEvent::Opened {
+                section_id: section_id.clone(),
+                available_seats: current.available_seats,
+            }),
+            None => {}
+            Some(prev) => {
+                match (strategy.has_seats(prev), now_open) {
+                    (false, true) => events.push(SeatEvent::Opened {
+                        section_id: section_id.clone(),
+                        available_seats: current.available_seats,
+                    }),
+                    (true, false) => events.push(SeatEvent::Closed {
+                        section_id: section_id.clone(),
+                    }),
+                    _ => {}
+                }
+
+                if prev.waitlist_ct != current.waitlist_ct {
+                    events.push(SeatEvent::WaitlistChanged {
+                        section_id: section_id.clone(),
+                        old: prev.waitlist_ct,
+                        new: current.waitlist_ct,
+                    });
+                }
+            }
+        }
+
+        events
+    }
+}
+
+/// A difference between a section in a saved snapshot and the same section now (see
+/// `util::diff_sections`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all_fields = "camelCase"))]
+pub enum SectionChange {
+    /// The section wasn't in the snapshot.
+    Added(CourseSection),
+    /// The section was in the snapshot, but isn't offered anymore.
+    Removed(CourseSection),
+    /// Some of the section's fields changed.
+    Modified {
+        /// The section ID.
+        section_id: String,
+        /// The fields that changed.
+        fields: Vec<FieldChange>,
+    },
+}
+
+/// A field of a section that changed between a saved snapshot and now.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct FieldChange {
+    /// The name of the field, as it appears when the section is serialized (e.g.,
+    /// `available_seats`).
+    pub field: String,
+    /// The field's value in the snapshot.
+    pub old: serde_json::Value,
+    /// The field's current value.
+    pub new: serde_json::Value,
+}
+
+/// A section family, which consists of the meetings shared by every section in the family
+/// (usually the lecture and the exams) and the sections that you can actually enroll in
+/// (usually the discussions).
+///
+/// This is the same information as `Courses`, except that the shared meetings aren't
+/// repeated across every section.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct SectionFamily {
+    /// The section family (e.g., `A`). For sections that don't belong to a family (e.g.,
+    /// `001`), this is the section code.
+    pub family: String,
+    /// The repeated meetings that are shared by every section in this family (e.g., the
+    /// lecture).
+    pub lecture: Vec<Meeting>,
+    /// The sections in this family that you can enroll in (e.g., `A01`, `A02`). The `meetings`
+    /// of each section only contain the meetings that are specific to that section.
+    ///
+    /// If the family doesn't have any discussions (e.g., a course that is only a lecture and
+    /// final exam), then this contains the lecture section itself, without any meetings.
+    pub discussions: Vec<CourseSection>,
+    /// The one-time meetings that are shared by every section in this family (e.g., the
+    /// midterms and the final exam).
+    pub exams: Vec<Meeting>,
+}
+
+/// The combined seat counts for every section in a section family (e.g., all of the `A0x`
+/// sections under the `A00` lecture).
+///
+/// When one discussion section is full, you may still be able to get into the lecture by
+/// picking another discussion in the same family, so this is usually what you want to look at
+/// when deciding whether a lecture has space.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct FamilyAvailability {
+    /// The section family (e.g., `A`). For sections that don't belong to a family (e.g.,
+    /// `001`), this is the section code.
+    pub family: String,
+    /// The section codes of the sections in this family.
+    pub section_codes: Vec<String>,
+    /// The total number of seats across all sections in this family.
+    pub total_seats: i64,
+    /// The total number of students enrolled across all sections in this family.
+    pub enrolled_ct: i64,
+    /// The total number of students on the waitlist across all sections in this family.
+    pub waitlist_ct: i64,
+    /// The total number of available seats across all sections in this family.
+    pub available_seats: i64,
+    /// The number of sections in this family that have seats (see `CourseSection::has_seats`).
+    pub sections_with_seats: usize,
+}
+
+impl FamilyAvailability {
+    /// Checks if any section in this family has seats left.
+    ///
+    /// # Returns
+    /// `true` if there is at least one section that you can enroll in and `false` otherwise.
+    pub fn has_seats(&self) -> bool {
+        self.sections_with_seats > 0
+    }
+}
+
+impl Display for FamilyAvailability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}] {}/{} enrolled, {} available, {} waitlisted ({} of {} sections open)",
+            self.family,
+            self.enrolled_ct,
+            self.total_seats,
+            self.available_seats,
+            self.waitlist_ct,
+            self.sections_with_seats,
+            self.section_codes.len()
+        )
+    }
+}
+
+/// A meeting. Usually represents a lecture, final exam, discussion, and more. All strings
+/// (e.g., the building and room) are trimmed.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
 pub struct Meeting {
-    /// The meeting type. For example, this can be `LE`, `FI`, `DI`, etc.
+    /// The meeting type. For example, this can be `LE`, `FI`, `DI`, etc. For one-time
+    /// meetings, this is the special meeting code (see `Meeting::special_meeting`).
     pub meeting_type: String,
     /// The meeting day(s). This is an enum that represents either a reoccurring meeting
     /// or one-time meeting.
-    #[serde(rename = "meeting_days")]
     pub meeting_days: MeetingDay,
     /// The start hour. For example, if the meeting starts at 14:15, this would be `14`.
     pub start_hr: TimeType,
@@ -130,35 +982,265 @@ pub struct Meeting {
     /// The room number where this meeting will occur. For example, if the meeting is held in
     /// `CENTR 115`, then this would be `115`.
     pub room: String,
-    /// The instructors assigned to this meeting.
+    /// The instructors that WebReg lists for this meeting. This may be empty (e.g., if the
+    /// instructor hasn't been decided yet). The section-level `all_instructors` is the union
+    /// of this across all of the section's meetings.
     pub instructors: Vec<String>,
 }
 
-/// An enum that represents the meeting days for a section meeting.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
-#[serde(untagged)]
-pub enum MeetingDay {
-    /// The meeting is repeated. In this case, each element in the vector will be one of the
-    /// following: `M`, `Tu`, `W`, `Th`, `F`, `Sa`, or `Su`.
-    Repeated(Vec<String>),
-    /// The meeting occurs once. In this case, the string will just be the date representation
-    /// in the form `YYYY-MM-DD`.
-    OneTime(String),
-    /// There is no meeting.
-    None,
+/// The kind of a special (one-time) meeting. WebReg gives this as a two-letter code, which is
+/// used as the `meeting_type` of the meeting.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum SpecialMeetingCode {
+    /// A final exam (`FI`).
+    FinalExam,
+    /// A midterm (`MI`).
+    Midterm,
+    /// A review session (`RE`).
+    ReviewSession,
+    /// A problem session (`PB`).
+    ProblemSession,
+    /// A make-up session (`MU`).
+    MakeUpSession,
+    /// Some other meeting (`OT`).
+    Other,
+    /// A code that isn't known.
+    Unknown(String),
 }
 
-impl Display for Meeting {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[{}] ", self.meeting_type)?;
-        match &self.meeting_days {
-            MeetingDay::Repeated(r) => write!(f, "{} ", r.join("")),
-            MeetingDay::OneTime(r) => write!(f, "{} ", r),
-            MeetingDay::None => write!(f, "N/A "),
-        }?;
-
-        write!(
-            f,
+impl SpecialMeetingCode {
+    /// Gets the special meeting corresponding to the code given by WebReg.
+    ///
+    /// # Parameters
+    /// - `code`: The code (e.g., `FI`).
+    ///
+    /// # Returns
+    /// The special meeting (e.g., `FinalExam`).
+    pub fn from_code(code: &str) -> Self {
+        match code.trim() {
+            "FI" => SpecialMeetingCode::FinalExam,
+            "MI" => SpecialMeetingCode::Midterm,
+            "RE" => SpecialMeetingCode::ReviewSession,
+            "PB" => SpecialMeetingCode::ProblemSession,
+            "MU" => SpecialMeetingCode::MakeUpSession,
+            "OT" => SpecialMeetingCode::Other,
+            other => SpecialMeetingCode::Unknown(other.to_string()),
+        }
+    }
+
+    /// Gets the code that WebReg uses for this special meeting.
+    ///
+    /// # Returns
+    /// The code (e.g., `FI` for `FinalExam`).
+    pub fn code(&self) -> &str {
+        match self {
+            SpecialMeetingCode::FinalExam => "FI",
+            SpecialMeetingCode::Midterm => "MI",
+            SpecialMeetingCode::ReviewSession => "RE",
+            SpecialMeetingCode::ProblemSession => "PB",
+            SpecialMeetingCode::MakeUpSession => "MU",
+            SpecialMeetingCode::Other => "OT",
+            SpecialMeetingCode::Unknown(code) => code,
+        }
+    }
+
+    /// Gets a human-readable description of this special meeting.
+    ///
+    /// # Returns
+    /// The description (e.g., `Final Exam` for `FinalExam`).
+    pub fn description(&self) -> &str {
+        match self {
+            SpecialMeetingCode::FinalExam => "Final Exam",
+            SpecialMeetingCode::Midterm => "Midterm",
+            SpecialMeetingCode::ReviewSession => "Review Session",
+            SpecialMeetingCode::ProblemSession => "Problem Session",
+            SpecialMeetingCode::MakeUpSession => "Make-up Session",
+            SpecialMeetingCode::Other => "Other",
+            SpecialMeetingCode::Unknown(code) => code,
+        }
+    }
+}
+
+impl Display for SpecialMeetingCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+/// An enum that represents the meeting days for a section meeting.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MeetingDay {
+    /// The meeting is repeated. In this case, each element in the vector will be one of the
+    /// following: `M`, `Tu`, `W`, `Th`, `F`, `Sa`, or `Su`.
+    Repeated(Vec<String>),
+    /// The meeting occurs once. In this case, the string will just be the date representation
+    /// in the form `YYYY-MM-DD`.
+    OneTime(String),
+    /// There is no meeting.
+    None,
+}
+
+impl Meeting {
+    /// Gets the instructors of this meeting combined with the section-level instructors.
+    ///
+    /// # Parameters
+    /// - `section_instructors`: The section-level instructors (see
+    ///   `CourseSection::section_instructors`).
+    ///
+    /// # Returns
+    /// All instructors for this meeting, sorted and without duplicates.
+    pub fn instructors_with(&self, section_instructors: &[String]) -> Vec<String> {
+        util::get_all_instructors(
+            section_instructors
+                .iter()
+                .chain(self.instructors.iter())
+                .cloned(),
+        )
+    }
+
+    /// Gets the instructors that are assigned to this meeting, but aren't section-level
+    /// instructors. For example, for a discussion led by a TA, this would be the TA.
+    ///
+    /// # Parameters
+    /// - `section_instructors`: The section-level instructors (see
+    ///   `CourseSection::section_instructors`).
+    ///
+    /// # Returns
+    /// The additional instructors, sorted and without duplicates.
+    pub fn additional_instructors(&self, section_instructors: &[String]) -> Vec<String> {
+        util::get_all_instructors(
+            self.instructors
+                .iter()
+                .filter(|i| !section_instructors.contains(i))
+                .cloned(),
+        )
+    }
+
+    /// Gets the kind of special meeting (e.g., a final exam or review session) that this is.
+    ///
+    /// # Returns
+    /// The special meeting code, or `None` if this isn't a one-time meeting.
+    pub fn special_meeting(&self) -> Option<SpecialMeetingCode> {
+        match self.meeting_days {
+            MeetingDay::OneTime(_) => Some(SpecialMeetingCode::from_code(&self.meeting_type)),
+            _ => None,
+        }
+    }
+
+    /// Checks whether this meeting overlaps with another meeting. Two meetings overlap if they
+    /// share at least one day (or are on the same date, for one-time meetings) and their times
+    /// overlap.
+    ///
+    /// Note that a repeated meeting is never considered to overlap with a one-time meeting, and
+    /// meetings without any days never overlap with anything.
+    ///
+    /// # Parameters
+    /// - `other`: The other meeting.
+    ///
+    /// # Returns
+    /// `true` if the two meetings overlap and `false` otherwise.
+    pub fn conflicts_with(&self, other: &Meeting) -> bool {
+        let same_day = match (&self.meeting_days, &other.meeting_days) {
+            (MeetingDay::Repeated(a), MeetingDay::Repeated(b)) => a.iter().any(|d| b.contains(d)),
+            (MeetingDay::OneTime(a), MeetingDay::OneTime(b)) => a == b,
+            _ => false,
+        };
+
+        if !same_day {
+            return false;
+        }
+
+        let self_start = self.start_hr * 60 + self.start_min;
+        let self_end = self.end_hr * 60 + self.end_min;
+        let other_start = other.start_hr * 60 + other.start_min;
+        let other_end = other.end_hr * 60 + other.end_min;
+        self_start < other_end && other_start < self_end
+    }
+
+    /// Gets the time that this meeting starts on the given date, in WebReg's time zone
+    /// (Pacific time). Note that this doesn't check whether the meeting actually occurs on
+    /// that date.
+    ///
+    /// # Parameters
+    /// - `date`: The date.
+    ///
+    /// # Returns
+    /// The start time, or `None` if the meeting's time is invalid.
+    #[cfg(feature = "chrono")]
+    pub fn start_on(&self, date: chrono::NaiveDate) -> Option<chrono::DateTime<chrono_tz::Tz>> {
+        crate::util::to_webreg_datetime(date, self.start_hr, self.start_min)
+    }
+
+    /// Gets the time that this meeting ends on the given date, in WebReg's time zone
+    /// (Pacific time). Note that this doesn't check whether the meeting actually occurs on
+    /// that date.
+    ///
+    /// # Parameters
+    /// - `date`: The date.
+    ///
+    /// # Returns
+    /// The end time, or `None` if the meeting's time is invalid.
+    #[cfg(feature = "chrono")]
+    pub fn end_on(&self, date: chrono::NaiveDate) -> Option<chrono::DateTime<chrono_tz::Tz>> {
+        crate::util::to_webreg_datetime(date, self.end_hr, self.end_min)
+    }
+
+    /// Gets the next time that this meeting starts after the given time. The result is in
+    /// WebReg's time zone (Pacific time); use `with_timezone` to convert it to your own.
+    ///
+    /// For repeated meetings, this doesn't know when the term starts or ends, so it will
+    /// simply return the next matching day of the week.
+    ///
+    /// # Parameters
+    /// - `after`: The time to search after, in any time zone.
+    ///
+    /// # Returns
+    /// The next start time, or `None` if the meeting has no days, has already happened (for
+    /// one-time meetings), or its date or time is invalid.
+    #[cfg(feature = "chrono")]
+    pub fn next_start_after<T: chrono::TimeZone>(
+        &self,
+        after: &chrono::DateTime<T>,
+    ) -> Option<chrono::DateTime<chrono_tz::Tz>> {
+        use chrono::Datelike;
+
+        match &self.meeting_days {
+            MeetingDay::OneTime(date) => {
+                let date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+                self.start_on(date).filter(|start| *start > *after)
+            }
+            MeetingDay::Repeated(days) => {
+                let today = after
+                    .with_timezone(&crate::util::WEBREG_TIME_ZONE)
+                    .date_naive();
+                // Checking a week and a day covers the case where the meeting is later
+                // today, or today but already started.
+                (0..=7)
+                    .filter_map(|offset| today.checked_add_days(chrono::Days::new(offset)))
+                    .filter(|date| {
+                        let abbr = crate::util::weekday_abbreviation(date.weekday());
+                        days.iter().any(|d| d == abbr)
+                    })
+                    .filter_map(|date| self.start_on(date))
+                    .find(|start| *start > *after)
+            }
+            MeetingDay::None => None,
+        }
+    }
+}
+
+impl Display for Meeting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] ", self.meeting_type)?;
+        match &self.meeting_days {
+            MeetingDay::Repeated(r) => write!(f, "{} ", r.join("")),
+            MeetingDay::OneTime(r) => write!(f, "{} ", r),
+            MeetingDay::None => write!(f, "N/A "),
+        }?;
+
+        write!(
+            f,
             "at {}:{:02} - {}:{:02} ",
             self.start_hr, self.start_min, self.end_hr, self.end_min
         )?;
@@ -170,7 +1252,10 @@ impl Display for Meeting {
 
 /// A section that is currently in your schedule. Note that this can either be a course that you
 /// are enrolled in, waitlisted for, or planned.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+///
+/// All strings that come from WebReg (e.g., the subject code and course title) are trimmed.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
 pub struct ScheduledSection {
     /// The section ID, for example `79903`.
     pub section_id: String,
@@ -188,14 +1273,20 @@ pub struct ScheduledSection {
     pub enrolled_count: i64,
     /// The number of available seats left.
     pub available_seats: i64,
-    /// The grading option. This can be one of `L`, `P`, or `S`.
-    pub grade_option: String,
-    /// All instructors that appear in all of the meetings.
+    /// The grading option. This will be `None` if WebReg didn't give a grading option, or if
+    /// it gave one that isn't known.
+    pub grade_option: Option<GradeOption>,
+    /// Whether WebReg doesn't allow the grading option to be changed (e.g., a seminar that is
+    /// only offered P/NP). This is inferred from WebReg's `GRADE_OPTN_CD_PLUS` marker being
+    /// blank. See `WrapperTermRequest::validate_grading_option` for its limitations.
+    #[serde(default)]
+    pub grade_option_locked: bool,
+    /// All instructors that appear in all of the meetings. This is sorted and has no
+    /// duplicates, the same as `CourseSection::all_instructors`.
     pub all_instructors: Vec<String>,
     /// The number of units that you are taking this course for.
     pub units: i64,
     /// Your enrollment status.
-    #[serde(rename = "enrolled_status")]
     pub enrolled_status: EnrollmentStatus,
     /// The number of people on the waitlist.
     pub waitlist_ct: i64,
@@ -203,6 +1294,25 @@ pub struct ScheduledSection {
     pub meetings: Vec<Meeting>,
 }
 
+impl ScheduledSection {
+    /// Gets the course that this section belongs to.
+    ///
+    /// # Returns
+    /// The course.
+    pub fn course(&self) -> CourseCode {
+        CourseCode::new(&self.subject_code, &self.course_code)
+    }
+
+    /// Gets the section-level instructors, i.e., the instructors that are assigned to every
+    /// meeting that has an instructor. Usually, these are the lecture's instructors.
+    ///
+    /// # Returns
+    /// The section-level instructors, sorted.
+    pub fn section_instructors(&self) -> Vec<String> {
+        util::get_section_instructors(&self.meetings)
+    }
+}
+
 impl Display for ScheduledSection {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(
@@ -232,7 +1342,10 @@ impl Display for ScheduledSection {
         }?;
 
         writeln!(f, "\t\tUnits: {}", self.units)?;
-        writeln!(f, "\t\tGrade Option: {}", self.grade_option)?;
+        match self.grade_option {
+            Some(grade_option) => writeln!(f, "\t\tGrade Option: {grade_option}"),
+            None => writeln!(f, "\t\tGrade Option: N/A"),
+        }?;
         writeln!(f, "\tMeeting Information:")?;
         for meeting in &self.meetings {
             write!(f, "\t\t{meeting}")?;
@@ -242,18 +1355,74 @@ impl Display for ScheduledSection {
     }
 }
 
-/// An enum that represents your enrollment status.
+/// A final exam for a section in your schedule. See `WrapperTermRequest::get_final_exams`.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
-#[serde(tag = "enroll_status")]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct FinalExam {
+    /// The section ID of the section that the final exam is for, for example `79903`.
+    pub section_id: String,
+    /// The subject code, for example `CSE`.
+    pub subject_code: String,
+    /// The course code, for example `100`.
+    pub course_code: String,
+    /// The course title, for example `Advanced Data Structure`.
+    pub course_title: String,
+    /// The section code of the section that the final exam is for, for example `A01`.
+    pub section_code: String,
+    /// Your enrollment status in the section.
+    pub enrolled_status: EnrollmentStatus,
+    /// The date of the final exam, in the form `YYYY-MM-DD`.
+    pub date: String,
+    /// The start hour. For example, if the final exam starts at 11:30, this would be `11`.
+    pub start_hr: TimeType,
+    /// The start minute. For example, if the final exam starts at 11:30, this would be `30`.
+    pub start_min: TimeType,
+    /// The end hour. For example, if the final exam ends at 14:29, this would be `14`.
+    pub end_hr: TimeType,
+    /// The end minute. For example, if the final exam ends at 14:29, this would be `29`.
+    pub end_min: TimeType,
+    /// The building where the final exam will be held, for example `CENTR`.
+    pub building: String,
+    /// The room where the final exam will be held, for example `115`.
+    pub room: String,
+}
+
+impl Display for FinalExam {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} ({}): {} at {}:{:02} - {}:{:02} in {} {}",
+            self.subject_code,
+            self.course_code,
+            self.section_code,
+            self.date,
+            self.start_hr,
+            self.start_min,
+            self.end_hr,
+            self.end_min,
+            self.building,
+            self.room
+        )
+    }
+}
+
+/// An enum that represents your enrollment status.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "camel_case"), serde(tag = "enroll_status"))]
+#[cfg_attr(feature = "camel_case", serde(tag = "enrollStatus"))]
 pub enum EnrollmentStatus {
     Enrolled,
-    Waitlist { waitlist_pos: i64 },
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    Waitlist {
+        waitlist_pos: i64,
+    },
     Planned,
     Unknown,
 }
 
 /// A prerequisite for a course.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
 pub struct PrerequisiteInfo {
     /// Any course prerequisites. This is a vector of vector of prerequisites,
     /// where each vector contains one or more prerequisites. Any prerequisites
@@ -273,14 +1442,19 @@ pub struct PrerequisiteInfo {
     pub exam_prerequisites: Vec<String>,
 }
 
-/// A course prerequisite.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+/// A course prerequisite. All strings are trimmed.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
 pub struct CoursePrerequisite {
     /// The subject, course ID. For example, `CSE 100`.
     pub subj_course_id: String,
 
     /// The course title, for example `Advanced Data Structure`.
     pub course_title: String,
+
+    /// The minimum grade needed in this course to satisfy the prerequisite. This will be
+    /// `None` if WebReg didn't give a minimum grade.
+    pub min_grade: Option<MinimumGrade>,
 }
 
 impl CoursePrerequisite {
@@ -296,12 +1470,123 @@ impl CoursePrerequisite {
         Self {
             subj_course_id: subj_course_id.into(),
             course_title: course_title.into(),
+            min_grade: None,
+        }
+    }
+
+    /// Sets the minimum grade needed in this course to satisfy the prerequisite.
+    ///
+    /// # Parameters
+    /// - `min_grade`: The minimum grade.
+    ///
+    /// # Returns
+    /// The `CoursePrerequisite` object.
+    pub fn with_min_grade(mut self, min_grade: MinimumGrade) -> Self {
+        self.min_grade = Some(min_grade);
+        self
+    }
+}
+
+/// The minimum grade that is needed in a course to satisfy a prerequisite.
+///
+/// WebReg gives this as a code that is a multiple of 50, where `50` is an A+, `100` is an A,
+/// and so on, down to `600`, which is a D- (i.e., any passing grade).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum MinimumGrade {
+    APlus,
+    A,
+    AMinus,
+    BPlus,
+    B,
+    BMinus,
+    CPlus,
+    C,
+    CMinus,
+    DPlus,
+    D,
+    DMinus,
+    /// A code that doesn't correspond to any known grade.
+    Other(u32),
+}
+
+impl MinimumGrade {
+    const GRADES: [MinimumGrade; 12] = [
+        MinimumGrade::APlus,
+        MinimumGrade::A,
+        MinimumGrade::AMinus,
+        MinimumGrade::BPlus,
+        MinimumGrade::B,
+        MinimumGrade::BMinus,
+        MinimumGrade::CPlus,
+        MinimumGrade::C,
+        MinimumGrade::CMinus,
+        MinimumGrade::DPlus,
+        MinimumGrade::D,
+        MinimumGrade::DMinus,
+    ];
+
+    /// Gets the minimum grade corresponding to the code given by WebReg.
+    ///
+    /// # Parameters
+    /// - `code`: The code (e.g., `450`).
+    ///
+    /// # Returns
+    /// The minimum grade (e.g., `CMinus`).
+    // `is_multiple_of` needs Rust 1.87, which is newer than the crate otherwise requires.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn from_code(code: u32) -> Self {
+        if code == 0 || code % 50 != 0 {
+            return MinimumGrade::Other(code);
+        }
+
+        Self::GRADES
+            .get((code / 50 - 1) as usize)
+            .copied()
+            .unwrap_or(MinimumGrade::Other(code))
+    }
+
+    /// Gets the code that WebReg uses for this grade.
+    ///
+    /// # Returns
+    /// The code (e.g., `450` for `CMinus`).
+    pub fn code(&self) -> u32 {
+        match self {
+            MinimumGrade::Other(code) => *code,
+            grade => {
+                (Self::GRADES
+                    .iter()
+                    .position(|g| g == grade)
+                    .unwrap_or_default() as u32
+                    + 1)
+                    * 50
+            }
+        }
+    }
+}
+
+impl Display for MinimumGrade {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MinimumGrade::APlus => write!(f, "A+"),
+            MinimumGrade::A => write!(f, "A"),
+            MinimumGrade::AMinus => write!(f, "A-"),
+            MinimumGrade::BPlus => write!(f, "B+"),
+            MinimumGrade::B => write!(f, "B"),
+            MinimumGrade::BMinus => write!(f, "B-"),
+            MinimumGrade::CPlus => write!(f, "C+"),
+            MinimumGrade::C => write!(f, "C"),
+            MinimumGrade::CMinus => write!(f, "C-"),
+            MinimumGrade::DPlus => write!(f, "D+"),
+            MinimumGrade::D => write!(f, "D"),
+            MinimumGrade::DMinus => write!(f, "D-"),
+            MinimumGrade::Other(code) => write!(f, "Unknown ({code})"),
         }
     }
 }
 
 /// An event on WebReg.
-#[derive(Debug, Clone, Serialize, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
 pub struct Event {
     /// The location of the event.
     pub location: String,
@@ -338,10 +1623,16 @@ impl Display for Event {
 
 #[derive(Error, Debug)]
 pub enum WrapperError {
-    /// Occurs if there was an error encountered by the reqwest library.
+    /// Occurs if there was an error encountered by the reqwest library while sending a
+    /// request with the wrapper's client.
+    #[cfg(feature = "reqwest")]
     #[error("Request error occurred: {0}")]
     RequestError(#[from] reqwest::Error),
 
+    /// Occurs if there was an error encountered by the wrapper's `HttpBackend`.
+    #[error("Backend error occurred: {0}")]
+    BackendError(#[from] HttpBackendError),
+
     /// Occurs when there was an error parsing the URL.
     #[error("Malformed url: {0}")]
     UrlParseError(#[from] url::ParseError),
@@ -367,16 +1658,28 @@ pub enum WrapperError {
     BadTimeError,
 
     // =============== //
-    /// Occurs when an error from WebReg was returned. These are usually errors relating
-    /// to you not being able to perform some operation (e.g, attempting to enroll in a
-    /// class that you aren't able to enroll in).
+    /// Occurs when an error from WebReg was returned outside of a `POST` operation (e.g.,
+    /// a verification error when the term isn't associated with your cookies). Rejected
+    /// operations, like attempting to enroll in a class that you aren't able to enroll in,
+    /// are reported as `WebRegRejected` instead.
     #[error("Error from WebReg: {0}")]
     WebRegError(String),
 
+    /// Occurs when WebReg rejected a `POST` request (e.g., enrolling in a section), including
+    /// when the wrapper's own validation of the request failed. This includes the full
+    /// response from WebReg.
+    #[error("Error from WebReg: {}", .0.reason_text())]
+    WebRegRejected(Box<WebRegOpsResponse>),
+
     /// Occurs if a section that you're trying to look for isn't available.
     #[error("Section ID not found: {0} (context: {1}")]
     SectionIdNotFound(String, SectionIdNotFoundContext),
 
+    /// Occurs when trying to change the grading option of a section that doesn't allow it
+    /// (see `WrapperTermRequest::validate_grading_option`). The string is the section ID.
+    #[error("The grading option {0} isn't allowed for section {1}.")]
+    GradeOptionNotAllowed(GradeOption, String),
+
     /// Occurs if there's an error with the parsing logic.
     #[error("An error occurred when parsing the response from WebReg: {0}")]
     WrapperParsingError(String),
@@ -384,6 +1687,242 @@ pub enum WrapperError {
     /// Occurs when your cookies may have expired.
     #[error("The current session is not valid. Are your cookies valid?")]
     SessionNotValid,
+
+    /// Occurs when WebReg responds with its login page instead of the requested data, which
+    /// usually means that your cookies have expired.
+    #[error("WebReg responded with its login page. Your session has likely expired.")]
+    SessionExpired,
+
+    /// Occurs when trying to plan a section that is already planned. The string is the
+    /// error message that WebReg gave.
+    #[error("The section is already planned: {0}")]
+    AlreadyPlanned(String),
+
+    /// Occurs when a request wasn't made because it would have gone over one of the quotas
+    /// set with `WebRegWrapperBuilder::with_quota`. The number is the quota's limit.
+    #[error("The {0} quota ({1}) has been used up.")]
+    QuotaExceeded(QuotaKind, u32),
+
+    /// Occurs when a response from WebReg was larger than the limit set with
+    /// `WebRegWrapperBuilder::with_max_response_size`. The number is the limit, in bytes.
+    #[error("The response was larger than the limit of {0} bytes.")]
+    ResponseTooLarge(u64),
+
+    /// Occurs when a request failed and couldn't be retried because the retry budget for the
+    /// operation was used up. The inner error is the error from the last attempt.
+    #[error("The retry budget was used up. Last error: {0}")]
+    RetryBudgetExhausted(Box<WrapperError>),
+
+    /// Occurs when strict parsing is enabled (see
+    /// `WebRegWrapperBuilder::should_use_strict_parsing`) and WebReg gave a value that isn't
+    /// known (e.g., a new display type).
+    #[error("WebReg gave an unknown value: {0}")]
+    UnknownValue(ParseWarning),
+
+    /// Occurs when trying to override the cookies for a request, but the wrapper reuses
+    /// connections (see `WebRegWrapperBuilder::should_close_after_request`).
+    #[error(
+        "The cookies can only be overridden if the wrapper closes connections after each request."
+    )]
+    CookieOverrideNotAllowed,
+
+    /// Occurs when a term was associated, but WebReg still wouldn't give any data for it
+    /// (see `WebRegWrapper::associate_term_verified`). This usually means that the term
+    /// doesn't exist, or isn't open yet. The second string is why the check failed.
+    #[error("The term {0} can't be used: {1}")]
+    TermNotUsable(String, String),
+
+    /// Occurs when reading from or writing to a file failed.
+    #[error("An I/O error occurred: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+impl WrapperError {
+    /// Gets the reason that WebReg gave for this error, if this error came from WebReg.
+    ///
+    /// # Returns
+    /// The reason, with any HTML tags removed, or `None` if this error didn't come from WebReg.
+    pub fn webreg_reason(&self) -> Option<String> {
+        match self {
+            WrapperError::WebRegError(reason) => Some(reason.clone()),
+            WrapperError::WebRegRejected(res) => Some(res.reason_text()),
+            _ => None,
+        }
+    }
+
+    /// Classifies the reason that WebReg gave for this error (see `webreg_reason`), so that
+    /// you can handle kinds of errors (e.g., time conflicts) without matching on WebReg's
+    /// messages yourself.
+    ///
+    /// # Returns
+    /// The classified reason, or `None` if this error didn't come from WebReg.
+    ///
+    /// # Example
+    /// ```rust
+    /// use webweg::types::{EnrollmentFailure, WrapperError};
+    ///
+    /// let err = WrapperError::WebRegError("This section conflicts with CSE 100.".into());
+    /// assert!(matches!(err.rejection(), Some(EnrollmentFailure::TimeConflict(_))));
+    /// assert!(WrapperError::SessionExpired.rejection().is_none());
+    /// ```
+    pub fn rejection(&self) -> Option<EnrollmentFailure> {
+        match self {
+            WrapperError::AlreadyPlanned(reason) => {
+                Some(EnrollmentFailure::AlreadyAdded(reason.clone()))
+            }
+            _ => self.webreg_reason().map(EnrollmentFailure::from_reason),
+        }
+    }
+
+    /// Checks whether this error is likely to go away if the request is made again (e.g., a
+    /// timeout, or WebReg responding with a `5xx` or `429` status code). WebReg rejecting a
+    /// request is never transient.
+    ///
+    /// # Returns
+    /// Whether the error is transient.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            #[cfg(feature = "reqwest")]
+            WrapperError::RequestError(e) => e.is_timeout() || e.is_connect(),
+            WrapperError::BackendError(e) => !matches!(e, HttpBackendError::Other(_)),
+            WrapperError::BadStatusCode(code, _) => *code >= 500 || *code == 429,
+            WrapperError::RetryBudgetExhausted(e) => e.is_transient(),
+            _ => false,
+        }
+    }
+
+    /// Checks whether this error means that WebReg doesn't consider the term to be associated
+    /// with your session (see `WebRegWrapper::associate_term`).
+    ///
+    /// # Returns
+    /// Whether the error is a verification error.
+    pub fn is_verification_error(&self) -> bool {
+        matches!(self, WrapperError::WebRegError(reason) if reason == VERIFY_FAIL_MSG)
+    }
+
+    /// Checks whether this error means that your session can't be used to make requests
+    /// (e.g., your cookies have expired, WebReg responded with a `401` or `403` status code, or
+    /// the term isn't associated with your session). Retrying the request, or making a similar
+    /// one, won't help until the session is fixed.
+    ///
+    /// # Returns
+    /// Whether the error is a session error.
+    pub fn is_session_error(&self) -> bool {
+        matches!(
+            self,
+            WrapperError::SessionNotValid
+                | WrapperError::SessionExpired
+                | WrapperError::BadStatusCode(401 | 403, _)
+        ) || self.is_verification_error()
+    }
+}
+
+/// The response that WebReg gives when it is pinged (see `WebRegWrapper::ping_details`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PingResponse {
+    /// Whether the session is still valid.
+    #[serde(rename = "SESSION_OK", default)]
+    pub session_ok: bool,
+    /// Any other fields that WebReg included in the response (e.g., timing information).
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PingResponse {
+    /// Gets one of the other fields as a number, if it is one. WebReg sometimes gives numbers
+    /// as strings, so those are parsed as well.
+    ///
+    /// # Parameters
+    /// - `key`: The name of the field, for example `TIMEOUT`.
+    ///
+    /// # Returns
+    /// The number, or `None` if the field doesn't exist or isn't a number.
+    pub fn get_number(&self, key: &str) -> Option<f64> {
+        match self.extra.get(key)? {
+            serde_json::Value::Number(n) => n.as_f64(),
+            serde_json::Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// The response that WebReg gives to a `POST` request (e.g., enrolling in a section or adding
+/// an event).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebRegOpsResponse {
+    /// The result of the operation. This is `SUCCESS` if the operation went through, and
+    /// usually `FAIL` otherwise.
+    #[serde(rename = "OPS", default)]
+    pub ops: String,
+    /// The reason that WebReg gave, if any. This is usually only given when the operation
+    /// failed, and may contain HTML.
+    #[serde(rename = "REASON", default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Any other fields that WebReg included in the response.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl WebRegOpsResponse {
+    /// Whether the operation went through.
+    ///
+    /// # Returns
+    /// `true` if WebReg reported success, and `false` otherwise.
+    pub fn is_success(&self) -> bool {
+        self.ops == "SUCCESS"
+    }
+
+    /// Gets the reason that WebReg gave, with any HTML tags removed.
+    ///
+    /// # Returns
+    /// The reason, or an empty string if WebReg didn't give one.
+    pub fn reason_text(&self) -> String {
+        let mut parsed_str = String::new();
+        let mut is_in_brace = false;
+        for c in self.reason.as_deref().unwrap_or("").trim().chars() {
+            match c {
+                '<' => is_in_brace = true,
+                '>' => is_in_brace = false,
+                _ if is_in_brace => {}
+                _ => parsed_str.push(c),
+            }
+        }
+
+        parsed_str
+    }
+}
+
+/// Self-imposed limits on how many requests a wrapper can make. Once a limit is reached, any
+/// further requests fail with a `QuotaExceeded` error (without being sent) until enough time
+/// has passed. Retries count as separate requests.
+///
+/// By default, there are no limits.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct UsageQuota {
+    /// The maximum number of requests (of any kind) that can be made in any one-hour window.
+    pub max_requests_per_hour: Option<u32>,
+    /// The maximum number of mutations (requests that change something, like enrolling in a
+    /// section or adding an event) that can be made in any 24-hour window.
+    pub max_mutations_per_day: Option<u32>,
+}
+
+/// The kind of quota that was used up.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum QuotaKind {
+    /// The maximum number of requests per hour.
+    RequestsPerHour,
+    /// The maximum number of mutations per day.
+    MutationsPerDay,
+}
+
+impl Display for QuotaKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuotaKind::RequestsPerHour => write!(f, "requests per hour"),
+            QuotaKind::MutationsPerDay => write!(f, "mutations per day"),
+        }
+    }
 }
 
 /// An enum to be used for giving more context into where the section ID wasn't found.
@@ -406,11 +1945,2278 @@ impl Display for SectionIdNotFoundContext {
     }
 }
 
+/// The status of a WebReg session.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct SessionStatus {
+    /// Whether the session is valid (i.e., whether you are logged in).
+    pub valid: bool,
+    /// The name of the person that is logged in. This will be `None` if the session isn't valid.
+    pub name: Option<String>,
+    /// When the session was checked.
+    pub checked_at: SystemTime,
+}
+
 /// A term that is available on WebReg.
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
 pub struct Term {
     /// The term ID.
     pub seq_id: i64,
     /// The term code (e.g., `SP23`).
     pub term_code: String,
 }
+
+/// A term that is available on WebReg, with all of the information that WebReg gives.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct TermInfo {
+    /// The term ID.
+    pub seq_id: i64,
+    /// The term code (e.g., `SP23`).
+    pub term_code: String,
+    /// The term description (e.g., `Spring 2023`).
+    pub term_desc: String,
+    /// Any other fields that WebReg gave for this term.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    /// The term's enrollment periods. This is only filled in by
+    /// `WebRegWrapper::get_all_term_details`, and is `None` otherwise.
+    pub enrollment: Option<EnrollmentPhases>,
+}
+
+/// The enrollment periods of a term (e.g., when first pass starts), as given by WebReg's term
+/// status and eligibility endpoints. See `WebRegWrapper::get_all_term_details`.
+///
+/// These endpoints aren't documented, and which fields they give depends on the term and on
+/// you (e.g., your enrollment appointments), so every date is optional and is kept exactly as
+/// WebReg gave it. The dates are only recognized on a best-effort basis (see
+/// `ww_parser::parse_enrollment_phases`), so everything that WebReg gave is also kept in
+/// `extra`, in case the field you need isn't recognized.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct EnrollmentPhases {
+    /// When first pass starts.
+    pub first_pass: Option<String>,
+    /// When second pass starts.
+    pub second_pass: Option<String>,
+    /// The last day to add a class.
+    pub add_deadline: Option<String>,
+    /// The last day to drop a class.
+    pub drop_deadline: Option<String>,
+    /// Every field that WebReg gave.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Usage statistics collected by a wrapper over every request it has made.
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct WrapperStats {
+    /// The total number of requests that were sent.
+    pub total_requests: u64,
+    /// The number of requests sent to each endpoint. The key is the last part of the
+    /// endpoint's path (e.g., `get-class` or `search-load-group-data`).
+    pub requests_by_endpoint: HashMap<String, u64>,
+    /// The number of failed requests, grouped by the kind of failure.
+    pub errors_by_class: HashMap<RequestErrorClass, u64>,
+    /// The number of bytes of response bodies received, counted after any decompression.
+    pub bytes_received: u64,
+    /// The total time spent waiting for WebReg to respond (including reading each response),
+    /// across all requests.
+    pub total_latency: Duration,
+}
+
+impl WrapperStats {
+    /// Computes the average amount of time it took WebReg to respond to a request.
+    ///
+    /// # Returns
+    /// The average latency, or a zero duration if no requests have been made.
+    pub fn average_latency(&self) -> Duration {
+        if self.total_requests == 0 {
+            return Duration::ZERO;
+        }
+
+        Duration::from_nanos((self.total_latency.as_nanos() / self.total_requests as u128) as u64)
+    }
+}
+
+/// A named set of pacing settings. If you aren't sure how fast you can safely make requests,
+/// pick one of these.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize)]
+pub enum PacingPreset {
+    /// Makes requests quickly, with little delay and few retries. Only use this if you need
+    /// data quickly for a short amount of time.
+    Aggressive,
+    /// A reasonable balance between speed and being gentle on WebReg.
+    Normal,
+    /// Makes requests slowly. This is recommended for long-running programs (e.g., trackers
+    /// that poll WebReg all day).
+    Polite,
+}
+
+/// Settings that control how quickly a wrapper makes requests to WebReg.
+///
+/// By default, no pacing is done; that is, there is no limit on the number of concurrent
+/// requests, no delay between requests, and no retries.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Default)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct PacingProfile {
+    /// The maximum number of requests that can be in progress at once. If this is `0`, there
+    /// is no limit.
+    pub max_concurrent_requests: usize,
+    /// The minimum amount of time between two requests to the same endpoint.
+    pub min_request_interval: Duration,
+    /// The maximum number of times a request will be retried if it fails because of a
+    /// connection problem, a timeout, or WebReg being overloaded. Requests that change
+    /// something (e.g., enrolling or planning) are never retried, since WebReg may have
+    /// applied the request even though it failed.
+    pub max_retries: u32,
+    /// How long to wait before the first retry. This doubles after each retry.
+    pub retry_backoff: Duration,
+}
+
+impl PacingProfile {
+    /// Gets the pacing settings for the given preset.
+    ///
+    /// # Parameters
+    /// - `preset`: The preset.
+    ///
+    /// # Returns
+    /// The pacing settings.
+    pub fn from_preset(preset: PacingPreset) -> Self {
+        match preset {
+            PacingPreset::Aggressive => Self {
+                max_concurrent_requests: 16,
+                min_request_interval: Duration::ZERO,
+                max_retries: 1,
+                retry_backoff: Duration::from_millis(250),
+            },
+            PacingPreset::Normal => Self {
+                max_concurrent_requests: 4,
+                min_request_interval: Duration::from_millis(250),
+                max_retries: 2,
+                retry_backoff: Duration::from_secs(1),
+            },
+            PacingPreset::Polite => Self {
+                max_concurrent_requests: 1,
+                min_request_interval: Duration::from_secs(1),
+                max_retries: 3,
+                retry_backoff: Duration::from_secs(3),
+            },
+        }
+    }
+}
+
+impl From<PacingPreset> for PacingProfile {
+    fn from(preset: PacingPreset) -> Self {
+        Self::from_preset(preset)
+    }
+}
+
+/// Which requests share a rate limit.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub enum RateLimitScope {
+    /// Every request made through the wrapper shares one limit.
+    #[default]
+    Global,
+    /// Each term has its own limit. Requests that don't belong to a term (e.g., pinging the
+    /// server) share one limit.
+    PerTerm,
+}
+
+/// A token-bucket rate limit on the requests made through a wrapper. Unlike a `UsageQuota`,
+/// requests that would go over the limit aren't rejected; they wait until they're allowed to
+/// be sent. Retries count as separate requests.
+///
+/// By default, there is no rate limit.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct RateLimit {
+    /// How many requests can be made per second, on average.
+    pub requests_per_second: u32,
+    /// How many requests can be made at once after a period of no requests. This is always
+    /// at least `1`.
+    pub burst: u32,
+    /// Which requests share the limit.
+    pub scope: RateLimitScope,
+}
+
+impl RateLimit {
+    /// Creates a global rate limit with no bursts.
+    ///
+    /// # Parameters
+    /// - `requests_per_second`: How many requests can be made per second.
+    ///
+    /// # Returns
+    /// The rate limit.
+    pub fn new(requests_per_second: u32) -> Self {
+        Self {
+            requests_per_second,
+            burst: 1,
+            scope: RateLimitScope::Global,
+        }
+    }
+
+    /// Sets how many requests can be made at once after a period of no requests.
+    ///
+    /// # Parameters
+    /// - `burst`: The burst size.
+    ///
+    /// # Returns
+    /// The rate limit.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst;
+        self
+    }
+
+    /// Sets which requests share the limit.
+    ///
+    /// # Parameters
+    /// - `scope`: The scope.
+    ///
+    /// # Returns
+    /// The rate limit.
+    pub fn with_scope(mut self, scope: RateLimitScope) -> Self {
+        self.scope = scope;
+        self
+    }
+}
+
+/// A limit on the number of retries that can be made across several requests. This is meant
+/// to be shared by all of the requests that make up one larger operation (e.g., searching and
+/// then getting information for every result), so that one flaky endpoint can't cause hundreds
+/// of retries.
+///
+/// Retries are only made if the pacing settings allow for them (see
+/// `PacingProfile::max_retries`); the budget only limits the total. Cloning a budget gives you
+/// a handle to the same budget.
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    remaining: Arc<AtomicUsize>,
+    exhausted: Arc<AtomicBool>,
+}
+
+impl RetryBudget {
+    /// Creates a new retry budget.
+    ///
+    /// # Parameters
+    /// - `max_retries`: The total number of retries that can be made.
+    ///
+    /// # Returns
+    /// The retry budget.
+    pub fn new(max_retries: usize) -> Self {
+        Self {
+            remaining: Arc::new(AtomicUsize::new(max_retries)),
+            exhausted: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Gets the number of retries left.
+    ///
+    /// # Returns
+    /// The number of retries left.
+    pub fn remaining(&self) -> usize {
+        self.remaining.load(Ordering::SeqCst)
+    }
+
+    /// Checks whether a request wanted to retry, but couldn't because the budget was used up.
+    /// If this is `true`, then some results of the operation may be missing.
+    ///
+    /// # Returns
+    /// `true` if the budget ran out and `false` otherwise.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted.load(Ordering::SeqCst)
+    }
+
+    /// Takes one retry from the budget, if there are any left.
+    ///
+    /// # Returns
+    /// `true` if a retry can be made and `false` otherwise.
+    pub(crate) fn try_spend(&self) -> bool {
+        let spent = self
+            .remaining
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok();
+        if !spent {
+            self.exhausted.store(true, Ordering::SeqCst);
+        }
+
+        spent
+    }
+}
+
+/// The kind of failure that a request ran into.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize)]
+pub enum RequestErrorClass {
+    /// The request timed out.
+    Timeout,
+    /// The connection to WebReg could not be established.
+    Connect,
+    /// WebReg responded with a non-successful status code.
+    BadStatusCode,
+    /// Any other error encountered by the reqwest library.
+    Other,
+}
+
+/// The result of trying to plan a section only if it doesn't conflict with the schedule.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub enum PlanOutcome {
+    /// The section was planned.
+    Planned,
+    /// The section wasn't planned because it conflicts with this section in the schedule.
+    Conflict(Box<ScheduledSection>),
+}
+
+/// A snapshot of everything in your WebReg account for a term.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct AccountSnapshot {
+    /// The term that this snapshot is for.
+    pub term: String,
+    /// All of your schedules, keyed by the schedule name.
+    pub schedules: HashMap<String, Schedule>,
+    /// All of your events.
+    pub events: Events,
+    /// All sections that you are enrolled in.
+    pub enrolled: Vec<ScheduledSection>,
+    /// All sections that you are waitlisted for.
+    pub waitlisted: Vec<ScheduledSection>,
+    /// When the snapshot was taken.
+    pub taken_at: SystemTime,
+}
+
+/// One point in an `EnrollmentSeries`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct EnrollmentPoint {
+    /// When the counts were taken.
+    pub timestamp: SystemTime,
+    /// The number of students enrolled.
+    pub enrolled_ct: i64,
+    /// The number of available seats.
+    pub available_seats: i64,
+    /// The number of students on the waitlist.
+    pub waitlist_ct: i64,
+}
+
+/// How quickly the counts in an `EnrollmentSeries` are changing, per hour. A positive rate
+/// means that the count is going up.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct EnrollmentRate {
+    /// The change in the number of students enrolled, per hour.
+    pub enrolled_per_hour: f64,
+    /// The change in the number of available seats, per hour.
+    pub available_per_hour: f64,
+    /// The change in the number of students on the waitlist, per hour.
+    pub waitlist_per_hour: f64,
+}
+
+/// The enrollment history of a section, e.g., for graphing how quickly a section fills up.
+///
+/// Only the most recent points are kept; once the series is full, adding a point removes the
+/// oldest point. Deserializing a series with a capacity of `0` fails, and if there are more
+/// points than the capacity, only the most recent ones are kept.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "EnrollmentSeriesData")]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct EnrollmentSeries {
+    capacity: usize,
+    points: VecDeque<EnrollmentPoint>,
+}
+
+/// A deserialized `EnrollmentSeries`, before its capacity has been checked.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+struct EnrollmentSeriesData {
+    capacity: usize,
+    points: VecDeque<EnrollmentPoint>,
+}
+
+impl TryFrom<EnrollmentSeriesData> for EnrollmentSeries {
+    type Error = String;
+
+    fn try_from(data: EnrollmentSeriesData) -> Result<Self, Self::Error> {
+        if data.capacity == 0 {
+            return Err("the capacity of an enrollment series must be at least 1".into());
+        }
+
+        let mut series = Self::new(data.capacity);
+        for point in data.points {
+            series.push(point);
+        }
+
+        Ok(series)
+    }
+}
+
+impl EnrollmentSeries {
+    /// Creates a new, empty series.
+    ///
+    /// # Parameters
+    /// - `capacity`: The maximum number of points to keep. This must be at least `1`.
+    ///
+    /// # Returns
+    /// The series.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            points: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a point to the end of the series, removing the oldest point if the series is full.
+    ///
+    /// Points should be added in order of time.
+    ///
+    /// # Parameters
+    /// - `point`: The point.
+    pub fn push(&mut self, point: EnrollmentPoint) {
+        while self.points.len() >= self.capacity.max(1) {
+            self.points.pop_front();
+        }
+
+        self.points.push_back(point);
+    }
+
+    /// Adds the current counts of a section to the end of the series. See `push`.
+    ///
+    /// # Parameters
+    /// - `timestamp`: When the counts were taken.
+    /// - `section`: The section.
+    pub fn push_section(&mut self, timestamp: SystemTime, section: &CourseSection) {
+        self.push(EnrollmentPoint {
+            timestamp,
+            enrolled_ct: section.enrolled_ct,
+            available_seats: section.available_seats,
+            waitlist_ct: section.waitlist_ct,
+        });
+    }
+
+    /// Gets the maximum number of points that this series keeps.
+    ///
+    /// # Returns
+    /// The capacity.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Gets the number of points in this series.
+    ///
+    /// # Returns
+    /// The number of points.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Checks whether this series has no points.
+    ///
+    /// # Returns
+    /// `true` if there are no points and `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Gets the points in this series, from oldest to newest.
+    ///
+    /// # Returns
+    /// An iterator over the points.
+    pub fn points(&self) -> impl Iterator<Item = &EnrollmentPoint> {
+        self.points.iter()
+    }
+
+    /// Gets the newest point in this series.
+    ///
+    /// # Returns
+    /// The newest point, or `None` if there are no points.
+    pub fn latest(&self) -> Option<&EnrollmentPoint> {
+        self.points.back()
+    }
+
+    /// Computes how quickly the counts are changing, using the oldest and newest points that
+    /// are within the given window of the newest point.
+    ///
+    /// # Parameters
+    /// - `window`: How far back to look (e.g., one hour). Use `Duration::MAX` to use the
+    ///   entire series.
+    ///
+    /// # Returns
+    /// The rates of change, or `None` if there aren't at least two points (at different times)
+    /// in the window.
+    pub fn rate_of_change(&self, window: Duration) -> Option<EnrollmentRate> {
+        let newest = self.points.back()?;
+        let oldest = self.points.iter().find(|p| {
+            newest
+                .timestamp
+                .duration_since(p.timestamp)
+                .map_or(true, |d| d <= window)
+        })?;
+
+        let hours = newest
+            .timestamp
+            .duration_since(oldest.timestamp)
+            .ok()?
+            .as_secs_f64()
+            / 3600.0;
+        if hours <= 0.0 {
+            return None;
+        }
+
+        Some(EnrollmentRate {
+            enrolled_per_hour: (newest.enrolled_ct - oldest.enrolled_ct) as f64 / hours,
+            available_per_hour: (newest.available_seats - oldest.available_seats) as f64 / hours,
+            waitlist_per_hour: (newest.waitlist_ct - oldest.waitlist_ct) as f64 / hours,
+        })
+    }
+}
+
+/// A meeting that takes place in a room, along with the section it belongs to.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct RoomBooking {
+    /// The subject, course ID. For example, `CSE 100`.
+    pub subj_course_id: String,
+    /// The section code of the first section found with this meeting. For example, `A01`.
+    pub section_code: String,
+    /// The meeting.
+    pub meeting: Meeting,
+}
+
+/// An index of which meetings take place in which rooms, built from a scrape of a term (e.g.,
+/// the sections of every course). This can be used to find empty classrooms.
+///
+/// Meetings without a room (e.g., `TBA` or remote meetings) aren't indexed. Rooms are matched
+/// case-insensitively.
+#[derive(Debug, Clone, Default)]
+pub struct RoomIndex {
+    rooms: BTreeMap<(String, String), Vec<RoomBooking>>,
+}
+
+impl RoomIndex {
+    /// Builds a room index from the given sections. A meeting that is shared by several
+    /// sections (e.g., a lecture shared by `A01` and `A02`) is only indexed once.
+    ///
+    /// # Parameters
+    /// - `sections`: The sections.
+    ///
+    /// # Returns
+    /// The room index.
+    pub fn from_sections(sections: &[CourseSection]) -> Self {
+        let mut rooms: BTreeMap<(String, String), Vec<RoomBooking>> = BTreeMap::new();
+        for section in sections {
+            for meeting in &section.meetings {
+                let Some(key) = Self::room_key(&meeting.building, &meeting.room) else {
+                    continue;
+                };
+
+                let bookings = rooms.entry(key).or_default();
+                if bookings
+                    .iter()
+                    .any(|b| b.subj_course_id == section.subj_course_id && &b.meeting == meeting)
+                {
+                    continue;
+                }
+
+                bookings.push(RoomBooking {
+                    subj_course_id: section.subj_course_id.clone(),
+                    section_code: section.section_code.clone(),
+                    meeting: meeting.clone(),
+                });
+            }
+        }
+
+        Self { rooms }
+    }
+
+    /// Gets every room in this index.
+    ///
+    /// # Returns
+    /// An iterator over the building and room number of each room, sorted.
+    pub fn rooms(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.rooms.keys().map(|(b, r)| (b.as_str(), r.as_str()))
+    }
+
+    /// Gets every meeting that takes place in the given room.
+    ///
+    /// # Parameters
+    /// - `building`: The building, for example `CENTR`.
+    /// - `room`: The room number, for example `115`.
+    ///
+    /// # Returns
+    /// The meetings in that room. This is empty if the room isn't in the index.
+    pub fn bookings(&self, building: &str, room: &str) -> &[RoomBooking] {
+        Self::room_key(building, room)
+            .and_then(|key| self.rooms.get(&key))
+            .map_or(&[], |b| b.as_slice())
+    }
+
+    /// Checks whether the given room is free at the given time. A room that isn't in the index
+    /// is considered free.
+    ///
+    /// # Parameters
+    /// - `building`: The building, for example `CENTR`.
+    /// - `room`: The room number, for example `115`.
+    /// - `days`: The days to check, for example `MeetingDay::Repeated` with `M`, `W`, and `F`.
+    /// - `start`: The start time, as `(hour, minute)`.
+    /// - `end`: The end time, as `(hour, minute)`.
+    ///
+    /// # Returns
+    /// `true` if no meeting in the room overlaps with the given time, and `false` otherwise.
+    pub fn is_free(
+        &self,
+        building: &str,
+        room: &str,
+        days: &MeetingDay,
+        start: (TimeType, TimeType),
+        end: (TimeType, TimeType),
+    ) -> bool {
+        let probe = Self::probe(days, start, end);
+        !self
+            .bookings(building, room)
+            .iter()
+            .any(|b| b.meeting.conflicts_with(&probe))
+    }
+
+    /// Gets every room in this index that is free at the given time. See `is_free`.
+    ///
+    /// # Parameters
+    /// - `days`: The days to check.
+    /// - `start`: The start time, as `(hour, minute)`.
+    /// - `end`: The end time, as `(hour, minute)`.
+    ///
+    /// # Returns
+    /// The building and room number of each free room, sorted.
+    pub fn free_rooms(
+        &self,
+        days: &MeetingDay,
+        start: (TimeType, TimeType),
+        end: (TimeType, TimeType),
+    ) -> Vec<(&str, &str)> {
+        let probe = Self::probe(days, start, end);
+        self.rooms
+            .iter()
+            .filter(|(_, bookings)| !bookings.iter().any(|b| b.meeting.conflicts_with(&probe)))
+            .map(|((b, r), _)| (b.as_str(), r.as_str()))
+            .collect()
+    }
+
+    /// Gets the key for a room.
+    ///
+    /// # Parameters
+    /// - `building`: The building.
+    /// - `room`: The room number.
+    ///
+    /// # Returns
+    /// The key, or `None` if this isn't an actual room.
+    fn room_key(building: &str, room: &str) -> Option<(String, String)> {
+        let building = building.trim().to_uppercase();
+        let room = room.trim().to_uppercase();
+        if building.is_empty() || room.is_empty() || building == "TBA" || room == "TBA" {
+            None
+        } else {
+            Some((building, room))
+        }
+    }
+
+    /// Creates a meeting that can be checked for conflicts.
+    ///
+    /// # Parameters
+    /// - `days`: The days.
+    /// - `start`: The start time.
+    /// - `end`: The end time.
+    ///
+    /// # Returns
+    /// The meeting.
+    fn probe(days: &MeetingDay, start: (TimeType, TimeType), end: (TimeType, TimeType)) -> Meeting {
+        Meeting {
+            meeting_type: String::new(),
+            meeting_days: days.clone(),
+            start_hr: start.0,
+            start_min: start.1,
+            end_hr: end.0,
+            end_min: end.1,
+            building: String::new(),
+            room: String::new(),
+            instructors: vec![],
+        }
+    }
+}
+
+/// The sections taught by one instructor. See `InstructorIndex`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct InstructorLoad {
+    /// Every form of the instructor's name that was seen (e.g., `Smith, John A.`).
+    pub names: Vec<String>,
+    /// The sections that the instructor teaches.
+    pub sections: Vec<CourseSection>,
+}
+
+impl InstructorLoad {
+    /// Gets the courses that the instructor teaches.
+    ///
+    /// # Returns
+    /// The courses (e.g., `CSE 100`), sorted and without duplicates.
+    pub fn courses(&self) -> Vec<&str> {
+        let mut courses = self
+            .sections
+            .iter()
+            .map(|s| s.subj_course_id.as_str())
+            .collect::<Vec<_>>();
+        courses.sort_unstable();
+        courses.dedup();
+        courses
+    }
+}
+
+/// An index of the sections taught by each instructor, built from a scrape of a term (e.g., the
+/// sections of every course). This can be used to answer questions like "what is X teaching
+/// this term?" without searching WebReg again.
+///
+/// Instructors are grouped by their normalized name (see `util::normalize_instructor_name`),
+/// so `Smith, John A.` and `Smith, John` are treated as the same instructor. The `Staff`
+/// placeholder isn't indexed.
+#[derive(Debug, Clone, Default)]
+pub struct InstructorIndex {
+    instructors: BTreeMap<String, InstructorLoad>,
+}
+
+impl InstructorIndex {
+    /// Builds an instructor index from the given sections.
+    ///
+    /// # Parameters
+    /// - `sections`: The sections.
+    ///
+    /// # Returns
+    /// The instructor index.
+    pub fn from_sections(sections: &[CourseSection]) -> Self {
+        let mut instructors: BTreeMap<String, InstructorLoad> = BTreeMap::new();
+        for section in sections {
+            for name in &section.all_instructors {
+                let normalized = util::normalize_instructor_name(name);
+                if normalized.is_empty() || normalized == "staff" {
+                    continue;
+                }
+
+                let load = instructors.entry(normalized).or_default();
+                if !load.names.contains(name) {
+                    load.names.push(name.clone());
+                }
+
+                if !load.sections.iter().any(|s| {
+                    s.subj_course_id == section.subj_course_id && s.section_id == section.section_id
+                }) {
+                    load.sections.push(section.clone());
+                }
+            }
+        }
+
+        Self { instructors }
+    }
+
+    /// Gets every instructor in this index.
+    ///
+    /// # Returns
+    /// An iterator over the normalized name of each instructor, sorted.
+    pub fn instructors(&self) -> impl Iterator<Item = &str> {
+        self.instructors.keys().map(|k| k.as_str())
+    }
+
+    /// Gets the sections taught by the given instructor.
+    ///
+    /// # Parameters
+    /// - `name`: The instructor's name, in any form that normalizes to the same name (e.g.,
+    ///   `Smith, John` or `John Smith`).
+    ///
+    /// # Returns
+    /// The instructor's sections, or `None` if the instructor isn't in the index.
+    pub fn get(&self, name: &str) -> Option<&InstructorLoad> {
+        self.instructors.get(&util::normalize_instructor_name(name))
+    }
+
+    /// Gets the courses taught by the given instructor. See `InstructorLoad::courses`.
+    ///
+    /// # Parameters
+    /// - `name`: The instructor's name.
+    ///
+    /// # Returns
+    /// The courses, which is empty if the instructor isn't in the index.
+    pub fn courses_taught_by(&self, name: &str) -> Vec<&str> {
+        self.get(name).map(|l| l.courses()).unwrap_or_default()
+    }
+}
+
+/// Which fields of a section to include when exporting sections (see `util::sections_to_json`
+/// and `util::sections_to_csv`). The course, section ID, and section code are always included.
+/// By default, every field is included.
+///
+/// # Example
+/// ```rust
+/// use webweg::types::ExportFields;
+///
+/// let fields = ExportFields::default()
+///     .with_meetings(false)
+///     .with_instructors(true);
+/// assert!(!fields.meetings);
+/// assert!(fields.instructors);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct ExportFields {
+    /// Whether to include the meetings.
+    pub meetings: bool,
+    /// Whether to include the instructors (`all_instructors` and `instructor_info`).
+    pub instructors: bool,
+    /// Whether to include the seat counts (available seats, enrolled count, total seats,
+    /// and waitlist count).
+    pub seats: bool,
+    /// Whether to include the section notes.
+    pub section_notes: bool,
+    /// Whether to include whether the section is visible.
+    pub visibility: bool,
+}
+
+impl Default for ExportFields {
+    fn default() -> Self {
+        Self {
+            meetings: true,
+            instructors: true,
+            seats: true,
+            section_notes: true,
+            visibility: true,
+        }
+    }
+}
+
+impl ExportFields {
+    /// Creates a new `ExportFields` that only includes the fields that are always included
+    /// (the course, section ID, and section code).
+    ///
+    /// # Returns
+    /// The new `ExportFields`.
+    pub fn none() -> Self {
+        Self {
+            meetings: false,
+            instructors: false,
+            seats: false,
+            section_notes: false,
+            visibility: false,
+        }
+    }
+
+    /// Sets whether to include the meetings.
+    ///
+    /// # Parameters
+    /// - `include`: Whether to include the meetings.
+    ///
+    /// # Returns
+    /// The `ExportFields`.
+    pub fn with_meetings(mut self, include: bool) -> Self {
+        self.meetings = include;
+        self
+    }
+
+    /// Sets whether to include the instructors.
+    ///
+    /// # Parameters
+    /// - `include`: Whether to include the instructors.
+    ///
+    /// # Returns
+    /// The `ExportFields`.
+    pub fn with_instructors(mut self, include: bool) -> Self {
+        self.instructors = include;
+        self
+    }
+
+    /// Sets whether to include the seat counts.
+    ///
+    /// # Parameters
+    /// - `include`: Whether to include the seat counts.
+    ///
+    /// # Returns
+    /// The `ExportFields`.
+    pub fn with_seats(mut self, include: bool) -> Self {
+        self.seats = include;
+        self
+    }
+
+    /// Sets whether to include the section notes.
+    ///
+    /// # Parameters
+    /// - `include`: Whether to include the section notes.
+    ///
+    /// # Returns
+    /// The `ExportFields`.
+    pub fn with_section_notes(mut self, include: bool) -> Self {
+        self.section_notes = include;
+        self
+    }
+
+    /// Sets whether to include whether the section is visible.
+    ///
+    /// # Parameters
+    /// - `include`: Whether to include the visibility.
+    ///
+    /// # Returns
+    /// The `ExportFields`.
+    pub fn with_visibility(mut self, include: bool) -> Self {
+        self.visibility = include;
+        self
+    }
+}
+
+/// A week in a term.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum TermWeek {
+    /// A week of instruction. The first week of instruction is week `1`.
+    Instruction(u32),
+    /// Finals week.
+    Finals,
+}
+
+/// The important dates for a term. WebReg doesn't provide these, so you'll need to
+/// provide them yourself (e.g., from the academic calendar).
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct TermDates {
+    /// The first day of instruction.
+    pub instruction_start: chrono::NaiveDate,
+    /// The first day of finals week. Regular meetings don't occur on or after this date.
+    pub finals_start: chrono::NaiveDate,
+    /// The last day of the term.
+    pub term_end: chrono::NaiveDate,
+}
+
+#[cfg(feature = "chrono")]
+impl TermDates {
+    /// Creates a new `TermDates` object.
+    ///
+    /// # Parameters
+    /// - `instruction_start`: The first day of instruction.
+    /// - `finals_start`: The first day of finals week.
+    /// - `term_end`: The last day of the term.
+    ///
+    /// # Returns
+    /// The term dates.
+    pub fn new(
+        instruction_start: chrono::NaiveDate,
+        finals_start: chrono::NaiveDate,
+        term_end: chrono::NaiveDate,
+    ) -> Self {
+        Self {
+            instruction_start,
+            finals_start,
+            term_end,
+        }
+    }
+
+    /// Gets the week of the term that the given date is in. Weeks start on Monday, and the
+    /// week containing the first day of instruction is week `1`.
+    ///
+    /// # Parameters
+    /// - `date`: The date.
+    ///
+    /// # Returns
+    /// The week, or `None` if the date isn't in the term.
+    pub fn week_of(&self, date: chrono::NaiveDate) -> Option<TermWeek> {
+        use chrono::Datelike;
+
+        if date < self.instruction_start || date > self.term_end {
+            return None;
+        }
+
+        if date >= self.finals_start {
+            return Some(TermWeek::Finals);
+        }
+
+        let first_monday = self.instruction_start
+            - chrono::Duration::days(self.instruction_start.weekday().num_days_from_monday() as i64);
+        Some(TermWeek::Instruction(
+            ((date - first_monday).num_days() / 7) as u32 + 1,
+        ))
+    }
+
+    /// Gets all meetings in the schedule that occur on the given date, sorted by start time.
+    ///
+    /// Repeated meetings (e.g., lectures and discussions) are only included if the date is
+    /// during instruction, while one-time meetings (e.g., midterms and finals) are included if
+    /// they're on the date.
+    ///
+    /// # Parameters
+    /// - `date`: The date.
+    /// - `schedule`: The schedule.
+    ///
+    /// # Returns
+    /// Each section and meeting that occurs on the date.
+    pub fn meetings_on<'a>(
+        &self,
+        date: chrono::NaiveDate,
+        schedule: &'a [ScheduledSection],
+    ) -> Vec<(&'a ScheduledSection, &'a Meeting)> {
+        use chrono::Datelike;
+
+        let Some(week) = self.week_of(date) else {
+            return vec![];
+        };
+
+        let day = crate::util::weekday_abbreviation(date.weekday());
+        let date_str = date.format("%Y-%m-%d").to_string();
+        let mut meetings = schedule
+            .iter()
+            .flat_map(|sec| sec.meetings.iter().map(move |m| (sec, m)))
+            .filter(|(_, m)| match &m.meeting_days {
+                MeetingDay::Repeated(days) => {
+                    matches!(week, TermWeek::Instruction(_)) && days.iter().any(|d| d == day)
+                }
+                MeetingDay::OneTime(d) => *d == date_str,
+                MeetingDay::None => false,
+            })
+            .collect::<Vec<_>>();
+
+        meetings.sort_by_key(|(_, m)| m.start_hr * 60 + m.start_min);
+        meetings
+    }
+}
+
+/// The result of planning a section that might already be planned.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum PlanUpsertOutcome {
+    /// The section wasn't planned, so it was added to the plan.
+    Added,
+    /// The section was already planned, but with a different unit count or grading option,
+    /// so it was updated.
+    Updated,
+    /// The section was already planned with the same unit count and grading option, so
+    /// nothing was done.
+    Unchanged,
+}
+
+/// How the sections planned in a schedule compare to what you are actually enrolled in. See
+/// `WrapperTermRequest::reconcile_plan`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct PlanReconciliation {
+    /// The name of the schedule that was reconciled.
+    pub schedule_name: String,
+    /// The planned sections for courses that you are now enrolled in.
+    pub enrolled: Vec<ScheduledSection>,
+    /// The planned sections for courses that you are now waitlisted for.
+    pub waitlisted: Vec<ScheduledSection>,
+    /// The planned sections that you can still enroll in (i.e., they have seats).
+    pub open: Vec<ScheduledSection>,
+    /// The planned sections that don't have any seats left.
+    pub full: Vec<ScheduledSection>,
+    /// The planned sections that are no longer offered (e.g., they were canceled).
+    pub cancelled: Vec<ScheduledSection>,
+}
+
+impl PlanReconciliation {
+    /// Checks whether every planned section has been dealt with (i.e., you are enrolled in or
+    /// waitlisted for every planned course).
+    ///
+    /// # Returns
+    /// `true` if there are no open, full, or canceled planned sections left.
+    pub fn is_done(&self) -> bool {
+        self.open.is_empty() && self.full.is_empty() && self.cancelled.is_empty()
+    }
+}
+
+/// One period of an enrollment unit cap schedule (e.g., first pass).
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct UnitCapPeriod {
+    /// A name for the period (e.g., `First Pass`).
+    pub label: String,
+    /// When the period starts. The period lasts until the next period starts.
+    pub starts_at: SystemTime,
+    /// The maximum number of units that you can be enrolled in or waitlisted for during the
+    /// period.
+    pub max_units: i64,
+}
+
+/// How the maximum number of units that you can enroll in changes over an enrollment period.
+/// For example, the cap is usually lower during first pass than during second pass, and
+/// summer sessions have their own caps.
+///
+/// WebReg doesn't expose these caps through the endpoints that this library uses, so you'll
+/// need to fill this in yourself (e.g., from the registrar's calendar).
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct UnitCapSchedule {
+    /// The periods, in any order.
+    pub periods: Vec<UnitCapPeriod>,
+}
+
+impl UnitCapSchedule {
+    /// Creates an empty cap schedule.
+    ///
+    /// # Returns
+    /// The cap schedule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a period to the cap schedule.
+    ///
+    /// # Parameters
+    /// - `label`: A name for the period (e.g., `Second Pass`).
+    /// - `starts_at`: When the period starts.
+    /// - `max_units`: The unit cap during the period.
+    ///
+    /// # Returns
+    /// The cap schedule.
+    pub fn with_period(
+        mut self,
+        label: impl Into<String>,
+        starts_at: SystemTime,
+        max_units: i64,
+    ) -> Self {
+        self.periods.push(UnitCapPeriod {
+            label: label.into(),
+            starts_at,
+            max_units,
+        });
+        self
+    }
+
+    /// Gets the period that applies at the given time; that is, the period that started most
+    /// recently.
+    ///
+    /// # Parameters
+    /// - `time`: The time.
+    ///
+    /// # Returns
+    /// The period, or `None` if no period has started yet.
+    pub fn period_at(&self, time: SystemTime) -> Option<&UnitCapPeriod> {
+        self.periods
+            .iter()
+            .filter(|p| p.starts_at <= time)
+            .max_by_key(|p| p.starts_at)
+    }
+
+    /// Gets the unit cap that applies at the given time.
+    ///
+    /// # Parameters
+    /// - `time`: The time.
+    ///
+    /// # Returns
+    /// The unit cap, or `None` if no period has started yet.
+    pub fn cap_at(&self, time: SystemTime) -> Option<i64> {
+        self.period_at(time).map(|p| p.max_units)
+    }
+}
+
+/// What `WrapperTermRequest::enroll_from_plan` did for a planned section.
+#[derive(Debug)]
+pub enum PlanEnrollOutcome {
+    /// You were enrolled in the section.
+    Enrolled,
+    /// You were waitlisted for the section.
+    Waitlisted,
+    /// The section was skipped because it doesn't have any seats, and waitlisting wasn't
+    /// allowed.
+    SkippedFull,
+    /// The section was skipped because adding it would have put you over the unit limit
+    /// (either the one in the options, or the one from the unit cap schedule).
+    SkippedUnitLimit,
+    /// The section was skipped because another section of the same course was already added.
+    SkippedCourseAdded,
+    /// The section couldn't be added.
+    Failed(WrapperError),
+}
+
+impl PlanEnrollOutcome {
+    /// Gets a short name for the outcome (e.g., `skipped_full`), which is suitable for logs.
+    ///
+    /// # Returns
+    /// The name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlanEnrollOutcome::Enrolled => "enrolled",
+            PlanEnrollOutcome::Waitlisted => "waitlisted",
+            PlanEnrollOutcome::SkippedFull => "skipped_full",
+            PlanEnrollOutcome::SkippedUnitLimit => "skipped_unit_limit",
+            PlanEnrollOutcome::SkippedCourseAdded => "skipped_course_added",
+            PlanEnrollOutcome::Failed(_) => "failed",
+        }
+    }
+}
+
+/// What `WrapperTermRequest::auto_enroll` ended up doing.
+#[derive(Debug)]
+pub enum AutoEnrollStatus {
+    /// You were enrolled in the section.
+    Enrolled,
+    /// You were waitlisted for the section.
+    Waitlisted,
+    /// Nothing was tried because the section doesn't have any seats, and waitlisting wasn't
+    /// allowed.
+    SkippedFull,
+    /// WebReg rejected the last add that was tried.
+    Rejected(EnrollmentFailure),
+    /// The last add that was tried failed for some other reason (e.g., WebReg kept timing
+    /// out).
+    Failed(WrapperError),
+}
+
+impl AutoEnrollStatus {
+    /// Gets a short name for the status (e.g., `skipped_full`), which is suitable for logs.
+    ///
+    /// # Returns
+    /// The name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AutoEnrollStatus::Enrolled => "enrolled",
+            AutoEnrollStatus::Waitlisted => "waitlisted",
+            AutoEnrollStatus::SkippedFull => "skipped_full",
+            AutoEnrollStatus::Rejected(_) => "rejected",
+            AutoEnrollStatus::Failed(_) => "failed",
+        }
+    }
+}
+
+/// The result of `WrapperTermRequest::auto_enroll`, describing every step that was taken.
+#[derive(Debug)]
+pub struct EnrollOutcome {
+    /// The section, as it was when its seats were checked.
+    pub section: CourseSection,
+    /// Whether the section had seats (according to the wrapper's seat strategy) when its
+    /// seats were checked.
+    pub had_seats: bool,
+    /// Whether enrolling was tried.
+    pub tried_enroll: bool,
+    /// Whether waitlisting was tried, either because the section was full or because
+    /// enrolling failed.
+    pub tried_waitlist: bool,
+    /// The number of add requests that were made, including retries.
+    pub attempts: u32,
+    /// What happened in the end.
+    pub status: AutoEnrollStatus,
+    /// The section's status in your schedule after everything was done, if confirmation was
+    /// enabled and the section was found in your schedule.
+    pub confirmed: Option<EnrollmentStatus>,
+}
+
+impl EnrollOutcome {
+    /// Checks whether you were enrolled in, or waitlisted for, the section.
+    ///
+    /// # Returns
+    /// Whether the section was added.
+    pub fn is_added(&self) -> bool {
+        matches!(
+            self.status,
+            AutoEnrollStatus::Enrolled | AutoEnrollStatus::Waitlisted
+        )
+    }
+}
+
+/// A record of one attempt made by an automated enrollment helper (e.g.,
+/// `WrapperTermRequest::enroll_from_plan`). See `AttemptSink`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct AttemptRecord {
+    /// When the attempt was finished.
+    pub at: SystemTime,
+    /// The term that the attempt was for.
+    pub term: String,
+    /// The section ID of the section.
+    pub section_id: String,
+    /// The section code of the section (e.g., `A01`).
+    pub section_code: String,
+    /// The course (e.g., `CSE 100`).
+    pub course: String,
+    /// What happened (see `PlanEnrollOutcome::name` and `AutoEnrollStatus::name`).
+    pub outcome: String,
+    /// The reason that WebReg gave, or the error that occurred, if the attempt failed.
+    pub reason: Option<String>,
+}
+
+/// A place to record every attempt made by the automated enrollment helpers, so that you can
+/// check exactly what was done (e.g., after your enrollment window, or after a crash).
+///
+/// This is implemented for closures, so you can pass something like
+/// `|attempt: &AttemptRecord| println!("{attempt:?}")`. See also
+/// `util::JsonLinesAttemptSink`, which appends each attempt to a file.
+pub trait AttemptSink: Send + Sync {
+    /// Records an attempt. This is called as soon as the attempt is finished, so anything
+    /// slow here will slow down enrollment.
+    ///
+    /// # Parameters
+    /// - `attempt`: The attempt.
+    fn record(&self, attempt: &AttemptRecord);
+}
+
+impl<F> AttemptSink for F
+where
+    F: Fn(&AttemptRecord) + Send + Sync,
+{
+    fn record(&self, attempt: &AttemptRecord) {
+        self(attempt)
+    }
+}
+
+/// A record of one mutating request (e.g., enrolling, dropping, planning, or changing a grading
+/// option) made through the wrapper. See `ActionLog`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct ActionRecord {
+    /// When the request was finished.
+    pub at: SystemTime,
+    /// The term that the request was for, if any.
+    pub term: Option<String>,
+    /// The name of the endpoint (e.g., `add-enroll`).
+    pub endpoint: String,
+    /// The form parameters that were sent (e.g., `section` and `grade`).
+    pub params: Vec<(String, String)>,
+    /// The status code that WebReg responded with, if WebReg responded.
+    pub status: Option<u16>,
+    /// The response that WebReg gave, if WebReg responded.
+    pub response: Option<String>,
+    /// The error that occurred, if the request couldn't be sent or WebReg didn't respond.
+    pub error: Option<String>,
+}
+
+/// Every mutating request made through the wrapper, oldest first. This is only recorded if the
+/// wrapper was built with action recording enabled (see the builder's `should_record_actions`
+/// function), and can be retrieved with the wrapper's `action_log` function.
+///
+/// This might be useful for finding out what an enrollment bot actually did, or for keeping an
+/// audit trail.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct ActionLog {
+    /// The requests, in the order that they were finished.
+    pub actions: Vec<ActionRecord>,
+}
+
+impl ActionLog {
+    /// Converts this log to JSON.
+    ///
+    /// # Returns
+    /// The log as a JSON string, or an error if it couldn't be converted.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// A planned section, along with what `WrapperTermRequest::enroll_from_plan` did for it.
+#[derive(Debug)]
+pub struct PlanEnrollAttempt {
+    /// The planned section.
+    pub section: ScheduledSection,
+    /// What was done.
+    pub outcome: PlanEnrollOutcome,
+}
+
+/// The result of `WrapperTermRequest::enroll_from_plan`.
+#[derive(Debug)]
+pub struct EnrollFromPlanReport {
+    /// The state of the plan before any sections were added.
+    pub reconciliation: PlanReconciliation,
+    /// What was done for each section that you weren't already enrolled in or waitlisted for,
+    /// in the order that the sections were tried. Canceled sections aren't tried.
+    pub attempts: Vec<PlanEnrollAttempt>,
+}
+
+impl EnrollFromPlanReport {
+    /// Gets the sections that you were enrolled in or waitlisted for.
+    ///
+    /// # Returns
+    /// An iterator over the sections that were added.
+    pub fn added(&self) -> impl Iterator<Item = &ScheduledSection> {
+        self.attempts
+            .iter()
+            .filter(|a| {
+                matches!(
+                    a.outcome,
+                    PlanEnrollOutcome::Enrolled | PlanEnrollOutcome::Waitlisted
+                )
+            })
+            .map(|a| &a.section)
+    }
+}
+
+/// The result of adding several linked sections (e.g., a lecture and a lab under different
+/// course numbers) together.
+#[derive(Debug)]
+pub struct LinkedAddResult {
+    /// The section IDs of the sections that were added, in the order that they were added.
+    pub added: Vec<String>,
+    /// The section that couldn't be added, along with the reason why. If this is `None`, every
+    /// section was added. Any sections after this one were not attempted.
+    pub failed: Option<(String, WrapperError)>,
+}
+
+impl LinkedAddResult {
+    /// Checks whether every section was added.
+    ///
+    /// # Returns
+    /// `true` if every section was added and `false` otherwise.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_none()
+    }
+}
+
+/// The result of swapping one section for another (see `WrapperTermRequest::swap_section`).
+#[derive(Debug)]
+pub enum SwapOutcome {
+    /// The old section was dropped, and the new section was added.
+    Swapped,
+    /// The new section couldn't be added, so the old section was added back. This is the
+    /// reason why the new section couldn't be added.
+    RolledBack(WrapperError),
+    /// The new section couldn't be added, and neither could the old section, so you are in
+    /// neither section. The first error is why the new section couldn't be added, and the
+    /// second error is why the old section couldn't be added back.
+    RollbackFailed(WrapperError, WrapperError),
+}
+
+impl SwapOutcome {
+    /// Checks whether the swap went through.
+    ///
+    /// # Returns
+    /// `true` if you are now in the new section and `false` otherwise.
+    pub fn is_swapped(&self) -> bool {
+        matches!(self, SwapOutcome::Swapped)
+    }
+}
+
+/// What `WrapperTermRequest::apply_drop_policy` did for a waitlisted section.
+#[derive(Debug)]
+pub enum WaitlistDropOutcome {
+    /// The section was dropped.
+    Dropped,
+    /// The section would have been dropped, but the policy was a dry run.
+    WouldDrop,
+    /// The section couldn't be dropped.
+    Failed(WrapperError),
+}
+
+impl WaitlistDropOutcome {
+    /// Gets a short name for the outcome (e.g., `would_drop`), which is suitable for logs.
+    ///
+    /// # Returns
+    /// The name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            WaitlistDropOutcome::Dropped => "dropped",
+            WaitlistDropOutcome::WouldDrop => "would_drop",
+            WaitlistDropOutcome::Failed(_) => "failed",
+        }
+    }
+}
+
+/// A waitlisted section that a drop policy applied to, along with what was done.
+#[derive(Debug)]
+pub struct WaitlistDropAttempt {
+    /// The waitlisted section.
+    pub section: ScheduledSection,
+    /// The rule that applied to the section.
+    pub rule: DropRule,
+    /// What was done.
+    pub outcome: WaitlistDropOutcome,
+}
+
+/// The result of `WrapperTermRequest::apply_drop_policy`.
+#[derive(Debug)]
+pub struct WaitlistDropReport {
+    /// Every waitlisted section that the policy applied to, in schedule order.
+    pub attempts: Vec<WaitlistDropAttempt>,
+}
+
+impl WaitlistDropReport {
+    /// Gets the sections that were dropped.
+    ///
+    /// # Returns
+    /// An iterator over the sections that were dropped.
+    pub fn dropped(&self) -> impl Iterator<Item = &ScheduledSection> {
+        self.attempts
+            .iter()
+            .filter(|a| matches!(a.outcome, WaitlistDropOutcome::Dropped))
+            .map(|a| &a.section)
+    }
+
+    /// Gets the sections that couldn't be dropped, along with the error.
+    ///
+    /// # Returns
+    /// An iterator over the sections that couldn't be dropped.
+    pub fn failures(&self) -> impl Iterator<Item = (&ScheduledSection, &WrapperError)> {
+        self.attempts.iter().filter_map(|a| match &a.outcome {
+            WaitlistDropOutcome::Failed(e) => Some((&a.section, e)),
+            _ => None,
+        })
+    }
+}
+
+/// How far along a full catalog dump is (see `WrapperTermRequest::get_all_courses`). This
+/// is given after each department is finished.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct CatalogProgress {
+    /// The department that was just finished.
+    pub department: String,
+    /// The number of departments finished so far, including this one.
+    pub departments_done: usize,
+    /// The total number of departments.
+    pub departments_total: usize,
+    /// The number of unique sections found so far.
+    pub sections_found: usize,
+}
+
+/// The result of planning the same sections into several schedules (see
+/// `WrapperTermRequest::apply_plan_to_schedules`). Each row is a schedule, and each column is
+/// a section.
+#[derive(Debug)]
+pub struct PlanMatrix {
+    /// The schedules, in the order that they were given.
+    pub schedules: Vec<String>,
+    /// The section IDs of the sections, in the order that they were given.
+    pub section_ids: Vec<String>,
+    /// The outcome of planning each section into each schedule, where `outcomes[i][j]` is the
+    /// outcome of planning section `j` into schedule `i`.
+    pub outcomes: Vec<Vec<Result<PlanUpsertOutcome>>>,
+}
+
+impl PlanMatrix {
+    /// Gets the outcome of planning a section into a schedule.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule.
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// The outcome, or `None` if the schedule or section wasn't part of the plan.
+    pub fn get(&self, schedule_name: &str, section_id: &str) -> Option<&Result<PlanUpsertOutcome>> {
+        let row = self.schedules.iter().position(|s| s == schedule_name)?;
+        let col = self.section_ids.iter().position(|s| s == section_id)?;
+        self.outcomes.get(row)?.get(col)
+    }
+
+    /// Gets every section that couldn't be planned.
+    ///
+    /// # Returns
+    /// An iterator over the schedule, section ID, and error of each failure.
+    pub fn failures(&self) -> impl Iterator<Item = (&str, &str, &WrapperError)> {
+        self.schedules
+            .iter()
+            .zip(&self.outcomes)
+            .flat_map(move |(schedule, row)| {
+                self.section_ids
+                    .iter()
+                    .zip(row)
+                    .filter_map(move |(section_id, outcome)| {
+                        outcome
+                            .as_ref()
+                            .err()
+                            .map(|e| (schedule.as_str(), section_id.as_str(), e))
+                    })
+            })
+    }
+
+    /// Checks whether every section was planned into every schedule.
+    ///
+    /// # Returns
+    /// `true` if nothing failed and `false` otherwise.
+    pub fn is_complete(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+/// The result of planning or unplanning several sections at once (see
+/// `WrapperTermRequest::add_many_to_plan` and `WrapperTermRequest::remove_many_from_plan`).
+#[derive(Debug)]
+pub struct BulkPlanReport {
+    /// The section ID of each section, along with the result of planning or unplanning it, in
+    /// the order that the sections were given.
+    pub results: Vec<(String, Result<bool>)>,
+}
+
+impl BulkPlanReport {
+    /// Gets every section that was planned or unplanned.
+    ///
+    /// # Returns
+    /// An iterator over the section IDs.
+    pub fn succeeded(&self) -> impl Iterator<Item = &str> {
+        self.results
+            .iter()
+            .filter(|(_, res)| res.is_ok())
+            .map(|(section_id, _)| section_id.as_str())
+    }
+
+    /// Gets every section that couldn't be planned or unplanned.
+    ///
+    /// # Returns
+    /// An iterator over the section ID and error of each failure.
+    pub fn failures(&self) -> impl Iterator<Item = (&str, &WrapperError)> {
+        self.results
+            .iter()
+            .filter_map(|(section_id, res)| res.as_ref().err().map(|e| (section_id.as_str(), e)))
+    }
+
+    /// Checks whether every section was planned or unplanned.
+    ///
+    /// # Returns
+    /// `true` if nothing failed and `false` otherwise.
+    pub fn is_complete(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+impl Display for BulkPlanReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} sections succeeded",
+            self.succeeded().count(),
+            self.results.len()
+        )?;
+        for (section_id, e) in self.failures() {
+            write!(f, "\n\t{section_id}: {e}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The result of probing a range of course numbers (see `WrapperTermRequest::scan_course_range`).
+#[derive(Debug, Default)]
+pub struct CourseRangeScan {
+    /// The course numbers that exist along with their sections, in the order that they were
+    /// probed.
+    pub found: Vec<(String, Courses)>,
+    /// The course numbers that couldn't be probed (e.g., because WebReg responded with an error
+    /// status code for a restricted course), along with the error, in the order that they were
+    /// probed.
+    pub errors: Vec<(String, WrapperError)>,
+}
+
+impl CourseRangeScan {
+    /// Checks whether every course number was probed successfully.
+    ///
+    /// # Returns
+    /// `true` if no course number failed and `false` otherwise.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// A problem with the options given to the `WebRegWrapperBuilder`. See
+/// `WebRegWrapperBuilder::diagnose`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub enum BuilderProblem {
+    /// No cookies were given.
+    MissingCookies,
+    /// The cookies that were given are empty.
+    EmptyCookies,
+    /// The cookies don't look like a cookie header (e.g., `name=value; other=value`). The
+    /// string describes what's wrong.
+    MalformedCookies(String),
+    /// The timeout is zero, so every request would time out.
+    ZeroTimeout,
+    /// `should_override_cookies` was set, but `should_close_after_request` wasn't, so every
+    /// attempt to override the cookies would fail.
+    CookieOverrideWithoutClose,
+    /// A client was given with `with_client`, but some options that only apply when the
+    /// builder creates the client (e.g., `should_accept_invalid_certs`) were also set. These
+    /// options are ignored.
+    ClientOptionsIgnored,
+    /// The client couldn't be built with the given options. The string is the reason.
+    ClientBuildFailed(String),
+    /// The crate was built without the `reqwest` feature, so there's no client to send
+    /// requests with, and no backend was given with `with_backend` either.
+    MissingBackend,
+}
+
+impl BuilderProblem {
+    /// Whether this problem stops the wrapper from being built at all. Other problems are only
+    /// reported by `WebRegWrapperBuilder::diagnose` and `WebRegWrapperBuilder::build_wrapper`.
+    ///
+    /// # Returns
+    /// Whether this problem is fatal.
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            BuilderProblem::MissingCookies
+                | BuilderProblem::EmptyCookies
+                | BuilderProblem::ClientBuildFailed(_)
+                | BuilderProblem::MissingBackend
+        )
+    }
+}
+
+impl Display for BuilderProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuilderProblem::MissingCookies => write!(f, "no cookies were given"),
+            BuilderProblem::EmptyCookies => write!(f, "the cookies are empty"),
+            BuilderProblem::MalformedCookies(reason) => {
+                write!(f, "the cookies are malformed: {reason}")
+            }
+            BuilderProblem::ZeroTimeout => write!(f, "the timeout is zero"),
+            BuilderProblem::CookieOverrideWithoutClose => write!(
+                f,
+                "cookies will be overridden, but connections aren't closed after each request"
+            ),
+            BuilderProblem::ClientOptionsIgnored => write!(
+                f,
+                "a client was given, so the other client options are ignored"
+            ),
+            BuilderProblem::ClientBuildFailed(reason) => {
+                write!(f, "the client couldn't be built: {reason}")
+            }
+            BuilderProblem::MissingBackend => write!(
+                f,
+                "no backend was given, and there's no client without the `reqwest` feature"
+            ),
+        }
+    }
+}
+
+/// A data-quality issue that was found while parsing WebReg's data. When these come up, the
+/// parser either normalizes the data (e.g., clamping negative seat counts to `0`) or skips it,
+/// so the parsed data might not be exactly what WebReg gave.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub enum ParseWarning {
+    /// A meeting's day code contained characters that don't represent a day of the week. These
+    /// characters were ignored.
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    UnknownDayCode {
+        /// The section code of the meeting.
+        section_code: String,
+        /// The day code given by WebReg.
+        day_code: String,
+    },
+    /// A section had a negative number of available seats, which happens when a section is
+    /// over-enrolled. The number of available seats was clamped to `0`.
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    NegativeSeats {
+        /// The section ID.
+        section_id: String,
+        /// The number of available seats given by WebReg.
+        available_seats: i64,
+    },
+    /// A section family (e.g., all sections starting with `A`) had no lecture, final exam, or
+    /// similar meeting, so none of the sections in that family could be parsed.
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    MissingGeneralMeeting {
+        /// The section family.
+        family: char,
+    },
+    /// A meeting had a display type that isn't known, so it was skipped.
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    UnknownDisplayType {
+        /// The section code of the meeting.
+        section_code: String,
+        /// The display type given by WebReg.
+        display_type: String,
+    },
+    /// A meeting couldn't be deserialized (e.g., a field was missing or had the wrong type),
+    /// so it was skipped.
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    MalformedMeeting {
+        /// The position of the meeting in WebReg's response.
+        index: usize,
+        /// Why the meeting couldn't be deserialized.
+        error: String,
+    },
+    /// A meeting had a special meeting code that isn't known, so it was kept as
+    /// `SpecialMeetingCode::Unknown`.
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    UnknownMeetingType {
+        /// The section code of the meeting.
+        section_code: String,
+        /// The special meeting code given by WebReg.
+        meeting_type: String,
+    },
+    /// A scheduled section had an enrollment status that isn't known, so it was parsed as
+    /// `EnrollmentStatus::Unknown`.
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    UnknownEnrollStatus {
+        /// The section ID.
+        section_id: String,
+        /// The enrollment status given by WebReg.
+        enroll_status: String,
+    },
+    /// A scheduled section had a grading option that isn't known, so its grading option was
+    /// parsed as `None`. A blank grading option doesn't cause this warning.
+    #[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+    UnknownGradeOption {
+        /// The section ID.
+        section_id: String,
+        /// The grading option given by WebReg.
+        grade_option: String,
+    },
+}
+
+impl ParseWarning {
+    /// Whether this warning was caused by WebReg giving a value that isn't known (e.g., an
+    /// unknown display type), as opposed to a known value that had to be normalized. These
+    /// are the warnings that cause an error when strict parsing is enabled.
+    ///
+    /// # Returns
+    /// Whether this warning was caused by an unknown value.
+    pub fn is_unknown_value(&self) -> bool {
+        matches!(
+            self,
+            ParseWarning::UnknownDayCode { .. }
+                | ParseWarning::UnknownDisplayType { .. }
+                | ParseWarning::UnknownMeetingType { .. }
+                | ParseWarning::UnknownEnrollStatus { .. }
+                | ParseWarning::UnknownGradeOption { .. }
+        )
+    }
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseWarning::UnknownDayCode {
+                section_code,
+                day_code,
+            } => write!(f, "unknown day code '{day_code}' for {section_code}"),
+            ParseWarning::NegativeSeats {
+                section_id,
+                available_seats,
+            } => write!(f, "{section_id} has {available_seats} available seats"),
+            ParseWarning::MissingGeneralMeeting { family } => {
+                write!(f, "section family {family} has no general meetings")
+            }
+            ParseWarning::UnknownDisplayType {
+                section_code,
+                display_type,
+            } => write!(
+                f,
+                "unknown display type '{display_type}' for {section_code}"
+            ),
+            ParseWarning::MalformedMeeting { index, error } => {
+                write!(f, "skipped malformed meeting #{index}: {error}")
+            }
+            ParseWarning::UnknownMeetingType {
+                section_code,
+                meeting_type,
+            } => write!(
+                f,
+                "unknown meeting type '{meeting_type}' for {section_code}"
+            ),
+            ParseWarning::UnknownEnrollStatus {
+                section_id,
+                enroll_status,
+            } => write!(
+                f,
+                "unknown enrollment status '{enroll_status}' for {section_id}"
+            ),
+            ParseWarning::UnknownGradeOption {
+                section_id,
+                grade_option,
+            } => write!(
+                f,
+                "unknown grading option '{grade_option}' for {section_id}"
+            ),
+        }
+    }
+}
+
+/// The result of checking whether a section can be enrolled in, waitlisted, or planned,
+/// without actually doing so.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct ValidationOutcome {
+    /// Whether WebReg said that the section can be added.
+    pub ok: bool,
+    /// Why the section can't be added, if WebReg gave a reason. This is `None` if `ok` is
+    /// `true`.
+    pub reason: Option<EnrollmentFailure>,
+}
+
+impl ValidationOutcome {
+    /// Creates a validation outcome from the reason that WebReg gave for rejecting a
+    /// section.
+    ///
+    /// # Parameters
+    /// - `reason`: The reason given by WebReg.
+    ///
+    /// # Returns
+    /// The validation outcome.
+    pub fn rejected(reason: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            reason: Some(EnrollmentFailure::from_reason(reason)),
+        }
+    }
+
+    /// Converts a failed validation into the error that the wrapper returns when WebReg
+    /// rejects a `POST` request, so that callers only have to handle `WebRegRejected`.
+    ///
+    /// # Returns
+    /// The error.
+    pub(crate) fn into_error(self) -> WrapperError {
+        WrapperError::WebRegRejected(Box::new(WebRegOpsResponse {
+            ops: "FAIL".into(),
+            reason: Some(
+                self.reason
+                    .map(|r| r.message().to_owned())
+                    .filter(|r| !r.trim().is_empty())
+                    .unwrap_or_else(|| "WebReg rejected this without giving a reason.".into()),
+            ),
+            extra: serde_json::Map::new(),
+        }))
+    }
+}
+
+/// A reason why WebReg won't let you add a section (or make some other change), classified
+/// from the message that WebReg gave. Each variant contains WebReg's original message. To
+/// classify an error, use `WrapperError::rejection`.
+///
+/// WebReg only gives free-form messages, so this classification is a best guess based on the
+/// wording of the message.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub enum EnrollmentFailure {
+    /// The section is restricted (e.g., to certain majors, colleges, or class levels), or
+    /// requires department approval.
+    Restriction(String),
+    /// The section conflicts with something already in your schedule.
+    TimeConflict(String),
+    /// It isn't your enrollment appointment time yet, or enrollment has closed.
+    AppointmentTime(String),
+    /// Adding the section would put you over your unit limit.
+    UnitLimit(String),
+    /// You don't meet the prerequisites for the course.
+    Prerequisite(String),
+    /// You're already enrolled in, waitlisted for, or planning the course.
+    AlreadyAdded(String),
+    /// The section's waitlist is full or closed.
+    WaitlistFull(String),
+    /// Any other reason.
+    Other(String),
+}
+
+impl EnrollmentFailure {
+    /// Classifies the reason that WebReg gave for rejecting a section.
+    ///
+    /// # Parameters
+    /// - `reason`: The reason given by WebReg.
+    ///
+    /// # Returns
+    /// The classified reason.
+    pub fn from_reason(reason: impl Into<String>) -> Self {
+        let reason = reason.into();
+        let lower = reason.to_lowercase();
+        let mentions_waitlist = lower.contains("waitlist") || lower.contains("wait list");
+        if mentions_waitlist
+            && (lower.contains("full")
+                || lower.contains("closed")
+                || lower.contains("capacity")
+                || lower.contains("maximum"))
+        {
+            Self::WaitlistFull(reason)
+        } else if lower.contains("conflict") {
+            Self::TimeConflict(reason)
+        } else if lower.contains("appointment")
+            || lower.contains("not open")
+            || lower.contains("closed")
+        {
+            Self::AppointmentTime(reason)
+        } else if lower.contains("unit") && (lower.contains("limit") || lower.contains("exceed")) {
+            Self::UnitLimit(reason)
+        } else if lower.contains("prerequisite") {
+            Self::Prerequisite(reason)
+        } else if lower.contains("restrict") || lower.contains("approval") {
+            Self::Restriction(reason)
+        } else if lower.contains("already") {
+            Self::AlreadyAdded(reason)
+        } else {
+            Self::Other(reason)
+        }
+    }
+
+    /// Gets the message that WebReg gave.
+    ///
+    /// # Returns
+    /// The message.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Restriction(m)
+            | Self::TimeConflict(m)
+            | Self::AppointmentTime(m)
+            | Self::UnitLimit(m)
+            | Self::Prerequisite(m)
+            | Self::AlreadyAdded(m)
+            | Self::WaitlistFull(m)
+            | Self::Other(m) => m,
+        }
+    }
+}
+
+impl Display for EnrollmentFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+/// The result of one check in a health report.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct HealthCheck {
+    /// The name of the check (e.g., `ping`).
+    pub name: &'static str,
+    /// Whether the check passed.
+    pub ok: bool,
+    /// How long the check took.
+    pub latency: Duration,
+    /// The error that caused the check to fail, if any.
+    pub error: Option<String>,
+}
+
+/// A report on whether the wrapper can currently talk to WebReg. This can be exposed by
+/// services as a readiness probe.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct HealthReport {
+    /// The results of each check that was run.
+    pub checks: Vec<HealthCheck>,
+    /// The name of the person that is logged in, if it could be retrieved.
+    pub account_name: Option<String>,
+    /// When the checks were started.
+    pub checked_at: SystemTime,
+}
+
+impl HealthReport {
+    /// Checks whether every check passed.
+    ///
+    /// # Returns
+    /// `true` if every check passed and `false` otherwise.
+    pub fn is_healthy(&self) -> bool {
+        self.checks.iter().all(|c| c.ok)
+    }
+}
+
+/// Whether an endpoint's response could still be understood by the wrapper.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum Compatibility {
+    /// The response was parsed without any problems.
+    Compatible,
+    /// The response couldn't be parsed, or it contained values that the wrapper doesn't know
+    /// about. This most likely means that WebReg changed the shape of its response.
+    Incompatible,
+    /// The endpoint couldn't be checked because the request itself failed (e.g., your
+    /// session isn't valid, or WebReg is down).
+    Unavailable,
+    /// The endpoint wasn't checked (e.g., because there was no data to check it with).
+    Skipped,
+}
+
+/// The result of checking one endpoint in a compatibility report.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct EndpointCompatibility {
+    /// The name of the endpoint (e.g., `get-class`).
+    pub endpoint: &'static str,
+    /// Whether the endpoint's response could be understood.
+    pub compatibility: Compatibility,
+    /// The error from checking the endpoint, if any.
+    pub error: Option<String>,
+}
+
+impl EndpointCompatibility {
+    /// Creates the result of checking an endpoint from the result of requesting it. Parsing
+    /// errors (including unknown values found with strict parsing) mean that the endpoint is
+    /// incompatible, and any other error means that the endpoint is unavailable.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The name of the endpoint.
+    /// - `res`: The result of requesting the endpoint.
+    ///
+    /// # Returns
+    /// The result of checking the endpoint.
+    pub fn from_result<T>(endpoint: &'static str, res: &Result<T>) -> Self {
+        let compatibility = match res {
+            Ok(_) => Compatibility::Compatible,
+            Err(
+                WrapperError::SerdeError(_)
+                | WrapperError::WrapperParsingError(_)
+                | WrapperError::UnknownValue(_),
+            ) => Compatibility::Incompatible,
+            Err(_) => Compatibility::Unavailable,
+        };
+
+        Self {
+            endpoint,
+            compatibility,
+            error: res.as_ref().err().map(|e| e.to_string()),
+        }
+    }
+}
+
+/// A report on whether WebReg's responses can still be understood by the wrapper. Since WebReg
+/// can change without notice, services can check this (e.g., once every morning) to find out
+/// about changes before their users do.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "camel_case", serde(rename_all = "camelCase"))]
+pub struct CompatibilityReport {
+    /// The version of this library that made the report.
+    pub wrapper_version: &'static str,
+    /// The results of checking each endpoint.
+    pub endpoints: Vec<EndpointCompatibility>,
+    /// When the checks were started.
+    pub checked_at: SystemTime,
+}
+
+impl CompatibilityReport {
+    /// Checks whether no endpoint was found to be incompatible. Endpoints that were
+    /// unavailable or skipped don't count.
+    ///
+    /// # Returns
+    /// `true` if no endpoint was incompatible and `false` otherwise.
+    pub fn is_compatible(&self) -> bool {
+        self.incompatible().next().is_none()
+    }
+
+    /// Gets every endpoint that was found to be incompatible.
+    ///
+    /// # Returns
+    /// An iterator over the incompatible endpoints.
+    pub fn incompatible(&self) -> impl Iterator<Item = &EndpointCompatibility> {
+        self.endpoints
+            .iter()
+            .filter(|e| e.compatibility == Compatibility::Incompatible)
+    }
+}
+
+/// A source of the current time. The wrapper uses this whenever it needs the current time
+/// (e.g., for the cache-busting query parameter, or for timestamps in the data it returns).
+///
+/// By default, the wrapper uses the `SystemClock`. You can implement this trait if you want
+/// to control time yourself (e.g., to simulate a specific time of day in tests).
+pub trait Clock: Send + Sync {
+    /// Gets the current time.
+    ///
+    /// # Returns
+    /// The current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// A clock that uses the system's time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A source of fresh WebReg cookies. You can implement this trait to plug in your own login
+/// flow (e.g., a headless browser, or a script that goes through SSO).
+///
+/// If the wrapper has a cookie provider and a request fails because the session is no
+/// longer valid, the wrapper will ask the provider for new cookies, use them from then on,
+/// and retry the failed request once.
+pub trait CookieProvider: Send + Sync {
+    /// Gets fresh cookies for WebReg.
+    ///
+    /// # Returns
+    /// The new cookies, or the error that occurred while getting them.
+    fn refresh_cookies(&self) -> BoxFuture<'_, Result<String>>;
+}
+
+/// A request for an `HttpBackend` to send. This is independent of any HTTP library, so that
+/// backends don't need to know about `reqwest`. Every request that the wrapper makes is put
+/// together as one of these, whether or not it's sent with a backend.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HttpRequest {
+    /// The request method (e.g., `GET` or `POST`).
+    pub method: String,
+    /// The full URL, including the query string.
+    pub url: String,
+    /// Every header (including the cookies), in the order that they were set.
+    pub headers: Vec<(String, String)>,
+    /// The body (e.g., `section=079911&termcode=FA23`), if any.
+    pub body: Option<Vec<u8>>,
+    /// How long the request can take before it should time out, if there's a limit.
+    pub timeout: Option<Duration>,
+}
+
+/// A response from an `HttpBackend` (or the wrapper's client). The wrapper processes every
+/// response in this form.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HttpResponse {
+    /// The status code.
+    pub status: u16,
+    /// The URL that the response came from. This should be the final URL after any
+    /// redirects, since a redirect away from WebReg means that the session has expired.
+    pub url: String,
+    /// The response headers.
+    pub headers: Vec<(String, String)>,
+    /// The body.
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Whether the status code is a success (`2xx`) status code.
+    ///
+    /// # Returns
+    /// Whether the request succeeded.
+    pub(crate) fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Gets the body as text. Anything that isn't valid UTF-8 is replaced.
+    ///
+    /// # Returns
+    /// The body.
+    pub(crate) fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// An error from an `HttpBackend`. The kind of error decides whether the wrapper retries the
+/// request.
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum HttpBackendError {
+    /// The request timed out. This is retried.
+    #[error("The request timed out: {0}")]
+    Timeout(String),
+    /// The backend couldn't connect to WebReg. This is retried.
+    #[error("Could not connect: {0}")]
+    Connect(String),
+    /// Any other error. This isn't retried.
+    #[error("{0}")]
+    Other(String),
+}
+
+/// Sends the wrapper's requests in place of its `reqwest` client. This can be used to plug in
+/// another HTTP library for the actual I/O, or a test double (see `wrapper::mock::MockWebReg`)
+/// so that code using the wrapper can be run without a live WebReg session.
+///
+/// Requests still go through everything else that the wrapper does (e.g., pacing, rate
+/// limiting, quotas, and retries), and the responses are processed exactly as if they came
+/// from WebReg.
+///
+/// The wrapper puts every request together as an `HttpRequest` and processes every response
+/// as an `HttpResponse`, so a backend sees exactly what the client would have sent. Errors from
+/// the backend are reported as `WrapperError::BackendError`.
+///
+/// With a backend, the wrapper doesn't need `reqwest` at all. Turning off the default
+/// `reqwest` feature removes the dependency on it (along with the wrapper's client), in which
+/// case a backend must be given to `WebRegWrapperBuilder::with_backend`.
+///
+/// A blocking library (e.g., `ureq`) can be used by doing the work inside of the returned
+/// future, although this will block whatever runtime is driving the wrapper.
+pub trait HttpBackend: Send + Sync {
+    /// Sends a request, following any redirects.
+    ///
+    /// # Parameters
+    /// - `request`: The request, with every header (including the cookies) already set.
+    ///
+    /// # Returns
+    /// The response, or the error that occurred while sending the request. Responses with an
+    /// unsuccessful status code should be returned as responses, not errors.
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, HttpBackendError>>;
+}
+
+impl<T: HttpBackend + ?Sized> HttpBackend for Arc<T> {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, HttpBackendError>> {
+        (**self).send(request)
+    }
+}
@@ -1,7 +1,25 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::time::SystemTime;
 
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use url::{form_urlencoded, Url};
+
 use crate::raw_types::RawWebRegMeeting;
-use crate::types::MeetingDay;
+use crate::types;
+use crate::types::{
+    AttemptRecord, AttemptSink, CourseSection, EnrollmentStatus, ExportFields, FamilyAvailability,
+    FieldChange, FinalExam, InstructorInfo, InstructorInfoProvider, Meeting, MeetingDay,
+    ScheduledSection, SectionChange, WrapperError,
+};
+#[cfg(feature = "chrono")]
+use crate::types::{Clock, Event, TermDates, TimeType};
 
 /// Gets the meeting type (e.g. Lecture, Final Exam, Discussion, etc.) and the meeting time from
 /// an arbitrary `WebRegMeeting`.
@@ -15,7 +33,7 @@ use crate::types::MeetingDay;
 /// - the second element is/are the day(s) that this meeting occurs
 #[inline]
 pub fn parse_meeting_type_date(w_meeting: &RawWebRegMeeting) -> (&str, MeetingDay) {
-    let special_meeting = w_meeting.special_meeting.trim();
+    let special_meeting = w_meeting.special_meeting.as_str();
     if !special_meeting.is_empty() && special_meeting != "TBA" {
         assert!(!w_meeting.section_start_date.is_empty());
         return (
@@ -24,8 +42,8 @@ pub fn parse_meeting_type_date(w_meeting: &RawWebRegMeeting) -> (&str, MeetingDa
         );
     }
 
-    let regular_meeting = w_meeting.meeting_type.trim();
-    let day_code = w_meeting.day_code.trim();
+    let regular_meeting = w_meeting.meeting_type.as_str();
+    let day_code = w_meeting.day_code.as_str();
     assert!(day_code.chars().all(|x| x.is_numeric()));
 
     if day_code.is_empty() {
@@ -168,18 +186,140 @@ pub fn get_formatted_course_num(course_code: &str) -> String {
     }
 }
 
-/// Gets the current epoch time.
+/// Splits a course, as a user might type it, into its subject code and course number. This is
+/// tolerant of case, spacing, and separators, so `cse100`, `CSE 100`, `cse-100`, and
+/// `Math 20D` are all accepted.
+///
+/// # Parameters
+/// - `input`: The course (e.g., `cse-100`).
+///
+/// # Returns
+/// The subject code and course number, both in uppercase (e.g., `("CSE", "100")`), or an
+/// error if the input doesn't look like a course.
+///
+/// # Example
+/// ```rust
+/// use webweg::util::parse_course_identifier;
+///
+/// assert_eq!(
+///     ("MATH".to_string(), "20D".to_string()),
+///     parse_course_identifier("Math 20d").unwrap()
+/// );
+/// assert!(parse_course_identifier("100 CSE").is_err());
+/// ```
+pub fn parse_course_identifier(input: &str) -> types::Result<(String, String)> {
+    let compact: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '_' | '.' | ':' | '/'))
+        .collect::<String>()
+        .to_uppercase();
+
+    let subj_len = compact
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(compact.len());
+    let (subject, number) = compact.split_at(subj_len);
+    let num_digits = number
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(number.len());
+
+    if !(1..=4).contains(&subject.len())
+        || !(1..=3).contains(&num_digits)
+        || number.len() - num_digits > 2
+        || !number[num_digits..]
+            .chars()
+            .all(|c| c.is_ascii_alphabetic())
+    {
+        return Err(WrapperError::InputError(
+            "course",
+            "expected a subject code followed by a course number (e.g., CSE 100)",
+        ));
+    }
+
+    Ok((subject.to_string(), number.to_string()))
+}
+
+/// Gets the epoch time of the given time.
+///
+/// # Parameters
+/// - `now`: The time.
 ///
 /// # Returns
-/// The current time.
+/// The epoch time, in milliseconds.
 #[inline(always)]
-pub(crate) fn get_epoch_time() -> u128 {
-    SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
+pub(crate) fn get_epoch_time(now: SystemTime) -> u128 {
+    now.duration_since(SystemTime::UNIX_EPOCH)
         .unwrap()
         .as_millis()
 }
 
+thread_local! {
+    /// The base URLs that `build_url` has parsed, keyed by the unparsed base URL, along with
+    /// the buffer that it writes each query into.
+    static URL_CACHE: RefCell<(HashMap<String, Url>, String)> =
+        RefCell::new((HashMap::new(), String::with_capacity(128)));
+}
+
+/// Builds a URL from a base URL and query parameters, optionally adding the `_`
+/// (cache-busting) parameter as the last query parameter.
+///
+/// Each base URL is only parsed once (per thread), and the query is written into a buffer
+/// that's reused between calls, so building a URL only needs to parse the new query. Since
+/// every base URL is kept, this is meant for a small, fixed set of base URLs (like WebReg's
+/// endpoints).
+///
+/// # Parameters
+/// - `base`: The base URL. This may already have a query (or end with `?`).
+/// - `params`: The query parameters.
+/// - `cache_buster`: The time to use for the `_` parameter, or `None` to leave it out.
+///
+/// # Returns
+/// The URL, or an error if the URL is malformed.
+///
+/// # Example
+/// ```rust
+/// use webweg::util::build_url;
+///
+/// let url = build_url(
+///     "https://act.ucsd.edu/webreg2/svc/wradapter/secure/search-by-all?",
+///     &[("subjcode", "CSE"), ("termcode", "FA23")],
+///     None,
+/// )
+/// .unwrap();
+/// assert_eq!(Some("subjcode=CSE&termcode=FA23"), url.query());
+/// ```
+pub fn build_url(
+    base: &str,
+    params: &[(&str, &str)],
+    cache_buster: Option<SystemTime>,
+) -> types::Result<Url> {
+    URL_CACHE.with(|cache| {
+        let (bases, buf) = &mut *cache.borrow_mut();
+        let mut url = match bases.get(base) {
+            Some(url) => url.clone(),
+            None => {
+                let url = Url::parse(base)?;
+                bases.insert(base.to_owned(), url.clone());
+                url
+            }
+        };
+
+        buf.clear();
+        buf.push_str(url.query().unwrap_or_default());
+        form_urlencoded::Serializer::for_suffix(&mut *buf, 0).extend_pairs(params);
+        if let Some(now) = cache_buster {
+            if !buf.is_empty() {
+                buf.push('&');
+            }
+
+            // Writing to a string can't fail.
+            let _ = write!(buf, "_={}", get_epoch_time(now));
+        }
+
+        url.set_query(Some(buf));
+        Ok(url)
+    })
+}
+
 /// Gets the instructor's names.
 ///
 /// # Parameters
@@ -221,6 +361,865 @@ where
     all_inst
 }
 
+/// Gets the instructors that are assigned to every meeting that has at least one instructor.
+///
+/// # Parameters
+/// - `meetings`: The meetings.
+///
+/// # Returns
+/// The instructors common to those meetings, sorted and without duplicates.
+pub(crate) fn get_section_instructors(meetings: &[Meeting]) -> Vec<String> {
+    let mut with_instructors = meetings.iter().filter(|m| !m.instructors.is_empty());
+    let Some(first) = with_instructors.next() else {
+        return vec![];
+    };
+
+    let rest = with_instructors.collect::<Vec<_>>();
+    get_all_instructors(
+        first
+            .instructors
+            .iter()
+            .filter(|i| rest.iter().all(|m| m.instructors.contains(i)))
+            .cloned(),
+    )
+}
+
+/// Normalizes an instructor's name so that it can be matched against names from other
+/// sources. WebReg gives names in the form `Last, First Middle`; this turns the name into
+/// `first last`, in lowercase, without any middle names or punctuation.
+///
+/// # Parameters
+/// - `name`: The instructor's name.
+///
+/// # Returns
+/// The normalized name.
+///
+/// # Example
+/// ```rust
+/// use webweg::util::normalize_instructor_name;
+///
+/// assert_eq!("john smith", normalize_instructor_name("Smith, John A."));
+/// assert_eq!("john smith", normalize_instructor_name("John Smith"));
+/// ```
+pub fn normalize_instructor_name(name: &str) -> String {
+    let clean = |s: &str| -> Vec<String> {
+        s.split_whitespace()
+            .map(|w| {
+                w.chars()
+                    .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '\'')
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .filter(|w| !w.is_empty())
+            .collect()
+    };
+
+    let (first, last) = match name.split_once(',') {
+        Some((last, rest)) => (clean(rest).into_iter().next(), clean(last).join(" ")),
+        None => {
+            let mut parts = clean(name);
+            let last = parts.pop().unwrap_or_default();
+            (parts.into_iter().next(), last)
+        }
+    };
+
+    match first {
+        Some(first) if !last.is_empty() => format!("{first} {last}"),
+        Some(first) => first,
+        None => last,
+    }
+}
+
+/// Attaches extra information about each section's instructors from the given provider. The
+/// information is stored in each section's `instructor_info` field.
+///
+/// Each instructor is looked up by their normalized name (see `normalize_instructor_name`),
+/// and each instructor is only looked up once.
+///
+/// # Parameters
+/// - `sections`: The sections.
+/// - `provider`: The source of the information.
+///
+/// # Returns
+/// The number of instructors (across all sections) that information was attached to.
+pub fn enrich_sections(
+    sections: &mut [CourseSection],
+    provider: &dyn InstructorInfoProvider,
+) -> usize {
+    let mut seen: HashMap<String, Option<InstructorInfo>> = HashMap::new();
+    let mut num_enriched = 0;
+    for section in sections {
+        for instructor in &section.all_instructors {
+            let normalized = normalize_instructor_name(instructor);
+            if normalized.is_empty() {
+                continue;
+            }
+
+            let info = seen
+                .entry(normalized)
+                .or_insert_with_key(|name| provider.lookup(name));
+            if let Some(info) = info {
+                section
+                    .instructor_info
+                    .insert(instructor.to_string(), info.clone());
+                num_enriched += 1;
+            }
+        }
+    }
+
+    num_enriched
+}
+
+/// Combines the seat counts of every section in each section family. For example, the
+/// sections `A01`, `A02`, and `A03` would be combined into one entry for the `A` family.
+///
+/// # Parameters
+/// - `sections`: The sections. These would usually come from
+///   `WrapperTermRequest::get_course_info` or `WrapperTermRequest::get_enrollment_count`.
+///
+/// # Returns
+/// The seat counts for each family, sorted by family.
+pub fn summarize_families(sections: &[CourseSection]) -> Vec<FamilyAvailability> {
+    let mut families: BTreeMap<String, FamilyAvailability> = BTreeMap::new();
+    for section in sections {
+        let family = section
+            .family()
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| section.section_code.clone());
+
+        let entry = families
+            .entry(family.clone())
+            .or_insert_with(|| FamilyAvailability {
+                family,
+                section_codes: vec![],
+                total_seats: 0,
+                enrolled_ct: 0,
+                waitlist_ct: 0,
+                available_seats: 0,
+                sections_with_seats: 0,
+            });
+
+        entry.section_codes.push(section.section_code.clone());
+        entry.total_seats += section.total_seats;
+        entry.enrolled_ct += section.enrolled_ct;
+        entry.waitlist_ct += section.waitlist_ct;
+        entry.available_seats += section.available_seats;
+        if section.has_seats() {
+            entry.sections_with_seats += 1;
+        }
+    }
+
+    families.into_values().collect()
+}
+
+/// Puts the given sections into a canonical order, so that two scrapes of the same data
+/// always look exactly the same once serialized. In particular,
+/// - sections are sorted by course, then section code, then section ID,
+/// - each section's meetings are sorted by meeting type, then day, then time, then location,
+/// - and all instructor lists are sorted.
+///
+/// # Parameters
+/// - `sections`: The sections to canonicalize.
+pub fn canonicalize_sections(sections: &mut [CourseSection]) {
+    for section in sections.iter_mut() {
+        section.all_instructors.sort();
+        for meeting in &mut section.meetings {
+            meeting.instructors.sort();
+        }
+
+        section.meetings.sort_by(|a, b| {
+            a.meeting_type
+                .cmp(&b.meeting_type)
+                .then_with(|| {
+                    meeting_day_key(&a.meeting_days).cmp(&meeting_day_key(&b.meeting_days))
+                })
+                .then_with(|| (a.start_hr, a.start_min).cmp(&(b.start_hr, b.start_min)))
+                .then_with(|| (a.end_hr, a.end_min).cmp(&(b.end_hr, b.end_min)))
+                .then_with(|| (&a.building, &a.room).cmp(&(&b.building, &b.room)))
+        });
+    }
+
+    sections.sort_by(|a, b| {
+        (&a.subj_course_id, &a.section_code, &a.section_id).cmp(&(
+            &b.subj_course_id,
+            &b.section_code,
+            &b.section_id,
+        ))
+    });
+}
+
+/// Serializes the given sections to pretty-printed JSON in canonical form (see
+/// `canonicalize_sections`). This is useful for archiving scrapes, since the output for
+/// unchanged data is byte-for-byte identical and diffs between archives only show real
+/// changes.
+///
+/// # Parameters
+/// - `sections`: The sections to serialize. These are not modified.
+///
+/// # Returns
+/// The JSON, or an error if serialization failed.
+pub fn to_canonical_json(sections: &[CourseSection]) -> types::Result<String> {
+    let mut sections = sections.to_vec();
+    canonicalize_sections(&mut sections);
+    serde_json::to_string_pretty(&sections).map_err(WrapperError::SerdeError)
+}
+
+/// Converts a section to JSON, keeping only the fields selected by `fields`.
+///
+/// # Parameters
+/// - `section`: The section to convert.
+/// - `fields`: The fields to keep.
+///
+/// # Returns
+/// The JSON object, or an error if serialization failed.
+pub fn project_section(
+    section: &CourseSection,
+    fields: &ExportFields,
+) -> types::Result<serde_json::Value> {
+    let mut value = serde_json::to_value(section).map_err(WrapperError::SerdeError)?;
+    let Some(obj) = value.as_object_mut() else {
+        return Ok(value);
+    };
+
+    let mut removed = vec![];
+    if !fields.meetings {
+        removed.push("meetings");
+    }
+    if !fields.instructors {
+        removed.extend(["all_instructors", "instructor_info"]);
+    }
+    if !fields.seats {
+        removed.extend([
+            "available_seats",
+            "available_seats_raw",
+            "enrolled_ct",
+            "total_seats",
+            "waitlist_ct",
+        ]);
+    }
+    if !fields.section_notes {
+        removed.push("section_notes");
+    }
+    if !fields.visibility {
+        removed.push("is_visible");
+    }
+
+    for key in removed {
+        obj.remove(&serde_key(key));
+    }
+
+    Ok(value)
+}
+
+/// Serializes the given sections to pretty-printed JSON, keeping only the fields selected by
+/// `fields`.
+///
+/// # Parameters
+/// - `sections`: The sections to serialize.
+/// - `fields`: The fields to keep.
+///
+/// # Returns
+/// The JSON, or an error if serialization failed.
+pub fn sections_to_json(
+    sections: &[CourseSection],
+    fields: &ExportFields,
+) -> types::Result<String> {
+    let values = sections
+        .iter()
+        .map(|s| project_section(s, fields))
+        .collect::<types::Result<Vec<_>>>()?;
+    serde_json::to_string_pretty(&values).map_err(WrapperError::SerdeError)
+}
+
+/// Serializes the given sections to CSV (with a header row), keeping only the columns selected
+/// by `fields`. Fields with multiple values (instructors, notes, and meetings) are joined with
+/// `; ` into one column.
+///
+/// # Parameters
+/// - `sections`: The sections to serialize.
+/// - `fields`: The columns to keep.
+///
+/// # Returns
+/// The CSV.
+pub fn sections_to_csv(sections: &[CourseSection], fields: &ExportFields) -> String {
+    let mut header = vec!["subj_course_id", "section_id", "section_code"];
+    if fields.instructors {
+        header.push("all_instructors");
+    }
+    if fields.seats {
+        header.extend([
+            "available_seats",
+            "available_seats_raw",
+            "enrolled_ct",
+            "total_seats",
+            "waitlist_ct",
+        ]);
+    }
+    if fields.section_notes {
+        header.push("section_notes");
+    }
+    if fields.visibility {
+        header.push("is_visible");
+    }
+    if fields.meetings {
+        header.push("meetings");
+    }
+
+    let mut csv = header
+        .iter()
+        .map(|h| serde_key(h))
+        .collect::<Vec<_>>()
+        .join(",");
+    csv.push('\n');
+
+    for section in sections {
+        let mut row = vec![
+            section.subj_course_id.clone(),
+            section.section_id.clone(),
+            section.section_code.clone(),
+        ];
+        if fields.instructors {
+            row.push(section.all_instructors.join("; "));
+        }
+        if fields.seats {
+            row.extend(
+                [
+                    section.available_seats,
+                    section.available_seats_raw,
+                    section.enrolled_ct,
+                    section.total_seats,
+                    section.waitlist_ct,
+                ]
+                .map(|n| n.to_string()),
+            );
+        }
+        if fields.section_notes {
+            row.push(section.section_notes.join("; "));
+        }
+        if fields.visibility {
+            row.push(section.is_visible.to_string());
+        }
+        if fields.meetings {
+            row.push(
+                section
+                    .meetings
+                    .iter()
+                    .map(|m| m.to_string().trim().to_string())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            );
+        }
+
+        csv.push_str(
+            &row.iter()
+                .map(|col| escape_csv(col))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// Escapes a value so that it can be used as one column in a CSV file.
+///
+/// # Parameters
+/// - `value`: The value.
+///
+/// # Returns
+/// The escaped value.
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Gets the name that a field is serialized with, which depends on whether the `camel_case`
+/// feature is enabled.
+///
+/// # Parameters
+/// - `name`: The field name, in snake case.
+///
+/// # Returns
+/// The serialized name.
+fn serde_key(name: &str) -> String {
+    if cfg!(feature = "camel_case") {
+        let mut parts = name.split('_');
+        let mut key = parts.next().unwrap_or_default().to_string();
+        for part in parts {
+            let mut chars = part.chars();
+            if let Some(c) = chars.next() {
+                key.push(c.to_ascii_uppercase());
+                key.push_str(chars.as_str());
+            }
+        }
+        key
+    } else {
+        name.to_string()
+    }
+}
+
+/// Gets a key that can be used to order meeting days. Repeated meetings come first (ordered by
+/// their days of the week), then one-time meetings (ordered by date), then meetings without
+/// any days.
+///
+/// # Parameters
+/// - `days`: The meeting days.
+///
+/// # Returns
+/// The key.
+fn meeting_day_key(days: &MeetingDay) -> (u8, Vec<usize>, &str) {
+    match days {
+        MeetingDay::Repeated(d) => (
+            0,
+            d.iter()
+                .map(|day| DAYS.iter().position(|x| x == day).unwrap_or(DAYS.len()))
+                .collect(),
+            "",
+        ),
+        MeetingDay::OneTime(date) => (1, vec![], date.as_str()),
+        MeetingDay::None => (2, vec![], ""),
+    }
+}
+
+/// Writes items as JSON Lines (one JSON object per line), optionally rotating to a new file
+/// once the current file reaches a certain size.
+///
+/// Files are named after the given path. For example, if the path is `sections.jsonl`, the
+/// files will be `sections.jsonl`, `sections.1.jsonl`, `sections.2.jsonl`, and so on. An item
+/// is never split across files.
+pub struct JsonLinesWriter {
+    path: PathBuf,
+    max_bytes: Option<u64>,
+    file: BufWriter<File>,
+    bytes_written: u64,
+    files: Vec<PathBuf>,
+}
+
+impl JsonLinesWriter {
+    /// Creates a new writer, creating (or truncating) the first file.
+    ///
+    /// # Parameters
+    /// - `path`: The path to the first file.
+    /// - `max_bytes`: The size that a file can grow to before the writer moves on to the next
+    ///   file, or `None` if everything should be written to one file. A file can go over this
+    ///   size if a single item is larger than it.
+    ///
+    /// # Returns
+    /// The writer, or an error if the file couldn't be created.
+    pub fn new(path: impl AsRef<Path>, max_bytes: Option<u64>) -> types::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        Ok(Self {
+            file: BufWriter::new(File::create(&path)?),
+            files: vec![path.clone()],
+            path,
+            max_bytes,
+            bytes_written: 0,
+        })
+    }
+
+    /// Writes one item as a line of JSON.
+    ///
+    /// # Parameters
+    /// - `item`: The item to write.
+    ///
+    /// # Returns
+    /// Nothing, or an error if the item couldn't be serialized or written.
+    pub fn write<T: Serialize + ?Sized>(&mut self, item: &T) -> types::Result<()> {
+        let mut line = serde_json::to_vec(item)?;
+        line.push(b'\n');
+
+        let len = line.len() as u64;
+        if self
+            .max_bytes
+            .is_some_and(|max| self.bytes_written > 0 && self.bytes_written + len > max)
+        {
+            self.rotate()?;
+        }
+
+        self.file.write_all(&line)?;
+        self.bytes_written += len;
+        Ok(())
+    }
+
+    /// Flushes anything that hasn't been written to the current file yet.
+    ///
+    /// # Returns
+    /// Nothing, or an error if flushing failed.
+    pub fn flush(&mut self) -> types::Result<()> {
+        Ok(self.file.flush()?)
+    }
+
+    /// Flushes the current file and gets every file that this writer has written to.
+    ///
+    /// # Returns
+    /// The paths of the files, in the order that they were written, or an error if flushing
+    /// failed.
+    pub fn finish(mut self) -> types::Result<Vec<PathBuf>> {
+        self.flush()?;
+        Ok(self.files)
+    }
+
+    /// Moves on to the next file.
+    ///
+    /// # Returns
+    /// Nothing, or an error if the next file couldn't be created.
+    fn rotate(&mut self) -> types::Result<()> {
+        self.file.flush()?;
+
+        let stem = self
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let name = match self.path.extension() {
+            Some(ext) => format!("{stem}.{}.{}", self.files.len(), ext.to_string_lossy()),
+            None => format!("{stem}.{}", self.files.len()),
+        };
+        let next = self.path.with_file_name(name);
+
+        self.file = BufWriter::new(File::create(&next)?);
+        self.files.push(next);
+        self.bytes_written = 0;
+        Ok(())
+    }
+}
+
+/// An attempt sink that appends each attempt to a file as a line of JSON (see
+/// `JsonLinesWriter`). Each attempt is flushed as soon as it's recorded, so the file is up to
+/// date even if your program crashes.
+///
+/// Errors from writing to the file are ignored, since they shouldn't stop enrollment.
+pub struct JsonLinesAttemptSink {
+    writer: Mutex<JsonLinesWriter>,
+}
+
+impl JsonLinesAttemptSink {
+    /// Creates a new sink, creating (or truncating) the file.
+    ///
+    /// # Parameters
+    /// - `path`: The path to the file.
+    ///
+    /// # Returns
+    /// The sink, or an error if the file couldn't be created.
+    pub fn new(path: impl AsRef<Path>) -> types::Result<Self> {
+        Ok(Self {
+            writer: Mutex::new(JsonLinesWriter::new(path, None)?),
+        })
+    }
+}
+
+impl AttemptSink for JsonLinesAttemptSink {
+    fn record(&self, attempt: &AttemptRecord) {
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        if writer.write(attempt).is_ok() {
+            let _ = writer.flush();
+        }
+    }
+}
+
+/// Writes everything from a stream of results (e.g., sections from a scraper) to the given
+/// JSON Lines writer as it arrives, without collecting the stream first. Each result from the
+/// stream can contain several items (e.g., all sections of a course), each of which is
+/// written as its own line.
+///
+/// Note that writing to the file is blocking.
+///
+/// # Parameters
+/// - `stream`: The stream.
+/// - `writer`: The writer.
+///
+/// # Returns
+/// The number of items written, or the first error from either the stream or the writer.
+/// Anything written before the error stays written.
+///
+/// # Example
+/// ```rust,no_run
+/// use futures::{stream, StreamExt};
+/// use reqwest::Client;
+/// use webweg::util::{write_json_lines, JsonLinesWriter};
+/// use webweg::wrapper::WebRegWrapper;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+/// let req = wrapper.req("FA23").parsed();
+///
+/// let courses = stream::iter([("CSE", "100"), ("CSE", "101")])
+///     .then(|course| req.get_course_info(course));
+/// let mut writer = JsonLinesWriter::new("sections.jsonl", Some(10 * 1024 * 1024)).unwrap();
+/// match write_json_lines(courses, &mut writer).await {
+///     Ok(count) => println!("Wrote {count} sections"),
+///     Err(e) => eprintln!("An error occurred! {e}"),
+/// }
+/// writer.finish().unwrap();
+/// # }
+/// ```
+pub async fn write_json_lines<S, I>(stream: S, writer: &mut JsonLinesWriter) -> types::Result<usize>
+where
+    S: Stream<Item = types::Result<I>>,
+    I: IntoIterator,
+    I::Item: Serialize,
+{
+    futures::pin_mut!(stream);
+    let mut count = 0;
+    while let Some(items) = stream.next().await {
+        for item in items? {
+            writer.write(&item)?;
+            count += 1;
+        }
+    }
+
+    writer.flush()?;
+    Ok(count)
+}
+
+/// Compares a saved snapshot of sections against the sections as they are now. Sections are
+/// matched by their section IDs.
+///
+/// # Parameters
+/// - `baseline`: The sections in the snapshot.
+/// - `current`: The sections now.
+///
+/// # Returns
+/// The changes, sorted by section ID. Sections that didn't change aren't included.
+pub fn diff_sections(baseline: &[CourseSection], current: &[CourseSection]) -> Vec<SectionChange> {
+    let mut old: BTreeMap<&str, &CourseSection> = baseline
+        .iter()
+        .map(|sec| (sec.section_id.as_str(), sec))
+        .collect();
+
+    let mut changes: Vec<(&str, SectionChange)> = vec![];
+    for sec in current {
+        let id = sec.section_id.as_str();
+        match old.remove(id) {
+            None => changes.push((id, SectionChange::Added(sec.clone()))),
+            Some(prev) if prev == sec => {}
+            Some(prev) => {
+                let fields = changed_fields(prev, sec);
+                if !fields.is_empty() {
+                    changes.push((
+                        id,
+                        SectionChange::Modified {
+                            section_id: sec.section_id.clone(),
+                            fields,
+                        },
+                    ));
+                }
+            }
+        }
+    }
+
+    changes.extend(
+        old.into_iter()
+            .map(|(id, sec)| (id, SectionChange::Removed(sec.clone()))),
+    );
+    changes.sort_by_key(|(id, _)| *id);
+    changes.into_iter().map(|(_, change)| change).collect()
+}
+
+/// Gets the fields that differ between two versions of a section.
+///
+/// # Parameters
+/// - `old`: The old version.
+/// - `new`: The new version.
+///
+/// # Returns
+/// The fields that differ, in the order that they're serialized.
+fn changed_fields(old: &CourseSection, new: &CourseSection) -> Vec<FieldChange> {
+    let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(mut new))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return vec![];
+    };
+
+    old.into_iter()
+        .filter_map(|(field, old)| {
+            let new = new.remove(&field).unwrap_or(serde_json::Value::Null);
+            (old != new).then_some(FieldChange { field, old, new })
+        })
+        .collect()
+}
+
+/// Compares a snapshot of sections saved as JSON Lines (e.g., with `JsonLinesWriter`) against
+/// the sections as they are now. This is useful for reports like "what changed since
+/// yesterday" that run on a schedule, without keeping a watcher running.
+///
+/// Note that reading the file is blocking.
+///
+/// # Parameters
+/// - `path`: The path to the snapshot. Empty lines are skipped.
+/// - `current`: The sections now.
+///
+/// # Returns
+/// The changes (see `diff_sections`), or an error if the snapshot couldn't be read or parsed.
+///
+/// # Example
+/// ```rust,no_run
+/// use reqwest::Client;
+/// use webweg::util::diff_against_baseline;
+/// use webweg::wrapper::WebRegWrapper;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+/// let sections = wrapper
+///     .req("FA23")
+///     .parsed()
+///     .get_course_info(("CSE", "100"))
+///     .await
+///     .unwrap();
+///
+/// for change in diff_against_baseline("cse100.jsonl", &sections).unwrap() {
+///     println!("{change:?}");
+/// }
+/// # }
+/// ```
+pub fn diff_against_baseline(
+    path: impl AsRef<Path>,
+    current: &[CourseSection],
+) -> types::Result<Vec<SectionChange>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut baseline = vec![];
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            baseline.push(serde_json::from_str::<CourseSection>(&line)?);
+        }
+    }
+
+    Ok(diff_sections(&baseline, current))
+}
+
+/// Removes personally identifying information from a raw WebReg response, so that it can be
+/// shared (e.g., contributed as a test fixture when a response fails to parse). Everything else,
+/// including the structure of the response and the instructor names, is left alone, so the
+/// result still parses the same way.
+///
+/// Specifically:
+/// - PIDs (in `PERSON_ID`, and after the `;` in `PERSON_FULL_NAME`) are replaced with fake PIDs
+///   of the same length. The same PID is always replaced with the same fake PID, so instructors
+///   are still grouped correctly.
+/// - internal reference IDs (`FK_PCH_INTRL_REFID`) are replaced with sequential numbers.
+/// - the names and locations of your events are replaced with generic ones.
+///
+/// # Parameters
+/// - `json`: The response, as returned by WebReg.
+///
+/// # Returns
+/// The anonymized response, as pretty-printed JSON, or an error if the response isn't JSON.
+///
+/// # Example
+/// ```rust
+/// use webweg::util::anonymize_response;
+///
+/// let json = r#"[{"PERSON_FULL_NAME": "Doe, Jane ;A12345678", "PERSON_ID": "A87654321"}]"#;
+/// let anonymized = anonymize_response(json).unwrap();
+/// assert!(anonymized.contains("Doe, Jane ;A0000000"));
+/// assert!(!anonymized.contains("A12345678"));
+/// assert!(!anonymized.contains("A87654321"));
+/// ```
+pub fn anonymize_response(json: &str) -> types::Result<String> {
+    let mut value = serde_json::from_str::<serde_json::Value>(json)?;
+    let mut pids = HashMap::new();
+    let mut ref_ids = HashMap::new();
+    let mut events = 0;
+    anonymize_value(&mut value, &mut pids, &mut ref_ids, &mut events);
+    Ok(serde_json::to_string_pretty(&value)?)
+}
+
+/// Gets the fake PID for a PID, making a new one if the PID hasn't been seen yet. The fake PID
+/// keeps the first character (e.g., `A`) and the length of the original.
+///
+/// # Parameters
+/// - `pids`: The fake PID for each PID that was already seen.
+/// - `pid`: The PID.
+///
+/// # Returns
+/// The fake PID.
+fn fake_pid(pids: &mut HashMap<String, String>, pid: &str) -> String {
+    let next = pids.len() + 1;
+    pids.entry(pid.to_string())
+        .or_insert_with(|| {
+            let width = pid.len().saturating_sub(1).max(1);
+            format!("{}{next:0width$}", pid.chars().next().unwrap_or('A'))
+        })
+        .clone()
+}
+
+/// Anonymizes a JSON value in place. See `anonymize_response`.
+///
+/// # Parameters
+/// - `value`: The value.
+/// - `pids`: The fake PID for each PID that was already seen.
+/// - `ref_ids`: The fake ID for each internal reference ID that was already seen.
+/// - `events`: The number of events that were already seen.
+fn anonymize_value(
+    value: &mut serde_json::Value,
+    pids: &mut HashMap<String, String>,
+    ref_ids: &mut HashMap<String, u64>,
+    events: &mut usize,
+) {
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                anonymize_value(item, pids, ref_ids, events);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            // Only events have a timestamp.
+            if map.contains_key("TIME_STAMP") {
+                *events += 1;
+                for (key, name) in [("DESCRIPTION", "Event"), ("LOCATION", "Location")] {
+                    if map.contains_key(key) {
+                        map.insert(key.into(), format!("{name} {events}").into());
+                    }
+                }
+            }
+
+            for (key, field) in map.iter_mut() {
+                let replacement: Option<serde_json::Value> = match (key.as_str(), &*field) {
+                    ("PERSON_ID", serde_json::Value::String(pid)) if !pid.trim().is_empty() => {
+                        Some(fake_pid(pids, pid.trim()).into())
+                    }
+                    // This is in the form `name1 ;pid1:name2 ;pid2:...`.
+                    ("PERSON_FULL_NAME", serde_json::Value::String(names))
+                        if names.contains(';') =>
+                    {
+                        Some(
+                            names
+                                .split(':')
+                                .map(|part| match part.split_once(';') {
+                                    Some((name, pid)) if !pid.trim().is_empty() => {
+                                        format!("{name};{}", fake_pid(pids, pid.trim()))
+                                    }
+                                    _ => part.to_string(),
+                                })
+                                .collect::<Vec<_>>()
+                                .join(":")
+                                .into(),
+                        )
+                    }
+                    ("FK_PCH_INTRL_REFID", id) if !id.is_null() => {
+                        let next = ref_ids.len() as u64 + 1;
+                        Some((*ref_ids.entry(id.to_string()).or_insert(next)).into())
+                    }
+                    _ => None,
+                };
+
+                match replacement {
+                    Some(replacement) => *field = replacement,
+                    None => anonymize_value(field, pids, ref_ids, events),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Formats multiple course inputs into a string that WebReg can recognize
 /// for its search queries.
 ///
@@ -283,3 +1282,469 @@ pub fn format_multiple_courses<T: AsRef<str>>(query: &[T]) -> String {
         .join(";")
         .to_uppercase()
 }
+
+/// Gets the final exams for every section in a schedule. Only final exams (`FI` meetings)
+/// that have a date are included.
+///
+/// # Parameters
+/// - `schedule`: The schedule.
+///
+/// # Returns
+/// The final exams, sorted by when they start.
+pub fn get_final_exams(schedule: &[ScheduledSection]) -> Vec<FinalExam> {
+    let mut exams = schedule
+        .iter()
+        .flat_map(|sec| {
+            sec.meetings
+                .iter()
+                .filter(|m| m.meeting_type == "FI")
+                .filter_map(move |m| match &m.meeting_days {
+                    MeetingDay::OneTime(date) => Some(FinalExam {
+                        section_id: sec.section_id.clone(),
+                        subject_code: sec.subject_code.clone(),
+                        course_code: sec.course_code.clone(),
+                        course_title: sec.course_title.clone(),
+                        section_code: sec.section_code.clone(),
+                        enrolled_status: sec.enrolled_status.clone(),
+                        date: date.clone(),
+                        start_hr: m.start_hr,
+                        start_min: m.start_min,
+                        end_hr: m.end_hr,
+                        end_min: m.end_min,
+                        building: m.building.clone(),
+                        room: m.room.clone(),
+                    }),
+                    _ => None,
+                })
+        })
+        .collect::<Vec<_>>();
+
+    // Dates are in the form YYYY-MM-DD, so they can be compared as strings.
+    exams.sort_by(|a, b| {
+        (&a.date, a.start_hr, a.start_min).cmp(&(&b.date, b.start_hr, b.start_min))
+    });
+    exams
+}
+
+/// Renders a schedule as a standalone HTML page that can be printed or emailed. Each
+/// meeting of each section gets its own row in a table, similar to the list view of
+/// WebReg's own printable schedule.
+///
+/// # Parameters
+/// - `title`: The title of the page (e.g., the schedule name and term).
+/// - `schedule`: The schedule.
+///
+/// # Returns
+/// The HTML page.
+pub fn render_schedule_html(title: &str, schedule: &[ScheduledSection]) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!("<title>{}</title>\n", escape_html(title)));
+    html.push_str(
+        "<style>\
+        body { font-family: sans-serif; } \
+        table { border-collapse: collapse; width: 100%; } \
+        th, td { border: 1px solid #999; padding: 4px 8px; text-align: left; }\
+        </style>\n",
+    );
+    html.push_str("</head>\n<body>\n");
+    html.push_str(&format!("<h1>{}</h1>\n", escape_html(title)));
+    html.push_str(
+        "<table>\n<tr><th>Course</th><th>Title</th><th>Section</th><th>Type</th>\
+        <th>Days</th><th>Time</th><th>Location</th><th>Instructor</th><th>Grade Option</th>\
+        <th>Units</th><th>Status</th></tr>\n",
+    );
+
+    for section in schedule {
+        let status = match section.enrolled_status {
+            EnrollmentStatus::Enrolled => "Enrolled".to_string(),
+            EnrollmentStatus::Waitlist { waitlist_pos } => format!("Waitlisted ({waitlist_pos})"),
+            EnrollmentStatus::Planned => "Planned".to_string(),
+            EnrollmentStatus::Unknown => "Unknown".to_string(),
+        };
+        let grade_option = section
+            .grade_option
+            .map(|g| g.to_string())
+            .unwrap_or_default();
+
+        // Sections without any meetings (e.g., independent study) still get a row.
+        let rows: Vec<Option<&Meeting>> = if section.meetings.is_empty() {
+            vec![None]
+        } else {
+            section.meetings.iter().map(Some).collect()
+        };
+
+        for meeting in rows {
+            let (meeting_type, days, time, location) = match meeting {
+                Some(m) => (
+                    m.meeting_type.as_str(),
+                    match &m.meeting_days {
+                        MeetingDay::Repeated(r) => r.join(""),
+                        MeetingDay::OneTime(r) => r.to_string(),
+                        MeetingDay::None => "TBA".to_string(),
+                    },
+                    format!(
+                        "{}:{:02} - {}:{:02}",
+                        m.start_hr, m.start_min, m.end_hr, m.end_min
+                    ),
+                    format!("{} {}", m.building, m.room).trim().to_string(),
+                ),
+                None => ("", "TBA".to_string(), "TBA".to_string(), "TBA".to_string()),
+            };
+
+            html.push_str(&format!(
+                "<tr><td>{} {}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>\
+                <td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&section.subject_code),
+                escape_html(&section.course_code),
+                escape_html(&section.course_title),
+                escape_html(&section.section_code),
+                escape_html(meeting_type),
+                escape_html(&days),
+                escape_html(&time),
+                escape_html(&location),
+                escape_html(&section.all_instructors.join(", ")),
+                escape_html(&grade_option),
+                section.units,
+                escape_html(&status),
+            ));
+        }
+    }
+
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+/// Escapes any characters that have special meaning in HTML.
+///
+/// # Parameters
+/// - `text`: The text to escape.
+///
+/// # Returns
+/// The escaped text.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// The time zone that all WebReg times are given in.
+#[cfg(feature = "chrono")]
+pub const WEBREG_TIME_ZONE: chrono_tz::Tz = chrono_tz::America::Los_Angeles;
+
+/// Converts a time given by WebReg (which is always in Pacific time) on the given date to a
+/// time zone aware date and time. You can then use `with_timezone` to convert it to any
+/// other time zone.
+///
+/// Around daylight saving time transitions, a time that occurs twice (when the clocks go back)
+/// resolves to the earlier of the two, and a time that doesn't exist (when the clocks go
+/// forward) is moved forward by an hour.
+///
+/// # Parameters
+/// - `date`: The date.
+/// - `hr`: The hour, from `0` to `23`.
+/// - `min`: The minute, from `0` to `59`.
+///
+/// # Returns
+/// The date and time, or `None` if the hour or minute is invalid.
+#[cfg(feature = "chrono")]
+pub fn to_webreg_datetime(
+    date: chrono::NaiveDate,
+    hr: TimeType,
+    min: TimeType,
+) -> Option<chrono::DateTime<chrono_tz::Tz>> {
+    use chrono::TimeZone;
+
+    let naive = date.and_hms_opt(hr, min, 0)?;
+    WEBREG_TIME_ZONE
+        .from_local_datetime(&naive)
+        .earliest()
+        .or_else(|| {
+            WEBREG_TIME_ZONE
+                .from_local_datetime(&(naive + chrono::Duration::hours(1)))
+                .earliest()
+        })
+}
+
+/// Gets the abbreviation that WebReg uses for the given day of the week (e.g., `M` or `Th`).
+///
+/// # Parameters
+/// - `weekday`: The day of the week.
+///
+/// # Returns
+/// The abbreviation.
+#[cfg(feature = "chrono")]
+pub fn weekday_abbreviation(weekday: chrono::Weekday) -> &'static str {
+    DAYS[weekday.num_days_from_monday() as usize]
+}
+
+/// The iCalendar day codes, in the same order as `DAYS`.
+#[cfg(feature = "chrono")]
+const ICAL_DAYS: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+/// The definition of WebReg's time zone, which every event in an exported calendar refers to.
+#[cfg(feature = "chrono")]
+const ICAL_TIME_ZONE: [&str; 17] = [
+    "BEGIN:VTIMEZONE",
+    "TZID:America/Los_Angeles",
+    "BEGIN:DAYLIGHT",
+    "TZOFFSETFROM:-0800",
+    "TZOFFSETTO:-0700",
+    "TZNAME:PDT",
+    "DTSTART:19700308T020000",
+    "RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU",
+    "END:DAYLIGHT",
+    "BEGIN:STANDARD",
+    "TZOFFSETFROM:-0700",
+    "TZOFFSETTO:-0800",
+    "TZNAME:PST",
+    "DTSTART:19701101T020000",
+    "RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU",
+    "END:STANDARD",
+    "END:VTIMEZONE",
+];
+
+/// One event in an exported calendar.
+#[cfg(feature = "chrono")]
+struct IcalEvent {
+    uid: String,
+    summary: String,
+    location: String,
+    description: String,
+    date: chrono::NaiveDate,
+    start: (TimeType, TimeType),
+    end: (TimeType, TimeType),
+    /// The days that the event repeats on each week, along with the last date that it can
+    /// occur on, or `None` if the event only occurs once.
+    repeat: Option<(Vec<String>, chrono::NaiveDate)>,
+}
+
+/// Exports a schedule, along with your WebReg events, as an iCalendar (RFC 5545) file that
+/// can be imported into most calendar apps (e.g., Google Calendar).
+///
+/// Repeated meetings (e.g., lectures) repeat weekly from the first day of instruction until
+/// the day before finals week, while one-time meetings (e.g., finals) occur only on their
+/// date. Events repeat weekly until the end of the term. All times are in WebReg's time zone.
+///
+/// # Parameters
+/// - `schedule`: The schedule.
+/// - `events`: Your events (see `WrapperTermRequest::get_events`), if any.
+/// - `term`: The term's dates.
+/// - `clock`: The clock used to timestamp the events (e.g., `SystemClock`).
+///
+/// # Returns
+/// The contents of the `.ics` file.
+///
+/// # Example
+/// ```rust,no_run
+/// use chrono::NaiveDate;
+/// use reqwest::Client;
+/// use webweg::types::{SystemClock, TermDates};
+/// use webweg::util::to_ical;
+/// use webweg::wrapper::WebRegWrapper;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+/// let req = wrapper.req("FA23").parsed();
+///
+/// let schedule = req.get_schedule(None).await.unwrap();
+/// let events = req.get_events().await.unwrap();
+/// let term = TermDates::new(
+///     NaiveDate::from_ymd_opt(2023, 9, 28).unwrap(),
+///     NaiveDate::from_ymd_opt(2023, 12, 9).unwrap(),
+///     NaiveDate::from_ymd_opt(2023, 12, 16).unwrap(),
+/// );
+///
+/// let ical = to_ical(&schedule, &events, &term, &SystemClock);
+/// std::fs::write("schedule.ics", ical).unwrap();
+/// # }
+/// ```
+#[cfg(feature = "chrono")]
+pub fn to_ical(
+    schedule: &[ScheduledSection],
+    events: &[Event],
+    term: &TermDates,
+    clock: &dyn Clock,
+) -> String {
+    let last_class_day = term.finals_start.pred_opt().unwrap_or(term.finals_start);
+
+    let mut ical_events = vec![];
+    for sec in schedule {
+        for (idx, meeting) in sec.meetings.iter().enumerate() {
+            let (date, repeat) = match &meeting.meeting_days {
+                MeetingDay::Repeated(days) => {
+                    let Some(date) = first_weekly_date(term.instruction_start, days) else {
+                        continue;
+                    };
+                    (date, Some((days.clone(), last_class_day)))
+                }
+                MeetingDay::OneTime(date) => {
+                    match chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                        Ok(date) => (date, None),
+                        Err(_) => continue,
+                    }
+                }
+                MeetingDay::None => continue,
+            };
+
+            ical_events.push(IcalEvent {
+                uid: format!("{}-{}-{idx}@webweg", sec.section_id, meeting.meeting_type),
+                summary: format!(
+                    "{} {} {} ({})",
+                    sec.subject_code, sec.course_code, meeting.meeting_type, sec.section_code
+                ),
+                location: format!("{} {}", meeting.building, meeting.room)
+                    .trim()
+                    .to_string(),
+                description: format!("{}\n{}", sec.course_title, sec.all_instructors.join("; ")),
+                date,
+                start: (meeting.start_hr, meeting.start_min),
+                end: (meeting.end_hr, meeting.end_min),
+                repeat,
+            });
+        }
+    }
+
+    for event in events {
+        let Some(date) = first_weekly_date(term.instruction_start, &event.days) else {
+            continue;
+        };
+
+        ical_events.push(IcalEvent {
+            uid: format!("event-{}@webweg", event.timestamp.trim()),
+            summary: event.name.clone(),
+            location: event.location.clone(),
+            description: String::new(),
+            date,
+            start: (event.start_hr, event.start_min),
+            end: (event.end_hr, event.end_min),
+            repeat: Some((event.days.clone(), term.term_end)),
+        });
+    }
+
+    let stamp = chrono::DateTime::<chrono::Utc>::from(clock.now())
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//webweg//WebReg Schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    lines.extend(ICAL_TIME_ZONE.iter().map(|line| line.to_string()));
+
+    for event in ical_events {
+        let local = |(hr, min): (TimeType, TimeType)| {
+            format!(
+                "TZID=America/Los_Angeles:{}T{hr:02}{min:02}00",
+                event.date.format("%Y%m%d")
+            )
+        };
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", escape_ical_text(&event.uid)));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        lines.push(format!("DTSTART;{}", local(event.start)));
+        lines.push(format!("DTEND;{}", local(event.end)));
+        if let Some((days, until)) = &event.repeat {
+            let by_day = days
+                .iter()
+                .filter_map(|d| DAYS.iter().position(|x| x == d).map(|i| ICAL_DAYS[i]))
+                .collect::<Vec<_>>()
+                .join(",");
+            // An empty UNTIL isn't valid, so leave it out (i.e., repeat forever) if we can't
+            // figure out when the last day ends.
+            match to_webreg_datetime(*until, 23, 59) {
+                Some(until) => lines.push(format!(
+                    "RRULE:FREQ=WEEKLY;BYDAY={by_day};UNTIL={}",
+                    until.with_timezone(&chrono::Utc).format("%Y%m%dT%H%M%SZ")
+                )),
+                None => lines.push(format!("RRULE:FREQ=WEEKLY;BYDAY={by_day}")),
+            }
+        }
+        lines.push(format!("SUMMARY:{}", escape_ical_text(&event.summary)));
+        if !event.location.is_empty() {
+            lines.push(format!("LOCATION:{}", escape_ical_text(&event.location)));
+        }
+        if !event.description.trim().is_empty() {
+            lines.push(format!(
+                "DESCRIPTION:{}",
+                escape_ical_text(event.description.trim())
+            ));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| fold_ical_line(line)).collect()
+}
+
+/// Gets the first date, on or after the given date, that falls on one of the given days.
+///
+/// # Parameters
+/// - `start`: The earliest date.
+/// - `days`: The days (e.g., `M` or `Th`).
+///
+/// # Returns
+/// The date, or `None` if none of the days are valid.
+#[cfg(feature = "chrono")]
+fn first_weekly_date(start: chrono::NaiveDate, days: &[String]) -> Option<chrono::NaiveDate> {
+    use chrono::Datelike;
+
+    start.iter_days().take(7).find(|date| {
+        days.iter()
+            .any(|d| d == weekday_abbreviation(date.weekday()))
+    })
+}
+
+/// Escapes text for use as the value of an iCalendar property.
+///
+/// # Parameters
+/// - `text`: The text.
+///
+/// # Returns
+/// The escaped text.
+#[cfg(feature = "chrono")]
+fn escape_ical_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Folds an iCalendar content line so that no line is longer than 75 bytes, and ends it with
+/// a CRLF.
+///
+/// # Parameters
+/// - `line`: The line.
+///
+/// # Returns
+/// The folded line.
+#[cfg(feature = "chrono")]
+fn fold_ical_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 2);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+
+        folded.push(c);
+        width += c.len_utf8();
+    }
+
+    folded.push_str("\r\n");
+    folded
+}
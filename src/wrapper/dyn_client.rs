@@ -0,0 +1,290 @@
+use futures::future::BoxFuture;
+
+use crate::types;
+use crate::types::{CourseCode, Courses, Events, Schedule, SearchResult};
+use crate::wrapper::input_types::{AddType, EnrollWaitAdd, ExplicitAddType, PlanAdd, SearchType};
+use crate::wrapper::WebRegWrapper;
+
+/// The main operations of the wrapper, as an object-safe trait.
+///
+/// Unlike `TermRequester`, which is implemented by the (borrowed) parsed requester, this is
+/// implemented by the wrapper itself and takes the term with each call. This means that you can
+/// store an `Arc<dyn DynWebRegClient>` in your application's services and swap it out (e.g.,
+/// for a cached or fake client in tests) without making everything generic.
+///
+/// # Example
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use reqwest::Client;
+/// use webweg::types::CourseCode;
+/// use webweg::wrapper::dyn_client::DynWebRegClient;
+/// use webweg::wrapper::WebRegWrapper;
+///
+/// struct CourseService {
+///     client: Arc<dyn DynWebRegClient>,
+/// }
+///
+/// impl CourseService {
+///     async fn section_count(&self) -> usize {
+///         self.client
+///             .get_course_info("FA23", &CourseCode::new("CSE", "100"))
+///             .await
+///             .map_or(0, |sections| sections.len())
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let service = CourseService {
+///     client: Arc::new(WebRegWrapper::new(Client::new(), "my cookies")),
+/// };
+///
+/// println!("{}", service.section_count().await);
+/// # }
+/// ```
+pub trait DynWebRegClient: Send + Sync {
+    /// Gets course information for a particular course. See
+    /// `WrapperTermRequest::get_course_info` for more information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `course`: The course (e.g., `CSE 100`).
+    ///
+    /// # Returns
+    /// The sections of the course.
+    fn get_course_info<'b>(
+        &'b self,
+        term: &'b str,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>>;
+
+    /// Gets enrollment counts for a particular course. See
+    /// `WrapperTermRequest::get_enrollment_count` for more information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `course`: The course (e.g., `CSE 100`).
+    ///
+    /// # Returns
+    /// The sections of the course, with only the enrollment counts filled out.
+    fn get_enrollment_count<'b>(
+        &'b self,
+        term: &'b str,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>>;
+
+    /// Gets your current schedule. See `WrapperTermRequest::get_schedule` for more
+    /// information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The schedule.
+    fn get_schedule<'b>(
+        &'b self,
+        term: &'b str,
+        schedule_name: Option<&'b str>,
+    ) -> BoxFuture<'b, types::Result<Schedule>>;
+
+    /// Gets the names of all of your schedules. See `WrapperTermRequest::get_schedule_list`
+    /// for more information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    ///
+    /// # Returns
+    /// The schedule names.
+    fn get_schedule_list<'b>(&'b self, term: &'b str) -> BoxFuture<'b, types::Result<Vec<String>>>;
+
+    /// Searches for courses. See `WrapperTermRequest::search_courses` for more information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `filter_by`: The search to make.
+    ///
+    /// # Returns
+    /// The search results.
+    fn search_courses<'b>(
+        &'b self,
+        term: &'b str,
+        filter_by: SearchType,
+    ) -> BoxFuture<'b, types::Result<SearchResult>>;
+
+    /// Gets all of your events. See `WrapperTermRequest::get_events` for more information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    ///
+    /// # Returns
+    /// The events.
+    fn get_events<'b>(&'b self, term: &'b str) -> BoxFuture<'b, types::Result<Events>>;
+
+    /// Enrolls in, or waitlists, a section. See `WrapperTermRequest::add_section` for more
+    /// information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `add_type`: The add type.
+    /// - `enroll_options`: Information for the section that you want to add.
+    /// - `validate`: Whether to validate the add beforehand.
+    ///
+    /// # Returns
+    /// `true` if the section was added.
+    fn add_section<'b>(
+        &'b self,
+        term: &'b str,
+        add_type: AddType,
+        enroll_options: EnrollWaitAdd<'b>,
+        validate: bool,
+    ) -> BoxFuture<'b, types::Result<bool>>;
+
+    /// Drops a section. See `WrapperTermRequest::drop_section` for more information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `prev_enroll_status`: Whether you're enrolled in or waitlisted for the section.
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// `true` if the section was dropped.
+    fn drop_section<'b>(
+        &'b self,
+        term: &'b str,
+        prev_enroll_status: ExplicitAddType,
+        section_id: &'b str,
+    ) -> BoxFuture<'b, types::Result<bool>>;
+
+    /// Plans a section. See `WrapperTermRequest::add_to_plan` for more information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `plan_options`: Information for the section that you want to plan.
+    /// - `validate`: Whether to validate the plan beforehand.
+    ///
+    /// # Returns
+    /// `true` if the section was planned.
+    fn add_to_plan<'b>(
+        &'b self,
+        term: &'b str,
+        plan_options: PlanAdd<'b>,
+        validate: bool,
+    ) -> BoxFuture<'b, types::Result<bool>>;
+
+    /// Removes a section from a schedule. See `WrapperTermRequest::remove_from_plan` for more
+    /// information.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `section_id`: The section ID.
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// `true` if the section was removed.
+    fn remove_from_plan<'b>(
+        &'b self,
+        term: &'b str,
+        section_id: &'b str,
+        schedule_name: Option<&'b str>,
+    ) -> BoxFuture<'b, types::Result<bool>>;
+}
+
+impl DynWebRegClient for WebRegWrapper {
+    fn get_course_info<'b>(
+        &'b self,
+        term: &'b str,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>> {
+        Box::pin(async move { self.req(term).parsed().get_course_info(course).await })
+    }
+
+    fn get_enrollment_count<'b>(
+        &'b self,
+        term: &'b str,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>> {
+        Box::pin(async move { self.req(term).parsed().get_enrollment_count(course).await })
+    }
+
+    fn get_schedule<'b>(
+        &'b self,
+        term: &'b str,
+        schedule_name: Option<&'b str>,
+    ) -> BoxFuture<'b, types::Result<Schedule>> {
+        Box::pin(async move { self.req(term).parsed().get_schedule(schedule_name).await })
+    }
+
+    fn get_schedule_list<'b>(&'b self, term: &'b str) -> BoxFuture<'b, types::Result<Vec<String>>> {
+        Box::pin(async move { self.req(term).parsed().get_schedule_list().await })
+    }
+
+    fn search_courses<'b>(
+        &'b self,
+        term: &'b str,
+        filter_by: SearchType,
+    ) -> BoxFuture<'b, types::Result<SearchResult>> {
+        Box::pin(async move { self.req(term).parsed().search_courses(filter_by).await })
+    }
+
+    fn get_events<'b>(&'b self, term: &'b str) -> BoxFuture<'b, types::Result<Events>> {
+        Box::pin(async move { self.req(term).parsed().get_events().await })
+    }
+
+    fn add_section<'b>(
+        &'b self,
+        term: &'b str,
+        add_type: AddType,
+        enroll_options: EnrollWaitAdd<'b>,
+        validate: bool,
+    ) -> BoxFuture<'b, types::Result<bool>> {
+        Box::pin(async move {
+            self.req(term)
+                .parsed()
+                .add_section(add_type, enroll_options, validate)
+                .await
+        })
+    }
+
+    fn drop_section<'b>(
+        &'b self,
+        term: &'b str,
+        prev_enroll_status: ExplicitAddType,
+        section_id: &'b str,
+    ) -> BoxFuture<'b, types::Result<bool>> {
+        Box::pin(async move {
+            self.req(term)
+                .parsed()
+                .drop_section(prev_enroll_status, section_id)
+                .await
+        })
+    }
+
+    fn add_to_plan<'b>(
+        &'b self,
+        term: &'b str,
+        plan_options: PlanAdd<'b>,
+        validate: bool,
+    ) -> BoxFuture<'b, types::Result<bool>> {
+        Box::pin(async move {
+            self.req(term)
+                .parsed()
+                .add_to_plan(plan_options, validate)
+                .await
+        })
+    }
+
+    fn remove_from_plan<'b>(
+        &'b self,
+        term: &'b str,
+        section_id: &'b str,
+        schedule_name: Option<&'b str>,
+    ) -> BoxFuture<'b, types::Result<bool>> {
+        Box::pin(async move {
+            self.req(term)
+                .parsed()
+                .remove_from_plan(section_id, schedule_name)
+                .await
+        })
+    }
+}
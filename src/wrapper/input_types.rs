@@ -1,5 +1,11 @@
-use crate::types::TimeType;
+use crate::types::{
+    CourseCode, EnrollmentStatus, Event, ScheduledSection, TimeType, UnitCapSchedule, WrapperError,
+};
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
 
 /// Use this struct to add more information regarding the section that you want to enroll/waitlist
 /// in.
@@ -26,6 +32,68 @@ impl<'a> EnrollWaitAdd<'a> {
             unit_count: None,
         }
     }
+
+    /// Describes the section to enroll/waitlist in by its course and section code (e.g.,
+    /// `CSE 100`, section `A01`) instead of its section ID. The section ID can then be looked up
+    /// by passing the result to the requester's `resolve_enroll_wait_add` function.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("CSE", "100")`.
+    /// - `section_code`: The section code. For example, `A01`.
+    ///
+    /// # Returns
+    /// The section lookup, which can be further customized with a grading option and unit count.
+    pub fn for_course(
+        course: impl Into<CourseCode>,
+        section_code: impl Into<Cow<'a, str>>,
+    ) -> SectionLookup<'a> {
+        SectionLookup {
+            course: course.into(),
+            section_code: section_code.into(),
+            grading_option: None,
+            unit_count: None,
+        }
+    }
+}
+
+/// A section that is identified by its course and section code, rather than its section ID.
+///
+/// Use `EnrollWaitAdd::for_course` to construct this object.
+pub struct SectionLookup<'a> {
+    /// The course. For example, `CSE 100`.
+    pub course: CourseCode,
+    /// The section code. For example, `A01`.
+    pub section_code: Cow<'a, str>,
+    /// The grading option. If None is specified, this uses the default option.
+    pub grading_option: Option<GradeOption>,
+    /// The number of units. If none is specified, this uses the default unit count.
+    pub unit_count: Option<u8>,
+}
+
+impl<'a> SectionLookup<'a> {
+    /// Sets the grading option (L, P/NP, S/U) for this section.
+    ///
+    /// # Parameters
+    /// - `grading_option`: The grading option.
+    ///
+    /// # Returns
+    /// The section lookup.
+    pub fn with_grading_option(mut self, grading_option: GradeOption) -> Self {
+        self.grading_option = Some(grading_option);
+        self
+    }
+
+    /// Sets the number of units for this section.
+    ///
+    /// # Parameters
+    /// - `units`: The number of units.
+    ///
+    /// # Returns
+    /// The section lookup.
+    pub fn with_unit_count(mut self, units: u8) -> Self {
+        self.unit_count = Some(units);
+        self
+    }
 }
 
 pub struct EnrollWaitAddBuilder<'a> {
@@ -193,6 +261,20 @@ impl<'a> PlanAddBuilder<'a> {
         self
     }
 
+    /// Sets both the subject code and course code for this builder.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("CSE", "100")`.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_course(mut self, course: impl Into<CourseCode>) -> Self {
+        let CourseCode { subject, number } = course.into();
+        self.subject_code = Some(Cow::Owned(subject));
+        self.course_code = Some(Cow::Owned(number));
+        self
+    }
+
     /// Sets the section ID for this builder. For example, `0123123` is a possible section ID.
     ///
     /// # Parameters
@@ -295,6 +377,21 @@ impl<'a> Default for PlanAddBuilder<'a> {
 /// A struct that represents an event to be added.
 ///
 /// Prefer using the corresponding `EventAddBuilder` to build this object.
+///
+/// This can be serialized to, and deserialized from, JSON. This is the format used by the
+/// `export_events` and `import_events` functions. For example:
+/// ```json
+/// {
+///   "event_name": "Study Session",
+///   "location": "Geisel",
+///   "event_days": ["Monday", "Wednesday"],
+///   "start_hr": 14,
+///   "start_min": 0,
+///   "end_hr": 15,
+///   "end_min": 30
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventAdd<'a> {
     /// The name of the event. This is required.
     pub event_name: Cow<'a, str>,
@@ -325,6 +422,48 @@ impl<'a> EventAdd<'a> {
     pub fn builder() -> EventAddBuilder<'a> {
         EventAddBuilder::new()
     }
+
+    /// Checks whether this event is the same as another event. Two events are considered the
+    /// same if they have the same name, start and end times, and days (the location is ignored).
+    ///
+    /// # Parameters
+    /// - `other`: The other event.
+    ///
+    /// # Returns
+    /// Whether the two events are the same.
+    pub fn is_same_event(&self, other: &EventAdd<'_>) -> bool {
+        let mut our_days = self.event_days.clone();
+        let mut their_days = other.event_days.clone();
+        our_days.sort();
+        our_days.dedup();
+        their_days.sort();
+        their_days.dedup();
+
+        self.event_name == other.event_name
+            && self.start_hr == other.start_hr
+            && self.start_min == other.start_min
+            && self.end_hr == other.end_hr
+            && self.end_min == other.end_min
+            && our_days == their_days
+    }
+}
+
+impl From<&Event> for EventAdd<'static> {
+    fn from(event: &Event) -> Self {
+        EventAdd {
+            event_name: Cow::Owned(event.name.clone()),
+            location: if event.location.is_empty() {
+                None
+            } else {
+                Some(Cow::Owned(event.location.clone()))
+            },
+            event_days: event.days.iter().filter_map(|d| d.parse().ok()).collect(),
+            start_hr: event.start_hr,
+            start_min: event.start_min,
+            end_hr: event.end_hr,
+            end_min: event.end_min,
+        }
+    }
 }
 
 pub struct EventAddBuilder<'a> {
@@ -464,7 +603,7 @@ impl<'a> Default for EventAddBuilder<'a> {
 }
 
 /// The possible grading options.
-#[derive(PartialOrd, PartialEq, Debug)]
+#[derive(PartialOrd, PartialEq, Eq, Hash, Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum GradeOption {
     /// S/U grading (Satisfactory/Unsatisfactory) option.
     S,
@@ -490,6 +629,475 @@ impl GradeOption {
     }
 }
 
+impl Display for GradeOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for GradeOption {
+    type Err = WrapperError;
+
+    /// Parses a grading option. Along with the single-letter codes (`L`, `P`, `S`), this also
+    /// accepts the longer codes that WebReg sometimes uses in schedules (`P/NP`, `S/U`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "L" => Ok(GradeOption::L),
+            "P" | "P/NP" => Ok(GradeOption::P),
+            "S" | "S/U" => Ok(GradeOption::S),
+            _ => Err(WrapperError::InputError(
+                "grade_option",
+                "grading option must be one of L, P, P/NP, S, or S/U.",
+            )),
+        }
+    }
+}
+
+/// Options for the requester's `enroll_from_plan` function.
+#[derive(Debug, Clone)]
+pub struct EnrollFromPlanOptions {
+    /// The order to try the planned sections in. Sections that aren't listed are tried after
+    /// the listed ones, in the order that they appear in the schedule.
+    pub priority: Vec<PlanPriority>,
+    /// Whether to waitlist a section if it doesn't have any seats (or if WebReg turns down the
+    /// enroll).
+    pub waitlist_if_full: bool,
+    /// The maximum number of units that you want to be enrolled in or waitlisted for,
+    /// including the sections that you're already in. If `None`, there is no limit (other than
+    /// WebReg's own).
+    pub max_units: Option<i64>,
+    /// The unit caps for the enrollment period, if known. The cap that applies at the time
+    /// `enroll_from_plan` is called is used on top of `max_units`, so that sections that
+    /// WebReg would reject for going over the cap aren't attempted.
+    pub unit_caps: Option<UnitCapSchedule>,
+    /// Whether to validate each add before making it (see `add_section`).
+    pub validate: bool,
+}
+
+impl EnrollFromPlanOptions {
+    /// Creates the default options, which tries the sections in schedule order, doesn't
+    /// waitlist, doesn't limit units, and validates each add.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn new() -> Self {
+        Self {
+            priority: vec![],
+            waitlist_if_full: false,
+            max_units: None,
+            unit_caps: None,
+            validate: true,
+        }
+    }
+
+    /// Sets the order to try the planned sections in.
+    ///
+    /// # Parameters
+    /// - `priority`: The sections or courses (e.g., `("CSE", "100")`), highest priority first.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn with_priority<I, P>(mut self, priority: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PlanPriority>,
+    {
+        self.priority = priority.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets whether to waitlist sections that don't have any seats.
+    ///
+    /// # Parameters
+    /// - `waitlist`: Whether to waitlist.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn should_waitlist_if_full(mut self, waitlist: bool) -> Self {
+        self.waitlist_if_full = waitlist;
+        self
+    }
+
+    /// Sets the maximum number of units.
+    ///
+    /// # Parameters
+    /// - `max_units`: The maximum number of units.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn with_max_units(mut self, max_units: i64) -> Self {
+        self.max_units = Some(max_units);
+        self
+    }
+
+    /// Sets the unit caps for the enrollment period (e.g., first and second pass).
+    ///
+    /// # Parameters
+    /// - `caps`: The unit cap schedule.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn with_unit_caps(mut self, caps: UnitCapSchedule) -> Self {
+        self.unit_caps = Some(caps);
+        self
+    }
+
+    /// Gets the unit limit that applies at the given time, which is the lower of `max_units`
+    /// and the cap from the unit cap schedule.
+    ///
+    /// # Parameters
+    /// - `now`: The current time.
+    ///
+    /// # Returns
+    /// The unit limit, or `None` if there is no limit.
+    pub fn unit_limit_at(&self, now: SystemTime) -> Option<i64> {
+        let cap = self.unit_caps.as_ref().and_then(|c| c.cap_at(now));
+        match (self.max_units, cap) {
+            (Some(max), Some(cap)) => Some(max.min(cap)),
+            (max, cap) => max.or(cap),
+        }
+    }
+
+    /// Sets whether to validate each add before making it.
+    ///
+    /// # Parameters
+    /// - `validate`: Whether to validate.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn should_validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Gets the priority of a section, where a lower number means a higher priority.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section's ID.
+    /// - `course`: The section's course.
+    ///
+    /// # Returns
+    /// The priority.
+    pub(crate) fn priority_of(&self, section_id: &str, course: &CourseCode) -> usize {
+        let section_id = section_id.trim().trim_start_matches('0');
+        self.priority
+            .iter()
+            .position(|p| match p {
+                PlanPriority::Section(id) => id.trim().trim_start_matches('0') == section_id,
+                PlanPriority::Course(c) => c == course,
+            })
+            .unwrap_or(usize::MAX)
+    }
+}
+
+impl Default for EnrollFromPlanOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An entry in `EnrollFromPlanOptions::priority`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanPriority {
+    /// A section, by its section ID (e.g., `079911`).
+    Section(String),
+    /// Every section of a course.
+    Course(CourseCode),
+}
+
+impl PlanPriority {
+    /// Creates an entry for a section.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID. For example, `079911`.
+    ///
+    /// # Returns
+    /// The entry.
+    pub fn section(section_id: impl Into<String>) -> Self {
+        Self::Section(section_id.into())
+    }
+}
+
+impl From<CourseCode> for PlanPriority {
+    fn from(course: CourseCode) -> Self {
+        Self::Course(course)
+    }
+}
+
+impl<S: AsRef<str>, N: AsRef<str>> From<(S, N)> for PlanPriority {
+    fn from(course: (S, N)) -> Self {
+        Self::Course(course.into())
+    }
+}
+
+/// Options for the requester's `auto_enroll` function.
+#[derive(Debug, Clone)]
+pub struct AutoEnrollOptions {
+    /// The grading option to add the section with. If `None`, the default grading option is
+    /// used.
+    pub grading_option: Option<GradeOption>,
+    /// The number of units to add the section with. If `None`, the default unit count is used.
+    pub unit_count: Option<u8>,
+    /// Whether to waitlist the section if it doesn't have any seats (or if enrolling fails).
+    pub waitlist_if_full: bool,
+    /// Whether to validate each add before making it (see `add_section`).
+    pub validate: bool,
+    /// The maximum number of times to try each add, if it keeps failing with a transient
+    /// error (see `WrapperError::is_transient`). Each add is tried at least once.
+    pub max_attempts: u32,
+    /// How long to wait before trying an add again.
+    pub retry_delay: Duration,
+    /// Whether to check your schedule afterwards to confirm the section's status.
+    pub confirm: bool,
+}
+
+impl AutoEnrollOptions {
+    /// Creates the default options, which uses the default grading option and unit count,
+    /// doesn't waitlist, validates each add, tries each add up to 3 times (waiting half a
+    /// second between tries), and confirms the result with your schedule.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn new() -> Self {
+        Self {
+            grading_option: None,
+            unit_count: None,
+            waitlist_if_full: false,
+            validate: true,
+            max_attempts: 3,
+            retry_delay: Duration::from_millis(500),
+            confirm: true,
+        }
+    }
+
+    /// Sets the grading option to add the section with.
+    ///
+    /// # Parameters
+    /// - `grading_option`: The grading option.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn with_grading_option(mut self, grading_option: GradeOption) -> Self {
+        self.grading_option = Some(grading_option);
+        self
+    }
+
+    /// Sets the number of units to add the section with.
+    ///
+    /// # Parameters
+    /// - `unit_count`: The number of units.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn with_unit_count(mut self, unit_count: u8) -> Self {
+        self.unit_count = Some(unit_count);
+        self
+    }
+
+    /// Sets whether to waitlist the section if it doesn't have any seats.
+    ///
+    /// # Parameters
+    /// - `waitlist`: Whether to waitlist.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn should_waitlist_if_full(mut self, waitlist: bool) -> Self {
+        self.waitlist_if_full = waitlist;
+        self
+    }
+
+    /// Sets whether to validate each add before making it.
+    ///
+    /// # Parameters
+    /// - `validate`: Whether to validate.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn should_validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Sets how many times each add is tried if it keeps failing with a transient error,
+    /// and how long to wait between tries.
+    ///
+    /// # Parameters
+    /// - `max_attempts`: The maximum number of tries.
+    /// - `retry_delay`: How long to wait between tries.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn with_retries(mut self, max_attempts: u32, retry_delay: Duration) -> Self {
+        self.max_attempts = max_attempts;
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Sets whether to check your schedule afterwards to confirm the section's status.
+    ///
+    /// # Parameters
+    /// - `confirm`: Whether to confirm.
+    ///
+    /// # Returns
+    /// The options.
+    pub fn should_confirm(mut self, confirm: bool) -> Self {
+        self.confirm = confirm;
+        self
+    }
+}
+
+impl Default for AutoEnrollOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A rule for `WrapperTermRequest::apply_drop_policy`, describing which waitlisted sections
+/// should be dropped. Only sections that you are waitlisted for are ever dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DropRule {
+    /// Drop the waitlisted course `waitlisted` if you are enrolled in the course `enrolled`.
+    IfEnrolledIn {
+        waitlisted: CourseCode,
+        enrolled: CourseCode,
+    },
+    /// Drop every waitlisted section where your position is after `position` (e.g., if this
+    /// is `5`, then waitlist position 6 and later are dropped). If `after` is given, this rule
+    /// only applies at or after that time.
+    BelowPosition {
+        position: i64,
+        after: Option<SystemTime>,
+    },
+}
+
+impl DropRule {
+    /// Checks whether this rule says that the section should be dropped.
+    ///
+    /// # Parameters
+    /// - `section`: The section, which should be one that you're waitlisted for.
+    /// - `schedule`: Your entire schedule.
+    /// - `now`: The current time.
+    ///
+    /// # Returns
+    /// `true` if the section should be dropped.
+    pub fn applies_to(
+        &self,
+        section: &ScheduledSection,
+        schedule: &[ScheduledSection],
+        now: SystemTime,
+    ) -> bool {
+        let EnrollmentStatus::Waitlist { waitlist_pos } = section.enrolled_status else {
+            return false;
+        };
+
+        match self {
+            DropRule::IfEnrolledIn {
+                waitlisted,
+                enrolled,
+            } => {
+                section.course() == *waitlisted
+                    && schedule.iter().any(|s| {
+                        s.enrolled_status == EnrollmentStatus::Enrolled && s.course() == *enrolled
+                    })
+            }
+            DropRule::BelowPosition { position, after } => {
+                waitlist_pos > *position && after.is_none_or(|after| now >= after)
+            }
+        }
+    }
+}
+
+/// A policy for the requester's `apply_drop_policy` function, which drops waitlisted sections
+/// that you no longer need (e.g., at the end of the add period).
+#[derive(Debug, Clone, Default)]
+pub struct WaitlistDropPolicy {
+    /// The rules. A waitlisted section is dropped if any rule applies to it.
+    pub rules: Vec<DropRule>,
+    /// Whether to only report what would be dropped, without dropping anything.
+    pub dry_run: bool,
+}
+
+impl WaitlistDropPolicy {
+    /// Creates an empty policy, which doesn't drop anything.
+    ///
+    /// # Returns
+    /// The policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule that drops your waitlist for one course if you're enrolled in another.
+    ///
+    /// # Parameters
+    /// - `waitlisted`: The waitlisted course to drop (e.g., `("CSE", "100")`).
+    /// - `enrolled`: The course that you need to be enrolled in (e.g., `("CSE", "101")`).
+    ///
+    /// # Returns
+    /// The policy.
+    pub fn drop_if_enrolled_in(
+        mut self,
+        waitlisted: impl Into<CourseCode>,
+        enrolled: impl Into<CourseCode>,
+    ) -> Self {
+        self.rules.push(DropRule::IfEnrolledIn {
+            waitlisted: waitlisted.into(),
+            enrolled: enrolled.into(),
+        });
+        self
+    }
+
+    /// Adds a rule that drops every waitlist where your position is after the given position.
+    ///
+    /// # Parameters
+    /// - `position`: The last waitlist position to keep.
+    /// - `after`: If given, the rule only applies at or after this time.
+    ///
+    /// # Returns
+    /// The policy.
+    pub fn drop_below_position(mut self, position: i64, after: Option<SystemTime>) -> Self {
+        self.rules.push(DropRule::BelowPosition { position, after });
+        self
+    }
+
+    /// Sets whether to only report what would be dropped.
+    ///
+    /// # Parameters
+    /// - `dry_run`: Whether to skip the drops.
+    ///
+    /// # Returns
+    /// The policy.
+    pub fn should_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Finds the waitlisted sections in the schedule that should be dropped, without dropping
+    /// anything.
+    ///
+    /// # Parameters
+    /// - `schedule`: Your schedule.
+    /// - `now`: The current time.
+    ///
+    /// # Returns
+    /// Each section that should be dropped, along with the first rule that applies to it, in
+    /// schedule order.
+    pub fn evaluate<'a>(
+        &'a self,
+        schedule: &'a [ScheduledSection],
+        now: SystemTime,
+    ) -> Vec<(&'a ScheduledSection, &'a DropRule)> {
+        schedule
+            .iter()
+            .filter_map(|sec| {
+                self.rules
+                    .iter()
+                    .find(|rule| rule.applies_to(sec, schedule, now))
+                    .map(|rule| (sec, rule))
+            })
+            .collect()
+    }
+}
+
 /// An enum that represents how a course should be added to the person's schedule when
 /// calling the corresponding `add_section` method (and associated methods).
 pub enum AddType {
@@ -510,6 +1118,25 @@ pub enum ExplicitAddType {
     Waitlist,
 }
 
+/// An enum that represents what a polling stream (e.g., `watch_seat_counts`) should do when
+/// the consumer of the stream falls behind (i.e., it takes longer than the polling interval to
+/// handle a snapshot).
+///
+/// Regardless of the option, WebReg is only polled when the consumer asks for the next
+/// snapshot, so snapshots never pile up in memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LagBehavior {
+    /// Skip the polls that were missed, and poll again at the next scheduled time. This means
+    /// that intermediate snapshots are dropped, and the consumer gets the most recent data.
+    #[default]
+    Skip,
+    /// Poll right away, then wait the full interval before the poll after that.
+    Delay,
+    /// Make all of the polls that were missed, one after another, until the stream has caught
+    /// up to the schedule.
+    Burst,
+}
+
 /// Used to construct search requests for the `search_courses` function.
 ///
 /// When building your request, you can either use one of the helper methods
@@ -724,7 +1351,7 @@ impl Default for SearchRequestBuilder {
 
 /// The day of week enum, which designates what days you want
 /// to filter specific sections by.
-#[derive(PartialOrd, PartialEq, Debug)]
+#[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum DayOfWeek {
     Monday,
     Tuesday,
@@ -735,6 +1362,28 @@ pub enum DayOfWeek {
     Sunday,
 }
 
+impl FromStr for DayOfWeek {
+    type Err = WrapperError;
+
+    /// Parses a day of the week. This accepts either the full name of the day (e.g., `Monday`) or
+    /// the abbreviation that WebReg uses (e.g., `M`, `Tu`, `Th`, `Sa`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "m" | "monday" => Ok(DayOfWeek::Monday),
+            "tu" | "tuesday" => Ok(DayOfWeek::Tuesday),
+            "w" | "wednesday" => Ok(DayOfWeek::Wednesday),
+            "th" | "thursday" => Ok(DayOfWeek::Thursday),
+            "f" | "friday" => Ok(DayOfWeek::Friday),
+            "sa" | "saturday" => Ok(DayOfWeek::Saturday),
+            "su" | "sunday" => Ok(DayOfWeek::Sunday),
+            _ => Err(WrapperError::InputError(
+                "day_of_week",
+                "day must be a day of the week (e.g., Monday or M).",
+            )),
+        }
+    }
+}
+
 /// The course level filter enum, which can be used to filter
 /// specific sections by.
 pub enum CourseLevelFilter {
@@ -775,4 +1424,9 @@ pub enum SearchType {
 
     /// Searches for a (set of) course(s) by multiple specifications.
     Advanced(SearchRequestBuilder),
+
+    /// Searches for a (set of) course(s) using WebReg's basic search, which takes a single
+    /// free-text query (e.g., `data structures` or `CSE 100`). Note that WebReg matches this
+    /// query differently from the structured filters in `Advanced`.
+    FreeText(String),
 }
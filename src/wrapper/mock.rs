@@ -0,0 +1,285 @@
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+
+use crate::types;
+use crate::types::{
+    CourseCode, Courses, Events, HttpBackend, HttpBackendError, HttpRequest, HttpResponse,
+    Schedule, SearchResult,
+};
+use crate::wrapper::input_types::SearchType;
+use crate::wrapper::term_requester::TermRequester;
+use crate::wrapper::WebRegWrapper;
+
+/// A request that was sent to a `MockWebReg`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MockRequest {
+    /// The request method (e.g., `GET`).
+    pub method: String,
+    /// The full URL, including the query string.
+    pub url: String,
+    /// The form body, if any (e.g., `section=079911&termcode=FA23`).
+    pub body: Option<String>,
+}
+
+/// A canned response, served for any request whose URL contains the pattern.
+struct MockRoute {
+    pattern: String,
+    status: u16,
+    body: String,
+}
+
+/// A fake WebReg that serves canned responses (e.g., saved JSON fixtures), so that code using
+/// the wrapper can be tested without cookies or a network connection. Use it with the builder's
+/// `with_backend` function.
+///
+/// Each response is registered under a pattern, and is served for any request whose full URL
+/// (including the query string) contains that pattern. The patterns are checked in the order
+/// that they were added, so more specific patterns should be added first. Requests that don't
+/// match any pattern get a `404` response.
+///
+/// # Example
+/// ```rust
+/// use webweg::wrapper::mock::MockWebReg;
+/// use webweg::wrapper::WebRegWrapper;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let mock = MockWebReg::new()
+///     .with_response("subjcode=CSE&crsecode=100", "[]")
+///     .with_status("search-load-group-data", 500, "");
+///
+/// let wrapper = WebRegWrapper::builder()
+///     .with_cookies("my cookies")
+///     .with_backend(mock)
+///     .try_build_wrapper()
+///     .unwrap();
+///
+/// let sections = wrapper
+///     .req("FA23")
+///     .parsed()
+///     .get_course_info(("CSE", "100"))
+///     .await
+///     .unwrap();
+/// assert!(sections.is_empty());
+/// # }
+/// ```
+#[derive(Default)]
+pub struct MockWebReg {
+    routes: Vec<MockRoute>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl MockWebReg {
+    /// Creates a mock without any responses.
+    ///
+    /// # Returns
+    /// The mock.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a successful response.
+    ///
+    /// # Parameters
+    /// - `pattern`: The part of the URL to match (e.g., an endpoint name like
+    ///   `get-current-name`, or a query parameter like `subjcode=CSE`).
+    /// - `body`: The response body (e.g., a saved JSON fixture).
+    ///
+    /// # Returns
+    /// The mock.
+    pub fn with_response(self, pattern: impl Into<String>, body: impl Into<String>) -> Self {
+        self.with_status(pattern, 200, body)
+    }
+
+    /// Adds a response with the given status code (e.g., to test how errors are handled).
+    ///
+    /// # Parameters
+    /// - `pattern`: The part of the URL to match.
+    /// - `status`: The status code.
+    /// - `body`: The response body.
+    ///
+    /// # Returns
+    /// The mock.
+    pub fn with_status(
+        mut self,
+        pattern: impl Into<String>,
+        status: u16,
+        body: impl Into<String>,
+    ) -> Self {
+        self.routes.push(MockRoute {
+            pattern: pattern.into(),
+            status,
+            body: body.into(),
+        });
+        self
+    }
+
+    /// Gets every request that was sent to the mock so far, oldest first.
+    ///
+    /// # Returns
+    /// The requests.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Builds the response for a request.
+    ///
+    /// # Parameters
+    /// - `url`: The request URL.
+    ///
+    /// # Returns
+    /// The status code and body.
+    fn respond(&self, url: &str) -> (u16, String) {
+        match self.routes.iter().find(|r| url.contains(&r.pattern)) {
+            Some(route) => (route.status, route.body.clone()),
+            None => (404, format!("no mock response for {url}")),
+        }
+    }
+}
+
+impl HttpBackend for MockWebReg {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, HttpBackendError>> {
+        let (status, body) = self.respond(&request.url);
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(MockRequest {
+                method: request.method,
+                url: request.url.clone(),
+                body: request
+                    .body
+                    .map(|b| String::from_utf8_lossy(&b).into_owned()),
+            });
+
+        let response = HttpResponse {
+            status,
+            // So that the response looks like it came from WebReg, and isn't mistaken for a
+            // redirect to the login page.
+            url: request.url,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: body.into_bytes(),
+        };
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+/// A `TermRequester` that serves canned responses from a `MockWebReg`, for testing code that
+/// is written against the `TermRequester` trait. The responses are parsed exactly like they
+/// would be for a real requester, so saved JSON fixtures can be used as-is.
+///
+/// # Example
+/// ```rust
+/// use webweg::types::CourseCode;
+/// use webweg::wrapper::mock::{MockTermRequester, MockWebReg};
+/// use webweg::wrapper::term_requester::TermRequester;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let req = MockTermRequester::new(
+///     MockWebReg::new().with_response("subjcode=CSE&crsecode=100", "[]"),
+///     "FA23",
+/// );
+///
+/// let sections = req
+///     .get_course_info(&CourseCode::new("CSE", "100"))
+///     .await
+///     .unwrap();
+/// assert!(sections.is_empty());
+/// assert_eq!(1, req.mock().requests().len());
+/// # }
+/// ```
+pub struct MockTermRequester {
+    mock: Arc<MockWebReg>,
+    wrapper: WebRegWrapper,
+    term: String,
+}
+
+impl MockTermRequester {
+    /// Creates a requester for the given term that sends its requests to the mock.
+    ///
+    /// # Parameters
+    /// - `mock`: The mock to send requests to.
+    /// - `term`: The term to make requests for. For example, `FA23`.
+    ///
+    /// # Returns
+    /// The requester.
+    pub fn new(mock: MockWebReg, term: impl Into<String>) -> Self {
+        let mock = Arc::new(mock);
+        let wrapper = WebRegWrapper::builder()
+            .with_cookies("mock=cookies")
+            .with_backend(mock.clone())
+            .try_build_wrapper()
+            .expect("a wrapper with cookies and a backend can always be built");
+        Self {
+            mock,
+            wrapper,
+            term: term.into(),
+        }
+    }
+
+    /// Gets the mock that requests are sent to (e.g., to check which requests were made).
+    ///
+    /// # Returns
+    /// The mock.
+    pub fn mock(&self) -> &MockWebReg {
+        &self.mock
+    }
+
+    /// Gets the wrapper that requests are made with (e.g., to check its statistics).
+    ///
+    /// # Returns
+    /// The wrapper.
+    pub fn wrapper(&self) -> &WebRegWrapper {
+        &self.wrapper
+    }
+}
+
+impl TermRequester for MockTermRequester {
+    fn get_course_info<'b>(
+        &'b self,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>> {
+        Box::pin(async move {
+            let req = self.wrapper.req(&self.term).parsed();
+            req.get_course_info(course).await
+        })
+    }
+
+    fn get_enrollment_count<'b>(
+        &'b self,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>> {
+        Box::pin(async move {
+            let req = self.wrapper.req(&self.term).parsed();
+            req.get_enrollment_count(course).await
+        })
+    }
+
+    fn get_schedule<'b>(
+        &'b self,
+        schedule_name: Option<&'b str>,
+    ) -> BoxFuture<'b, types::Result<Schedule>> {
+        Box::pin(async move {
+            let req = self.wrapper.req(&self.term).parsed();
+            req.get_schedule(schedule_name).await
+        })
+    }
+
+    fn search_courses(&self, filter_by: SearchType) -> BoxFuture<'_, types::Result<SearchResult>> {
+        Box::pin(async move {
+            let req = self.wrapper.req(&self.term).parsed();
+            req.search_courses(filter_by).await
+        })
+    }
+
+    fn get_events(&self) -> BoxFuture<'_, types::Result<Events>> {
+        Box::pin(async move {
+            let req = self.wrapper.req(&self.term).parsed();
+            req.get_events().await
+        })
+    }
+}
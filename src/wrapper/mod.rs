@@ -1,24 +1,48 @@
-#[cfg(feature = "multi")]
+#[cfg(all(feature = "multi", feature = "reqwest"))]
 use parking_lot::Mutex;
+#[cfg(feature = "reqwest")]
+use std::sync::Arc;
+#[cfg(feature = "reqwest")]
 use std::time::Duration;
+use std::time::Instant;
 
+use futures::stream::{self, StreamExt, TryStreamExt};
+#[cfg(feature = "reqwest")]
 use reqwest::Client;
-use serde_json::{json, Value};
-use url::Url;
 
 use crate::constants::*;
 use crate::raw_types::RawTermListItem;
-use crate::types::{Term, WrapperError};
+use crate::types;
+use crate::types::{
+    ActionLog, Compatibility, CompatibilityReport, EndpointCompatibility, HealthCheck,
+    HealthReport, ObservedErrors, PingResponse, SessionStatus, Term, TermInfo, WrapperError,
+    WrapperStats,
+};
+#[cfg(feature = "reqwest")]
+use crate::types::{DefaultSeatStrategy, PacingProfile, SystemClock};
 use crate::wrapper::request_builder::WrapperTermRequestBuilder;
+#[cfg(feature = "reqwest")]
+use crate::wrapper::request_data::{
+    tokio_sleep, ActionRecorder, CatalogCache, ErrorCollector, Pacer, QuotaGuard, ScheduleCache,
+    StatsTracker, TermTracker, WaitlistTracker,
+};
 use crate::wrapper::request_data::{ReqType, ReqwestWebRegClientData, WebRegWrapperData};
+use crate::wrapper::requester_multi::WrapperMultiTermRequest;
 use crate::wrapper::wrapper_builder::WebRegWrapperBuilder;
-use crate::wrapper::ww_helper::{associate_term_helper, process_get_result};
-use crate::{types, util};
+use crate::wrapper::ww_helper::{
+    associate_term_helper, extract_text, fetch_term_status, process_get_text,
+};
+use crate::ww_parser;
 
+pub mod dyn_client;
 pub mod input_types;
+pub mod mock;
 pub mod request_builder;
 mod request_data;
+pub mod requester_multi;
 pub mod requester_term;
+pub mod seat_watcher;
+pub mod term_requester;
 pub mod wrapper_builder;
 mod ww_helper;
 
@@ -61,6 +85,7 @@ impl<'a> WebRegWrapper {
     /// let client = Client::new();
     /// let wrapper = WebRegWrapper::new(client, "my cookies".to_string());
     /// ```
+    #[cfg(feature = "reqwest")]
     pub fn new(client: Client, cookies: impl Into<String>) -> Self {
         Self {
             data: WebRegWrapperData {
@@ -72,6 +97,29 @@ impl<'a> WebRegWrapper {
                 timeout: Duration::from_secs(30),
                 user_agent: MY_USER_AGENT.to_owned(),
                 close_after_request: false,
+                cache_buster: true,
+                stats: StatsTracker::default(),
+                errors: ErrorCollector::new(false),
+                actions: ActionRecorder::new(false, DEFAULT_ACTION_LOG_CAPACITY),
+                on_session_invalid: None,
+                on_parse_warning: None,
+                strict_parsing: false,
+                always_validate_enroll: true,
+                always_validate_plan: true,
+                seat_strategy: Arc::new(DefaultSeatStrategy),
+                pacer: Pacer::new(PacingProfile::default()),
+                rate_limiter: None,
+                clock: Arc::new(SystemClock),
+                catalog_cache: CatalogCache::new(DEFAULT_CATALOG_CACHE_TTL),
+                associated_terms: TermTracker::default(),
+                schedule_cache: ScheduleCache::default(),
+                quota: QuotaGuard::default(),
+                closed_waitlists: WaitlistTracker::default(),
+                cookie_refresher: None,
+                attempt_sink: None,
+                backend: None,
+                max_response_size: None,
+                sleeper: tokio_sleep,
             },
         }
     }
@@ -95,6 +143,10 @@ impl<'a> WebRegWrapper {
     #[cfg(not(feature = "multi"))]
     pub fn set_cookies(&mut self, new_cookies: impl Into<String>) {
         self.data.cookies = new_cookies.into();
+        self.data.associated_terms.clear();
+        if let Some(refresher) = &self.data.cookie_refresher {
+            refresher.reset();
+        }
     }
 
     /// Sets the cookies to the new, specified cookies.
@@ -110,11 +162,18 @@ impl<'a> WebRegWrapper {
     pub fn set_cookies(&self, new_cookies: impl Into<String>) {
         let mut cookies = self.data.cookies.lock();
         *cookies = new_cookies.into();
+        self.data.associated_terms.clear();
+        if let Some(refresher) = &self.data.cookie_refresher {
+            refresher.reset();
+        }
     }
 
     /// Checks if the current WebReg instance is valid. Specifically, this will check if you
     /// are logged in.
     ///
+    /// If you also want to know who is logged in, or when the check was made, consider using
+    /// `get_session_status` instead.
+    ///
     /// # Returns
     /// `true` if the instance is valid and `false` otherwise.
     ///
@@ -133,10 +192,50 @@ impl<'a> WebRegWrapper {
         self.ping_server().await
     }
 
+    /// Checks the status of the current WebReg session. Specifically, this will check if you
+    /// are logged in and, if so, who you are logged in as.
+    ///
+    /// # Returns
+    /// The session status. An error is only returned if the session is valid but the name of
+    /// the account could not be retrieved.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies".to_string());
+    /// let status = wrapper.get_session_status().await.unwrap();
+    /// match status.name {
+    ///     Some(name) if status.valid => println!("Logged in as {name}"),
+    ///     _ => println!("Not logged in."),
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_session_status(&self) -> types::Result<SessionStatus> {
+        let checked_at = self.data.clock.now();
+        if !self.is_valid().await {
+            return Ok(SessionStatus {
+                valid: false,
+                name: None,
+                checked_at,
+            });
+        }
+
+        Ok(SessionStatus {
+            valid: true,
+            name: Some(self.fetch_account_name().await?),
+            checked_at,
+        })
+    }
+
     /// Gets the name of the owner associated with this account.
     ///
     /// # Returns
-    /// The name of the person, or an empty string if the cookies that were given were invalid.
+    /// The name of the person, or a `SessionNotValid` error if the cookies that were given were
+    /// invalid.
     ///
     /// # Example
     /// ```rust,no_run
@@ -154,13 +253,27 @@ impl<'a> WebRegWrapper {
             return Err(WrapperError::SessionNotValid);
         }
 
-        Ok(self
-            .data
-            .req(ReqType::Get(ACC_NAME))
-            .send()
-            .await?
-            .text()
-            .await?)
+        self.fetch_account_name().await
+    }
+
+    /// Gets the name of the owner associated with this account, without checking if the
+    /// session is valid first.
+    ///
+    /// # Returns
+    /// The name of the person, or a `SessionNotValid` error if WebReg didn't give a name.
+    async fn fetch_account_name(&self) -> types::Result<String> {
+        let name = extract_text(
+            &self.data,
+            self.data.req(ReqType::Get(ACC_NAME)).send().await,
+        )
+        .await?;
+        let name = name.trim();
+        // WebReg gives back an empty response if the session isn't valid.
+        if name.is_empty() {
+            return Err(WrapperError::SessionNotValid);
+        }
+
+        Ok(name.to_string())
     }
 
     /// Registers all terms to your current session so that you can freely
@@ -213,23 +326,103 @@ impl<'a> WebRegWrapper {
     /// # }
     /// ```
     pub async fn get_all_terms(&self) -> types::Result<Vec<Term>> {
-        let url = Url::parse_with_params(
-            TERM_LIST,
-            &[("_", util::get_epoch_time().to_string().as_str())],
-        )?;
+        Ok(self
+            .get_all_term_info()
+            .await?
+            .into_iter()
+            .map(
+                |TermInfo {
+                     seq_id, term_code, ..
+                 }| Term { seq_id, term_code },
+            )
+            .collect())
+    }
 
-        process_get_result::<Vec<RawTermListItem>>(self.data.req(ReqType::Get(url)).send().await)
-            .await
-            .map(|raw_term_list| {
-                raw_term_list
-                    .into_iter()
-                    .map(
-                        |RawTermListItem {
-                             seq_id, term_code, ..
-                         }| Term { seq_id, term_code },
-                    )
-                    .collect()
+    /// Gets all terms available on WebReg, including every field that WebReg gives for each
+    /// term (e.g., the term description).
+    ///
+    /// # Returns
+    /// A vector of term information objects. If an error occurs, you will get that instead.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies".to_string());
+    /// for term in wrapper.get_all_term_info().await.unwrap() {
+    ///     println!("{} ({})", term.term_desc, term.term_code);
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_all_term_info(&self) -> types::Result<Vec<TermInfo>> {
+        Ok(
+            process_get_text::<Vec<RawTermListItem>>(self.get_all_terms_raw().await?)?
+                .into_iter()
+                .map(|raw| TermInfo {
+                    seq_id: raw.seq_id,
+                    term_code: raw.term_code,
+                    term_desc: raw.term_desc,
+                    extra: raw.extra,
+                    enrollment: None,
+                })
+                .collect(),
+        )
+    }
+
+    /// Gets all terms available on WebReg, along with each term's enrollment periods (e.g.,
+    /// when first and second pass start, and the add and drop deadlines). This might be useful
+    /// for scheduling actions around registration windows. Use `get_all_term_info` if you
+    /// don't need the enrollment periods.
+    ///
+    /// **This associates every term with your session**, since the enrollment periods come
+    /// from the same endpoints that are used to associate a term (see `associate_term`). This
+    /// makes two extra requests per term, and up to `concurrency` terms are requested at once
+    /// (subject to the wrapper's pacing).
+    ///
+    /// # Parameters
+    /// - `concurrency`: The maximum number of terms to request at once. A value of `0` is
+    ///   treated as `1`.
+    ///
+    /// # Returns
+    /// A vector of term information objects, with `enrollment` filled in, in the order given
+    /// by WebReg. If an error occurs, you will get that instead.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies".to_string());
+    /// for term in wrapper.get_all_term_details(4).await.unwrap() {
+    ///     let first_pass = term.enrollment.and_then(|e| e.first_pass);
+    ///     println!("{}: first pass starts {first_pass:?}", term.term_code);
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_all_term_details(&self, concurrency: usize) -> types::Result<Vec<TermInfo>> {
+        stream::iter(self.get_all_term_info().await?)
+            .map(|mut term| async move {
+                let responses = fetch_term_status(&self.data, &term.term_code).await?;
+                term.enrollment = Some(ww_parser::parse_enrollment_phases(&responses));
+                Ok(term)
             })
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await
+    }
+
+    /// Gets all terms available on WebReg, as returned by WebReg.
+    ///
+    /// # Returns
+    /// The term list, as returned by WebReg. If an error occurs, you will get that instead.
+    pub async fn get_all_terms_raw(&self) -> types::Result<String> {
+        let url = self.data.build_url(TERM_LIST, &[])?;
+        extract_text(&self.data, self.data.req(ReqType::Get(url)).send().await).await
     }
 
     /// Associates a particular term to this current instance of the wrapper.
@@ -266,6 +459,124 @@ impl<'a> WebRegWrapper {
         associate_term_helper(&self.data, term).await
     }
 
+    /// Associates a term with your session (see `associate_term`), and then checks that the
+    /// term can actually be used by requesting its department list. WebReg doesn't complain
+    /// when associating a term that doesn't exist, so this is the only way to know for sure.
+    /// If the check fails, the term is associated and checked once more.
+    ///
+    /// # Parameters
+    /// - `term`: The term to associate with your session cookies.
+    ///
+    /// # Returns
+    /// Nothing if the term can be used. If the term still can't be used after the second
+    /// check, a `TermNotUsable` error is returned and the term is removed from
+    /// `get_associated_terms`. Errors from associating the term, and errors caused by your
+    /// session not being valid, are returned as is.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::types::WrapperError;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies".to_string());
+    /// match wrapper.associate_term_verified("FA23").await {
+    ///     Ok(_) => println!("FA23 is ready to use"),
+    ///     Err(WrapperError::TermNotUsable(term, reason)) => eprintln!("{term} isn't usable: {reason}"),
+    ///     Err(e) => eprintln!("An error occurred! {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn associate_term_verified(&self, term: impl AsRef<str>) -> types::Result<()> {
+        let term = term.as_ref().to_uppercase();
+        let mut reason = String::new();
+        for _ in 0..2 {
+            self.associate_term(&term).await?;
+            match self.req(&term).parsed().get_department_codes().await {
+                Ok(departments) if !departments.is_empty() => return Ok(()),
+                Ok(_) => reason = "WebReg gave no departments for the term".into(),
+                Err(
+                    e @ (WrapperError::SessionExpired
+                    | WrapperError::SessionNotValid
+                    | WrapperError::QuotaExceeded(..)),
+                ) => return Err(e),
+                Err(e) => reason = e.to_string(),
+            }
+        }
+
+        self.data.associated_terms.remove(&term);
+        Err(WrapperError::TermNotUsable(term, reason))
+    }
+
+    /// Gets the terms that have been associated (see `associate_term`) through this wrapper
+    /// since it was created, or since the cookies were last changed.
+    ///
+    /// This is only a local record, so it won't know about terms that were associated some
+    /// other way (e.g., by selecting a term on WebReg itself), and it won't know if WebReg has
+    /// since forgotten about a term. Use `verify_associated_terms` to check with WebReg.
+    ///
+    /// # Returns
+    /// The associated terms, sorted.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies".to_string());
+    /// wrapper.associate_term("FA23").await.unwrap();
+    /// assert!(wrapper.is_term_associated("FA23"));
+    /// assert_eq!(vec!["FA23".to_string()], wrapper.get_associated_terms());
+    /// # }
+    /// ```
+    pub fn get_associated_terms(&self) -> Vec<String> {
+        self.data.associated_terms.list()
+    }
+
+    /// Checks whether a term has been associated through this wrapper. See
+    /// `get_associated_terms` for the limitations of this check.
+    ///
+    /// # Parameters
+    /// - `term`: The term.
+    ///
+    /// # Returns
+    /// `true` if the term has been associated and `false` otherwise.
+    pub fn is_term_associated(&self, term: impl AsRef<str>) -> bool {
+        self.data.associated_terms.contains(term.as_ref())
+    }
+
+    /// Checks every term in `get_associated_terms` with WebReg by requesting your schedule list
+    /// for each term. Terms that WebReg says aren't associated are removed from the record.
+    /// Terms that couldn't be checked for any other reason (e.g., a timeout) are kept.
+    ///
+    /// # Returns
+    /// The terms that are still associated, sorted.
+    pub async fn verify_associated_terms(&self) -> Vec<String> {
+        let terms = self.get_associated_terms();
+        let results = futures::future::join_all(
+            terms
+                .iter()
+                .map(|term| async move { self.req(term).raw().get_schedule_list().await }),
+        )
+        .await;
+
+        terms
+            .into_iter()
+            .zip(results)
+            .filter_map(|(term, res)| match res {
+                Err(e) if e.is_verification_error() => {
+                    self.data.associated_terms.remove(&term);
+                    None
+                }
+                _ => Some(term),
+            })
+            .collect()
+    }
+
     /// Pings the WebReg server. Presumably, this is the endpoint that is used to ensure that
     /// your (authenticated) session is still valid. In other words, if this isn't called, I
     /// assume that you will be logged out, rendering your cookies invalid.
@@ -273,32 +584,303 @@ impl<'a> WebRegWrapper {
     /// # Returns
     /// `true` if the ping was successful and `false` otherwise.
     pub async fn ping_server(&self) -> bool {
-        let res = self
-            .data
-            .req(ReqType::Get(format!(
-                "{}?_={}",
-                PING_SERVER,
-                util::get_epoch_time()
-            )))
-            .send()
-            .await;
-
-        if let Ok(r) = res {
-            let text = r.text().await.unwrap_or_else(|_| {
-                json!({
-                    "SESSION_OK": false
-                })
-                .to_string()
-            });
+        self.ping_details().await.is_ok_and(|p| p.session_ok)
+    }
+
+    /// Pings the WebReg server, like `ping_server`, but returns everything that WebReg gave
+    /// back instead of just whether the session is valid.
+    ///
+    /// # Returns
+    /// The ping response, or an error if the ping failed (e.g., if the session has expired).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// match wrapper.ping_details().await {
+    ///     Ok(ping) => println!("Session OK: {} (other fields: {:?})", ping.session_ok, ping.extra),
+    ///     Err(e) => eprintln!("Ping failed: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn ping_details(&self) -> types::Result<PingResponse> {
+        let url = self.data.build_url(PING_SERVER, &[])?;
+        process_get_text(
+            extract_text(&self.data, self.data.req(ReqType::Get(url)).send().await).await?,
+        )
+    }
+
+    /// Runs a few cheap checks to see whether the wrapper can currently talk to WebReg. In
+    /// particular, this
+    /// - pings the WebReg server (see `ping_server`),
+    /// - checks that the given term is associated with your session by making a cheap request
+    ///   for that term, and
+    /// - gets the name of the person that is logged in.
+    ///
+    /// The checks are run concurrently. This might be useful as a readiness probe for services
+    /// that depend on WebReg.
+    ///
+    /// # Parameters
+    /// - `term`: The term that your service uses.
+    ///
+    /// # Returns
+    /// The health report.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    /// let report = wrapper.health_check("FA23").await;
+    /// if !report.is_healthy() {
+    ///     for check in report.checks.iter().filter(|c| !c.ok) {
+    ///         eprintln!("{} failed: {:?}", check.name, check.error);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn health_check(&self, term: impl AsRef<str>) -> HealthReport {
+        let checked_at = self.data.clock.now();
+        let term = term.as_ref();
+
+        let ping = async {
+            let start = Instant::now();
+            let ok = self.ping_server().await;
+            HealthCheck {
+                name: "ping",
+                ok,
+                latency: start.elapsed(),
+                error: (!ok).then(|| "session is not valid".to_string()),
+            }
+        };
+
+        let term_check = async {
+            let start = Instant::now();
+            let res = self.req(term).parsed().get_department_codes().await;
+            HealthCheck {
+                name: "term",
+                ok: res.is_ok(),
+                latency: start.elapsed(),
+                error: res.err().map(|e| e.to_string()),
+            }
+        };
 
-            let json: Value = serde_json::from_str(&text).unwrap_or_default();
-            // Use of unwrap here is safe since we know that there is a boolean value beforehand
-            json["SESSION_OK"].is_boolean() && json["SESSION_OK"].as_bool().unwrap()
-        } else {
-            false
+        let name_check = async {
+            let start = Instant::now();
+            let res = self.fetch_account_name().await;
+            let check = HealthCheck {
+                name: "account_name",
+                ok: res.is_ok(),
+                latency: start.elapsed(),
+                error: res.as_ref().err().map(|e| e.to_string()),
+            };
+            (check, res.ok())
+        };
+
+        let (ping, term_check, (name_check, account_name)) =
+            futures::join!(ping, term_check, name_check);
+
+        HealthReport {
+            checks: vec![ping, term_check, name_check],
+            account_name,
+            checked_at,
         }
     }
 
+    /// Checks whether WebReg's responses can still be understood by this library. This makes
+    /// a cheap request to each major endpoint and checks that the response can be parsed. In
+    /// particular, this checks
+    /// - `ping-server` and `get-term` (which don't need a term),
+    /// - `search-load-subject`, `search-load-department`, `get-class`, `event-get`, and
+    ///   `sched-get-schednames` for the given term, and
+    /// - `search-load-group-data`, using the first course in your schedule (this is skipped
+    ///   if your schedule is empty).
+    ///
+    /// Strict parsing is always used, so that values that this library doesn't know about are
+    /// reported too. The requests are made one after another so that they don't add to the
+    /// load on WebReg. Services can run this (e.g., every morning) to find out about changes
+    /// to WebReg before their users do.
+    ///
+    /// # Parameters
+    /// - `term`: The term to check with. This term should be associated with your session.
+    ///
+    /// # Returns
+    /// The compatibility report.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    /// let report = wrapper.probe_api_compatibility("FA23").await;
+    /// for endpoint in report.incompatible() {
+    ///     eprintln!("{} changed: {:?}", endpoint.endpoint, endpoint.error);
+    /// }
+    /// # }
+    /// ```
+    pub async fn probe_api_compatibility(&self, term: impl AsRef<str>) -> CompatibilityReport {
+        let checked_at = self.data.clock.now();
+        let term = term.as_ref();
+        let req = || {
+            let mut builder = self.req(term);
+            builder.data.strict_parsing = true;
+            builder.parsed()
+        };
+
+        let mut endpoints = vec![
+            EndpointCompatibility::from_result("ping-server", &self.ping_details().await),
+            EndpointCompatibility::from_result("get-term", &self.get_all_term_info().await),
+            EndpointCompatibility::from_result(
+                "search-load-subject",
+                &req().get_subject_codes().await,
+            ),
+            EndpointCompatibility::from_result(
+                "search-load-department",
+                &req().get_department_codes().await,
+            ),
+        ];
+
+        let schedule = req().get_schedule(None).await;
+        endpoints.push(EndpointCompatibility::from_result("get-class", &schedule));
+        endpoints.push(EndpointCompatibility::from_result(
+            "event-get",
+            &req().get_events().await,
+        ));
+        endpoints.push(EndpointCompatibility::from_result(
+            "sched-get-schednames",
+            &req().get_schedule_list().await,
+        ));
+
+        let course = schedule.ok().and_then(|s| s.into_iter().next());
+        endpoints.push(match course {
+            Some(c) => EndpointCompatibility::from_result(
+                "search-load-group-data",
+                &req().get_course_info((c.subject_code, c.course_code)).await,
+            ),
+            None => EndpointCompatibility {
+                endpoint: "search-load-group-data",
+                compatibility: Compatibility::Skipped,
+                error: None,
+            },
+        });
+
+        CompatibilityReport {
+            wrapper_version: env!("CARGO_PKG_VERSION"),
+            endpoints,
+            checked_at,
+        }
+    }
+
+    /// Gets statistics for all requests that have been made through this wrapper, including
+    /// requests made through any requesters built from this wrapper.
+    ///
+    /// This might be useful if you have a long-running service and want to see how much you are
+    /// using WebReg (e.g., to tune how often you poll).
+    ///
+    /// # Returns
+    /// A copy of the statistics collected so far.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies".to_string());
+    /// _ = wrapper.req("FA23").parsed().get_course_info(("CSE", "100")).await;
+    ///
+    /// let stats = wrapper.stats();
+    /// println!("Made {} requests.", stats.total_requests);
+    /// println!("Average latency: {:?}", stats.average_latency());
+    /// # }
+    /// ```
+    pub fn stats(&self) -> WrapperStats {
+        self.data.stats.snapshot()
+    }
+
+    /// Clears all statistics that have been collected by this wrapper so far.
+    pub fn reset_stats(&self) {
+        self.data.stats.reset();
+    }
+
+    /// Clears this wrapper's cached subject and department lists, so that the next calls to
+    /// `get_subjects_wrapper_cached` and `get_departments_wrapper_cached` will request them
+    /// from WebReg again.
+    pub fn clear_catalog_cache(&self) {
+        self.data.catalog_cache.clear();
+    }
+
+    /// Gets all unique error reasons that WebReg has given for requests made through this
+    /// wrapper, along with how many times each reason was seen.
+    ///
+    /// WebReg's error reasons are often the only documentation of how WebReg behaves, so this
+    /// might be useful for discovering failure modes that you didn't know about.
+    ///
+    /// Note that error reasons are only collected if the wrapper was built with error
+    /// collection enabled (see the builder's `should_collect_errors` function).
+    ///
+    /// # Returns
+    /// All error reasons, grouped by endpoint. This will always be empty if error collection
+    /// isn't enabled.
+    pub fn observed_errors(&self) -> ObservedErrors {
+        self.data.errors.snapshot()
+    }
+
+    /// Gets every mutating request (e.g., enrolling, dropping, planning, or changing a grading
+    /// option) made through this wrapper, along with the parameters that were sent and the
+    /// response that WebReg gave. Validation requests are included, since WebReg also uses
+    /// `POST` requests for those.
+    ///
+    /// Note that requests are only recorded if the wrapper was built with action recording
+    /// enabled (see the builder's `should_record_actions` function).
+    ///
+    /// # Returns
+    /// The action log, which can be converted to JSON. This will always be empty if action
+    /// recording isn't enabled.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use webweg::wrapper::input_types::GradeOption;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("my cookies")
+    ///     .should_record_actions(true)
+    ///     .try_build_wrapper()
+    ///     .unwrap();
+    ///
+    /// _ = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .change_grading_option("235181", GradeOption::P)
+    ///     .await;
+    ///
+    /// println!("{}", wrapper.action_log().to_json().unwrap());
+    /// # }
+    /// ```
+    pub fn action_log(&self) -> ActionLog {
+        self.data.actions.snapshot()
+    }
+
+    /// Clears every mutating request that has been recorded by this wrapper so far.
+    pub fn clear_action_log(&self) {
+        self.data.actions.clear();
+    }
+
     /// Returns a request builder that can be used to customize any settings for a specific
     /// request only.
     ///
@@ -311,4 +893,20 @@ impl<'a> WebRegWrapper {
     pub fn req(&'a self, term: &'a str) -> WrapperTermRequestBuilder {
         WrapperTermRequestBuilder::new_request(&self.data, term)
     }
+
+    /// Returns a requester that can be used to make the same request for several terms at
+    /// once. This might be useful if you want to compare a course's offerings across terms.
+    ///
+    /// # Parameters
+    /// - `terms`: The terms to use for this request.
+    ///
+    /// # Returns
+    /// The multi-term requester.
+    pub fn req_many(&'a self, terms: &[&'a str]) -> WrapperMultiTermRequest<'a> {
+        WrapperMultiTermRequest {
+            data: &self.data,
+            terms: terms.to_vec(),
+            retry_budget: None,
+        }
+    }
 }
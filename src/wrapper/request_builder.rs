@@ -1,8 +1,11 @@
 use std::time::Duration;
 
 use crate::wrapper::request_data::WebRegWrapperDataRef;
+#[cfg(feature = "reqwest")]
 use reqwest::Client;
 
+use crate::types;
+use crate::types::{RetryBudget, WrapperError};
 use crate::wrapper::requester_term::{WrapperTermRawRequest, WrapperTermRequest};
 use crate::wrapper::WebRegWrapperData;
 
@@ -35,10 +38,37 @@ impl<'a> WrapperTermRequestBuilder<'a> {
                 cookies: wrapper_data.cookies.lock().to_owned(),
                 #[cfg(not(feature = "multi"))]
                 cookies: wrapper_data.cookies.as_ref(),
+                #[cfg(feature = "reqwest")]
                 client: &wrapper_data.client,
                 user_agent: wrapper_data.user_agent.as_str(),
                 timeout: wrapper_data.timeout,
                 close_after_request: wrapper_data.close_after_request,
+                cache_buster: wrapper_data.cache_buster,
+                stats: &wrapper_data.stats,
+                errors: &wrapper_data.errors,
+                actions: &wrapper_data.actions,
+                on_session_invalid: wrapper_data.on_session_invalid.as_ref(),
+                on_parse_warning: wrapper_data.on_parse_warning.as_ref(),
+                strict_parsing: wrapper_data.strict_parsing,
+                always_validate_enroll: wrapper_data.always_validate_enroll,
+                always_validate_plan: wrapper_data.always_validate_plan,
+                seat_strategy: wrapper_data.seat_strategy.as_ref(),
+                pacer: &wrapper_data.pacer,
+                rate_limiter: wrapper_data.rate_limiter.as_ref(),
+                term,
+                clock: wrapper_data.clock.as_ref(),
+                catalog_cache: &wrapper_data.catalog_cache,
+                retry_budget: None,
+                associated_terms: &wrapper_data.associated_terms,
+                schedule_cache: &wrapper_data.schedule_cache,
+                quota: &wrapper_data.quota,
+                closed_waitlists: &wrapper_data.closed_waitlists,
+                cookie_refresher: wrapper_data.cookie_refresher.as_ref(),
+                attempt_sink: wrapper_data.attempt_sink.as_deref(),
+                backend: wrapper_data.backend.as_deref(),
+                max_response_size: wrapper_data.max_response_size,
+                sleeper: wrapper_data.sleeper,
+                include_section_notes: false,
             },
             term,
         }
@@ -46,31 +76,36 @@ impl<'a> WrapperTermRequestBuilder<'a> {
 
     /// Overrides the cookies for any requests made under this soon-to-be requester.
     ///
-    /// If you plan on overriding cookies for this particular request, you should ensure
-    /// requests are being closed on completion (call `should_close_after_request` for the
-    /// builder).
+    /// This is only allowed if the wrapper closes the connection after each request (see the
+    /// builder's [`should_close_after_request`](crate::wrapper::wrapper_builder::WebRegWrapperBuilder::should_close_after_request)
+    /// function). WebReg ties a session to the connection it was created on, so if the
+    /// connection were reused, requests made with the overridden cookies could end up being
+    /// made under the wrapper's session (or the other way around).
     ///
     /// # Parameters
     /// - `cookies`: The cookies to use. This will _not_ override the cookies for the
     ///              wrapper, just this request.
     ///
     /// # Returns
-    /// The builder.
-    ///
-    /// # Panic
-    /// This function will only panic if you configured the wrapper instance so that it does _not_
-    /// close the connection after a request is done.
-    ///
-    /// For some context, when creating the wrapper instance, you have the option of setting
-    /// whether the connection is closed after a request is done (via the builder's
-    /// [`should_close_after_request`](crate::wrapper::wrapper_builder::WebRegWrapperBuilder@should_close_after_request)
-    /// function). If you never called this function, or you used the default constructor to create
-    /// the wrapper, or you explicitly set this value to `false`, then the wrapper instance will
-    /// not close the connection after a request is done and thus will panic when _this_ function
-    /// is called.
-    pub fn override_cookies(mut self, cookies: &'a str) -> Self {
+    /// The builder, or `WrapperError::CookieOverrideNotAllowed` if the wrapper doesn't close
+    /// the connection after each request.
+    ///
+    /// # Example
+    /// ```rust
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("my cookies")
+    ///     .should_close_after_request(true)
+    ///     .try_build_wrapper()
+    ///     .unwrap();
+    ///
+    /// let req = wrapper.req("FA23").try_override_cookies("other cookies");
+    /// assert!(req.is_ok());
+    /// ```
+    pub fn try_override_cookies(mut self, cookies: &'a str) -> types::Result<Self> {
         if !self.data.close_after_request {
-            panic!("Your wrapper must be configured to close the connection after a request is done in order to override the cookies.");
+            return Err(WrapperError::CookieOverrideNotAllowed);
         }
 
         #[cfg(feature = "multi")]
@@ -81,7 +116,30 @@ impl<'a> WrapperTermRequestBuilder<'a> {
         {
             self.data.cookies = cookies;
         }
-        self
+        // The cookie provider's cookies are for the wrapper's session, not this one.
+        self.data.cookie_refresher = None;
+        Ok(self)
+    }
+
+    /// Overrides the cookies for any requests made under this soon-to-be requester. See
+    /// `try_override_cookies` for more information.
+    ///
+    /// # Parameters
+    /// - `cookies`: The cookies to use. This will _not_ override the cookies for the
+    ///   wrapper, just this request.
+    ///
+    /// # Returns
+    /// The builder.
+    ///
+    /// # Panic
+    /// This function will panic if the wrapper is not configured to close the connection
+    /// after a request is done.
+    #[deprecated(since = "0.10.0", note = "use `try_override_cookies` instead")]
+    pub fn override_cookies(self, cookies: &'a str) -> Self {
+        match self.try_override_cookies(cookies) {
+            Ok(builder) => builder,
+            Err(e) => panic!("{e}"),
+        }
     }
 
     /// Overrides the client for any requests made under this soon-to-be requester.
@@ -92,6 +150,7 @@ impl<'a> WrapperTermRequestBuilder<'a> {
     ///
     /// # Returns
     /// The builder.
+    #[cfg(feature = "reqwest")]
     pub fn override_client(mut self, client: &'a Client) -> Self {
         self.data.client = client;
         self
@@ -123,6 +182,34 @@ impl<'a> WrapperTermRequestBuilder<'a> {
         self
     }
 
+    /// Sets the retry budget for any requests made under this soon-to-be requester. Every
+    /// request made by the requester, including the requests made by methods that make
+    /// several requests, will take its retries from this budget.
+    ///
+    /// # Parameters
+    /// - `budget`: The retry budget. This can be shared with other requesters.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_retry_budget(mut self, budget: &'a RetryBudget) -> Self {
+        self.data.retry_budget = Some(budget);
+        self
+    }
+
+    /// Sets whether `get_course_info` should also fetch the notes for each section (e.g.,
+    /// special enrollment instructions) and attach them to the sections. This takes one extra
+    /// request per call. By default, notes aren't fetched.
+    ///
+    /// # Parameters
+    /// - `include`: Whether to include section notes.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn should_include_section_notes(mut self, include: bool) -> Self {
+        self.data.include_section_notes = include;
+        self
+    }
+
     /// Builds the request builder. Note that this function is meant to be called
     /// internally by one of the two public build functions.
     ///
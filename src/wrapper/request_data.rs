@@ -1,10 +1,37 @@
+use futures::future::BoxFuture;
 #[cfg(feature = "multi")]
 use parking_lot::Mutex;
-use reqwest::header::{CONNECTION, COOKIE, USER_AGENT};
-use reqwest::{Client, IntoUrl, RequestBuilder};
-use std::time::Duration;
+#[cfg(feature = "reqwest")]
+use reqwest::Client;
+use serde::Serialize;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::{Mutex as AsyncMutex, Semaphore, SemaphorePermit};
 
-pub(crate) enum ReqType<U: IntoUrl> {
+use url::Url;
+
+use crate::constants::{ELIGIBILITY, SCHEDULE_CACHE_TTL, STATUS_START};
+use crate::types::{
+    ActionLog, ActionRecord, AttemptSink, Clock, CookieProvider, HttpBackend, HttpBackendError,
+    HttpRequest, HttpResponse, ObservedErrors, PacingProfile, ParseWarning, QuotaKind, RateLimit,
+    RateLimitScope, RequestErrorClass, RetryBudget, Schedule, SeatStrategy, UsageQuota,
+    WrapperError, WrapperStats,
+};
+use crate::wrapper::ww_helper;
+use crate::{types, util};
+
+// The names of the headers that the wrapper sets.
+const COOKIE: &str = "cookie";
+const USER_AGENT: &str = "user-agent";
+const CONNECTION: &str = "connection";
+const CONTENT_TYPE: &str = "content-type";
+
+pub(crate) enum ReqType<U: AsRef<str>> {
     Post(U),
     Get(U),
 }
@@ -17,6 +44,7 @@ pub struct WebRegWrapperData {
     #[cfg(not(feature = "multi"))]
     pub(crate) cookies: String,
     /// The client used to make the request.
+    #[cfg(feature = "reqwest")]
     pub(crate) client: Client,
     /// The user agent.
     pub(crate) user_agent: String,
@@ -31,6 +59,53 @@ pub struct WebRegWrapperData {
     /// It is recommended that this field's value is set to `false` if you do not need to switch
     /// cookies for this wrapper.
     pub(crate) close_after_request: bool,
+    /// Whether to include the `_` (cache-busting) query parameter in requests.
+    pub(crate) cache_buster: bool,
+    /// The statistics for every request made through this wrapper.
+    pub(crate) stats: StatsTracker,
+    /// The error reasons that WebReg has given for requests made through this wrapper.
+    pub(crate) errors: ErrorCollector,
+    /// The mutating requests made through this wrapper.
+    pub(crate) actions: ActionRecorder,
+    /// The function to call when WebReg indicates that the session is no longer valid.
+    pub(crate) on_session_invalid: Option<SessionInvalidCallback>,
+    /// The function to call for each data-quality issue found while parsing.
+    pub(crate) on_parse_warning: Option<ParseWarningCallback>,
+    /// Whether values that WebReg isn't known to give should cause an error instead of a
+    /// warning.
+    pub(crate) strict_parsing: bool,
+    /// Whether enrolling or waitlisting should be validated by default.
+    pub(crate) always_validate_enroll: bool,
+    /// Whether planning should be validated by default.
+    pub(crate) always_validate_plan: bool,
+    /// The strategy used to decide whether a section has seats.
+    pub(crate) seat_strategy: Arc<dyn SeatStrategy>,
+    /// Controls how quickly requests are made.
+    pub(crate) pacer: Pacer,
+    /// Throttles requests to the rate limit, if any.
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    /// The source of the current time.
+    pub(crate) clock: Arc<dyn Clock>,
+    /// The cached subject and department lists.
+    pub(crate) catalog_cache: CatalogCache,
+    /// The terms that have been associated with the wrapper's cookies.
+    pub(crate) associated_terms: TermTracker,
+    /// The schedules recently fetched by the mutation helpers.
+    pub(crate) schedule_cache: ScheduleCache,
+    /// Enforces the self-imposed request quotas.
+    pub(crate) quota: QuotaGuard,
+    /// The sections whose waitlist WebReg has said is full.
+    pub(crate) closed_waitlists: WaitlistTracker,
+    /// The source of fresh cookies for when the session is no longer valid, if any.
+    pub(crate) cookie_refresher: Option<CookieRefresher>,
+    /// Where attempts made by the automated enrollment helpers are recorded, if anywhere.
+    pub(crate) attempt_sink: Option<Arc<dyn AttemptSink>>,
+    /// What sends requests in place of the client, if anything.
+    pub(crate) backend: Option<Arc<dyn HttpBackend>>,
+    /// The largest response, in bytes, that will be read, if there's a limit.
+    pub(crate) max_response_size: Option<u64>,
+    /// How the wrapper waits (e.g., between retries).
+    pub(crate) sleeper: Sleeper,
 }
 
 impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperData {
@@ -43,6 +118,7 @@ impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperData {
         self.cookies.as_str()
     }
 
+    #[cfg(feature = "reqwest")]
     fn get_client(&'a self) -> &'a Client {
         &self.client
     }
@@ -58,6 +134,110 @@ impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperData {
     fn close_after_request(&'a self) -> bool {
         self.close_after_request
     }
+
+    fn include_cache_buster(&'a self) -> bool {
+        self.cache_buster
+    }
+
+    fn get_stats(&'a self) -> &'a StatsTracker {
+        &self.stats
+    }
+
+    fn get_error_collector(&'a self) -> &'a ErrorCollector {
+        &self.errors
+    }
+
+    fn get_action_recorder(&'a self) -> &'a ActionRecorder {
+        &self.actions
+    }
+
+    fn notify_session_invalid(&'a self) {
+        if let Some(f) = &self.on_session_invalid {
+            f();
+        }
+    }
+
+    fn report_parse_warnings(&'a self, warnings: &[ParseWarning]) {
+        if let Some(f) = &self.on_parse_warning {
+            warnings.iter().for_each(|w| f(w));
+        }
+    }
+
+    fn strict_parsing(&'a self) -> bool {
+        self.strict_parsing
+    }
+
+    fn always_validate_enroll(&'a self) -> bool {
+        self.always_validate_enroll
+    }
+
+    fn always_validate_plan(&'a self) -> bool {
+        self.always_validate_plan
+    }
+
+    fn get_seat_strategy(&'a self) -> &'a dyn SeatStrategy {
+        self.seat_strategy.as_ref()
+    }
+
+    fn get_pacer(&'a self) -> &'a Pacer {
+        &self.pacer
+    }
+
+    fn get_rate_limiter(&'a self) -> Option<&'a RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    fn get_term(&'a self) -> Option<&'a str> {
+        None
+    }
+
+    fn get_clock(&'a self) -> &'a dyn Clock {
+        self.clock.as_ref()
+    }
+
+    fn get_catalog_cache(&'a self) -> &'a CatalogCache {
+        &self.catalog_cache
+    }
+
+    fn get_retry_budget(&'a self) -> Option<&'a RetryBudget> {
+        None
+    }
+
+    fn get_term_tracker(&'a self) -> &'a TermTracker {
+        &self.associated_terms
+    }
+
+    fn get_schedule_cache(&'a self) -> &'a ScheduleCache {
+        &self.schedule_cache
+    }
+
+    fn get_quota_guard(&'a self) -> &'a QuotaGuard {
+        &self.quota
+    }
+
+    fn get_waitlist_tracker(&'a self) -> &'a WaitlistTracker {
+        &self.closed_waitlists
+    }
+
+    fn get_cookie_refresher(&'a self) -> Option<&'a CookieRefresher> {
+        self.cookie_refresher.as_ref()
+    }
+
+    fn get_attempt_sink(&'a self) -> Option<&'a dyn AttemptSink> {
+        self.attempt_sink.as_deref()
+    }
+
+    fn get_backend(&'a self) -> Option<&'a dyn HttpBackend> {
+        self.backend.as_deref()
+    }
+
+    fn get_max_response_size(&'a self) -> Option<u64> {
+        self.max_response_size
+    }
+
+    fn get_sleeper(&'a self) -> Sleeper {
+        self.sleeper
+    }
 }
 
 /// A structure that represents data held by the wrapper or a request.
@@ -68,6 +248,7 @@ pub(crate) struct WebRegWrapperDataRef<'a> {
     #[cfg(not(feature = "multi"))]
     pub cookies: &'a str,
     /// The client used to make the request.
+    #[cfg(feature = "reqwest")]
     pub client: &'a Client,
     /// The user agent.
     pub user_agent: &'a str,
@@ -79,6 +260,59 @@ pub(crate) struct WebRegWrapperDataRef<'a> {
     /// with the same wrapper. However, this also means that you'll incur a performance hit when
     /// making a request.
     pub close_after_request: bool,
+    /// Whether to include the `_` (cache-busting) query parameter in requests.
+    pub cache_buster: bool,
+    /// The statistics tracker belonging to the wrapper.
+    pub stats: &'a StatsTracker,
+    /// The error collector belonging to the wrapper.
+    pub errors: &'a ErrorCollector,
+    /// The action recorder belonging to the wrapper.
+    pub actions: &'a ActionRecorder,
+    /// The function to call when WebReg indicates that the session is no longer valid.
+    pub on_session_invalid: Option<&'a SessionInvalidCallback>,
+    /// The function to call for each data-quality issue found while parsing.
+    pub on_parse_warning: Option<&'a ParseWarningCallback>,
+    /// Whether values that WebReg isn't known to give should cause an error instead of a
+    /// warning.
+    pub strict_parsing: bool,
+    /// Whether enrolling or waitlisting should be validated by default.
+    pub always_validate_enroll: bool,
+    /// Whether planning should be validated by default.
+    pub always_validate_plan: bool,
+    /// The strategy used to decide whether a section has seats.
+    pub seat_strategy: &'a dyn SeatStrategy,
+    /// The pacer belonging to the wrapper.
+    pub pacer: &'a Pacer,
+    /// The rate limiter belonging to the wrapper, if any.
+    pub rate_limiter: Option<&'a RateLimiter>,
+    /// The term that requests are being made for.
+    pub term: &'a str,
+    /// The source of the current time.
+    pub clock: &'a dyn Clock,
+    /// The cached subject and department lists belonging to the wrapper.
+    pub catalog_cache: &'a CatalogCache,
+    /// The retry budget shared by the requests made under this requester, if any.
+    pub retry_budget: Option<&'a RetryBudget>,
+    /// The terms that have been associated with the wrapper's cookies.
+    pub associated_terms: &'a TermTracker,
+    /// The schedules recently fetched by the mutation helpers.
+    pub schedule_cache: &'a ScheduleCache,
+    /// The quota guard belonging to the wrapper.
+    pub quota: &'a QuotaGuard,
+    /// The sections whose waitlist WebReg has said is full.
+    pub closed_waitlists: &'a WaitlistTracker,
+    /// The cookie refresher belonging to the wrapper, if any.
+    pub cookie_refresher: Option<&'a CookieRefresher>,
+    /// The attempt sink belonging to the wrapper, if any.
+    pub attempt_sink: Option<&'a dyn AttemptSink>,
+    /// The backend belonging to the wrapper, if any.
+    pub backend: Option<&'a dyn HttpBackend>,
+    /// The largest response, in bytes, that will be read, if there's a limit.
+    pub max_response_size: Option<u64>,
+    /// How the wrapper waits (e.g., between retries).
+    pub sleeper: Sleeper,
+    /// Whether `get_course_info` should also fetch and attach section notes.
+    pub include_section_notes: bool,
 }
 
 impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperDataRef<'a> {
@@ -91,6 +325,7 @@ impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperDataRef<'a> {
         self.cookies
     }
 
+    #[cfg(feature = "reqwest")]
     fn get_client(&'a self) -> &'a Client {
         self.client
     }
@@ -106,6 +341,110 @@ impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperDataRef<'a> {
     fn close_after_request(&'a self) -> bool {
         self.close_after_request
     }
+
+    fn include_cache_buster(&'a self) -> bool {
+        self.cache_buster
+    }
+
+    fn get_stats(&'a self) -> &'a StatsTracker {
+        self.stats
+    }
+
+    fn get_error_collector(&'a self) -> &'a ErrorCollector {
+        self.errors
+    }
+
+    fn get_action_recorder(&'a self) -> &'a ActionRecorder {
+        self.actions
+    }
+
+    fn notify_session_invalid(&'a self) {
+        if let Some(f) = self.on_session_invalid {
+            f();
+        }
+    }
+
+    fn report_parse_warnings(&'a self, warnings: &[ParseWarning]) {
+        if let Some(f) = self.on_parse_warning {
+            warnings.iter().for_each(|w| f(w));
+        }
+    }
+
+    fn strict_parsing(&'a self) -> bool {
+        self.strict_parsing
+    }
+
+    fn always_validate_enroll(&'a self) -> bool {
+        self.always_validate_enroll
+    }
+
+    fn always_validate_plan(&'a self) -> bool {
+        self.always_validate_plan
+    }
+
+    fn get_seat_strategy(&'a self) -> &'a dyn SeatStrategy {
+        self.seat_strategy
+    }
+
+    fn get_pacer(&'a self) -> &'a Pacer {
+        self.pacer
+    }
+
+    fn get_rate_limiter(&'a self) -> Option<&'a RateLimiter> {
+        self.rate_limiter
+    }
+
+    fn get_term(&'a self) -> Option<&'a str> {
+        Some(self.term)
+    }
+
+    fn get_clock(&'a self) -> &'a dyn Clock {
+        self.clock
+    }
+
+    fn get_catalog_cache(&'a self) -> &'a CatalogCache {
+        self.catalog_cache
+    }
+
+    fn get_retry_budget(&'a self) -> Option<&'a RetryBudget> {
+        self.retry_budget
+    }
+
+    fn get_term_tracker(&'a self) -> &'a TermTracker {
+        self.associated_terms
+    }
+
+    fn get_schedule_cache(&'a self) -> &'a ScheduleCache {
+        self.schedule_cache
+    }
+
+    fn get_quota_guard(&'a self) -> &'a QuotaGuard {
+        self.quota
+    }
+
+    fn get_waitlist_tracker(&'a self) -> &'a WaitlistTracker {
+        self.closed_waitlists
+    }
+
+    fn get_cookie_refresher(&'a self) -> Option<&'a CookieRefresher> {
+        self.cookie_refresher
+    }
+
+    fn get_attempt_sink(&'a self) -> Option<&'a dyn AttemptSink> {
+        self.attempt_sink
+    }
+
+    fn get_backend(&'a self) -> Option<&'a dyn HttpBackend> {
+        self.backend
+    }
+
+    fn get_max_response_size(&'a self) -> Option<u64> {
+        self.max_response_size
+    }
+
+    fn get_sleeper(&'a self) -> Sleeper {
+        self.sleeper
+    }
 }
 
 pub(crate) trait ReqwestWebRegClientData<'a> {
@@ -122,6 +461,7 @@ pub(crate) trait ReqwestWebRegClientData<'a> {
     ///
     /// # Returns
     /// The client.
+    #[cfg(feature = "reqwest")]
     fn get_client(&'a self) -> &'a Client;
 
     /// The user agent to be used for this request.
@@ -142,6 +482,190 @@ pub(crate) trait ReqwestWebRegClientData<'a> {
     /// Whether the connection should be closed after the request is completed.
     fn close_after_request(&'a self) -> bool;
 
+    /// Whether the `_` (cache-busting) query parameter should be included in requests.
+    ///
+    /// # Returns
+    /// Whether the cache-busting parameter should be included.
+    fn include_cache_buster(&'a self) -> bool;
+
+    /// The tracker that any requests should be recorded to.
+    ///
+    /// # Returns
+    /// The statistics tracker.
+    fn get_stats(&'a self) -> &'a StatsTracker;
+
+    /// The collector that any error reasons from WebReg should be recorded to.
+    ///
+    /// # Returns
+    /// The error collector.
+    fn get_error_collector(&'a self) -> &'a ErrorCollector;
+
+    /// The recorder that any mutating requests should be recorded to.
+    ///
+    /// # Returns
+    /// The action recorder.
+    fn get_action_recorder(&'a self) -> &'a ActionRecorder;
+
+    /// Notifies the user, through their callback (if any), that WebReg indicated that the
+    /// session is no longer valid.
+    fn notify_session_invalid(&'a self);
+
+    /// Passes any data-quality issues found while parsing to the user's callback, if any.
+    ///
+    /// # Parameters
+    /// - `warnings`: The warnings.
+    fn report_parse_warnings(&'a self, warnings: &[ParseWarning]);
+
+    /// Whether values that WebReg isn't known to give should cause an error.
+    ///
+    /// # Returns
+    /// Whether strict parsing is enabled.
+    fn strict_parsing(&'a self) -> bool;
+
+    /// Whether enrolling or waitlisting should be validated when the caller doesn't say.
+    ///
+    /// # Returns
+    /// Whether to validate by default.
+    fn always_validate_enroll(&'a self) -> bool;
+
+    /// Whether planning should be validated when the caller doesn't say.
+    ///
+    /// # Returns
+    /// Whether to validate by default.
+    fn always_validate_plan(&'a self) -> bool;
+
+    /// Passes any data-quality issues found while parsing to the user's callback (see
+    /// `report_parse_warnings`). Then, if strict parsing is enabled, fails on the first issue
+    /// caused by a value that WebReg isn't known to give.
+    ///
+    /// # Parameters
+    /// - `warnings`: The warnings.
+    ///
+    /// # Returns
+    /// Nothing, or the error if strict parsing is enabled and there was an unknown value.
+    fn check_parse_warnings(&'a self, warnings: &[ParseWarning]) -> types::Result<()> {
+        self.report_parse_warnings(warnings);
+        if !self.strict_parsing() {
+            return Ok(());
+        }
+
+        match warnings.iter().find(|w| w.is_unknown_value()) {
+            Some(w) => Err(WrapperError::UnknownValue(w.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// The strategy that should be used to decide whether a section has seats.
+    ///
+    /// # Returns
+    /// The seat strategy.
+    fn get_seat_strategy(&'a self) -> &'a dyn SeatStrategy;
+
+    /// The pacer that any requests should go through.
+    ///
+    /// # Returns
+    /// The pacer.
+    fn get_pacer(&'a self) -> &'a Pacer;
+
+    /// The rate limiter that any requests should go through, if any.
+    ///
+    /// # Returns
+    /// The rate limiter.
+    fn get_rate_limiter(&'a self) -> Option<&'a RateLimiter>;
+
+    /// The term that requests are being made for, if any.
+    ///
+    /// # Returns
+    /// The term, or `None` if the requests don't belong to a term.
+    fn get_term(&'a self) -> Option<&'a str>;
+
+    /// The source of the current time.
+    ///
+    /// # Returns
+    /// The clock.
+    fn get_clock(&'a self) -> &'a dyn Clock;
+
+    /// The cache that subject and department lists should be stored in.
+    ///
+    /// # Returns
+    /// The catalog cache.
+    fn get_catalog_cache(&'a self) -> &'a CatalogCache;
+
+    /// The retry budget that requests should take retries from, if any.
+    ///
+    /// # Returns
+    /// The retry budget, or `None` if retries are only limited by the pacing settings.
+    fn get_retry_budget(&'a self) -> Option<&'a RetryBudget>;
+
+    /// The record of which terms have been associated.
+    ///
+    /// # Returns
+    /// The term tracker.
+    fn get_term_tracker(&'a self) -> &'a TermTracker;
+
+    /// The cache that schedules fetched by the mutation helpers should be stored in.
+    ///
+    /// # Returns
+    /// The schedule cache.
+    fn get_schedule_cache(&'a self) -> &'a ScheduleCache;
+
+    /// The quota guard that every request should be checked against.
+    ///
+    /// # Returns
+    /// The quota guard.
+    fn get_quota_guard(&'a self) -> &'a QuotaGuard;
+
+    /// The record of which sections' waitlists are known to be full.
+    ///
+    /// # Returns
+    /// The waitlist tracker.
+    fn get_waitlist_tracker(&'a self) -> &'a WaitlistTracker;
+
+    /// The source of fresh cookies for when the session is no longer valid, if any.
+    ///
+    /// # Returns
+    /// The cookie refresher, or `None` if requests shouldn't try to get fresh cookies.
+    fn get_cookie_refresher(&'a self) -> Option<&'a CookieRefresher>;
+
+    /// Where attempts made by the automated enrollment helpers should be recorded, if
+    /// anywhere.
+    ///
+    /// # Returns
+    /// The attempt sink.
+    fn get_attempt_sink(&'a self) -> Option<&'a dyn AttemptSink>;
+
+    /// The backend that requests should be sent with in place of the client, if any.
+    ///
+    /// # Returns
+    /// The backend, or `None` if requests should be sent with the client.
+    fn get_backend(&'a self) -> Option<&'a dyn HttpBackend>;
+
+    /// The largest response, in bytes, that requests should read, if there's a limit.
+    ///
+    /// # Returns
+    /// The limit, or `None` if responses can be any size.
+    fn get_max_response_size(&'a self) -> Option<u64>;
+
+    /// How requests should wait (e.g., between retries, or to stay under the rate limit).
+    ///
+    /// # Returns
+    /// The sleeper.
+    fn get_sleeper(&'a self) -> Sleeper;
+
+    /// Builds a URL with the specified query parameters. If enabled, the `_` (cache-busting)
+    /// parameter will be added as the last query parameter.
+    ///
+    /// # Parameters
+    /// - `base`: The base URL.
+    /// - `params`: The query parameters.
+    ///
+    /// # Returns
+    /// The URL, or an error if the URL is malformed.
+    fn build_url(&'a self, base: &str, params: &[(&str, &str)]) -> types::Result<Url> {
+        let now = self.include_cache_buster().then(|| self.get_clock().now());
+        util::build_url(base, params, now)
+    }
+
     /// Makes a request with the desired request type using the headers provided by the user.
     ///
     /// # Parameters
@@ -149,23 +673,1366 @@ pub(crate) trait ReqwestWebRegClientData<'a> {
     ///
     /// # Returns
     /// A request builder that can further be built on top of, if needed.
-    fn req<U>(&'a self, req_type: ReqType<U>) -> RequestBuilder
+    fn req<U>(&'a self, req_type: ReqType<U>) -> TrackedRequestBuilder<'a>
     where
-        U: IntoUrl,
+        U: AsRef<str>,
     {
-        let client = self.get_client();
-        let mut req = match req_type {
-            ReqType::Post(u) => client.post(u),
-            ReqType::Get(u) => client.get(u),
-        }
-        .header(COOKIE, self.get_cookies())
-        .header(USER_AGENT, self.get_user_agent())
-        .timeout(self.get_timeout());
+        let (method, url, is_mutation) = match req_type {
+            ReqType::Post(u) => ("POST", u.as_ref().to_owned(), true),
+            ReqType::Get(u) => ("GET", u.as_ref().to_owned(), false),
+        };
+        let endpoint = get_endpoint_name(&url);
 
+        // Cookies from the cookie provider take priority, since they're the most recent.
+        let refresher = self.get_cookie_refresher();
+        let refreshed = refresher.and_then(|r| r.latest());
+        let cookies = match &refreshed {
+            Some(cookies) => cookies.to_owned(),
+            None => self.get_cookies().to_owned(),
+        };
+
+        let reauth = refresher.map(|refresher| Reauth {
+            host: Url::parse(&url)
+                .ok()
+                .and_then(|u| u.host_str().map(str::to_owned)),
+            refresher,
+            terms: self.get_term_tracker(),
+            sent: refreshed,
+        });
+
+        let mut headers = vec![
+            (COOKIE.to_owned(), cookies),
+            (USER_AGENT.to_owned(), self.get_user_agent().to_owned()),
+        ];
         if self.close_after_request() {
-            req = req.header(CONNECTION, "close");
+            headers.push((CONNECTION.to_owned(), "close".to_owned()));
+        }
+
+        let actions = self.get_action_recorder();
+        TrackedRequestBuilder {
+            inner: HttpRequest {
+                method: method.to_owned(),
+                url,
+                headers,
+                body: None,
+                timeout: Some(self.get_timeout()),
+            },
+            form_error: false,
+            #[cfg(feature = "reqwest")]
+            client: self.get_client(),
+            endpoint,
+            stats: self.get_stats(),
+            actions: (is_mutation && actions.is_enabled()).then_some(actions),
+            clock: self.get_clock(),
+            pacer: self.get_pacer(),
+            rate_limiter: self.get_rate_limiter(),
+            term: self.get_term(),
+            retry_budget: self.get_retry_budget(),
+            quota: self.get_quota_guard(),
+            is_mutation,
+            reauth,
+            backend: self.get_backend(),
+            max_response_size: self.get_max_response_size(),
+            sleeper: self.get_sleeper(),
+        }
+    }
+}
+
+/// A function that is called when WebReg indicates that the session is no longer valid.
+pub(crate) type SessionInvalidCallback = Arc<dyn Fn() + Send + Sync>;
+
+/// A function that is called for each data-quality issue found while parsing WebReg's data.
+pub(crate) type ParseWarningCallback = Arc<dyn Fn(&ParseWarning) + Send + Sync>;
+
+/// A function that gives a future which finishes once the given amount of time has passed.
+pub(crate) type Sleeper = fn(Duration) -> BoxFuture<'static, ()>;
+
+/// Waits with `tokio`'s timer. This is how the wrapper waits, unless it's driven without a
+/// `tokio` runtime (see the `blocking` module).
+///
+/// # Parameters
+/// - `duration`: How long to wait.
+///
+/// # Returns
+/// The future that finishes once the time has passed.
+pub(crate) fn tokio_sleep(duration: Duration) -> BoxFuture<'static, ()> {
+    Box::pin(tokio::time::sleep(duration))
+}
+
+/// Gets the name of the endpoint that a URL points to. This is just the last part of the
+/// URL's path, so `https://act.ucsd.edu/webreg2/svc/wradapter/secure/get-class?...` would
+/// give `get-class`.
+///
+/// # Parameters
+/// - `url`: The URL.
+///
+/// # Returns
+/// The endpoint name.
+pub(crate) fn get_endpoint_name(url: &str) -> String {
+    let path = url.split_once('?').map(|(path, _)| path).unwrap_or(url);
+    path.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// A thin layer over an `HttpRequest` that paces the request according to the wrapper's
+/// pacing settings, sends it with the wrapper's backend (or, if there isn't one, the wrapper's
+/// client), and records the outcome of the request to the wrapper's statistics tracker once it
+/// is sent.
+pub(crate) struct TrackedRequestBuilder<'a> {
+    inner: HttpRequest,
+    /// Whether the form data given to `form` couldn't be encoded.
+    form_error: bool,
+    #[cfg(feature = "reqwest")]
+    client: &'a Client,
+    endpoint: String,
+    stats: &'a StatsTracker,
+    actions: Option<&'a ActionRecorder>,
+    clock: &'a dyn Clock,
+    pacer: &'a Pacer,
+    rate_limiter: Option<&'a RateLimiter>,
+    term: Option<&'a str>,
+    retry_budget: Option<&'a RetryBudget>,
+    quota: &'a QuotaGuard,
+    is_mutation: bool,
+    reauth: Option<Reauth<'a>>,
+    backend: Option<&'a dyn HttpBackend>,
+    max_response_size: Option<u64>,
+    sleeper: Sleeper,
+}
+
+/// What a request needs in order to get fresh cookies if the session turns out to be no
+/// longer valid.
+struct Reauth<'a> {
+    /// The host that the request was made to.
+    host: Option<String>,
+    refresher: &'a CookieRefresher,
+    terms: &'a TermTracker,
+    /// The cookies from the cookie provider that the request was made with, if any.
+    sent: Option<String>,
+}
+
+impl<'a> Reauth<'a> {
+    /// Gets fresh cookies and puts them into a copy of the request. Since the fresh cookies
+    /// belong to a new session, every term is forgotten.
+    ///
+    /// # Parameters
+    /// - `req`: A copy of the request.
+    ///
+    /// # Returns
+    /// The request with the fresh cookies, or the error that occurred when getting them.
+    async fn renew(self, mut req: HttpRequest) -> types::Result<HttpRequest> {
+        let cookies = self.refresher.refresh(self.sent.as_deref()).await?;
+        self.terms.clear();
+
+        if !is_valid_header_value(&cookies) {
+            return Err(WrapperError::InputError(
+                "cookies",
+                "the new cookies aren't valid",
+            ));
+        }
+
+        req.headers
+            .retain(|(name, _)| !name.eq_ignore_ascii_case(COOKIE));
+        req.headers.insert(0, (COOKIE.to_owned(), cookies));
+        Ok(req)
+    }
+}
+
+impl<'a> TrackedRequestBuilder<'a> {
+    /// Sets the form body of this request.
+    ///
+    /// # Parameters
+    /// - `form`: The form data.
+    ///
+    /// # Returns
+    /// The request builder.
+    pub fn form<T: Serialize + ?Sized>(mut self, form: &T) -> Self {
+        match serde_urlencoded::to_string(form) {
+            Ok(body) => {
+                self.inner
+                    .headers
+                    .retain(|(name, _)| !name.eq_ignore_ascii_case(CONTENT_TYPE));
+                self.inner.headers.push((
+                    CONTENT_TYPE.to_owned(),
+                    "application/x-www-form-urlencoded".to_owned(),
+                ));
+                self.inner.body = Some(body.into_bytes());
+            }
+            Err(_) => self.form_error = true,
+        }
+
+        self
+    }
+
+    /// Sends the request, recording the result of the request. If the request fails in a way
+    /// that is worth retrying, it will be retried as allowed by the pacing settings and the
+    /// retry budget (if any), unless the request is a mutation (a `POST` request). If the
+    /// retry budget is all that stops the request from being retried, a `RetryBudgetExhausted`
+    /// error is returned. If the
+    /// request fails because the session is no longer valid and there's a cookie provider, the
+    /// request's term (if any) is associated with the new session, and the request is retried
+    /// once with fresh cookies.
+    ///
+    /// # Returns
+    /// The response from WebReg, or the error that occurred when sending the request. If
+    /// sending the request would go over one of the wrapper's quotas, a `QuotaExceeded` error
+    /// is returned instead.
+    pub async fn send(self) -> types::Result<HttpResponse> {
+        if self.form_error {
+            return Err(WrapperError::InputError(
+                "form",
+                "the form data couldn't be encoded",
+            ));
+        }
+
+        if !self
+            .inner
+            .headers
+            .iter()
+            .all(|(_, value)| is_valid_header_value(value))
+        {
+            return Err(WrapperError::InputError(
+                "headers",
+                "a header (e.g., the cookies) contains characters that can't be sent",
+            ));
+        }
+
+        let profile = self.pacer.profile;
+        let mut backoff = profile.retry_backoff;
+        let mut attempt = 0;
+        let mut req = self.inner.clone();
+        let mut reauth = self.reauth;
+        let params = self.actions.map(|_| get_form_params(&req));
+        loop {
+            // Keep a copy of the request in case we need to retry it.
+            let next = (attempt < profile.max_retries || reauth.is_some()).then(|| req.clone());
+
+            self.quota.try_acquire(self.is_mutation)?;
+            if let Some(limiter) = self.rate_limiter {
+                limiter.acquire(self.term, self.sleeper).await;
+            }
+            let permit = self.pacer.wait_turn(&self.endpoint, self.sleeper).await;
+            let start = Instant::now();
+            // The whole response is read here, so that the size limit applies to every
+            // response and the bytes that were actually received (after decompression) are
+            // counted.
+            let mut bytes_read = 0;
+            let res = match self.backend {
+                Some(backend) => {
+                    send_with_backend(backend, req, self.max_response_size, &mut bytes_read).await
+                }
+                #[cfg(feature = "reqwest")]
+                None => {
+                    send_with_client(self.client, req, self.max_response_size, &mut bytes_read)
+                        .await
+                }
+                #[cfg(not(feature = "reqwest"))]
+                None => Err(WrapperError::InputError(
+                    "backend",
+                    "a backend is needed to send requests without the `reqwest` feature",
+                )),
+            };
+            self.stats
+                .record(self.endpoint.clone(), start.elapsed(), &res, bytes_read);
+            drop(permit);
+
+            let auth_failed = res.as_ref().is_ok_and(|r| {
+                reauth
+                    .as_ref()
+                    .is_some_and(|a| ww_helper::is_auth_failure(r, a.host.as_deref()))
+            });
+            let auth = if auth_failed { reauth.take() } else { None };
+            // Mutations are never retried, since WebReg may have applied a request that
+            // timed out or failed partway through (e.g., enrolling twice).
+            let should_retry = !self.is_mutation
+                && match &res {
+                    Ok(r) => (500..600).contains(&r.status) || r.status == 429,
+                    Err(e) => e.is_transient(),
+                };
+
+            let wants_retry = auth.is_none() && should_retry && next.is_some();
+            let can_retry = wants_retry && self.retry_budget.is_none_or(|b| b.try_spend());
+            match (auth, next) {
+                (Some(auth), Some(next)) => {
+                    // Getting fresh cookies doesn't count as a retry, since the request failed
+                    // because of the session rather than WebReg.
+                    let terms = auth.terms;
+                    req = auth.renew(next).await?;
+                    // The new session doesn't know about any term yet, so the request would
+                    // just fail with a verification error.
+                    if let Some(term) = self.term {
+                        let sibling = |inner, endpoint| TrackedRequestBuilder {
+                            inner,
+                            form_error: false,
+                            #[cfg(feature = "reqwest")]
+                            client: self.client,
+                            endpoint,
+                            stats: self.stats,
+                            actions: None,
+                            clock: self.clock,
+                            pacer: self.pacer,
+                            rate_limiter: self.rate_limiter,
+                            term: self.term,
+                            retry_budget: self.retry_budget,
+                            quota: self.quota,
+                            is_mutation: false,
+                            reauth: None,
+                            backend: self.backend,
+                            max_response_size: self.max_response_size,
+                            sleeper: self.sleeper,
+                        };
+                        reassociate_term(term, &req, sibling).await?;
+                        terms.insert(term);
+                    }
+                }
+                (None, Some(next)) if can_retry => {
+                    (self.sleeper)(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                    req = next;
+                }
+                // Only the retry budget stopped the request from being retried.
+                _ if wants_retry => {
+                    let err = match res {
+                        Ok(r) => WrapperError::BadStatusCode(r.status, Some(r.text())),
+                        Err(e) => e,
+                    };
+                    return Err(WrapperError::RetryBudgetExhausted(Box::new(err)));
+                }
+                _ => {
+                    return match (self.actions, params) {
+                        (Some(actions), Some(params)) => {
+                            let record = ActionRecord {
+                                at: self.clock.now(),
+                                term: self.term.map(str::to_owned),
+                                endpoint: self.endpoint,
+                                params,
+                                status: None,
+                                response: None,
+                                error: None,
+                            };
+                            actions.record_response(record, res)
+                        }
+                        _ => res,
+                    };
+                }
+            }
         }
+    }
+}
+
+/// Checks whether a value can be sent as a header value. Like `reqwest`, this allows any
+/// visible character, spaces, and tabs, but no other control characters.
+///
+/// # Parameters
+/// - `value`: The header value.
+///
+/// # Returns
+/// Whether the value can be sent.
+fn is_valid_header_value(value: &str) -> bool {
+    value
+        .bytes()
+        .all(|b| (b >= 0x20 && b != 0x7f) || b == b'\t')
+}
+
+/// Sends a request with the wrapper's client, reading the entire body of the response. If the
+/// response is compressed, the limit applies to the decompressed body.
+///
+/// # Parameters
+/// - `client`: The client.
+/// - `req`: The request.
+/// - `limit`: The largest body, in bytes, that should be read, if there's a limit.
+/// - `bytes_read`: Set to the number of bytes that were read, even if an error occurred.
+///
+/// # Returns
+/// The response, or the error that occurred when sending the request or reading the response.
+/// If the body is larger than the limit, a `ResponseTooLarge` error is returned without
+/// reading the rest of the body.
+#[cfg(feature = "reqwest")]
+async fn send_with_client(
+    client: &Client,
+    req: HttpRequest,
+    limit: Option<u64>,
+    bytes_read: &mut u64,
+) -> types::Result<HttpResponse> {
+    let method = reqwest::Method::from_bytes(req.method.as_bytes())
+        .map_err(|_| WrapperError::InputError("method", "the request method isn't valid"))?;
+    let mut builder = client.request(method, req.url.as_str());
+    for (name, value) in &req.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
 
-        req
+    if let Some(body) = req.body {
+        builder = builder.body(body);
+    }
+
+    if let Some(timeout) = req.timeout {
+        builder = builder.timeout(timeout);
+    }
+
+    let mut res = builder.send().await?;
+    // If the response says how large it is, there's no need to read it to know that it's too
+    // large. Compressed responses don't say how large they are once decompressed.
+    if let Some(limit) = limit.filter(|&limit| res.content_length().is_some_and(|len| len > limit))
+    {
+        return Err(WrapperError::ResponseTooLarge(limit));
+    }
+
+    let status = res.status().as_u16();
+    let url = res.url().to_string();
+    let headers = res
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                String::from_utf8_lossy(value.as_bytes()).into_owned(),
+            )
+        })
+        .collect();
+    let mut body = vec![];
+    while let Some(chunk) = res.chunk().await? {
+        *bytes_read += chunk.len() as u64;
+        if let Some(limit) = limit.filter(|&limit| *bytes_read > limit) {
+            return Err(WrapperError::ResponseTooLarge(limit));
+        }
+
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(HttpResponse {
+        status,
+        url,
+        headers,
+        body,
+    })
+}
+
+/// Sends a request with a backend instead of the client.
+///
+/// # Parameters
+/// - `backend`: The backend.
+/// - `req`: The request.
+/// - `limit`: The largest body, in bytes, that should be accepted, if there's a limit.
+/// - `bytes_read`: Set to the size of the body, even if it's larger than the limit.
+///
+/// # Returns
+/// The response, or the error that occurred when sending the request. If the body is larger
+/// than the limit, a `ResponseTooLarge` error is returned.
+async fn send_with_backend(
+    backend: &dyn HttpBackend,
+    req: HttpRequest,
+    limit: Option<u64>,
+    bytes_read: &mut u64,
+) -> types::Result<HttpResponse> {
+    let url = req.url.clone();
+    let mut res = backend.send(req).await?;
+    *bytes_read = res.body.len() as u64;
+    if let Some(limit) = limit.filter(|&limit| *bytes_read > limit) {
+        return Err(WrapperError::ResponseTooLarge(limit));
+    }
+
+    // Backends that don't keep track of redirects can leave the URL out.
+    if res.url.is_empty() {
+        res.url = url;
+    } else {
+        Url::parse(&res.url)?;
+    }
+
+    Ok(res)
+}
+
+/// Associates a term with the session that the given request belongs to, by calling the same
+/// endpoints as `associate_term`.
+///
+/// # Parameters
+/// - `term`: The term.
+/// - `template`: The request whose headers (including the cookies) should be used.
+/// - `sibling`: Turns a request into one that goes through the same pacing, quotas, and
+///   retries as the original request (but isn't retried with fresh cookies).
+///
+/// # Returns
+/// Nothing, or the error that occurred when associating the term.
+async fn reassociate_term<'a>(
+    term: &str,
+    template: &HttpRequest,
+    sibling: impl Fn(HttpRequest, String) -> TrackedRequestBuilder<'a>,
+) -> types::Result<()> {
+    let term = term.to_uppercase();
+    let seq_id = util::get_term_seq_id(&term).to_string();
+    let urls = [
+        util::build_url(
+            STATUS_START,
+            &[("termcode", term.as_str()), ("seqid", seq_id.as_str())],
+            None,
+        )?,
+        util::build_url(
+            ELIGIBILITY,
+            &[
+                ("termcode", term.as_str()),
+                ("seqid", seq_id.as_str()),
+                ("logged", "true"),
+            ],
+            None,
+        )?,
+    ];
+
+    for url in urls {
+        let req = HttpRequest {
+            method: "GET".to_owned(),
+            url: url.to_string(),
+            headers: template
+                .headers
+                .iter()
+                .filter(|(name, _)| !name.eq_ignore_ascii_case(CONTENT_TYPE))
+                .cloned()
+                .collect(),
+            body: None,
+            timeout: template.timeout,
+        };
+
+        let res = Box::pin(sibling(req, get_endpoint_name(url.as_str())).send()).await?;
+        if !res.is_success() {
+            return Err(WrapperError::BadStatusCode(res.status, Some(res.text())));
+        }
+    }
+
+    Ok(())
+}
+
+/// Gets the form parameters in the body of a request.
+///
+/// # Parameters
+/// - `req`: The request.
+///
+/// # Returns
+/// The form parameters, or nothing if the request has no body.
+fn get_form_params(req: &HttpRequest) -> Vec<(String, String)> {
+    req.body
+        .as_deref()
+        .map(|b| url::form_urlencoded::parse(b).into_owned().collect())
+        .unwrap_or_default()
+}
+
+/// Enforces the wrapper's self-imposed quotas by keeping track of when recent requests were
+/// made.
+#[derive(Default)]
+pub(crate) struct QuotaGuard {
+    quota: UsageQuota,
+    /// When each request in the last hour was made, oldest first.
+    requests: StdMutex<VecDeque<Instant>>,
+    /// When each mutation in the last day was made, oldest first.
+    mutations: StdMutex<VecDeque<Instant>>,
+}
+
+impl QuotaGuard {
+    /// Creates a new quota guard.
+    ///
+    /// # Parameters
+    /// - `quota`: The quotas to enforce.
+    ///
+    /// # Returns
+    /// The quota guard.
+    pub fn new(quota: UsageQuota) -> Self {
+        Self {
+            quota,
+            ..Default::default()
+        }
+    }
+
+    /// Records a request, if doing so wouldn't go over any quota.
+    ///
+    /// # Parameters
+    /// - `is_mutation`: Whether the request is a mutation.
+    ///
+    /// # Returns
+    /// Nothing if the request can be made, or a `QuotaExceeded` error if it can't.
+    pub fn try_acquire(&self, is_mutation: bool) -> types::Result<()> {
+        let now = Instant::now();
+        let mut requests = self.requests.lock().unwrap_or_else(|e| e.into_inner());
+        let mut mutations = self.mutations.lock().unwrap_or_else(|e| e.into_inner());
+
+        let check = |log: &mut VecDeque<Instant>, limit: Option<u32>, window: Duration, kind| {
+            while log
+                .front()
+                .is_some_and(|t| now.duration_since(*t) >= window)
+            {
+                log.pop_front();
+            }
+
+            match limit {
+                Some(limit) if log.len() >= limit as usize => {
+                    Err(WrapperError::QuotaExceeded(kind, limit))
+                }
+                _ => Ok(()),
+            }
+        };
+
+        check(
+            &mut requests,
+            self.quota.max_requests_per_hour,
+            Duration::from_secs(60 * 60),
+            QuotaKind::RequestsPerHour,
+        )?;
+        if is_mutation {
+            check(
+                &mut mutations,
+                self.quota.max_mutations_per_day,
+                Duration::from_secs(24 * 60 * 60),
+                QuotaKind::MutationsPerDay,
+            )?;
+        }
+
+        if self.quota.max_requests_per_hour.is_some() {
+            requests.push_back(now);
+        }
+        if is_mutation && self.quota.max_mutations_per_day.is_some() {
+            mutations.push_back(now);
+        }
+
+        Ok(())
+    }
+}
+
+/// Throttles requests according to the wrapper's rate limit, using a token bucket for each
+/// scope.
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    /// The number of tokens in each bucket, and when it was last refilled. The number of
+    /// tokens goes below zero when requests are waiting for their turn.
+    buckets: StdMutex<HashMap<String, (f64, Instant)>>,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter.
+    ///
+    /// # Parameters
+    /// - `limit`: The rate limit.
+    ///
+    /// # Returns
+    /// The rate limiter, or `None` if the rate limit allows `0` requests per second (which is
+    /// treated as no limit).
+    pub fn new(limit: RateLimit) -> Option<Self> {
+        (limit.requests_per_second > 0).then(|| Self {
+            limit,
+            buckets: StdMutex::new(HashMap::new()),
+        })
+    }
+
+    /// Waits until a request is allowed to be made.
+    ///
+    /// # Parameters
+    /// - `term`: The term that the request is for, if any.
+    /// - `sleep`: How to wait.
+    pub async fn acquire(&self, term: Option<&str>, sleep: Sleeper) {
+        let key = match self.limit.scope {
+            RateLimitScope::Global => String::new(),
+            RateLimitScope::PerTerm => term.unwrap_or_default().to_uppercase(),
+        };
+
+        let wait = {
+            let rate = f64::from(self.limit.requests_per_second);
+            let burst = f64::from(self.limit.burst.max(1));
+            let now = Instant::now();
+
+            let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+            let (tokens, refilled) = buckets.entry(key).or_insert((burst, now));
+            *tokens = (*tokens + now.duration_since(*refilled).as_secs_f64() * rate).min(burst);
+            *refilled = now;
+
+            // Reserve a token, even if it isn't there yet, so that requests are let through
+            // in the order that they arrived.
+            *tokens -= 1.0;
+            if *tokens >= 0.0 {
+                Duration::ZERO
+            } else {
+                Duration::from_secs_f64(-*tokens / rate)
+            }
+        };
+
+        if !wait.is_zero() {
+            sleep(wait).await;
+        }
+    }
+}
+
+/// Controls how quickly requests are made, based on the wrapper's pacing settings.
+pub(crate) struct Pacer {
+    profile: PacingProfile,
+    semaphore: Option<Semaphore>,
+    next_allowed: StdMutex<HashMap<String, Instant>>,
+}
+
+impl Pacer {
+    /// Creates a new pacer.
+    ///
+    /// # Parameters
+    /// - `profile`: The pacing settings.
+    ///
+    /// # Returns
+    /// The pacer.
+    pub fn new(profile: PacingProfile) -> Self {
+        Self {
+            profile,
+            semaphore: (profile.max_concurrent_requests > 0)
+                .then(|| Semaphore::new(profile.max_concurrent_requests)),
+            next_allowed: StdMutex::new(HashMap::new()),
+        }
+    }
+
+    /// Waits until a request can be made to the given endpoint.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The name of the endpoint.
+    /// - `sleep`: How to wait.
+    ///
+    /// # Returns
+    /// A permit, if the number of concurrent requests is limited. The permit should be held
+    /// until the request is done.
+    async fn wait_turn(&self, endpoint: &str, sleep: Sleeper) -> Option<SemaphorePermit<'_>> {
+        let permit = match &self.semaphore {
+            // The semaphore is never closed, so this can't fail.
+            Some(s) => s.acquire().await.ok(),
+            None => None,
+        };
+
+        if self.profile.min_request_interval.is_zero() {
+            return permit;
+        }
+
+        let wait = {
+            let mut next_allowed = self.next_allowed.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let slot = next_allowed
+                .get(endpoint)
+                .copied()
+                .map_or(now, |t| t.max(now));
+            next_allowed.insert(
+                endpoint.to_string(),
+                slot + self.profile.min_request_interval,
+            );
+            slot - now
+        };
+
+        if !wait.is_zero() {
+            sleep(wait).await;
+        }
+
+        permit
+    }
+}
+
+/// The kind of list stored in the catalog cache.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum CatalogKind {
+    Subjects,
+    Departments,
+}
+
+/// Caches the subject and department lists. These barely change between terms, so only one
+/// copy of each list is kept, regardless of which term it was requested for.
+///
+/// Each wrapper has its own cache, which is shared by every requester made from it.
+pub(crate) struct CatalogCache {
+    ttl: Duration,
+    subjects: StdMutex<Option<(SystemTime, Vec<String>)>>,
+    departments: StdMutex<Option<(SystemTime, Vec<String>)>>,
+}
+
+impl CatalogCache {
+    /// Creates a new, empty catalog cache.
+    ///
+    /// # Parameters
+    /// - `ttl`: How long a cached list should be used for before it is requested again.
+    ///
+    /// # Returns
+    /// The catalog cache.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            subjects: StdMutex::new(None),
+            departments: StdMutex::new(None),
+        }
+    }
+
+    /// Gets the slot that the given kind of list is stored in.
+    ///
+    /// # Parameters
+    /// - `kind`: The kind of list.
+    ///
+    /// # Returns
+    /// The slot.
+    fn slot(&self, kind: CatalogKind) -> &StdMutex<Option<(SystemTime, Vec<String>)>> {
+        match kind {
+            CatalogKind::Subjects => &self.subjects,
+            CatalogKind::Departments => &self.departments,
+        }
+    }
+
+    /// Gets a cached list, if it exists and hasn't expired.
+    ///
+    /// # Parameters
+    /// - `kind`: The kind of list.
+    /// - `now`: The current time, according to the wrapper's clock.
+    ///
+    /// # Returns
+    /// The cached list, or `None` if there is no usable cached list.
+    pub fn get(&self, kind: CatalogKind, now: SystemTime) -> Option<Vec<String>> {
+        self.slot(kind)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .filter(|(cached_at, _)| {
+                now.duration_since(*cached_at)
+                    .is_ok_and(|age| age < self.ttl)
+            })
+            .map(|(_, list)| list.clone())
+    }
+
+    /// Caches a list.
+    ///
+    /// # Parameters
+    /// - `kind`: The kind of list.
+    /// - `list`: The list.
+    /// - `now`: The current time, according to the wrapper's clock.
+    pub fn put(&self, kind: CatalogKind, list: Vec<String>, now: SystemTime) {
+        *self.slot(kind).lock().unwrap_or_else(|e| e.into_inner()) = Some((now, list));
+    }
+
+    /// Clears every cached list.
+    pub fn clear(&self) {
+        *self.subjects.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        *self.departments.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+/// A short-lived cache of schedules, so that a burst of mutation helpers (e.g., several
+/// `change_grading_option` calls) only needs to fetch the schedule once. The entire cache is
+/// invalidated whenever a mutation is made.
+///
+/// Schedules are cached per session (see `ScheduleCacheKey`), since requesters with
+/// overridden cookies share the wrapper's cache but not its schedule.
+#[derive(Default)]
+pub(crate) struct ScheduleCache {
+    /// The cached schedules, along with when they were fetched.
+    schedules: StdMutex<HashMap<ScheduleCacheKey, (SystemTime, Schedule)>>,
+    /// Incremented on every invalidation, so that a schedule fetched before a mutation isn't
+    /// cached after it.
+    generation: AtomicU64,
+    /// Held while fetching a schedule, so that concurrent callers wait for one fetch instead
+    /// of each making their own.
+    fetch_lock: AsyncMutex<()>,
+}
+
+/// Identifies a cached schedule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ScheduleCacheKey {
+    /// A hash of the cookies that the schedule was fetched with, so that each session gets its
+    /// own schedules without the cache holding on to a copy of the cookies.
+    session: u64,
+    /// The term, in uppercase.
+    term: String,
+    /// The schedule name.
+    schedule_name: String,
+}
+
+impl ScheduleCacheKey {
+    /// Creates a key.
+    ///
+    /// # Parameters
+    /// - `cookies`: The cookies that the schedule is requested with.
+    /// - `term`: The term.
+    /// - `schedule_name`: The schedule name.
+    ///
+    /// # Returns
+    /// The key.
+    pub fn new(cookies: impl AsRef<str>, term: &str, schedule_name: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        cookies.as_ref().hash(&mut hasher);
+        Self {
+            session: hasher.finish(),
+            term: term.to_uppercase(),
+            schedule_name: schedule_name.to_string(),
+        }
+    }
+}
+
+impl ScheduleCache {
+    /// Gets a schedule, fetching it with `fetch` if there isn't a recent enough copy.
+    ///
+    /// # Parameters
+    /// - `key`: The session, term, and schedule name.
+    /// - `clock`: The clock used to decide whether a cached schedule has expired.
+    /// - `fetch`: Fetches the schedule from WebReg.
+    ///
+    /// # Returns
+    /// The schedule, or the error from fetching it.
+    pub async fn get_or_fetch<F, Fut>(
+        &self,
+        key: ScheduleCacheKey,
+        clock: &dyn Clock,
+        fetch: F,
+    ) -> types::Result<Schedule>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = types::Result<Schedule>>,
+    {
+        if let Some(schedule) = self.get(&key, clock.now()) {
+            return Ok(schedule);
+        }
+
+        let _guard = self.fetch_lock.lock().await;
+        // Someone else may have fetched it while we were waiting.
+        if let Some(schedule) = self.get(&key, clock.now()) {
+            return Ok(schedule);
+        }
+
+        let generation = self.generation.load(Ordering::SeqCst);
+        let schedule = fetch().await?;
+        let mut schedules = self.schedules.lock().unwrap_or_else(|e| e.into_inner());
+        if self.generation.load(Ordering::SeqCst) == generation {
+            schedules.insert(key, (clock.now(), schedule.clone()));
+        }
+
+        Ok(schedule)
+    }
+
+    /// Gets a cached schedule, if it hasn't expired.
+    ///
+    /// # Parameters
+    /// - `key`: The session, term, and schedule name.
+    /// - `now`: The current time.
+    ///
+    /// # Returns
+    /// The schedule, or `None` if there is no recent enough copy.
+    fn get(&self, key: &ScheduleCacheKey, now: SystemTime) -> Option<Schedule> {
+        self.schedules
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(key)
+            .filter(|(at, _)| {
+                now.duration_since(*at)
+                    .is_ok_and(|age| age < SCHEDULE_CACHE_TTL)
+            })
+            .map(|(_, schedule)| schedule.clone())
+    }
+
+    /// Removes every cached schedule. This should be called whenever a mutation is made.
+    pub fn invalidate(&self) {
+        let mut schedules = self.schedules.lock().unwrap_or_else(|e| e.into_inner());
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        schedules.clear();
+    }
+}
+
+/// Keeps track of the sections whose waitlist WebReg has said is full in this session. WebReg
+/// doesn't include waitlist capacity in its course data, so this is the only way to know.
+#[derive(Default)]
+pub(crate) struct WaitlistTracker {
+    closed: StdMutex<HashSet<(String, String)>>,
+}
+
+impl WaitlistTracker {
+    /// Records whether a section's waitlist is open.
+    ///
+    /// # Parameters
+    /// - `term`: The term.
+    /// - `section_id`: The section ID.
+    /// - `open`: Whether the waitlist is open.
+    pub fn set_open(&self, term: &str, section_id: &str, open: bool) {
+        let key = Self::key(term, section_id);
+        let mut closed = self.closed.lock().unwrap_or_else(|e| e.into_inner());
+        if open {
+            closed.remove(&key);
+        } else {
+            closed.insert(key);
+        }
+    }
+
+    /// Checks whether a section's waitlist is open, as far as this session knows.
+    ///
+    /// # Parameters
+    /// - `term`: The term.
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// `false` if WebReg has said that the waitlist is full, and `true` otherwise.
+    pub fn is_open(&self, term: &str, section_id: &str) -> bool {
+        !self
+            .closed
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains(&Self::key(term, section_id))
+    }
+
+    /// Gets the key for a section.
+    ///
+    /// # Parameters
+    /// - `term`: The term.
+    /// - `section_id`: The section ID. Leading zeros are ignored.
+    ///
+    /// # Returns
+    /// The key.
+    fn key(term: &str, section_id: &str) -> (String, String) {
+        (
+            term.to_uppercase(),
+            section_id.trim().trim_start_matches('0').to_string(),
+        )
+    }
+}
+
+/// Keeps track of which terms have been associated (see `associate_term`) in this session.
+/// This is only a local record; WebReg itself isn't asked.
+#[derive(Default)]
+pub(crate) struct TermTracker {
+    terms: StdMutex<BTreeSet<String>>,
+}
+
+impl TermTracker {
+    /// Records that a term has been associated.
+    ///
+    /// # Parameters
+    /// - `term`: The term.
+    pub fn insert(&self, term: &str) {
+        self.terms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(term.to_uppercase());
+    }
+
+    /// Removes a term from the record.
+    ///
+    /// # Parameters
+    /// - `term`: The term.
+    pub fn remove(&self, term: &str) {
+        self.terms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&term.to_uppercase());
+    }
+
+    /// Checks whether a term has been associated.
+    ///
+    /// # Parameters
+    /// - `term`: The term.
+    ///
+    /// # Returns
+    /// `true` if the term has been associated and `false` otherwise.
+    pub fn contains(&self, term: &str) -> bool {
+        self.terms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains(&term.to_uppercase())
+    }
+
+    /// Gets every term that has been associated.
+    ///
+    /// # Returns
+    /// The terms, sorted.
+    pub fn list(&self) -> Vec<String> {
+        self.terms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Forgets every term.
+    pub fn clear(&self) {
+        self.terms.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+/// Holds the user's cookie provider, along with the most recent cookies that it gave.
+pub(crate) struct CookieRefresher {
+    provider: Arc<dyn CookieProvider>,
+    latest: StdMutex<Option<String>>,
+    /// Held while the provider is being asked for cookies, so that several requests failing
+    /// at once only cause one refresh.
+    refreshing: AsyncMutex<()>,
+}
+
+impl CookieRefresher {
+    /// Creates a new cookie refresher.
+    ///
+    /// # Parameters
+    /// - `provider`: The cookie provider.
+    ///
+    /// # Returns
+    /// The cookie refresher.
+    pub fn new(provider: Arc<dyn CookieProvider>) -> Self {
+        Self {
+            provider,
+            latest: StdMutex::new(None),
+            refreshing: AsyncMutex::new(()),
+        }
+    }
+
+    /// Gets the most recent cookies that the provider gave.
+    ///
+    /// # Returns
+    /// The cookies, or `None` if the provider hasn't been asked for cookies yet (or if they
+    /// were since replaced by the user).
+    pub fn latest(&self) -> Option<String> {
+        self.latest
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Forgets the cookies that the provider gave, so that the wrapper's own cookies are used
+    /// again.
+    pub fn reset(&self) {
+        *self.latest.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Asks the provider for fresh cookies. If another request already got fresh cookies
+    /// since the stale ones were used, those are returned instead.
+    ///
+    /// # Parameters
+    /// - `stale`: The cookies from the provider that the failed request was made with, if
+    ///   any.
+    ///
+    /// # Returns
+    /// The fresh cookies, or the error that the provider gave.
+    pub async fn refresh(&self, stale: Option<&str>) -> types::Result<String> {
+        let _guard = self.refreshing.lock().await;
+        if let Some(latest) = self.latest() {
+            if stale != Some(latest.as_str()) {
+                return Ok(latest);
+            }
+        }
+
+        let cookies = self.provider.refresh_cookies().await?;
+        *self.latest.lock().unwrap_or_else(|e| e.into_inner()) = Some(cookies.clone());
+        Ok(cookies)
+    }
+}
+
+/// Keeps track of statistics for all requests made under a wrapper.
+#[derive(Default)]
+pub(crate) struct StatsTracker {
+    stats: StdMutex<WrapperStats>,
+}
+
+impl StatsTracker {
+    /// Records the result of a request.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The name of the endpoint that the request was made to.
+    /// - `latency`: How long it took to get a response.
+    /// - `res`: The result of the request.
+    /// - `bytes_read`: The number of bytes of the response that were read.
+    fn record(
+        &self,
+        endpoint: String,
+        latency: Duration,
+        res: &types::Result<HttpResponse>,
+        bytes_read: u64,
+    ) {
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        stats.total_requests += 1;
+        stats.total_latency += latency;
+        *stats.requests_by_endpoint.entry(endpoint).or_insert(0) += 1;
+        stats.bytes_received += bytes_read;
+
+        let error_class = match res {
+            Ok(r) => {
+                if r.is_success() {
+                    None
+                } else {
+                    Some(RequestErrorClass::BadStatusCode)
+                }
+            }
+            #[cfg(feature = "reqwest")]
+            Err(WrapperError::RequestError(e)) if e.is_timeout() => {
+                Some(RequestErrorClass::Timeout)
+            }
+            #[cfg(feature = "reqwest")]
+            Err(WrapperError::RequestError(e)) if e.is_connect() => {
+                Some(RequestErrorClass::Connect)
+            }
+            Err(WrapperError::BackendError(HttpBackendError::Timeout(_))) => {
+                Some(RequestErrorClass::Timeout)
+            }
+            Err(WrapperError::BackendError(HttpBackendError::Connect(_))) => {
+                Some(RequestErrorClass::Connect)
+            }
+            Err(_) => Some(RequestErrorClass::Other),
+        };
+
+        if let Some(class) = error_class {
+            *stats.errors_by_class.entry(class).or_insert(0) += 1;
+        }
+    }
+
+    /// Gets a copy of the statistics collected so far.
+    ///
+    /// # Returns
+    /// The statistics.
+    pub fn snapshot(&self) -> WrapperStats {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Clears all statistics collected so far.
+    pub fn reset(&self) {
+        *self.stats.lock().unwrap_or_else(|e| e.into_inner()) = WrapperStats::default();
+    }
+}
+
+/// Records every mutating request made through the wrapper, if enabled. Only the most recent
+/// requests are kept, so the log doesn't grow without limit.
+pub(crate) struct ActionRecorder {
+    enabled: bool,
+    capacity: usize,
+    actions: StdMutex<VecDeque<ActionRecord>>,
+}
+
+impl ActionRecorder {
+    /// Creates a new action recorder.
+    ///
+    /// # Parameters
+    /// - `enabled`: Whether any requests should actually be recorded.
+    /// - `capacity`: The maximum number of requests to keep. Once this many requests have
+    ///   been recorded, the oldest request is removed to make room for each new one.
+    ///
+    /// # Returns
+    /// The action recorder.
+    pub fn new(enabled: bool, capacity: usize) -> Self {
+        Self {
+            enabled,
+            capacity,
+            actions: StdMutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Whether requests should be recorded.
+    ///
+    /// # Returns
+    /// `true` if this recorder is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Records a request along with WebReg's response.
+    ///
+    /// # Parameters
+    /// - `record`: The record of the request, without the response.
+    /// - `res`: The response, or the error that occurred when sending the request.
+    ///
+    /// # Returns
+    /// The response, or the error that occurred when sending the request.
+    pub fn record_response(
+        &self,
+        mut record: ActionRecord,
+        res: types::Result<HttpResponse>,
+    ) -> types::Result<HttpResponse> {
+        match &res {
+            Ok(r) => {
+                record.status = Some(r.status);
+                record.response = Some(r.text());
+            }
+            Err(e) => record.error = Some(e.to_string()),
+        }
+
+        self.push(record);
+        res
+    }
+
+    /// Adds a record to the log, removing the oldest records if the log is full.
+    ///
+    /// # Parameters
+    /// - `record`: The record.
+    fn push(&self, record: ActionRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut actions = self.actions.lock().unwrap_or_else(|e| e.into_inner());
+        while actions.len() >= self.capacity {
+            actions.pop_front();
+        }
+
+        actions.push_back(record);
+    }
+
+    /// Gets a copy of every request recorded so far.
+    ///
+    /// # Returns
+    /// The action log.
+    pub fn snapshot(&self) -> ActionLog {
+        ActionLog {
+            actions: self
+                .actions
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Clears every request recorded so far.
+    pub fn clear(&self) {
+        self.actions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+/// Collects all unique error reasons that WebReg has given, if enabled.
+pub(crate) struct ErrorCollector {
+    enabled: bool,
+    reasons: StdMutex<ObservedErrors>,
+}
+
+impl ErrorCollector {
+    /// Creates a new error collector.
+    ///
+    /// # Parameters
+    /// - `enabled`: Whether any error reasons should actually be recorded.
+    ///
+    /// # Returns
+    /// The error collector.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            reasons: StdMutex::new(ObservedErrors::new()),
+        }
+    }
+
+    /// Records an error reason given by WebReg. Nothing is recorded if this collector isn't
+    /// enabled.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The name of the endpoint that gave the error.
+    /// - `reason`: The error reason.
+    pub fn record(&self, endpoint: String, reason: &str) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut reasons = self.reasons.lock().unwrap_or_else(|e| e.into_inner());
+        *reasons
+            .entry(endpoint)
+            .or_default()
+            .entry(reason.to_string())
+            .or_insert(0) += 1;
+    }
+
+    /// Gets a copy of all error reasons collected so far.
+    ///
+    /// # Returns
+    /// The error reasons, grouped by endpoint.
+    pub fn snapshot(&self) -> ObservedErrors {
+        self.reasons
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
     }
 }
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::future::Future;
+
+use futures::future::join_all;
+
+use crate::types;
+use crate::types::{CourseCode, Courses, PrerequisiteInfo, RetryBudget};
+use crate::wrapper::request_builder::WrapperTermRequestBuilder;
+use crate::wrapper::requester_term::WrapperTermRequest;
+use crate::wrapper::WebRegWrapperData;
+
+/// A structure that can be used to make the same request for several terms at once. The
+/// requests for each term are made concurrently.
+///
+/// Keep in mind that, like with `WrapperTermRequest`, you need to be associated with each
+/// term (see `WebRegWrapper::associate_term`) before making any requests for that term.
+pub struct WrapperMultiTermRequest<'a> {
+    pub(crate) data: &'a WebRegWrapperData,
+    pub(crate) terms: Vec<&'a str>,
+    pub(crate) retry_budget: Option<&'a RetryBudget>,
+}
+
+impl<'a> WrapperMultiTermRequest<'a> {
+    /// Gets the terms that requests will be made for.
+    ///
+    /// # Returns
+    /// The terms.
+    pub fn terms(&self) -> &[&'a str] {
+        &self.terms
+    }
+
+    /// Sets the retry budget that is shared by the requests for every term. If the budget runs
+    /// out, the terms whose requests needed a retry that couldn't be made will have a
+    /// `WrapperError::RetryBudgetExhausted` error, while the results for the other terms
+    /// (including any other errors) are returned as usual.
+    ///
+    /// # Parameters
+    /// - `budget`: The retry budget.
+    ///
+    /// # Returns
+    /// The multi-term requester.
+    pub fn with_retry_budget(mut self, budget: &'a RetryBudget) -> Self {
+        self.retry_budget = Some(budget);
+        self
+    }
+
+    /// Makes a request for every term concurrently.
+    ///
+    /// # Parameters
+    /// - `f`: A function that, given the parsed requester for a term, makes the request.
+    ///
+    /// # Returns
+    /// The result of each request, keyed by term.
+    async fn fan_out<T, F, Fut>(&self, f: F) -> HashMap<String, types::Result<T>>
+    where
+        F: Fn(WrapperTermRequest<'a>) -> Fut,
+        Fut: Future<Output = types::Result<T>>,
+    {
+        let results = join_all(self.terms.iter().map(|&term| {
+            let mut builder = WrapperTermRequestBuilder::new_request(self.data, term);
+            if let Some(budget) = self.retry_budget {
+                builder = builder.with_retry_budget(budget);
+            }
+
+            f(builder.parsed())
+        }))
+        .await;
+
+        self.terms
+            .iter()
+            .map(|term| term.to_string())
+            .zip(results)
+            .collect()
+    }
+
+    /// Gets course information for a particular course for every term. See
+    /// `WrapperTermRequest::get_course_info` for more information.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("MATH", "100B")`.
+    ///
+    /// # Returns
+    /// The course information (or the error that occurred) for each term, keyed by term. A
+    /// request for one term failing (e.g., because the course isn't offered that term) doesn't
+    /// affect the other terms.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let all_info = wrapper
+    ///     .req_many(&["FA23", "WI24", "SP24"])
+    ///     .get_course_info_across_terms(("CSE", "100"))
+    ///     .await;
+    ///
+    /// for (term, info) in all_info {
+    ///     match info {
+    ///         Ok(o) => println!("{term}: {} sections", o.len()),
+    ///         Err(e) => eprintln!("{term}: an error occurred! {e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_course_info_across_terms(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> HashMap<String, types::Result<Courses>> {
+        let course = &course.into();
+        self.fan_out(|req| async move { req.get_course_info(course).await })
+            .await
+    }
+
+    /// Gets enrollment counts for a particular course for every term. See
+    /// `WrapperTermRequest::get_enrollment_count` for more information.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("MATH", "100B")`.
+    ///
+    /// # Returns
+    /// The enrollment counts (or the error that occurred) for each term, keyed by term.
+    pub async fn get_enrollment_count_across_terms(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> HashMap<String, types::Result<Courses>> {
+        let course = &course.into();
+        self.fan_out(|req| async move { req.get_enrollment_count(course).await })
+            .await
+    }
+
+    /// Gets the prerequisites for a particular course for every term. See
+    /// `WrapperTermRequest::get_prerequisites` for more information.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("MATH", "100B")`.
+    ///
+    /// # Returns
+    /// The prerequisites (or the error that occurred) for each term, keyed by term.
+    pub async fn get_prerequisites_across_terms(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> HashMap<String, types::Result<PrerequisiteInfo>> {
+        let course = &course.into();
+        self.fan_out(|req| async move { req.get_prerequisites(course).await })
+            .await
+    }
+}
@@ -1,5 +1,9 @@
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use tokio::time::{Interval, MissedTickBehavior};
 use url::Url;
 
 use crate::constants::{
@@ -11,22 +15,35 @@ use crate::constants::{
 };
 use crate::raw_types::{
     RawCourseTextItem, RawDepartmentElement, RawEvent, RawPrerequisite, RawScheduledMeeting,
-    RawSectionTextItem, RawSubjectElement, RawWebRegMeeting, RawWebRegSearchResultItem,
+    RawSeatCount, RawSectionTextItem, RawSubjectElement, RawWebRegSearchResultItem,
 };
 use crate::types::{
-    Courses, Events, PrerequisiteInfo, Schedule, SearchResult, SearchResultItem,
-    SectionIdNotFoundContext, WrapperError,
+    AccountSnapshot, AttemptRecord, AutoEnrollStatus, BulkPlanReport, CatalogProgress, CourseCode,
+    CourseRangeScan, CourseSection, Courses, EnrollFromPlanReport, EnrollOutcome,
+    EnrollmentFailure, EnrollmentStatus, Events, FamilyAvailability, FinalExam, LinkedAddResult,
+    PlanEnrollAttempt, PlanEnrollOutcome, PlanMatrix, PlanOutcome, PlanReconciliation,
+    PlanUpsertOutcome, PrerequisiteInfo, Schedule, ScheduledSection, SearchResult,
+    SearchResultItem, SeatCount, SeatStrategy, SectionFamily, SectionIdNotFoundContext,
+    SwapOutcome, ValidationOutcome, WaitlistDropAttempt, WaitlistDropOutcome, WaitlistDropReport,
+    WrapperError,
 };
 use crate::wrapper::input_types::{
-    AddType, DayOfWeek, EnrollWaitAdd, EventAdd, ExplicitAddType, GradeOption, PlanAdd, SearchType,
+    AddType, AutoEnrollOptions, DayOfWeek, EnrollFromPlanOptions, EnrollWaitAdd, EventAdd,
+    ExplicitAddType, GradeOption, LagBehavior, PlanAdd, SearchRequestBuilder, SearchType,
+    SectionLookup, WaitlistDropPolicy,
+};
+use crate::wrapper::request_data::{
+    CatalogKind, ReqType, ReqwestWebRegClientData, ScheduleCacheKey, WebRegWrapperDataRef,
 };
-use crate::wrapper::request_data::{ReqType, ReqwestWebRegClientData, WebRegWrapperDataRef};
 use crate::wrapper::ww_helper::{
-    associate_term_helper, extract_text, process_get_text, process_post_response,
+    associate_term_helper, classify_plan_error, extract_text, process_get_text,
+    process_post_response,
 };
 use crate::ww_parser::{
-    build_search_course_url, parse_course_info, parse_enrollment_count, parse_get_events,
-    parse_prerequisites, parse_schedule,
+    build_search_course_url, group_course_info, into_section_families,
+    parse_enrollment_count_with_warnings, parse_get_events, parse_prerequisites,
+    parse_raw_meetings, parse_schedule_with_warnings, parse_search_results, parse_seat_counts,
+    parse_section_info, CourseGroup,
 };
 use crate::{types, util};
 
@@ -43,30 +60,23 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// Gets all prerequisites for a specified course for the term set by the wrapper.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_code`: The course code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `course`: The course. For example, `("MATH", "100B")`.
     ///
     /// # Returns
     /// Prerequisite data as returned by WebReg.
-    pub async fn get_prerequisites(
-        &self,
-        subject_code: impl AsRef<str>,
-        course_code: impl AsRef<str>,
-    ) -> types::Result<String> {
-        let crsc_code = util::get_formatted_course_num(course_code.as_ref());
-        let url = Url::parse_with_params(
+    pub async fn get_prerequisites(&self, course: impl Into<CourseCode>) -> types::Result<String> {
+        let course = course.into();
+        let crsc_code = util::get_formatted_course_num(&course.number);
+        let url = self.info.build_url(
             PREREQS_INFO,
             &[
-                ("subjcode", subject_code.as_ref()),
+                ("subjcode", course.subject.as_str()),
                 ("crsecode", crsc_code.as_str()),
                 ("termcode", self.term),
-                ("_", util::get_epoch_time().to_string().as_ref()),
             ],
         )?;
 
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        extract_text(&self.info, self.info.req(ReqType::Get(url)).send().await).await
     }
 
     /// Gets your current schedule.
@@ -78,18 +88,17 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// # Returns
     /// Schedule data as returned by WebReg.
     pub async fn get_schedule(&self, schedule_name: Option<&str>) -> types::Result<String> {
-        let url = Url::parse_with_params(
+        let url = self.info.build_url(
             CURR_SCHEDULE,
             &[
                 ("schedname", schedule_name.unwrap_or(DEFAULT_SCHEDULE_NAME)),
                 ("final", ""),
                 ("sectnum", ""),
                 ("termcode", self.term),
-                ("_", util::get_epoch_time().to_string().as_str()),
             ],
         )?;
 
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        extract_text(&self.info, self.info.req(ReqType::Get(url)).send().await).await
     }
 
     /// Gets course information for a particular course.
@@ -102,30 +111,23 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// Additionally, this implementation will not retrieve canceled sections.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_num`: The course number. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `course`: The course. For example, `("MATH", "100B")`.
     ///
     /// # Returns
     /// Course information, as returned by WebReg.
-    pub async fn get_course_info(
-        &self,
-        subject_code: impl AsRef<str>,
-        course_num: impl AsRef<str>,
-    ) -> types::Result<String> {
-        let crsc_code = util::get_formatted_course_num(course_num.as_ref());
-        let url = Url::parse_with_params(
+    pub async fn get_course_info(&self, course: impl Into<CourseCode>) -> types::Result<String> {
+        let course = course.into();
+        let crsc_code = util::get_formatted_course_num(&course.number);
+        let url = self.info.build_url(
             COURSE_DATA,
             &[
-                ("subjcode", subject_code.as_ref()),
+                ("subjcode", course.subject.as_str()),
                 ("crsecode", crsc_code.as_str()),
                 ("termcode", self.term),
-                ("_", util::get_epoch_time().to_string().as_ref()),
             ],
         )?;
 
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        extract_text(&self.info, self.info.req(ReqType::Get(url)).send().await).await
     }
 
     /// Gets a list of all departments that are offering courses for the given term.
@@ -134,14 +136,11 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// Department codes, as returned by WebReg.
     pub async fn get_department_codes(&self) -> types::Result<String> {
         extract_text(
+            &self.info,
             self.info
-                .req(ReqType::Get(Url::parse_with_params(
-                    DEPT_LIST,
-                    &[
-                        ("termcode", self.term),
-                        ("_", util::get_epoch_time().to_string().as_str()),
-                    ],
-                )?))
+                .req(ReqType::Get(
+                    self.info.build_url(DEPT_LIST, &[("termcode", self.term)])?,
+                ))
                 .send()
                 .await,
         )
@@ -154,14 +153,11 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// Subject codes, as returned by WebReg.
     pub async fn get_subject_codes(&self) -> types::Result<String> {
         extract_text(
+            &self.info,
             self.info
-                .req(ReqType::Get(Url::parse_with_params(
-                    SUBJ_LIST,
-                    &[
-                        ("termcode", self.term),
-                        ("_", util::get_epoch_time().to_string().as_str()),
-                    ],
-                )?))
+                .req(ReqType::Get(
+                    self.info.build_url(SUBJ_LIST, &[("termcode", self.term)])?,
+                ))
                 .send()
                 .await,
         )
@@ -178,8 +174,15 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// Search results, as returned by WebReg.
     pub async fn search_courses(&self, filter_by: SearchType) -> types::Result<String> {
         extract_text(
+            &self.info,
             self.info
-                .req(ReqType::Get(build_search_course_url(filter_by, self.term)?))
+                .req(ReqType::Get(build_search_course_url(
+                    filter_by,
+                    self.term,
+                    self.info
+                        .include_cache_buster()
+                        .then(|| self.info.get_clock().now()),
+                )?))
                 .send()
                 .await,
         )
@@ -192,7 +195,7 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// Information about any events you added, as returned by WebReg.
     pub async fn get_events(&self) -> types::Result<String> {
         let url = Url::parse_with_params(EVENT_GET, &[("termcode", self.term)]).unwrap();
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        extract_text(&self.info, self.info.req(ReqType::Get(url)).send().await).await
     }
 
     /// Gets all of your schedules.
@@ -201,7 +204,7 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// Your schedule list, as returned by WebReg.
     pub async fn get_schedule_list(&self) -> types::Result<String> {
         let url = Url::parse_with_params(ALL_SCHEDULE, &[("termcode", self.term)])?;
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        extract_text(&self.info, self.info.req(ReqType::Get(url)).send().await).await
     }
 
     /// Gets a list of all course notes for one or more subjects.
@@ -221,7 +224,7 @@ impl<'a> WrapperTermRawRequest<'a> {
             COURSE_TEXT,
             &[("subjlist", subj_list.as_str()), ("termcode", self.term)],
         )?;
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        extract_text(&self.info, self.info.req(ReqType::Get(url)).send().await).await
     }
 
     /// Gets a list of all section notes for one or more sections.
@@ -241,7 +244,7 @@ impl<'a> WrapperTermRawRequest<'a> {
             SECTION_TEXT,
             &[("sectnumlist", sec_list.as_str()), ("termcode", self.term)],
         )?;
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        extract_text(&self.info, self.info.req(ReqType::Get(url)).send().await).await
     }
 
     /// Associates the term bound by this request to the cookies that are provided
@@ -276,13 +279,20 @@ pub struct WrapperTermRequest<'a> {
 }
 
 impl<'a> WrapperTermRequest<'a> {
+    /// Gets the seat strategy that the wrapper uses to decide whether a section has seats (see
+    /// `WebRegWrapperBuilder::with_seat_strategy`). Use this with `SeatCount::has_seats_with`
+    /// to check the results of `get_seat_counts` the same way that the wrapper checks sections.
+    ///
+    /// # Returns
+    /// The seat strategy.
+    pub fn seat_strategy(&self) -> &'a dyn SeatStrategy {
+        self.raw.info.seat_strategy
+    }
+
     /// Gets all prerequisites for a specified course for the term set by the wrapper.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_code`: The course code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `course`: The course. For example, `("MATH", "100B")`.
     ///
     /// # Returns
     /// All prerequisites for the specified course. This is a structure that has two fields: one
@@ -320,7 +330,7 @@ impl<'a> WrapperTermRequest<'a> {
     /// let prereqs = wrapper
     ///     .req("FA23")
     ///     .parsed()
-    ///     .get_prerequisites("COGS", "108")
+    ///     .get_prerequisites(("COGS", "108"))
     ///     .await;
     ///
     /// if let Ok(prereq_info) = prereqs {
@@ -331,13 +341,10 @@ impl<'a> WrapperTermRequest<'a> {
     /// ```
     pub async fn get_prerequisites(
         &self,
-        subject_code: impl AsRef<str>,
-        course_code: impl AsRef<str>,
+        course: impl Into<CourseCode>,
     ) -> types::Result<PrerequisiteInfo> {
         parse_prerequisites(process_get_text::<Vec<RawPrerequisite>>(
-            self.raw
-                .get_prerequisites(subject_code, course_code)
-                .await?,
+            self.raw.get_prerequisites(course).await?,
         )?)
     }
 
@@ -391,9 +398,112 @@ impl<'a> WrapperTermRequest<'a> {
     /// # }
     /// ```
     pub async fn get_schedule(&self, schedule_name: Option<&str>) -> types::Result<Schedule> {
-        parse_schedule(process_get_text::<Vec<RawScheduledMeeting>>(
-            self.raw.get_schedule(schedule_name).await?,
-        )?)
+        let (schedule, warnings) =
+            parse_schedule_with_warnings(process_get_text::<Vec<RawScheduledMeeting>>(
+                self.raw.get_schedule(schedule_name).await?,
+            )?)?;
+        self.raw.info.check_parse_warnings(&warnings)?;
+        Ok(schedule)
+    }
+
+    /// Gets your schedule, reusing a schedule that was fetched within the last few seconds
+    /// (and before the last mutation) if there is one. This is meant for the mutation helpers,
+    /// so that a burst of operations only needs to fetch the schedule once. Schedules are only
+    /// reused for requests made with the same cookies.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule name, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The schedule.
+    async fn get_schedule_cached(&self, schedule_name: Option<&str>) -> types::Result<Schedule> {
+        let key = ScheduleCacheKey::new(
+            self.raw.info.get_cookies(),
+            self.raw.term,
+            schedule_name.unwrap_or(DEFAULT_SCHEDULE_NAME),
+        );
+        self.raw
+            .info
+            .get_schedule_cache()
+            .get_or_fetch(key, self.raw.info.get_clock(), || {
+                self.get_schedule(schedule_name)
+            })
+            .await
+    }
+
+    /// Gets a printable version of your schedule, as a standalone HTML page. This might be
+    /// useful if you want to email someone their schedule.
+    ///
+    /// WebReg's own printable schedule is generated in the browser, so this renders an
+    /// equivalent page from your schedule instead (see `util::render_schedule_html`).
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule that you want to get. If `None` is given, this will default
+    ///   to your main schedule.
+    ///
+    /// # Returns
+    /// The HTML page, or an error if your schedule couldn't be retrieved.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// match wrapper.req("FA23").parsed().get_printable_schedule(None).await {
+    ///     Ok(html) => std::fs::write("schedule.html", html).unwrap(),
+    ///     Err(e) => eprintln!("An error occurred! {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_printable_schedule(
+        &self,
+        schedule_name: Option<&str>,
+    ) -> types::Result<String> {
+        let schedule = self.get_schedule(schedule_name).await?;
+        let title = format!(
+            "{} ({})",
+            schedule_name.unwrap_or(DEFAULT_SCHEDULE_NAME),
+            self.raw.term
+        );
+
+        Ok(util::render_schedule_html(&title, &schedule))
+    }
+
+    /// Gets the final exams for every section in your schedule (see `util::get_final_exams`).
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule that you want to get the final exams for. If `None` is
+    ///   given, this will default to your main schedule.
+    ///
+    /// # Returns
+    /// The final exams, sorted by when they start, or an error if your schedule couldn't be
+    /// retrieved.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// match wrapper.req("FA23").parsed().get_final_exams(None).await {
+    ///     Ok(exams) => exams.iter().for_each(|exam| println!("{exam}")),
+    ///     Err(e) => eprintln!("An error occurred! {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_final_exams(
+        &self,
+        schedule_name: Option<&str>,
+    ) -> types::Result<Vec<FinalExam>> {
+        let schedule = self.get_schedule(schedule_name).await?;
+        Ok(util::get_final_exams(&schedule))
     }
 
     /// Gets enrollment count for a particular course.
@@ -410,10 +520,7 @@ impl<'a> WrapperTermRequest<'a> {
     /// number of people enrolled in a section, this function is for you.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_num`: The course number. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `course`: The course. For example, `("MATH", "100B")`.
     ///
     /// # Returns
     /// Either a vector with all sections that match the given subject code & course code, or an
@@ -432,7 +539,7 @@ impl<'a> WrapperTermRequest<'a> {
     ///
     /// let sec_count = wrapper
     ///     .req("FA23").parsed()
-    ///     .get_enrollment_count("COGS", "108")
+    ///     .get_enrollment_count(("COGS", "108"))
     ///     .await;
     ///
     /// match sec_count {
@@ -443,22 +550,20 @@ impl<'a> WrapperTermRequest<'a> {
     /// ```
     pub async fn get_enrollment_count(
         &self,
-        subject_code: impl AsRef<str>,
-        course_num: impl AsRef<str>,
+        course: impl Into<CourseCode>,
     ) -> types::Result<Courses> {
-        let course_dept_id = format!(
-            "{} {}",
-            subject_code.as_ref().trim(),
-            course_num.as_ref().trim()
-        )
-        .to_uppercase();
+        let course = course.into();
+        let course_dept_id = course.to_string();
 
-        parse_enrollment_count(
-            process_get_text::<Vec<RawWebRegMeeting>>(
-                self.raw.get_course_info(subject_code, course_num).await?,
-            )?,
-            course_dept_id,
-        )
+        let (meetings, mut warnings) =
+            parse_raw_meetings(&self.raw.get_course_info(&course).await?)?;
+        let (mut sections, parse_warnings) =
+            parse_enrollment_count_with_warnings(meetings, course_dept_id)?;
+        warnings.extend(parse_warnings);
+
+        self.raw.info.check_parse_warnings(&warnings)?;
+        self.apply_waitlist_status(&mut sections);
+        Ok(sections)
     }
 
     /// Gets course information for a particular course.
@@ -470,11 +575,12 @@ impl<'a> WrapperTermRequest<'a> {
     ///
     /// Additonally, this implementation will not retrieve canceled sections.
     ///
+    /// If the requester was built with `should_include_section_notes`, the notes for each
+    /// section are fetched as well and stored in each section's `section_notes`. A note for a
+    /// lecture (e.g., `A00`) applies to every section in that family.
+    ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_num`: The course number. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `course`: The course. For example, `("MATH", "100B")`.
     ///
     /// # Returns
     /// A result containing either:
@@ -494,7 +600,7 @@ impl<'a> WrapperTermRequest<'a> {
     ///
     /// let course_info = wrapper
     ///     .req("FA23").parsed()
-    ///     .get_course_info("CSE", "105")
+    ///     .get_course_info(("CSE", "105"))
     ///     .await;
     ///
     /// match course_info {
@@ -503,511 +609,2241 @@ impl<'a> WrapperTermRequest<'a> {
     /// }
     /// # }
     /// ```
-    pub async fn get_course_info(
-        &self,
-        subject_code: impl AsRef<str>,
-        course_num: impl AsRef<str>,
-    ) -> types::Result<Courses> {
-        let course_dept_id = format!(
-            "{} {}",
-            subject_code.as_ref().trim(),
-            course_num.as_ref().trim()
-        )
-        .to_uppercase();
-
-        parse_course_info(
-            process_get_text::<Vec<RawWebRegMeeting>>(
-                self.raw.get_course_info(subject_code, course_num).await?,
-            )?,
-            course_dept_id,
-        )
-    }
-
-    /// Gets a list of all departments that are offering courses for the given term.
-    ///
-    /// # Returns
-    /// A vector of department codes.
-    pub async fn get_department_codes(&self) -> types::Result<Vec<String>> {
-        Ok(
-            process_get_text::<Vec<RawDepartmentElement>>(self.raw.get_department_codes().await?)?
-                .into_iter()
-                .map(|x| x.dep_code.trim().to_string())
-                .collect::<Vec<_>>(),
-        )
-    }
-
-    /// Gets a list of all subjects that have at least one course offered for the given term.
-    ///
-    /// # Returns
-    /// A vector of subject codes.
-    pub async fn get_subject_codes(&self) -> types::Result<Vec<String>> {
-        Ok(
-            process_get_text::<Vec<RawSubjectElement>>(self.raw.get_subject_codes().await?)?
-                .into_iter()
-                .map(|x| x.subject_code.trim().to_string())
-                .collect::<Vec<_>>(),
-        )
+    pub async fn get_course_info(&self, course: impl Into<CourseCode>) -> types::Result<Courses> {
+        Ok(self
+            .get_course_groups(course.into())
+            .await?
+            .into_iter()
+            .flat_map(CourseGroup::into_sections)
+            .collect())
     }
 
-    /// Gets all courses that are available. All this does is searches for all courses via WebReg's
-    /// menu. Thus, only basic details are shown.
+    /// Gets course information for a particular course, grouped by the meetings that the
+    /// sections share (see `get_course_info`).
     ///
     /// # Parameters
-    /// - `filter_by`: The request filter.
+    /// - `course`: The course.
     ///
     /// # Returns
-    /// A vector consisting of all courses that are available. Note that the data that is returned
-    /// is directly from WebReg's API, so care will need to be taken to clean the resulting data.
-    pub async fn search_courses(&self, filter_by: SearchType) -> types::Result<SearchResult> {
-        Ok(process_get_text::<Vec<RawWebRegSearchResultItem>>(
-            self.raw.search_courses(filter_by).await?,
-        )?
-        .into_iter()
-        .map(|item| SearchResultItem {
-            subj_code: item.subj_code.trim().to_owned(),
-            course_code: item.course_code.trim().to_owned(),
-            course_title: item.course_title.trim().to_owned(),
-        })
-        .collect())
+    /// The groups, or an error.
+    async fn get_course_groups(&self, course: CourseCode) -> types::Result<Vec<CourseGroup>> {
+        let course_dept_id = course.to_string();
+
+        let (meetings, mut warnings) =
+            parse_raw_meetings(&self.raw.get_course_info(&course).await?)?;
+        let section_codes = if self.raw.info.include_section_notes {
+            meetings
+                .iter()
+                .filter(|m| !m.section_id.is_empty() && !m.sect_code.is_empty())
+                .map(|m| (m.section_id.clone(), m.sect_code.clone()))
+                .collect::<HashMap<_, _>>()
+        } else {
+            HashMap::new()
+        };
+
+        let (mut groups, parse_warnings) = group_course_info(meetings, course_dept_id)?;
+        warnings.extend(parse_warnings);
+        self.raw.info.check_parse_warnings(&warnings)?;
+
+        // The notes and waitlist status are per section, so process every section at once and
+        // then put them back into their groups.
+        let counts = groups.iter().map(|g| g.sections.len()).collect::<Vec<_>>();
+        let mut sections = groups
+            .iter_mut()
+            .flat_map(|g| g.sections.drain(..))
+            .collect::<Vec<_>>();
+        if !section_codes.is_empty() {
+            self.attach_section_notes(&mut sections, &section_codes)
+                .await?;
+        }
+
+        self.apply_waitlist_status(&mut sections);
+        let mut sections = sections.into_iter();
+        for (group, count) in groups.iter_mut().zip(counts) {
+            group.sections.extend(sections.by_ref().take(count));
+        }
+
+        Ok(groups)
     }
 
-    /// Gets a list of all course notes for one or more subjects..
+    /// Marks the sections whose waitlist WebReg has said is full (see
+    /// `CourseSection::waitlist_open`).
     ///
     /// # Parameters
-    /// - `subj`: The list of subject codes to consider. It is assumed that each subject code
-    ///   is uppercase and formatted properly (i.e., no additional processing will be performed).
-    ///
-    /// # Returns
-    /// A map, where the key is the course code (e.g., `CSE 101`) and the value is the associated
-    /// course text (e.g., `Students are required to attend a CSE 101 discussion section.`).
-    pub async fn get_course_notes<T: AsRef<str>>(
-        &self,
-        subj: &[T],
-    ) -> types::Result<HashMap<String, String>> {
-        let res =
-            process_get_text::<Vec<RawCourseTextItem>>(self.raw.get_course_notes(subj).await?)?;
-
-        let mut map = HashMap::new();
-        // Keep in mind that, for whatever reason, some courses may have multiple entries of the
-        // course text. For example, CSE 101 has two entries:
-        //
-        // {"TEXT":"Students are required to attend a CSE 101 discussion   ","SUBJCRSE":"CSE-101"}
-        // {"TEXT":"section.                                               ","SUBJCRSE":"CSE-101"}
-        //
-        // The text are ordered so that consecutive partitioned course texts should appear next to
-        // each other in the final string (so, with CSE 101, we'll have the expected text of
-        // "Students are required to attend a CSE 101 discussion section.").
-        //
-        // So, we'll just group all text by their course and then finally join everything together.
-        for RawCourseTextItem { text, subj_crse } in res.iter() {
-            let course_text_vec = map.entry(subj_crse).or_insert(vec![]);
-            course_text_vec.push(text.trim());
+    /// - `sections`: The sections.
+    fn apply_waitlist_status(&self, sections: &mut [CourseSection]) {
+        let tracker = self.raw.info.get_waitlist_tracker();
+        for section in sections {
+            section.waitlist_open = tracker.is_open(self.raw.term, &section.section_id);
         }
-
-        Ok(map
-            .into_iter()
-            .map(|(k, v)| (k.replace('-', " "), v.join(" ")))
-            .collect())
     }
 
-    /// Gets a list of all notes for all sections in a course.
+    /// Fetches the notes for the given sections and attaches them to the matching sections.
+    /// A note for a lecture (a section code ending in `00`) is attached to every section in
+    /// that family; any other note is only attached to the section with the same section ID.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_code`: The course code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `sections`: The sections to attach the notes to.
+    /// - `section_codes`: A map from every section ID in the course (including the lecture
+    ///   section IDs) to its section code.
     ///
     /// # Returns
-    /// A map, where the key is the section family (e.g., section `A`, which encompasses all sections
-    /// that start with A, like A01, A02, ...), and the value is the note for that section.
-    pub async fn get_section_notes_by_course(
+    /// Nothing, or an error if the notes couldn't be fetched.
+    async fn attach_section_notes(
         &self,
-        subject_code: impl AsRef<str>,
-        course_num: impl AsRef<str>,
-    ) -> types::Result<HashMap<String, String>> {
-        // As usual, WebReg only has the best possible API design. The way WebReg's
-        // `search-get-section-text` endpoint works is that it takes a list of all section IDs.
-        // The section ID that users will normally see when searching a course on WebReg, or
-        // through my implementation of the `get_course_info` function, is the section ID associated
-        // with a discussion meeting (basically, whatever you can enroll in).
-        //
-        // In reality, each section actually has two section IDs -- the one that you can enroll
-        // with (usually a discussion section) and another for the parent meetings (like lectures
-        // or midterms or final exams). We need to get _that_ particular section ID as well.
+        sections: &mut [CourseSection],
+        section_codes: &HashMap<String, String>,
+    ) -> types::Result<()> {
+        let mut ids = section_codes.keys().collect::<Vec<_>>();
+        ids.sort();
+        let notes =
+            process_get_text::<Vec<RawSectionTextItem>>(self.raw.get_section_notes(&ids).await?)?;
 
-        // Begin by getting a list of all valid (section ID, section code) pairs.
-        let section_id_code = serde_json::from_str::<Vec<RawWebRegMeeting>>(
-            &self.raw.get_course_info(subject_code, course_num).await?,
-        )?
-        .into_iter()
-        .filter(|d| d.display_type != "CA" && !d.section_id.is_empty() && !d.sect_code.is_empty())
-        .map(|d| (d.section_id, d.sect_code))
-        .collect::<Vec<_>>();
-
-        // We can construct a map where the key is the parent section ID (e.g., section A), and the
-        // value is a list of all associated sections.
-        let mut parent_id_section_map: HashMap<&str, HashSet<&str>> = HashMap::new();
-        // We'll also maintain a map where the key is a section ID and the value is the associated
-        // parent ID.
-        let mut section_id_parent_map: HashMap<&str, &str> = HashMap::new();
-        for (section_id, section_code) in &section_id_code {
-            let section_family: &str = if section_code.as_bytes()[0].is_ascii_digit() {
-                section_code
-            } else {
-                // Get the first character of the section code (will be a letter)
-                &section_code[..1]
+        for RawSectionTextItem { sectnum, text } in &notes {
+            let text = text.trim();
+            let Some(code) = section_codes.get(sectnum) else {
+                continue;
             };
 
-            let entry = parent_id_section_map
-                .entry(section_family)
-                .or_insert(HashSet::new());
-            entry.insert(section_id);
-
-            // Because each section ID should be unique, we know that a section ID won't be
-            // assigned to two different section families. So, no need to modify the entry if it
-            // already exists.
-            section_id_parent_map
-                .entry(section_id)
-                .or_insert(section_family);
-        }
-
-        // Now that we have a list of all associated sections to IDs, we can make a call to
-        // that WebReg endpoint.
-        let all_ids = parent_id_section_map
-            .values()
-            .fold(vec![], |mut acc, curr| {
-                curr.iter().for_each(|elem| {
-                    if !acc.contains(elem) {
-                        acc.push(elem);
-                    }
-                });
-                acc
-            });
+            if text.is_empty() {
+                continue;
+            }
 
-        let res = process_get_text::<Vec<RawSectionTextItem>>(
-            self.raw.get_section_notes(&all_ids).await?,
-        )?;
+            let family = code
+                .chars()
+                .next()
+                .filter(|c| c.is_ascii_alphabetic() && code.ends_with("00"));
+            for section in sections.iter_mut() {
+                let applies = match family {
+                    Some(f) => section.family() == Some(f),
+                    None => section.section_id == *sectnum,
+                };
 
-        // Now that we have the response, we can figure out which section each note belongs to.
-        // For this map, the key will be the section family and the value will be the note.
-        //
-        // We'll make the (probably correct) assumption that no individual section will have
-        // different section notes. In other words, we should expect all sections under a family to
-        // have the same applicable note.
-        let mut map = HashMap::new();
-        for RawSectionTextItem { text, sectnum } in res.iter() {
-            let section_family = match section_id_parent_map.get(sectnum.as_str()) {
-                None => {
-                    dbg!(&text);
-                    continue;
+                if applies && !section.section_notes.iter().any(|n| n == text) {
+                    section.section_notes.push(text.to_string());
                 }
-                Some(s) => s,
-            };
-
-            let course_text_vec = map.entry(section_family).or_insert(vec![]);
-            course_text_vec.push(text.trim());
+            }
         }
 
-        Ok(map
-            .into_iter()
-            .map(|(k, v)| (k.to_string(), v.join(" ")))
-            .collect())
+        Ok(())
     }
 
-    /// Gets all event from your WebReg calendar.
+    /// Gets course information for a particular course, grouped by section family. Unlike
+    /// `get_course_info`, the meetings that are shared by every section in a family (e.g., the
+    /// lecture and final exam) are only listed once for the family, and each section only
+    /// lists its own meetings (e.g., its discussion).
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("MATH", "100B")`.
     ///
     /// # Returns
-    /// A vector of all events, or `None` if an error occurred.
+    /// The section families, sorted by family.
     ///
     /// # Example
-    /// Renaming the schedule "`Test Schedule`" to "`Another Schedule`."
     /// ```rust,no_run
     /// use reqwest::Client;
     /// use webweg::wrapper::WebRegWrapper;
     ///
-    ///
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
     ///
-    /// let events = wrapper
+    /// let families = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .get_course_structure(("CSE", "100"))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// for family in families {
+    ///     println!("Family {}: {} lecture meeting(s)", family.family, family.lecture.len());
+    ///     for discussion in family.discussions {
+    ///         println!("\t{} ({})", discussion.section_code, discussion.section_id);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_course_structure(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> types::Result<Vec<SectionFamily>> {
+        Ok(into_section_families(
+            self.get_course_groups(course.into()).await?,
+        ))
+    }
+
+    /// Probes every course number in a range for a subject and returns the courses that
+    /// exist, along with their sections. This is useful for finding courses that don't show up
+    /// in search (e.g., hidden or restricted offerings).
+    ///
+    /// Course numbers are probed in small batches (each batch is requested concurrently, subject
+    /// to the wrapper's pacing), so a large range will make many requests. Only plain numeric
+    /// course numbers are probed, so courses with a suffix (e.g., `100A`) won't be found.
+    ///
+    /// # Parameters
+    /// - `subject_code`: The subject code. For example, `CSE`.
+    /// - `course_nums`: The course numbers to probe. For example, `1..=199`.
+    ///
+    /// # Returns
+    /// The course numbers that exist along with their sections, and the course numbers that
+    /// couldn't be probed along with the error. A course number that fails (e.g., because
+    /// WebReg responds with an error status code for a restricted course) doesn't stop the
+    /// scan; only a session error (see `WrapperError::is_session_error`) does, since every
+    /// other course number would fail the same way.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let scan = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .scan_course_range("CSE", 1..=199)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// for (course_num, sections) in scan.found {
+    ///     println!("CSE {course_num}: {} section(s)", sections.len());
+    /// }
+    ///
+    /// for (course_num, e) in scan.errors {
+    ///     eprintln!("CSE {course_num}: {e}");
+    /// }
+    /// # }
+    /// ```
+    pub async fn scan_course_range(
+        &self,
+        subject_code: impl AsRef<str>,
+        course_nums: impl IntoIterator<Item = u32>,
+    ) -> types::Result<CourseRangeScan> {
+        const SCAN_BATCH_SIZE: usize = 8;
+
+        let subject_code = subject_code.as_ref();
+        let course_nums = course_nums
+            .into_iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+
+        let mut scan = CourseRangeScan::default();
+        for batch in course_nums.chunks(SCAN_BATCH_SIZE) {
+            let results = futures::future::join_all(
+                batch
+                    .iter()
+                    .map(|num| self.get_course_info((subject_code, num))),
+            )
+            .await;
+
+            for (num, res) in batch.iter().cloned().zip(results) {
+                match res {
+                    Ok(sections) if sections.is_empty() => {}
+                    Ok(sections) => scan.found.push((num, sections)),
+                    Err(e) if e.is_session_error() => return Err(e),
+                    Err(e) => scan.errors.push((num, e)),
+                }
+            }
+        }
+
+        Ok(scan)
+    }
+
+    /// Gets the section information for a specific section, including the meetings (e.g.,
+    /// lectures and final exams) that it shares with the rest of its section family.
+    ///
+    /// WebReg doesn't let you request one specific section, so this looks up the section's
+    /// course and then fetches the course's data. However, only the meetings related to the
+    /// section are parsed, which is cheaper than calling `get_course_info` and searching
+    /// through the results (e.g., if you are tracking one discussion section of a large
+    /// course).
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID. For example, `079911`.
+    ///
+    /// # Returns
+    /// The section, or an error if the section couldn't be found or something went wrong.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// match wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .get_section_info("079911")
+    ///     .await
+    /// {
+    ///     Ok(sec) => println!("{sec}"),
+    ///     Err(e) => eprintln!("An error occurred! {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_section_info(
+        &self,
+        section_id: impl AsRef<str>,
+    ) -> types::Result<CourseSection> {
+        let section_id = section_id.as_ref().trim();
+        let course = self.find_section_course(section_id).await?;
+        let course_dept_id = course.to_string();
+
+        let (meetings, warnings) = parse_raw_meetings(&self.raw.get_course_info(&course).await?)?;
+        self.raw.info.check_parse_warnings(&warnings)?;
+
+        let mut section =
+            parse_section_info(meetings, course_dept_id, section_id)?.ok_or_else(|| {
+                WrapperError::SectionIdNotFound(
+                    section_id.to_string(),
+                    SectionIdNotFoundContext::Catalog,
+                )
+            })?;
+        self.apply_waitlist_status(std::slice::from_mut(&mut section));
+        Ok(section)
+    }
+
+    /// Gets the seat counts for every section of a particular course. This is a faster version
+    /// of `get_enrollment_count` that only reads the seat counts from the response, which is
+    /// useful if you're polling many courses frequently.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("MATH", "100B")`.
+    ///
+    /// # Returns
+    /// The seat counts for each section that can be enrolled in. Use
+    /// `SeatCount::has_seats_with` with `seat_strategy` to check whether a section has seats
+    /// according to the wrapper's seat strategy.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    /// let req = wrapper.req("FA23").parsed();
+    ///
+    /// let counts = req.get_seat_counts(("COGS", "108")).await.unwrap();
+    /// for count in counts {
+    ///     println!(
+    ///         "{}: {} seats left (open? {})",
+    ///         count.section_code,
+    ///         count.available_seats,
+    ///         count.has_seats_with(req.seat_strategy())
+    ///     );
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_seat_counts(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> types::Result<Vec<SeatCount>> {
+        Ok(parse_seat_counts(process_get_text::<Vec<RawSeatCount>>(
+            self.raw.get_course_info(course).await?,
+        )?))
+    }
+
+    /// Polls the seat counts for every section of a particular course, yielding a new snapshot
+    /// every `interval` (the first snapshot is taken right away). See `get_seat_counts` for
+    /// more information.
+    ///
+    /// The stream only polls WebReg when the next snapshot is requested, so a slow consumer
+    /// (e.g., one that sends each snapshot to a webhook) won't cause snapshots to pile up.
+    /// `on_lag` controls what happens when the consumer falls behind the interval.
+    ///
+    /// The stream never ends by itself; an error from one poll is yielded like any other
+    /// snapshot, and the next poll happens as usual.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("MATH", "100B")`.
+    /// - `interval`: How often to poll.
+    /// - `on_lag`: What to do when the consumer falls behind.
+    ///
+    /// # Returns
+    /// A stream of seat count snapshots, or an error if `interval` is zero. The stream must be
+    /// polled from within a Tokio runtime.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use futures::StreamExt;
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::LagBehavior;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    /// let req = wrapper.req("FA23").parsed();
+    ///
+    /// let stream = req
+    ///     .watch_seat_counts(("COGS", "108"), Duration::from_secs(30), LagBehavior::Skip)
+    ///     .unwrap();
+    /// futures::pin_mut!(stream);
+    /// while let Some(snapshot) = stream.next().await {
+    ///     match snapshot {
+    ///         Ok(counts) => println!("{} sections", counts.len()),
+    ///         Err(e) => eprintln!("An error occurred! {e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn watch_seat_counts(
+        &'a self,
+        course: impl Into<CourseCode>,
+        interval: Duration,
+        on_lag: LagBehavior,
+    ) -> types::Result<impl Stream<Item = types::Result<Vec<SeatCount>>> + 'a> {
+        if interval.is_zero() {
+            return Err(WrapperError::InputError(
+                "interval",
+                "The interval must be greater than zero.",
+            ));
+        }
+
+        let course = course.into();
+        let on_lag = match on_lag {
+            LagBehavior::Skip => MissedTickBehavior::Skip,
+            LagBehavior::Delay => MissedTickBehavior::Delay,
+            LagBehavior::Burst => MissedTickBehavior::Burst,
+        };
+
+        // The ticker is created on the first poll, since creating it requires a Tokio runtime.
+        Ok(stream::unfold(None, move |ticker: Option<Interval>| {
+            let course = course.clone();
+            async move {
+                let mut ticker = ticker.unwrap_or_else(|| {
+                    let mut ticker = tokio::time::interval(interval);
+                    ticker.set_missed_tick_behavior(on_lag);
+                    ticker
+                });
+                ticker.tick().await;
+                let snapshot = self.get_seat_counts(course).await;
+                Some((snapshot, Some(ticker)))
+            }
+        }))
+    }
+
+    /// Gets the combined seat counts for each section family (e.g., all sections under the
+    /// `A00` lecture) of a course. This is useful when a specific discussion section is full,
+    /// but other discussion sections for the same lecture still have space.
+    ///
+    /// See `util::summarize_families` for more information.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("MATH", "100B")`.
+    ///
+    /// # Returns
+    /// The seat counts for each section family, sorted by family.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// match wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .get_family_availability(("CSE", "100"))
+    ///     .await
+    /// {
+    ///     Ok(families) => families.iter().for_each(|fam| println!("{fam}")),
+    ///     Err(e) => eprintln!("An error occurred! {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_family_availability(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> types::Result<Vec<FamilyAvailability>> {
+        let sections = self.get_enrollment_count(course).await?;
+        Ok(util::summarize_families(&sections))
+    }
+
+    /// Gets a list of all departments that are offering courses for the given term.
+    ///
+    /// # Returns
+    /// A vector of department codes.
+    pub async fn get_department_codes(&self) -> types::Result<Vec<String>> {
+        Ok(
+            process_get_text::<Vec<RawDepartmentElement>>(self.raw.get_department_codes().await?)?
+                .into_iter()
+                .map(|x| x.dep_code.clone())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Gets a list of all subjects that have at least one course offered for the given term.
+    ///
+    /// # Returns
+    /// A vector of subject codes.
+    pub async fn get_subject_codes(&self) -> types::Result<Vec<String>> {
+        Ok(
+            process_get_text::<Vec<RawSubjectElement>>(self.raw.get_subject_codes().await?)?
+                .into_iter()
+                .map(|x| x.subject_code.clone())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Gets a list of all departments, using the list cached by this wrapper if possible.
+    /// Department lists barely change between terms, so only one list is cached for all terms;
+    /// the list is requested again (for this requester's term) once the cached list expires,
+    /// according to the wrapper's clock.
+    ///
+    /// The cache is scoped to the wrapper, not the process. Every requester made from the same
+    /// wrapper shares it, but separate wrappers (e.g., one per user) each request and keep
+    /// their own list. To only request the list once in your program, share one wrapper.
+    ///
+    /// This might be useful for interactive tools (e.g., search pages) that need the list often.
+    ///
+    /// # Returns
+    /// A vector of department codes.
+    pub async fn get_departments_wrapper_cached(&self) -> types::Result<Vec<String>> {
+        let cache = self.raw.info.get_catalog_cache();
+        if let Some(departments) =
+            cache.get(CatalogKind::Departments, self.raw.info.get_clock().now())
+        {
+            return Ok(departments);
+        }
+
+        let departments = self.get_department_codes().await?;
+        cache.put(
+            CatalogKind::Departments,
+            departments.clone(),
+            self.raw.info.get_clock().now(),
+        );
+        Ok(departments)
+    }
+
+    /// Gets a list of all subjects, using the list cached by this wrapper if possible. See
+    /// `get_departments_wrapper_cached` for more information.
+    ///
+    /// # Returns
+    /// A vector of subject codes.
+    pub async fn get_subjects_wrapper_cached(&self) -> types::Result<Vec<String>> {
+        let cache = self.raw.info.get_catalog_cache();
+        if let Some(subjects) = cache.get(CatalogKind::Subjects, self.raw.info.get_clock().now()) {
+            return Ok(subjects);
+        }
+
+        let subjects = self.get_subject_codes().await?;
+        cache.put(
+            CatalogKind::Subjects,
+            subjects.clone(),
+            self.raw.info.get_clock().now(),
+        );
+        Ok(subjects)
+    }
+
+    /// Gets all courses that are available. All this does is searches for all courses via WebReg's
+    /// menu. Thus, only basic details are shown.
+    ///
+    /// # Parameters
+    /// - `filter_by`: The request filter.
+    ///
+    /// # Returns
+    /// A vector consisting of all courses that are available. Note that the data that is returned
+    /// is directly from WebReg's API, so care will need to be taken to clean the resulting data.
+    pub async fn search_courses(&self, filter_by: SearchType) -> types::Result<SearchResult> {
+        let raw = process_get_text::<Vec<RawWebRegSearchResultItem>>(
+            self.raw.search_courses(filter_by).await?,
+        )?;
+        Ok(parse_search_results(raw))
+    }
+
+    /// Searches for courses (see `search_courses`), and then gets the sections of every
+    /// course that was found. The course information is requested concurrently, with at most
+    /// `concurrency` requests in progress at once (subject to the wrapper's pacing), which is
+    /// much faster than requesting each course one after another for large searches.
+    ///
+    /// # Parameters
+    /// - `filter_by`: The request filter.
+    /// - `concurrency`: The maximum number of course information requests to have in progress
+    ///   at once. A value of `0` is treated as `1`.
+    ///
+    /// If the requester has a retry budget (see `WrapperTermRequestBuilder::with_retry_budget`)
+    /// and it runs out, the courses whose information couldn't be retrieved because of that are
+    /// left out, so that the courses that were retrieved aren't lost. Use
+    /// `RetryBudget::is_exhausted` to check whether the results are complete.
+    ///
+    /// # Returns
+    /// Every course that was found, along with its sections, in the order given by the
+    /// search, or the first error that occurred (other than the retry budget running out).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::{SearchRequestBuilder, SearchType};
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let search = SearchType::Advanced(SearchRequestBuilder::new().add_subject("CSE"));
+    /// let courses = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .search_courses_detailed(search, 8)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// for (course, sections) in courses {
+    ///     println!("{} {}: {} section(s)", course.subj_code, course.course_code, sections.len());
+    /// }
+    /// # }
+    /// ```
+    pub async fn search_courses_detailed(
+        &self,
+        filter_by: SearchType,
+        concurrency: usize,
+    ) -> types::Result<Vec<(SearchResultItem, Courses)>> {
+        let results = self.search_courses(filter_by).await?;
+        stream::iter(results)
+            .map(|item| async move {
+                match self
+                    .get_course_info((&item.subj_code, &item.course_code))
+                    .await
+                {
+                    Ok(sections) => Ok(Some((item, sections))),
+                    Err(WrapperError::RetryBudgetExhausted(_)) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            })
+            .buffered(concurrency.max(1))
+            .try_filter_map(|course| async move { Ok(course) })
+            .try_collect()
+            .await
+    }
+
+    /// Gets every section offered this term, by searching each department (see
+    /// `get_department_codes`) and then getting every course that was found. Sections that
+    /// show up under more than one department (e.g., cross-listed courses) are only included
+    /// once.
+    ///
+    /// This makes a lot of requests, so consider setting up rate limiting (see the builder's
+    /// `with_rate_limit` function) before using this.
+    ///
+    /// # Parameters
+    /// - `concurrency`: The maximum number of courses to get at once (see
+    ///   `search_courses_detailed`).
+    /// - `on_progress`: Called after each department is finished.
+    ///
+    /// # Returns
+    /// Every section, in the order that they were found, or the first error that occurred.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let sections = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .get_all_courses(4, |p| {
+    ///         println!("{}/{} ({})", p.departments_done, p.departments_total, p.department)
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// println!("Found {} sections.", sections.len());
+    /// # }
+    /// ```
+    pub async fn get_all_courses(
+        &self,
+        concurrency: usize,
+        mut on_progress: impl FnMut(&CatalogProgress),
+    ) -> types::Result<Courses> {
+        let departments = self.get_department_codes().await?;
+        let mut seen = HashSet::new();
+        let mut sections = vec![];
+        for (idx, department) in departments.iter().enumerate() {
+            let search =
+                SearchType::Advanced(SearchRequestBuilder::new().add_department(department));
+            for (_, course) in self.search_courses_detailed(search, concurrency).await? {
+                sections.extend(
+                    course
+                        .into_iter()
+                        .filter(|sec| seen.insert(sec.section_id.clone())),
+                );
+            }
+
+            on_progress(&CatalogProgress {
+                department: department.clone(),
+                departments_done: idx + 1,
+                departments_total: departments.len(),
+                sections_found: sections.len(),
+            });
+        }
+
+        Ok(sections)
+    }
+
+    /// Gets a list of all course notes for one or more subjects..
+    ///
+    /// # Parameters
+    /// - `subj`: The list of subject codes to consider. It is assumed that each subject code
+    ///   is uppercase and formatted properly (i.e., no additional processing will be performed).
+    ///
+    /// # Returns
+    /// A map, where the key is the course code (e.g., `CSE 101`) and the value is the associated
+    /// course text (e.g., `Students are required to attend a CSE 101 discussion section.`).
+    pub async fn get_course_notes<T: AsRef<str>>(
+        &self,
+        subj: &[T],
+    ) -> types::Result<HashMap<String, String>> {
+        let res =
+            process_get_text::<Vec<RawCourseTextItem>>(self.raw.get_course_notes(subj).await?)?;
+
+        let mut map = HashMap::new();
+        // Keep in mind that, for whatever reason, some courses may have multiple entries of the
+        // course text. For example, CSE 101 has two entries:
+        //
+        // {"TEXT":"Students are required to attend a CSE 101 discussion   ","SUBJCRSE":"CSE-101"}
+        // {"TEXT":"section.                                               ","SUBJCRSE":"CSE-101"}
+        //
+        // The text are ordered so that consecutive partitioned course texts should appear next to
+        // each other in the final string (so, with CSE 101, we'll have the expected text of
+        // "Students are required to attend a CSE 101 discussion section.").
+        //
+        // So, we'll just group all text by their course and then finally join everything together.
+        for RawCourseTextItem { text, subj_crse } in res.iter() {
+            let course_text_vec = map.entry(subj_crse).or_insert(vec![]);
+            course_text_vec.push(text.trim());
+        }
+
+        Ok(map
+            .into_iter()
+            .map(|(k, v)| (k.replace('-', " "), v.join(" ")))
+            .collect())
+    }
+
+    /// Gets a list of all notes for all sections in a course.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("MATH", "100B")`.
+    ///
+    /// # Returns
+    /// A map, where the key is the section family (e.g., section `A`, which encompasses all sections
+    /// that start with A, like A01, A02, ...), and the value is the note for that section.
+    /// Use `notes::parse_section_notes` to get structured facts (e.g., cross-listings or
+    /// reserved seats) out of each note.
+    pub async fn get_section_notes_by_course(
+        &self,
+        course: impl Into<CourseCode>,
+    ) -> types::Result<HashMap<String, String>> {
+        // As usual, WebReg only has the best possible API design. The way WebReg's
+        // `search-get-section-text` endpoint works is that it takes a list of all section IDs.
+        // The section ID that users will normally see when searching a course on WebReg, or
+        // through my implementation of the `get_course_info` function, is the section ID associated
+        // with a discussion meeting (basically, whatever you can enroll in).
+        //
+        // In reality, each section actually has two section IDs -- the one that you can enroll
+        // with (usually a discussion section) and another for the parent meetings (like lectures
+        // or midterms or final exams). We need to get _that_ particular section ID as well.
+
+        // Begin by getting a list of all valid (section ID, section code) pairs.
+        let (meetings, warnings) = parse_raw_meetings(&self.raw.get_course_info(course).await?)?;
+        self.raw.info.check_parse_warnings(&warnings)?;
+        let section_id_code = meetings
+            .into_iter()
+            .filter(|d| {
+                d.display_type != "CA" && !d.section_id.is_empty() && !d.sect_code.is_empty()
+            })
+            .map(|d| (d.section_id, d.sect_code))
+            .collect::<Vec<_>>();
+
+        // We can construct a map where the key is the parent section ID (e.g., section A), and the
+        // value is a list of all associated sections.
+        let mut parent_id_section_map: HashMap<&str, HashSet<&str>> = HashMap::new();
+        // We'll also maintain a map where the key is a section ID and the value is the associated
+        // parent ID.
+        let mut section_id_parent_map: HashMap<&str, &str> = HashMap::new();
+        for (section_id, section_code) in &section_id_code {
+            let section_family: &str = if section_code.as_bytes()[0].is_ascii_digit() {
+                section_code
+            } else {
+                // Get the first character of the section code (will be a letter)
+                &section_code[..1]
+            };
+
+            let entry = parent_id_section_map
+                .entry(section_family)
+                .or_insert(HashSet::new());
+            entry.insert(section_id);
+
+            // Because each section ID should be unique, we know that a section ID won't be
+            // assigned to two different section families. So, no need to modify the entry if it
+            // already exists.
+            section_id_parent_map
+                .entry(section_id)
+                .or_insert(section_family);
+        }
+
+        // Now that we have a list of all associated sections to IDs, we can make a call to
+        // that WebReg endpoint.
+        let all_ids = parent_id_section_map
+            .values()
+            .fold(vec![], |mut acc, curr| {
+                curr.iter().for_each(|elem| {
+                    if !acc.contains(elem) {
+                        acc.push(elem);
+                    }
+                });
+                acc
+            });
+
+        let res = process_get_text::<Vec<RawSectionTextItem>>(
+            self.raw.get_section_notes(&all_ids).await?,
+        )?;
+
+        // Now that we have the response, we can figure out which section each note belongs to.
+        // For this map, the key will be the section family and the value will be the note.
+        //
+        // We'll make the (probably correct) assumption that no individual section will have
+        // different section notes. In other words, we should expect all sections under a family to
+        // have the same applicable note.
+        let mut map = HashMap::new();
+        for RawSectionTextItem { text, sectnum } in res.iter() {
+            let section_family = match section_id_parent_map.get(sectnum.as_str()) {
+                None => {
+                    dbg!(&text);
+                    continue;
+                }
+                Some(s) => s,
+            };
+
+            let course_text_vec = map.entry(section_family).or_insert(vec![]);
+            course_text_vec.push(text.trim());
+        }
+
+        Ok(map
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.join(" ")))
+            .collect())
+    }
+
+    /// Gets all event from your WebReg calendar.
+    ///
+    /// # Returns
+    /// A vector of all events, or `None` if an error occurred.
+    ///
+    /// # Example
+    /// Renaming the schedule "`Test Schedule`" to "`Another Schedule`."
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let events = wrapper
     ///     .req("FA23")
     ///     .parsed()
     ///     .get_events()
     ///     .await;
-    /// match events {
-    ///     Ok(events) => events.into_iter().for_each(|event| println!("{event}")),
-    ///     Err(e) => eprintln!("Error! {e}"),
-    /// };
+    /// match events {
+    ///     Ok(events) => events.into_iter().for_each(|event| println!("{event}")),
+    ///     Err(e) => eprintln!("Error! {e}"),
+    /// };
+    /// # }
+    /// ```
+    pub async fn get_events(&self) -> types::Result<Events> {
+        parse_get_events(process_get_text::<Vec<RawEvent>>(
+            self.raw.get_events().await?,
+        )?)
+    }
+
+    /// Gets all of your schedules.
+    ///
+    /// # Returns
+    /// Either a vector of strings representing the names of the schedules, or the error that
+    /// occurred.
+    pub async fn get_schedule_list(&self) -> types::Result<Vec<String>> {
+        process_get_text::<Vec<String>>(self.raw.get_schedule_list().await?)
+    }
+
+    /// Gets a snapshot of your account for this term. This includes all of your schedules,
+    /// events, and the sections that you are enrolled or waitlisted in. The requests needed to
+    /// build the snapshot are made concurrently.
+    ///
+    /// This might be useful for backing up your account, or archiving your schedules before
+    /// moving on to the next term.
+    ///
+    /// Note that WebReg doesn't expose your holds or enrollment appointments through the
+    /// endpoints this wrapper uses, so those are not part of the snapshot.
+    ///
+    /// # Returns
+    /// The snapshot, or an error if one occurred.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// match wrapper.req("FA23").parsed().export_account_snapshot().await {
+    ///     Ok(snapshot) => println!("{}", serde_json::to_string(&snapshot).unwrap()),
+    ///     Err(e) => eprintln!("Unable to export snapshot: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn export_account_snapshot(&self) -> types::Result<AccountSnapshot> {
+        let (mut schedule_names, events) =
+            futures::try_join!(self.get_schedule_list(), self.get_events())?;
+        if !schedule_names.iter().any(|s| s == DEFAULT_SCHEDULE_NAME) {
+            schedule_names.push(DEFAULT_SCHEDULE_NAME.to_owned());
+        }
+
+        let all_schedules = futures::future::try_join_all(
+            schedule_names
+                .iter()
+                .map(|name| self.get_schedule(Some(name.as_str()))),
+        )
+        .await?;
+
+        let schedules: HashMap<String, Schedule> =
+            schedule_names.into_iter().zip(all_schedules).collect();
+
+        // Enrolled and waitlisted sections show up in every schedule, so we only need to look
+        // at one of them.
+        let (enrolled, waitlisted) = schedules[DEFAULT_SCHEDULE_NAME]
+            .iter()
+            .filter(|sec| {
+                matches!(
+                    sec.enrolled_status,
+                    EnrollmentStatus::Enrolled | EnrollmentStatus::Waitlist { .. }
+                )
+            })
+            .cloned()
+            .partition(|sec| sec.enrolled_status == EnrollmentStatus::Enrolled);
+
+        Ok(AccountSnapshot {
+            term: self.raw.term.to_owned(),
+            schedules,
+            events,
+            enrolled,
+            waitlisted,
+            taken_at: self.raw.info.get_clock().now(),
+        })
+    }
+
+    /// Compares the sections planned in one of your schedules against what you are actually
+    /// enrolled in. Each planned section is put into exactly one of the following groups:
+    /// - `enrolled`/`waitlisted`: you are enrolled in (or waitlisted for) that course, possibly
+    ///   in a different section.
+    /// - `open`: the section still has seats (according to the wrapper's seat strategy).
+    /// - `full`: the section doesn't have any seats.
+    /// - `cancelled`: the section is no longer offered.
+    ///
+    /// This makes one request for the schedule, and one request for the enrollment counts of
+    /// each planned course that you aren't enrolled in.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule to reconcile, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The reconciliation.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let rec = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .reconcile_plan(Some("My Schedule"))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// for sec in &rec.open {
+    ///     println!("You can enroll in {} {} ({})", sec.subject_code, sec.course_code, sec.section_id);
+    /// }
+    /// # }
+    /// ```
+    pub async fn reconcile_plan(
+        &self,
+        schedule_name: Option<&str>,
+    ) -> types::Result<PlanReconciliation> {
+        let schedule = self.get_schedule(schedule_name).await?;
+        self.reconcile_schedule(&schedule, schedule_name).await
+    }
+
+    /// Reconciles a schedule that has already been fetched. See `reconcile_plan`.
+    ///
+    /// # Parameters
+    /// - `schedule`: The schedule.
+    /// - `schedule_name`: The name of the schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The reconciliation.
+    async fn reconcile_schedule(
+        &self,
+        schedule: &Schedule,
+        schedule_name: Option<&str>,
+    ) -> types::Result<PlanReconciliation> {
+        let status_of = |subj: &str, crsc: &str| {
+            schedule
+                .iter()
+                .find(|sec| {
+                    sec.subject_code == subj
+                        && sec.course_code == crsc
+                        && sec.enrolled_status != EnrollmentStatus::Planned
+                })
+                .map(|sec| &sec.enrolled_status)
+        };
+
+        let mut rec = PlanReconciliation {
+            schedule_name: schedule_name.unwrap_or(DEFAULT_SCHEDULE_NAME).to_string(),
+            enrolled: vec![],
+            waitlisted: vec![],
+            open: vec![],
+            full: vec![],
+            cancelled: vec![],
+        };
+
+        let mut to_check = vec![];
+        for sec in schedule
+            .iter()
+            .filter(|sec| sec.enrolled_status == EnrollmentStatus::Planned)
+        {
+            match status_of(&sec.subject_code, &sec.course_code) {
+                Some(EnrollmentStatus::Enrolled) => rec.enrolled.push(sec.clone()),
+                Some(EnrollmentStatus::Waitlist { .. }) => rec.waitlisted.push(sec.clone()),
+                _ => to_check.push(sec),
+            }
+        }
+
+        // Only look up each course once, even if several of its sections are planned.
+        let mut courses: Vec<(&str, &str)> = to_check
+            .iter()
+            .map(|sec| (sec.subject_code.as_str(), sec.course_code.as_str()))
+            .collect();
+        courses.sort_unstable();
+        courses.dedup();
+
+        let counts = futures::future::try_join_all(
+            courses
+                .iter()
+                .map(|&course| self.get_enrollment_count(course)),
+        )
+        .await?;
+        let sections: Vec<&CourseSection> = counts.iter().flatten().collect();
+
+        let strategy = self.raw.info.get_seat_strategy();
+        for sec in to_check {
+            let target_id = sec.section_id.trim_start_matches('0');
+            match sections
+                .iter()
+                .find(|s| s.section_id.trim_start_matches('0') == target_id)
+            {
+                Some(info) if info.has_seats_with(strategy) => rec.open.push(sec.clone()),
+                Some(_) => rec.full.push(sec.clone()),
+                None => rec.cancelled.push(sec.clone()),
+            }
+        }
+
+        Ok(rec)
+    }
+
+    /// Tries to enroll in every section planned in one of your schedules, like you would by
+    /// hand at your enrollment time. This first reconciles the plan (see `reconcile_plan`),
+    /// then goes through the planned sections that you aren't already enrolled in or waitlisted
+    /// for, in the order given by `options`:
+    /// - sections with seats are enrolled in. If that fails and waitlisting is allowed, the
+    ///   section is waitlisted instead.
+    /// - sections without seats are waitlisted, if waitlisting is allowed.
+    /// - sections that would put you over the unit limit are skipped.
+    /// - once a section of a course is added, any other planned sections of that course are
+    ///   skipped.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule to enroll from, or `None` for the default schedule.
+    /// - `options`: The options.
+    ///
+    /// # Returns
+    /// A report of what was done for each section.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::EnrollFromPlanOptions;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let options = EnrollFromPlanOptions::new()
+    ///     .with_priority([("CSE", "100"), ("MATH", "184")])
+    ///     .should_waitlist_if_full(true)
+    ///     .with_max_units(20);
+    ///
+    /// let report = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .enroll_from_plan(Some("My Schedule"), options)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// for attempt in &report.attempts {
+    ///     println!("{}: {:?}", attempt.section.section_id, attempt.outcome);
+    /// }
+    /// # }
+    /// ```
+    pub async fn enroll_from_plan(
+        &self,
+        schedule_name: Option<&str>,
+        options: EnrollFromPlanOptions,
+    ) -> types::Result<EnrollFromPlanReport> {
+        let schedule = self.get_schedule(schedule_name).await?;
+        let reconciliation = self.reconcile_schedule(&schedule, schedule_name).await?;
+
+        // Enrolled and waitlisted sections both count towards the unit limit.
+        let mut units: i64 = schedule
+            .iter()
+            .filter(|sec| sec.enrolled_status != EnrollmentStatus::Planned)
+            .map(|sec| sec.units)
+            .sum();
+
+        let unit_limit = options.unit_limit_at(self.raw.info.get_clock().now());
+        let mut candidates: Vec<(&ScheduledSection, bool)> = reconciliation
+            .open
+            .iter()
+            .map(|sec| (sec, true))
+            .chain(reconciliation.full.iter().map(|sec| (sec, false)))
+            .collect();
+        candidates
+            .sort_by_cached_key(|(sec, _)| options.priority_of(&sec.section_id, &sec.course()));
+
+        let mut added_courses: HashSet<CourseCode> = HashSet::new();
+        let mut attempts = vec![];
+        for (sec, has_seats) in candidates {
+            let course = sec.course();
+            let outcome = if added_courses.contains(&course) {
+                PlanEnrollOutcome::SkippedCourseAdded
+            } else if unit_limit.is_some_and(|max| units + sec.units > max) {
+                PlanEnrollOutcome::SkippedUnitLimit
+            } else if !has_seats && !options.waitlist_if_full {
+                PlanEnrollOutcome::SkippedFull
+            } else {
+                let add_options = || EnrollWaitAdd {
+                    section_id: Cow::Borrowed(sec.section_id.as_str()),
+                    grading_option: sec.grade_option,
+                    unit_count: u8::try_from(sec.units).ok(),
+                };
+
+                // Only try waitlisting if the section has no seats, or if enrolling failed
+                // (e.g., the last seat was taken since the plan was reconciled).
+                let enroll_err = if has_seats {
+                    self.add_section(AddType::Enroll, add_options(), options.validate)
+                        .await
+                        .err()
+                } else {
+                    None
+                };
+
+                match enroll_err {
+                    None if has_seats => PlanEnrollOutcome::Enrolled,
+                    // Waitlisting only makes sense if WebReg turned down the enroll; any other
+                    // error (e.g., an expired session) would just happen again.
+                    Some(e)
+                        if !options.waitlist_if_full
+                            || !matches!(e, WrapperError::WebRegRejected(_)) =>
+                    {
+                        PlanEnrollOutcome::Failed(e)
+                    }
+                    _ => match self
+                        .add_section(AddType::Waitlist, add_options(), options.validate)
+                        .await
+                    {
+                        Ok(_) => PlanEnrollOutcome::Waitlisted,
+                        Err(e) => PlanEnrollOutcome::Failed(e),
+                    },
+                }
+            };
+
+            if matches!(
+                outcome,
+                PlanEnrollOutcome::Enrolled | PlanEnrollOutcome::Waitlisted
+            ) {
+                units += sec.units;
+                added_courses.insert(course.clone());
+            }
+
+            if let Some(sink) = self.raw.info.get_attempt_sink() {
+                sink.record(&AttemptRecord {
+                    at: self.raw.info.get_clock().now(),
+                    term: self.raw.term.to_uppercase(),
+                    section_id: sec.section_id.clone(),
+                    section_code: sec.section_code.clone(),
+                    course: course.to_string(),
+                    outcome: outcome.name().to_owned(),
+                    reason: match &outcome {
+                        PlanEnrollOutcome::Failed(e) => {
+                            Some(e.webreg_reason().unwrap_or_else(|| e.to_string()))
+                        }
+                        _ => None,
+                    },
+                });
+            }
+
+            attempts.push(PlanEnrollAttempt {
+                section: sec.clone(),
+                outcome,
+            });
+        }
+
+        Ok(EnrollFromPlanReport {
+            reconciliation,
+            attempts,
+        })
+    }
+
+    /// Enrolls in a section, handling everything that usually needs to be done by hand:
+    /// - the section's seats are checked, so that the section is enrolled in if it has seats
+    ///   and waitlisted otherwise (if allowed);
+    /// - if enrolling fails (e.g., the last seat was just taken), the section is waitlisted
+    ///   instead (if allowed);
+    /// - each add is tried again if it fails with a transient error (e.g., a timeout), unless
+    ///   your schedule shows that the add went through anyways;
+    /// - and, afterwards, your schedule is checked to confirm the section's status.
+    ///
+    /// If an attempt sink is set (see the builder's `with_attempt_sink` function), the result
+    /// is recorded there.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID. For example, `079911`.
+    /// - `options`: How to add the section.
+    ///
+    /// # Returns
+    /// What happened, or an error if the section's seats couldn't be checked. Errors from
+    /// adding the section are part of the outcome instead.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::AutoEnrollOptions;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let outcome = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .auto_enroll("079911", AutoEnrollOptions::new().should_waitlist_if_full(true))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// println!("{:?} (confirmed: {:?})", outcome.status, outcome.confirmed);
+    /// # }
+    /// ```
+    pub async fn auto_enroll(
+        &self,
+        section_id: impl AsRef<str>,
+        options: AutoEnrollOptions,
+    ) -> types::Result<EnrollOutcome> {
+        let section_id = section_id.as_ref().trim();
+        let section = self.find_section_seats(section_id).await?;
+        let had_seats = section.has_seats_with(self.raw.info.get_seat_strategy());
+
+        let mut outcome = EnrollOutcome {
+            section,
+            had_seats,
+            tried_enroll: false,
+            tried_waitlist: false,
+            attempts: 0,
+            status: AutoEnrollStatus::SkippedFull,
+            confirmed: None,
+        };
+
+        let mut last_err = None;
+        if had_seats {
+            outcome.tried_enroll = true;
+            match self
+                .add_with_retries(section_id, false, &options, &mut outcome.attempts)
+                .await
+            {
+                Ok(_) => outcome.status = AutoEnrollStatus::Enrolled,
+                Err(e) => last_err = Some(e),
+            }
+        }
+
+        if !matches!(outcome.status, AutoEnrollStatus::Enrolled) && options.waitlist_if_full {
+            outcome.tried_waitlist = true;
+            match self
+                .add_with_retries(section_id, true, &options, &mut outcome.attempts)
+                .await
+            {
+                Ok(_) => {
+                    outcome.status = AutoEnrollStatus::Waitlisted;
+                    last_err = None;
+                }
+                Err(e) => last_err = Some(e),
+            }
+        }
+
+        if let Some(e) = last_err {
+            outcome.status = match e.rejection() {
+                Some(reason) => AutoEnrollStatus::Rejected(reason),
+                None => AutoEnrollStatus::Failed(e),
+            };
+        }
+
+        if options.confirm && (outcome.tried_enroll || outcome.tried_waitlist) {
+            let section_id = section_id.trim_start_matches('0');
+            outcome.confirmed = self.get_schedule(None).await.ok().and_then(|schedule| {
+                schedule
+                    .into_iter()
+                    .find(|sec| sec.section_id.trim_start_matches('0') == section_id)
+                    .map(|sec| sec.enrolled_status)
+            });
+        }
+
+        if let Some(sink) = self.raw.info.get_attempt_sink() {
+            sink.record(&AttemptRecord {
+                at: self.raw.info.get_clock().now(),
+                term: self.raw.term.to_uppercase(),
+                section_id: outcome.section.section_id.clone(),
+                section_code: outcome.section.section_code.clone(),
+                course: outcome.section.subj_course_id.clone(),
+                outcome: outcome.status.name().to_owned(),
+                reason: match &outcome.status {
+                    AutoEnrollStatus::Rejected(reason) => Some(reason.message().to_owned()),
+                    AutoEnrollStatus::Failed(e) => Some(e.to_string()),
+                    _ => None,
+                },
+            });
+        }
+
+        Ok(outcome)
+    }
+
+    /// Enrolls in, or waitlists, a section, trying again if the add fails with a transient
+    /// error. Used by `auto_enroll`.
+    ///
+    /// Since WebReg may have made the add even though the request failed (e.g., it timed
+    /// out), your schedule is checked before trying again, and the add is considered to have
+    /// worked if the section is already in your schedule. Likewise, if WebReg rejects a retry
+    /// because the section was already added, the add is considered to have worked.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    /// - `waitlist`: Whether to waitlist rather than enroll.
+    /// - `options`: The options, which control validation and retries.
+    /// - `attempts`: The number of add requests made so far, which is updated.
+    ///
+    /// # Returns
+    /// The result of the last try.
+    async fn add_with_retries(
+        &self,
+        section_id: &str,
+        waitlist: bool,
+        options: &AutoEnrollOptions,
+        attempts: &mut u32,
+    ) -> types::Result<bool> {
+        let mut tries = 0;
+        let mut had_transient = false;
+        loop {
+            let add_type = if waitlist {
+                AddType::Waitlist
+            } else {
+                AddType::Enroll
+            };
+            let add_options = EnrollWaitAdd {
+                section_id: Cow::Borrowed(section_id),
+                grading_option: options.grading_option,
+                unit_count: options.unit_count,
+            };
+
+            tries += 1;
+            *attempts += 1;
+            match self
+                .add_section(add_type, add_options, options.validate)
+                .await
+            {
+                Err(e) if e.is_transient() && tries < options.max_attempts => {
+                    (self.raw.info.get_sleeper())(options.retry_delay).await;
+                    if self.is_in_schedule(section_id).await {
+                        return Ok(true);
+                    }
+
+                    had_transient = true;
+                }
+                Err(e)
+                    if had_transient
+                        && matches!(e.rejection(), Some(EnrollmentFailure::AlreadyAdded(_))) =>
+                {
+                    return Ok(true);
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// Checks whether a section is in your schedule, in any form (enrolled, waitlisted, or
+    /// planned). Used by `add_with_retries`.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// `true` if the section is in your schedule, and `false` if it isn't or your schedule
+    /// couldn't be retrieved.
+    async fn is_in_schedule(&self, section_id: &str) -> bool {
+        let section_id = section_id.trim_start_matches('0');
+        self.get_schedule(None).await.is_ok_and(|schedule| {
+            schedule
+                .iter()
+                .any(|sec| sec.section_id.trim_start_matches('0') == section_id)
+        })
+    }
+
+    /// Sends an email to yourself using the same email that is used to confirm that you have
+    /// enrolled or waitlisted in a particular class. In other words, this will send an email
+    /// to you through the email `NoReplyRegistrar@ucsd.edu`.
+    ///
+    /// It is strongly recommended that this function not be abused.
+    ///
+    /// # Parameters
+    /// - `email_content`: The email to send.
+    ///
+    /// # Returns
+    /// `true` if the email was sent successfully and `false` otherwise.
+    ///
+    /// # Example
+    /// This will send an email to yourself with the content specified as the string shown below.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let email_res = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .send_email_to_self("hello, world.")
+    ///     .await;
+    ///
+    /// match email_res {
+    ///     Ok(_) => println!("Email sent successfully."),
+    ///     Err(e) => eprintln!("Email could not be sent: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn send_email_to_self(&self, email_content: &str) -> types::Result<()> {
+        let r = self
+            .raw
+            .info
+            .req(ReqType::Post(SEND_EMAIL))
+            .form(&[("actionevent", email_content), ("termcode", self.raw.term)])
+            .send()
+            .await?;
+
+        if !r.is_success() {
+            return Err(WrapperError::BadStatusCode(r.status, Some(r.text())));
+        }
+
+        let t = r.text();
+        if t.contains("\"YES\"") {
+            Ok(())
+        } else {
+            Err(WrapperError::WebRegError(t))
+        }
+    }
+
+    /// Changes the grading option for the class corresponding to the section ID.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID corresponding to the class that you want to change
+    /// the grading option for.
+    /// - `new_grade_opt`: The new grading option. This must either be `L` (letter),
+    /// `P` (pass/no pass), or `S` (satisfactory/unsatisfactory), and is enforced via an enum.
+    ///
+    /// # Returns
+    /// `true` if the process succeeded, or a string containing the error message from WebReg if
+    /// something wrong happened.
+    ///
+    /// # Example
+    /// Changing the section associated with section ID `235181` to letter grading option.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::GradeOption;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let change_res = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .change_grading_option("235181", GradeOption::P)
+    ///     .await;
+    ///
+    /// match change_res {
+    ///     Ok(res) => println!("Grade option changed? {res}"),
+    ///     Err(e) => eprintln!("Grade option error: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn change_grading_option(
+        &self,
+        section_id: &str,
+        new_grade_opt: GradeOption,
+    ) -> types::Result<bool> {
+        let poss_class = self.find_scheduled_section(section_id).await?;
+        let sec_id = poss_class.section_id.to_string();
+        let units = poss_class.units.to_string();
+
+        process_post_response(
+            &self.raw.info,
+            self.raw
+                .info
+                .req(ReqType::Post(CHANGE_ENROLL))
+                .form(&[
+                    ("section", sec_id.as_str()),
+                    ("subjCode", ""),
+                    ("crseCode", ""),
+                    ("unit", units.as_str()),
+                    ("grade", new_grade_opt.as_str()),
+                    // You don't actually need these
+                    ("oldGrade", ""),
+                    ("oldUnit", ""),
+                    ("termcode", self.raw.term),
+                ])
+                .send()
+                .await,
+        )
+        .await
+    }
+
+    /// Checks whether the class corresponding to the section ID can be switched to the given
+    /// grading option, so that you can find out before calling `change_grading_option`.
+    ///
+    /// This only catches sections that don't let you change the grading option at all (e.g.,
+    /// seminars that are only offered as P/NP). WebReg doesn't list the grading options that a
+    /// section allows, so this relies on the `GRADE_OPTN_CD_PLUS` marker in your schedule (see
+    /// `ScheduledSection::grade_option_locked`): `+` means that the grading option can be
+    /// changed, and a blank marker is taken to mean that it can't. If the marker is missing,
+    /// any grading option is allowed.
+    ///
+    /// Rules that depend on the number of units (e.g., a variable-unit course that only allows
+    /// P/NP at some unit counts) aren't checked, since WebReg doesn't give them out. In that
+    /// case, this returns `Ok` and `change_grading_option` fails with WebReg's reason instead.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID corresponding to the class that you want to change
+    ///   the grading option for.
+    /// - `new_grade_opt`: The grading option that you want to change to.
+    ///
+    /// # Returns
+    /// Nothing if the grading option is allowed. If it isn't, a
+    /// `WrapperError::GradeOptionNotAllowed` is returned.
+    ///
+    /// # Example
+    /// Changing the section associated with section ID `235181` to P/NP, but only if allowed.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::GradeOption;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    /// let req = wrapper.req("FA23").parsed();
+    ///
+    /// match req.validate_grading_option("235181", GradeOption::P).await {
+    ///     Ok(_) => {
+    ///         let _ = req.change_grading_option("235181", GradeOption::P).await;
+    ///     }
+    ///     Err(e) => eprintln!("Can't change the grading option: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn validate_grading_option(
+        &self,
+        section_id: &str,
+        new_grade_opt: GradeOption,
+    ) -> types::Result<()> {
+        let poss_class = self.find_scheduled_section(section_id).await?;
+        if poss_class.grade_option_locked && poss_class.grade_option != Some(new_grade_opt) {
+            return Err(WrapperError::GradeOptionNotAllowed(
+                new_grade_opt,
+                poss_class.section_id,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Finds the section in your schedule corresponding to the section ID.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID, with or without leading zeros.
+    ///
+    /// # Returns
+    /// The section, or `WrapperError::SectionIdNotFound` if it isn't in your schedule.
+    async fn find_scheduled_section(&self, section_id: &str) -> types::Result<ScheduledSection> {
+        // "Slice" any zeros off of the left-most side of the string. We need to do this
+        // because, when comparing section IDs in the schedule, WebReg gives us the
+        // section IDs as integers; however, for the rest of the API, it's given as a
+        // string.
+        //
+        // Essentially, this means that, while most of WebReg's API will take `"079911"` as
+        // an input and as an output (e.g. see `get_course_info`), the schedule API will
+        // specifically return an integer `79911`. The `get_schedule` function will simply
+        // convert this integer to a string, e.g. `79911` -> `"79911"` and return that along
+        // with the other parsed info for each scheduled section.
+        //
+        // So, we need to slice off any 0s from the input parameter `section_id` to account
+        // for this.
+        let trimmed = section_id.trim_start_matches('0');
+        self.get_schedule_cached(None)
+            .await?
+            .into_iter()
+            .find(|x| x.section_id == trimmed)
+            .ok_or_else(|| {
+                WrapperError::SectionIdNotFound(
+                    section_id.into(),
+                    SectionIdNotFoundContext::Schedule,
+                )
+            })
+    }
+
+    /// Validates that adding a course to your plan will cause no issue.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the course that you want to plan.
+    ///
+    /// # Returns
+    /// Whether the course can be planned and, if not, the reason that WebReg gave. An `Err` is
+    /// only returned if something went wrong with the request itself.
+    ///
+    /// # Example
+    /// Here, we will check whether we can add the course `CSE 100`, which has section ID
+    /// `079911` and section code `A01`, to our plan.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::{GradeOption, PlanAdd};
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let plan_add_data = PlanAdd::builder()
+    ///     .with_subject_code("CSE")
+    ///     .with_course_code("100")
+    ///     .with_section_id("079911")
+    ///     .with_section_code("A01")
+    ///     .with_grading_option(GradeOption::S)
+    ///     .with_unit_count(4)
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// let plan_res = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .validate_add_to_plan(&plan_add_data)
+    ///     .await;
+    ///
+    /// match plan_res {
+    ///     Ok(res) if res.ok => println!("Can plan!"),
+    ///     Ok(res) => println!("Can't plan: {:?}", res.reason),
+    ///     Err(e) => eprintln!("Unable to plan: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn validate_add_to_plan(
+        &self,
+        plan_options: &PlanAdd<'_>,
+    ) -> types::Result<ValidationOutcome> {
+        let crsc_code = util::get_formatted_course_num(plan_options.course_code.as_ref());
+        let res = process_post_response(
+            &self.raw.info,
+            self.raw
+                .info
+                .req(ReqType::Post(PLAN_EDIT))
+                .form(&[
+                    ("section", plan_options.section_id.as_ref()),
+                    ("subjcode", plan_options.subject_code.as_ref()),
+                    ("crsecode", crsc_code.as_str()),
+                    ("termcode", self.raw.term),
+                ])
+                .send()
+                .await,
+        )
+        .await;
+
+        to_validation_outcome(res)
+    }
+
+    /// Allows you to plan a course.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the course that you want to plan.
+    /// - `validate`: Whether to validate your planning of this course beforehand.
+    ///
+    /// # Returns
+    /// `true` if the process succeeded, or a string containing the error message from WebReg if
+    /// something wrong happened. If the course is already planned, an `AlreadyPlanned` error
+    /// is returned; consider using `upsert_plan` if this is a possibility.
+    ///
+    /// If `validate` is `true` and the validation request fails, the course isn't planned and
+    /// that error is returned. If WebReg says that you can't enroll in the course (e.g., because
+    /// it's restricted to certain majors), the course is still planned, since WebReg allows
+    /// this; if WebReg rejects the plan anyway, WebReg's reason from the validation is
+    /// returned instead.
+    ///
+    /// # Warning
+    /// Setting the `validate` parameter to `false` can cause issues. For example, when this is
+    /// `false`, you will be able to plan courses with more units than allowed (e.g. 42 units), set
+    /// the rading option to one that you are not allowed to use (e.g. S/U as an undergraduate),
+    /// and only enroll in specific components of a section (e.g. just the discussion section).
+    /// Some of these options can visually break WebReg (e.g. Remove/Enroll button will not appear).
+    ///
+    /// # Example
+    /// Here, we will add the course `POLI 145`, which has section ID `278941` and section code
+    /// `A00`, to our plan.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::{GradeOption, PlanAdd};
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let plan_add_data = PlanAdd::builder()
+    ///     .with_subject_code("CSE")
+    ///     .with_course_code("100")
+    ///     .with_section_id("079911")
+    ///     .with_section_code("A01")
+    ///     .with_grading_option(GradeOption::S)
+    ///     .with_unit_count(4)
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// let plan_res = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .add_to_plan(plan_add_data, true)
+    ///     .await;
+    ///
+    /// match plan_res {
+    ///     Ok(res) => println!("Planned? {res}"),
+    ///     Err(e) => eprintln!("Unable to plan: {e}"),
+    /// }
     /// # }
     /// ```
-    pub async fn get_events(&self) -> types::Result<Events> {
-        parse_get_events(process_get_text::<Vec<RawEvent>>(
-            self.raw.get_events().await?,
-        )?)
+    pub async fn add_to_plan(
+        &self,
+        plan_options: PlanAdd<'_>,
+        validate: bool,
+    ) -> types::Result<bool> {
+        if validate {
+            // We need to call the edit endpoint first, or else we'll have issues where we don't
+            // actually enroll in every component of the course. So, if the request itself
+            // failed, don't plan the course.
+            // WebReg can also reject the validation because you can't enroll in the class (e.g.
+            // the class you're trying to plan is a major-restricted class). You can still plan
+            // these classes, so the rejection is only reported if planning fails too.
+            let outcome = self.validate_add_to_plan(&plan_options).await?;
+            if !outcome.ok {
+                return match self.plan_without_validating(&plan_options).await {
+                    Err(WrapperError::WebRegRejected(_)) => Err(outcome.into_error()),
+                    res => res,
+                };
+            }
+        }
+
+        self.plan_without_validating(&plan_options).await
     }
 
-    /// Gets all of your schedules.
+    /// Plans a course without validating it first (see `add_to_plan`).
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the course that you want to plan.
     ///
     /// # Returns
-    /// Either a vector of strings representing the names of the schedules, or the error that
-    /// occurred.
-    pub async fn get_schedule_list(&self) -> types::Result<Vec<String>> {
-        process_get_text::<Vec<String>>(self.raw.get_schedule_list().await?)
+    /// `true` if the process succeeded, or an error if WebReg rejected it.
+    async fn plan_without_validating(&self, plan_options: &PlanAdd<'_>) -> types::Result<bool> {
+        let u = plan_options.unit_count.to_string();
+        let crsc_code = util::get_formatted_course_num(plan_options.course_code.as_ref());
+
+        process_post_response(
+            &self.raw.info,
+            self.raw
+                .info
+                .req(ReqType::Post(PLAN_ADD))
+                .form(&[
+                    ("subjcode", plan_options.subject_code.as_ref()),
+                    ("crsecode", crsc_code.as_str()),
+                    ("sectnum", plan_options.section_id.as_ref()),
+                    ("sectcode", plan_options.section_code.as_ref()),
+                    ("unit", u.as_str()),
+                    (
+                        "grade",
+                        plan_options
+                            .grading_option
+                            .unwrap_or(GradeOption::L)
+                            .as_str(),
+                    ),
+                    ("termcode", self.raw.term),
+                    (
+                        "schedname",
+                        match plan_options.schedule_name {
+                            Some(ref r) => r.as_ref(),
+                            None => DEFAULT_SCHEDULE_NAME,
+                        },
+                    ),
+                ])
+                .send()
+                .await,
+        )
+        .await
+        .map_err(classify_plan_error)
     }
 
-    /// Sends an email to yourself using the same email that is used to confirm that you have
-    /// enrolled or waitlisted in a particular class. In other words, this will send an email
-    /// to you through the email `NoReplyRegistrar@ucsd.edu`.
+    /// Plans a course, validating beforehand if the wrapper is set to do so (see the builder's
+    /// [`should_always_validate_plan`](crate::wrapper::wrapper_builder::WebRegWrapperBuilder::should_always_validate_plan)
+    /// function, which is `true` by default). See `add_to_plan` for more information.
     ///
-    /// It is strongly recommended that this function not be abused.
+    /// # Parameters
+    /// - `plan_options`: Information for the course that you want to plan.
+    ///
+    /// # Returns
+    /// `true` if the process succeeded, or a string containing the error message from WebReg if
+    /// something wrong happened.
+    pub async fn add_to_plan_default(&self, plan_options: PlanAdd<'_>) -> types::Result<bool> {
+        let validate = self.raw.info.always_validate_plan();
+        self.add_to_plan(plan_options, validate).await
+    }
+
+    /// Changes the unit count and grading option of a section that is already planned, without
+    /// removing it from the plan and planning it again. This is useful for planners, where the
+    /// unit count or grading option of a planned course can be adjusted.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the planned section, with the new unit count and
+    ///   grading option (if no grading option is given, letter grade is used). The
+    ///   section must already be planned in the given schedule.
+    ///
+    /// # Returns
+    /// `true` if the process succeeded, or an error if the section isn't planned (or if you're
+    /// enrolled or waitlisted in it), or if WebReg rejected the change.
+    ///
+    /// # Example
+    /// Here, we will change the already-planned course `CSE 100`, which has section ID
+    /// `079911` and section code `A01`, to be taken pass/no pass.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::{GradeOption, PlanAdd};
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let plan_edit_data = PlanAdd::builder()
+    ///     .with_subject_code("CSE")
+    ///     .with_course_code("100")
+    ///     .with_section_id("079911")
+    ///     .with_section_code("A01")
+    ///     .with_grading_option(GradeOption::P)
+    ///     .with_unit_count(4)
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// match wrapper.req("FA23").parsed().edit_plan(plan_edit_data).await {
+    ///     Ok(res) => println!("Edited? {res}"),
+    ///     Err(e) => eprintln!("Unable to edit plan: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn edit_plan(&self, plan_options: PlanAdd<'_>) -> types::Result<bool> {
+        // Recall that the schedule gives section IDs without any leading zeros.
+        let target_id = plan_options.section_id.trim_start_matches('0');
+        let existing = self
+            .get_schedule_cached(plan_options.schedule_name.as_deref())
+            .await?
+            .into_iter()
+            .find(|sec| sec.section_id == target_id);
+
+        match existing {
+            None => {
+                return Err(WrapperError::SectionIdNotFound(
+                    plan_options.section_id.to_string(),
+                    SectionIdNotFoundContext::Schedule,
+                ))
+            }
+            Some(sec) if sec.enrolled_status != EnrollmentStatus::Planned => {
+                return Err(WrapperError::InputError(
+                    "plan_options",
+                    "You are already enrolled or waitlisted in this section.",
+                ))
+            }
+            Some(_) => {}
+        }
+
+        let u = plan_options.unit_count.to_string();
+        let crsc_code = util::get_formatted_course_num(plan_options.course_code.as_ref());
+        process_post_response(
+            &self.raw.info,
+            self.raw
+                .info
+                .req(ReqType::Post(PLAN_EDIT))
+                .form(&[
+                    ("section", plan_options.section_id.as_ref()),
+                    ("subjcode", plan_options.subject_code.as_ref()),
+                    ("crsecode", crsc_code.as_str()),
+                    ("sectcode", plan_options.section_code.as_ref()),
+                    ("unit", u.as_str()),
+                    (
+                        "grade",
+                        plan_options
+                            .grading_option
+                            .unwrap_or(GradeOption::L)
+                            .as_str(),
+                    ),
+                    ("termcode", self.raw.term),
+                    (
+                        "schedname",
+                        match plan_options.schedule_name {
+                            Some(ref r) => r.as_ref(),
+                            None => DEFAULT_SCHEDULE_NAME,
+                        },
+                    ),
+                ])
+                .send()
+                .await,
+        )
+        .await
+    }
+
+    /// Plans a course or, if the course is already planned, updates its unit count and
+    /// grading option. Unlike `add_to_plan`, this won't fail if the course is already planned,
+    /// so it's safe to retry.
+    ///
+    /// Updating a planned course is done in place with `edit_plan`.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the course that you want to plan.
+    /// - `validate`: Whether to validate your planning of this course beforehand.
+    ///
+    /// # Returns
+    /// Whether the course was added, updated, or left unchanged. An error is returned if
+    /// something went wrong, or if you are already enrolled or waitlisted in the section.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::{GradeOption, PlanAdd};
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let plan_add_data = PlanAdd::builder()
+    ///     .with_subject_code("CSE")
+    ///     .with_course_code("100")
+    ///     .with_section_id("079911")
+    ///     .with_section_code("A01")
+    ///     .with_grading_option(GradeOption::P)
+    ///     .with_unit_count(4)
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// match wrapper.req("FA23").parsed().upsert_plan(plan_add_data, true).await {
+    ///     Ok(outcome) => println!("Done: {outcome:?}"),
+    ///     Err(e) => eprintln!("Unable to plan: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn upsert_plan(
+        &self,
+        plan_options: PlanAdd<'_>,
+        validate: bool,
+    ) -> types::Result<PlanUpsertOutcome> {
+        // Recall that the schedule gives section IDs without any leading zeros.
+        let target_id = plan_options.section_id.trim_start_matches('0');
+        let existing = self
+            .get_schedule_cached(plan_options.schedule_name.as_deref())
+            .await?
+            .into_iter()
+            .find(|sec| sec.section_id == target_id);
+
+        let existing = match existing {
+            Some(sec) => sec,
+            None => {
+                self.add_to_plan(plan_options, validate).await?;
+                return Ok(PlanUpsertOutcome::Added);
+            }
+        };
+
+        if existing.enrolled_status != EnrollmentStatus::Planned {
+            return Err(WrapperError::InputError(
+                "plan_options",
+                "You are already enrolled or waitlisted in this section.",
+            ));
+        }
+
+        let grade_option = plan_options.grading_option.unwrap_or(GradeOption::L);
+        if existing.units == plan_options.unit_count as i64
+            && existing.grade_option == Some(grade_option)
+        {
+            return Ok(PlanUpsertOutcome::Unchanged);
+        }
+
+        self.edit_plan(plan_options).await?;
+        Ok(PlanUpsertOutcome::Updated)
+    }
+
+    /// Plans several sections at once, instead of calling `add_to_plan` for each one. Up to
+    /// `concurrency` sections are planned at once, although every request still goes through
+    /// the wrapper's rate limiter and pacing (if any). The wrapper's default for validation
+    /// (see `add_to_plan_default`) is used.
     ///
     /// # Parameters
-    /// - `email_content`: The email to send.
+    /// - `plans`: Information for the courses that you want to plan.
+    /// - `concurrency`: The maximum number of sections to plan at once.
     ///
     /// # Returns
-    /// `true` if the email was sent successfully and `false` otherwise.
+    /// The result of planning each section. A failure doesn't stop the other sections from
+    /// being planned.
     ///
     /// # Example
-    /// This will send an email to yourself with the content specified as the string shown below.
     /// ```rust,no_run
     /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::PlanAdd;
     /// use webweg::wrapper::WebRegWrapper;
     ///
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
     ///
-    /// let email_res = wrapper
-    ///     .req("FA23")
-    ///     .parsed()
-    ///     .send_email_to_self("hello, world.")
-    ///     .await;
-    ///
-    /// match email_res {
-    ///     Ok(_) => println!("Email sent successfully."),
-    ///     Err(e) => eprintln!("Email could not be sent: {e}"),
-    /// }
+    /// let plans = ["079911", "079913"]
+    ///     .into_iter()
+    ///     .map(|section_id| {
+    ///         PlanAdd::builder()
+    ///             .with_course(("CSE", "100"))
+    ///             .with_section_id(section_id)
+    ///             .with_section_code("A01")
+    ///             .with_unit_count(4)
+    ///             .try_build()
+    ///             .unwrap()
+    ///     })
+    ///     .collect();
+    ///
+    /// let report = wrapper.req("FA23").parsed().add_many_to_plan(plans, 2).await;
+    /// println!("{report}");
     /// # }
     /// ```
-    pub async fn send_email_to_self(&self, email_content: &str) -> types::Result<()> {
-        let r = self
-            .raw
-            .info
-            .req(ReqType::Post(SEND_EMAIL))
-            .form(&[("actionevent", email_content), ("termcode", self.raw.term)])
-            .send()
-            .await?;
-
-        let status = r.status();
-        if !status.is_success() {
-            let text = r.text().await.ok();
-            return Err(WrapperError::BadStatusCode(status.as_u16(), text));
-        }
+    pub async fn add_many_to_plan(
+        &self,
+        plans: Vec<PlanAdd<'_>>,
+        concurrency: usize,
+    ) -> BulkPlanReport {
+        let results = stream::iter(plans)
+            .map(|plan_options| async move {
+                let section_id = plan_options.section_id.to_string();
+                (section_id, self.add_to_plan_default(plan_options).await)
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
 
-        let t = r.text().await?;
-        if t.contains("\"YES\"") {
-            Ok(())
-        } else {
-            Err(WrapperError::WebRegError(t))
-        }
+        BulkPlanReport { results }
     }
 
-    /// Changes the grading option for the class corresponding to the section ID.
+    /// Removes several sections from a schedule at once, instead of calling `remove_from_plan`
+    /// for each one. Up to `concurrency` sections are removed at once, although every request
+    /// still goes through the wrapper's rate limiter and pacing (if any).
     ///
     /// # Parameters
-    /// - `section_id`: The section ID corresponding to the class that you want to change
-    /// the grading option for.
-    /// - `new_grade_opt`: The new grading option. This must either be `L` (letter),
-    /// `P` (pass/no pass), or `S` (satisfactory/unsatisfactory), and is enforced via an enum.
+    /// - `section_ids`: The section IDs.
+    /// - `schedule_name`: The schedule name where the courses should be unplanned from.
+    /// - `concurrency`: The maximum number of sections to remove at once.
     ///
     /// # Returns
-    /// `true` if the process succeeded, or a string containing the error message from WebReg if
-    /// something wrong happened.
+    /// The result of removing each section. A failure doesn't stop the other sections from
+    /// being removed.
     ///
     /// # Example
-    /// Changing the section associated with section ID `235181` to letter grading option.
     /// ```rust,no_run
     /// use reqwest::Client;
-    /// use webweg::wrapper::input_types::GradeOption;
     /// use webweg::wrapper::WebRegWrapper;
     ///
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
     ///
-    /// let change_res = wrapper
+    /// let report = wrapper
     ///     .req("FA23")
     ///     .parsed()
-    ///     .change_grading_option("235181", GradeOption::P)
+    ///     .remove_many_from_plan(vec!["079911", "079913"], None, 2)
     ///     .await;
     ///
-    /// match change_res {
-    ///     Ok(res) => println!("Grade option changed? {res}"),
-    ///     Err(e) => eprintln!("Grade option error: {e}"),
+    /// for (section_id, e) in report.failures() {
+    ///     eprintln!("Unable to remove {section_id} from plan: {e}");
     /// }
     /// # }
     /// ```
-    pub async fn change_grading_option(
+    pub async fn remove_many_from_plan(
         &self,
-        section_id: &str,
-        new_grade_opt: GradeOption,
-    ) -> types::Result<bool> {
-        let new_grade_opt = match new_grade_opt {
-            GradeOption::L => "L",
-            GradeOption::S => "S",
-            GradeOption::P => "P",
-        };
-
-        // "Slice" any zeros off of the left-most side of the string. We need to do this
-        // because, when comparing section IDs in the schedule, WebReg gives us the
-        // section IDs as integers; however, for the rest of the API, it's given as a
-        // string.
-        //
-        // Essentially, this means that, while most of WebReg's API will take `"079911"` as
-        // an input and as an output (e.g. see `get_course_info`), the schedule API will
-        // specifically return an integer `79911`. The `get_schedule` function will simply
-        // convert this integer to a string, e.g. `79911` -> `"79911"` and return that along
-        // with the other parsed info for each scheduled section.
-        //
-        // So, we need to slice off any 0s from the input parameter `section_id` to account
-        // for this.
-        let mut left_idx = 0;
-        for c in section_id.chars() {
-            if c != '0' {
-                break;
-            }
-
-            left_idx += 1;
-            continue;
-        }
-
-        let poss_class = self
-            .get_schedule(None as Option<&str>)
-            .await?
-            .into_iter()
-            .find(|x| x.section_id == section_id[left_idx..]);
-
-        // don't care about previous poss_class
-        let poss_class = match poss_class {
-            Some(s) => s,
-            None => {
-                return Err(WrapperError::SectionIdNotFound(
-                    section_id.into(),
-                    SectionIdNotFoundContext::Schedule,
-                ))
-            }
-        };
-
-        let sec_id = poss_class.section_id.to_string();
-        let units = poss_class.units.to_string();
+        section_ids: Vec<&str>,
+        schedule_name: Option<&str>,
+        concurrency: usize,
+    ) -> BulkPlanReport {
+        let results = stream::iter(section_ids)
+            .map(|section_id| async move {
+                let res = self.remove_from_plan(section_id, schedule_name).await;
+                (section_id.to_string(), res)
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
 
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(CHANGE_ENROLL))
-                .form(&[
-                    ("section", sec_id.as_str()),
-                    ("subjCode", ""),
-                    ("crseCode", ""),
-                    ("unit", units.as_str()),
-                    ("grade", new_grade_opt),
-                    // You don't actually need these
-                    ("oldGrade", ""),
-                    ("oldUnit", ""),
-                    ("termcode", self.raw.term),
-                ])
-                .send()
-                .await,
-        )
-        .await
+        BulkPlanReport { results }
     }
 
-    /// Validates that adding a course to your plan will cause no issue.
+    /// Plans the same sections into several schedules (e.g., backup schedules that share most
+    /// of their courses). Each section is planned with `upsert_plan`, so sections that are
+    /// already planned are left alone and it's safe to run this again after a failure.
+    ///
+    /// The sections are planned into each schedule one at a time, in the given order, while
+    /// up to `concurrency` schedules are worked on at once. Every request still goes through
+    /// the wrapper's rate limiter and pacing (if any), so a higher concurrency won't go over
+    /// them. The wrapper's default for validation (see the builder's
+    /// [`should_always_validate_plan`](crate::wrapper::wrapper_builder::WebRegWrapperBuilder::should_always_validate_plan)
+    /// function) is used. The schedule name in each `PlanAdd` is ignored.
     ///
     /// # Parameters
-    /// - `plan_options`: Information for the course that you want to plan.
+    /// - `plan`: The sections to plan.
+    /// - `schedules`: The schedules to plan the sections into.
+    /// - `concurrency`: The maximum number of schedules to work on at once.
     ///
     /// # Returns
-    /// Either an `Ok` if the request generally went through, or an `Err` if something went wrong
-    /// with the request.
+    /// The outcome of planning each section into each schedule. A failure doesn't stop the
+    /// other sections from being planned.
     ///
     /// # Example
-    /// Here, we will add the course `CSE 100`, which has section ID `079911` and section code
-    /// `A01`, to our plan.
     /// ```rust,no_run
     /// use reqwest::Client;
-    /// use webweg::wrapper::input_types::{GradeOption, PlanAdd};
+    /// use webweg::wrapper::input_types::PlanAdd;
     /// use webweg::wrapper::WebRegWrapper;
     ///
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
     ///
-    /// let plan_add_data = PlanAdd::builder()
+    /// let plan = vec![PlanAdd::builder()
     ///     .with_subject_code("CSE")
     ///     .with_course_code("100")
     ///     .with_section_id("079911")
     ///     .with_section_code("A01")
-    ///     .with_grading_option(GradeOption::S)
     ///     .with_unit_count(4)
     ///     .try_build()
-    ///     .unwrap();
+    ///     .unwrap()];
     ///
-    /// let plan_res = wrapper
+    /// let matrix = wrapper
     ///     .req("FA23")
     ///     .parsed()
-    ///     .validate_add_to_plan(&plan_add_data)
+    ///     .apply_plan_to_schedules(&plan, &["Backup A", "Backup B"], 2)
     ///     .await;
     ///
-    /// match plan_res {
-    ///     Ok(res) => println!("Can plan? {res}"),
-    ///     Err(e) => eprintln!("Unable to plan: {e}"),
+    /// for (schedule, section_id, e) in matrix.failures() {
+    ///     eprintln!("Couldn't plan {section_id} into {schedule}: {e}");
     /// }
     /// # }
     /// ```
-    pub async fn validate_add_to_plan(&self, plan_options: &PlanAdd<'_>) -> types::Result<bool> {
-        let crsc_code = util::get_formatted_course_num(plan_options.course_code.as_ref());
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(PLAN_EDIT))
-                .form(&[
-                    ("section", plan_options.section_id.as_ref()),
-                    ("subjcode", plan_options.subject_code.as_ref()),
-                    ("crsecode", crsc_code.as_str()),
-                    ("termcode", self.raw.term),
-                ])
-                .send()
-                .await,
-        )
-        .await
+    pub async fn apply_plan_to_schedules(
+        &self,
+        plan: &[PlanAdd<'_>],
+        schedules: &[&str],
+        concurrency: usize,
+    ) -> PlanMatrix {
+        let validate = self.raw.info.always_validate_plan();
+        let outcomes: Vec<Vec<_>> = stream::iter(schedules)
+            .map(|schedule_name| async move {
+                let mut row = Vec::with_capacity(plan.len());
+                for add in plan {
+                    let plan_options = PlanAdd {
+                        subject_code: Cow::Borrowed(add.subject_code.as_ref()),
+                        course_code: Cow::Borrowed(add.course_code.as_ref()),
+                        section_id: Cow::Borrowed(add.section_id.as_ref()),
+                        section_code: Cow::Borrowed(add.section_code.as_ref()),
+                        grading_option: add.grading_option,
+                        schedule_name: Some(Cow::Borrowed(*schedule_name)),
+                        unit_count: add.unit_count,
+                    };
+                    row.push(self.upsert_plan(plan_options, validate).await);
+                }
+
+                row
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+
+        PlanMatrix {
+            schedules: schedules.iter().map(|s| s.to_string()).collect(),
+            section_ids: plan.iter().map(|p| p.section_id.to_string()).collect(),
+            outcomes,
+        }
     }
 
-    /// Allows you to plan a course.
+    /// Plans a course, but only if it doesn't conflict with any section (enrolled, waitlisted,
+    /// or planned) that is already in the target schedule.
+    ///
+    /// This might be useful when you want to plan many courses at once (e.g., when importing a
+    /// schedule) without ending up with overlapping sections.
     ///
     /// # Parameters
     /// - `plan_options`: Information for the course that you want to plan.
     /// - `validate`: Whether to validate your planning of this course beforehand.
     ///
     /// # Returns
-    /// `true` if the process succeeded, or a string containing the error message from WebReg if
-    /// something wrong happened.
-    ///
-    /// # Warning
-    /// Setting the `validate` parameter to `false` can cause issues. For example, when this is
-    /// `false`, you will be able to plan courses with more units than allowed (e.g. 42 units), set
-    /// the rading option to one that you are not allowed to use (e.g. S/U as an undergraduate),
-    /// and only enroll in specific components of a section (e.g. just the discussion section).
-    /// Some of these options can visually break WebReg (e.g. Remove/Enroll button will not appear).
+    /// Either `Planned` if the course was planned, or `Conflict` with the section in the schedule
+    /// that the course conflicts with. An error is returned if something went wrong.
     ///
     /// # Example
-    /// Here, we will add the course `POLI 145`, which has section ID `278941` and section code
-    /// `A00`, to our plan.
     /// ```rust,no_run
     /// use reqwest::Client;
-    /// use webweg::wrapper::input_types::{GradeOption, PlanAdd};
+    /// use webweg::types::PlanOutcome;
+    /// use webweg::wrapper::input_types::PlanAdd;
     /// use webweg::wrapper::WebRegWrapper;
     ///
     /// # #[tokio::main(flavor = "current_thread")]
@@ -1019,71 +2855,56 @@ impl<'a> WrapperTermRequest<'a> {
     ///     .with_course_code("100")
     ///     .with_section_id("079911")
     ///     .with_section_code("A01")
-    ///     .with_grading_option(GradeOption::S)
     ///     .with_unit_count(4)
     ///     .try_build()
     ///     .unwrap();
     ///
-    /// let plan_res = wrapper
-    ///     .req("FA23")
-    ///     .parsed()
-    ///     .add_to_plan(plan_add_data, true)
-    ///     .await;
-    ///
-    /// match plan_res {
-    ///     Ok(res) => println!("Planned? {res}"),
+    /// match wrapper.req("FA23").parsed().plan_if_no_conflict(plan_add_data, true).await {
+    ///     Ok(PlanOutcome::Planned) => println!("Planned!"),
+    ///     Ok(PlanOutcome::Conflict(sec)) => println!("Conflicts with {sec}"),
     ///     Err(e) => eprintln!("Unable to plan: {e}"),
     /// }
     /// # }
     /// ```
-    pub async fn add_to_plan(
+    pub async fn plan_if_no_conflict(
         &self,
         plan_options: PlanAdd<'_>,
         validate: bool,
-    ) -> types::Result<bool> {
-        let u = plan_options.unit_count.to_string();
-        let crsc_code = util::get_formatted_course_num(plan_options.course_code.as_ref());
+    ) -> types::Result<PlanOutcome> {
+        // Recall that the schedule gives section IDs without any leading zeros.
+        let target_id = plan_options.section_id.trim_start_matches('0');
+        let to_plan = self
+            .get_course_info((
+                plan_options.subject_code.as_ref(),
+                plan_options.course_code.as_ref(),
+            ))
+            .await?
+            .into_iter()
+            .find(|sec| sec.section_id.trim_start_matches('0') == target_id)
+            .ok_or_else(|| {
+                WrapperError::SectionIdNotFound(
+                    plan_options.section_id.to_string(),
+                    SectionIdNotFoundContext::Catalog,
+                )
+            })?;
 
-        if validate {
-            // We need to call the edit endpoint first, or else we'll have issues where we don't
-            // actually enroll in every component of the course.
-            // Also, this can potentially return "false" due to you not being able to enroll in the
-            // class, e.g. the class you're trying to plan is a major-restricted class.
-            self.validate_add_to_plan(&plan_options)
-                .await
-                .unwrap_or(false);
+        let conflict = self
+            .get_schedule_cached(plan_options.schedule_name.as_deref())
+            .await?
+            .into_iter()
+            .filter(|sec| sec.section_id != target_id)
+            .find(|sec| {
+                sec.meetings
+                    .iter()
+                    .any(|m| to_plan.meetings.iter().any(|p| p.conflicts_with(m)))
+            });
+
+        if let Some(sec) = conflict {
+            return Ok(PlanOutcome::Conflict(Box::new(sec)));
         }
 
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(PLAN_ADD))
-                .form(&[
-                    ("subjcode", plan_options.subject_code.as_ref()),
-                    ("crsecode", crsc_code.as_str()),
-                    ("sectnum", plan_options.section_id.as_ref()),
-                    ("sectcode", plan_options.section_code.as_ref()),
-                    ("unit", u.as_str()),
-                    (
-                        "grade",
-                        plan_options
-                            .grading_option
-                            .unwrap_or(GradeOption::L)
-                            .as_str(),
-                    ),
-                    ("termcode", self.raw.term),
-                    (
-                        "schedname",
-                        match plan_options.schedule_name {
-                            Some(ref r) => r.as_ref(),
-                            None => DEFAULT_SCHEDULE_NAME,
-                        },
-                    ),
-                ])
-                .send()
-                .await,
-        )
-        .await
+        self.add_to_plan(plan_options, validate).await?;
+        Ok(PlanOutcome::Planned)
     }
 
     /// Allows you to unplan a course.
@@ -1123,6 +2944,7 @@ impl<'a> WrapperTermRequest<'a> {
         schedule_name: Option<&str>,
     ) -> types::Result<bool> {
         process_post_response(
+            &self.raw.info,
             self.raw
                 .info
                 .req(ReqType::Post(PLAN_REMOVE))
@@ -1147,8 +2969,9 @@ impl<'a> WrapperTermRequest<'a> {
     /// the structure when calling the `add_section` function.
     ///
     /// # Returns
-    /// `true` if the process succeeded, or a string containing the error message from WebReg if
-    /// there is an issue when trying to enroll.
+    /// Whether the section can be added and, if not, the reason that WebReg gave (e.g., a
+    /// restriction or a time conflict). An `Err` is only returned if something went wrong with
+    /// the request itself.
     ///
     /// # Example
     /// Here, we will enroll in the course with section ID `078616`, and with the default grading
@@ -1174,7 +2997,8 @@ impl<'a> WrapperTermRequest<'a> {
     ///     .await;
     ///
     /// match add_res {
-    ///     Ok(res) => println!("Can add? {res}"),
+    ///     Ok(res) if res.ok => println!("Can add!"),
+    ///     Ok(res) => println!("Can't add: {:?}", res.reason),
     ///     Err(e) => eprintln!("Unable to add: {e}"),
     /// }
     /// # }
@@ -1183,7 +3007,7 @@ impl<'a> WrapperTermRequest<'a> {
         &self,
         add_type: AddType,
         enroll_options: &EnrollWaitAdd<'_>,
-    ) -> types::Result<bool> {
+    ) -> types::Result<ValidationOutcome> {
         let base_edit_url = match add_type {
             AddType::Enroll => ENROLL_EDIT,
             AddType::Waitlist => WAITLIST_EDIT,
@@ -1196,7 +3020,8 @@ impl<'a> WrapperTermRequest<'a> {
             },
         };
 
-        process_post_response(
+        let res = process_post_response(
+            &self.raw.info,
             self.raw
                 .info
                 .req(ReqType::Post(base_edit_url))
@@ -1211,13 +3036,16 @@ impl<'a> WrapperTermRequest<'a> {
                 .send()
                 .await,
         )
-        .await
+        .await;
+
+        to_validation_outcome(res)
     }
 
     /// Checks whether the user can enroll or waitlist into a section.
     ///
     /// Keep in mind that this implementation does _not_ check if you are able to enroll
-    /// into a class, just that there are enough seats for you to enroll.
+    /// into a class, just that there are enough seats for you to enroll. Whether there are
+    /// enough seats is decided by the wrapper's seat strategy.
     ///
     /// # Parameters
     /// - `section_id`: The section ID to check.
@@ -1226,38 +3054,130 @@ impl<'a> WrapperTermRequest<'a> {
     /// An enum value that can either be `Enroll` or `Waitlist` depending on whether
     /// the user can enroll into the specified section.
     pub async fn get_add_type(&self, section_id: &str) -> types::Result<ExplicitAddType> {
+        let info = self.find_section_seats(section_id).await?;
+        if info.has_seats_with(self.raw.info.get_seat_strategy()) {
+            Ok(ExplicitAddType::Enroll)
+        } else {
+            Ok(ExplicitAddType::Waitlist)
+        }
+    }
+
+    /// Looks up the course that a section belongs to by its section ID.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// The course, or an error if no such section is offered.
+    async fn find_section_course(&self, section_id: &str) -> types::Result<CourseCode> {
         let search_res = self
             .search_courses(SearchType::BySection(section_id.to_string()))
             .await?;
 
-        if search_res.is_empty() {
-            return Err(WrapperError::SectionIdNotFound(
-                section_id.into(),
-                SectionIdNotFoundContext::Catalog,
-            ));
-        }
-
-        let subject_code = search_res[0].subj_code.trim();
-        let course_code = search_res[0].course_code.trim();
-
-        let section_info = self
-            .get_enrollment_count(subject_code, course_code)
-            .await?
-            .into_iter()
-            .find(|sec| sec.section_id == section_id);
-        if let Some(info) = section_info {
-            if info.has_seats() {
-                Ok(ExplicitAddType::Enroll)
-            } else {
-                Ok(ExplicitAddType::Waitlist)
-            }
-        } else {
-            // In theory, this should never hit.
-            Err(WrapperError::SectionIdNotFound(
-                section_id.into(),
-                SectionIdNotFoundContext::Catalog,
-            ))
-        }
+        match search_res.first() {
+            Some(course) => Ok((&course.subj_code, &course.course_code).into()),
+            None => Err(WrapperError::SectionIdNotFound(
+                section_id.into(),
+                SectionIdNotFoundContext::Catalog,
+            )),
+        }
+    }
+
+    /// Looks up a section's current seat counts by its section ID.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// The section, or an error if no such section is offered.
+    async fn find_section_seats(&self, section_id: &str) -> types::Result<CourseSection> {
+        let course = self.find_section_course(section_id).await?;
+        self.get_enrollment_count(course)
+            .await?
+            .into_iter()
+            .find(|sec| sec.section_id == section_id)
+            // In theory, this should never hit.
+            .ok_or_else(|| {
+                WrapperError::SectionIdNotFound(
+                    section_id.into(),
+                    SectionIdNotFoundContext::Catalog,
+                )
+            })
+    }
+
+    /// Looks up the section ID of a section by its course and section code.
+    ///
+    /// # Parameters
+    /// - `course`: The course. For example, `("MATH", "100B")`.
+    /// - `section_code`: The section code. For example, `A01`.
+    ///
+    /// # Returns
+    /// The section ID, or an error if no such section is offered.
+    pub async fn resolve_section_id(
+        &self,
+        course: impl Into<CourseCode>,
+        section_code: impl AsRef<str>,
+    ) -> types::Result<String> {
+        let course = course.into();
+        let section_code = section_code.as_ref().trim();
+
+        self.get_course_info(&course)
+            .await?
+            .into_iter()
+            .find(|sec| sec.section_code.eq_ignore_ascii_case(section_code))
+            .map(|sec| sec.section_id)
+            .ok_or_else(|| {
+                WrapperError::SectionIdNotFound(
+                    format!("{course} {section_code}"),
+                    SectionIdNotFoundContext::Catalog,
+                )
+            })
+    }
+
+    /// Turns a section lookup (created through `EnrollWaitAdd::for_course`) into an
+    /// `EnrollWaitAdd` object by looking up the section's ID.
+    ///
+    /// # Parameters
+    /// - `lookup`: The section lookup.
+    ///
+    /// # Returns
+    /// The `EnrollWaitAdd` object that can be used to enroll in, or waitlist, the section.
+    ///
+    /// # Example
+    /// Here, we will enroll in section `A01` of `CSE 100` without knowing its section ID.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::{AddType, EnrollWaitAdd};
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    /// let requester = wrapper.req("FA23").parsed();
+    ///
+    /// let enroll_options = requester
+    ///     .resolve_enroll_wait_add(EnrollWaitAdd::for_course(("CSE", "100"), "A01"))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// let add_res = requester
+    ///     .add_section(AddType::DecideForMe, enroll_options, true)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn resolve_enroll_wait_add<'b>(
+        &self,
+        lookup: SectionLookup<'b>,
+    ) -> types::Result<EnrollWaitAdd<'b>> {
+        let section_id = self
+            .resolve_section_id(&lookup.course, lookup.section_code.as_ref())
+            .await?;
+
+        Ok(EnrollWaitAdd {
+            section_id: section_id.into(),
+            grading_option: lookup.grading_option,
+            unit_count: lookup.unit_count,
+        })
     }
 
     /// Enrolls in, or waitlists, a class.
@@ -1326,12 +3246,26 @@ impl<'a> WrapperTermRequest<'a> {
             Some(r) => r.to_string(),
             None => "".to_string(),
         };
+        let is_waitlist = base_reg_url == WAITLIST_ADD;
+        let waitlists = self.raw.info.get_waitlist_tracker();
+        let section_id = enroll_options.section_id.as_ref();
 
         if validate {
-            self.validate_add_section(add_type, &enroll_options).await?;
+            if let Some(reason) = self
+                .validate_add_section(add_type, &enroll_options)
+                .await?
+                .reason
+            {
+                if is_waitlist && matches!(reason, EnrollmentFailure::WaitlistFull(_)) {
+                    waitlists.set_open(self.raw.term, section_id, false);
+                }
+
+                return Err(ValidationOutcome::rejected(reason.message()).into_error());
+            }
         }
 
-        process_post_response(
+        let res = process_post_response(
+            &self.raw.info,
             self.raw
                 .info
                 .req(ReqType::Post(base_reg_url))
@@ -1354,10 +3288,28 @@ impl<'a> WrapperTermRequest<'a> {
                 .send()
                 .await,
         )
-        .await?;
+        .await;
+
+        if is_waitlist {
+            let full = res
+                .as_ref()
+                .err()
+                .and_then(|e| e.webreg_reason())
+                .is_some_and(|r| {
+                    matches!(
+                        EnrollmentFailure::from_reason(r),
+                        EnrollmentFailure::WaitlistFull(_)
+                    )
+                });
+            if full || res.is_ok() {
+                waitlists.set_open(self.raw.term, section_id, !full);
+            }
+        }
+        res?;
 
         // This will always return true
         process_post_response(
+            &self.raw.info,
             self.raw
                 .info
                 .req(ReqType::Post(PLAN_REMOVE_ALL))
@@ -1371,6 +3323,110 @@ impl<'a> WrapperTermRequest<'a> {
         .await
     }
 
+    /// Enrolls in, or waitlists, a class, validating beforehand if the wrapper is set to do so
+    /// (see the builder's
+    /// [`should_always_validate_enroll`](crate::wrapper::wrapper_builder::WebRegWrapperBuilder::should_always_validate_enroll)
+    /// function, which is `true` by default). See `add_section` for more information.
+    ///
+    /// # Parameters
+    /// - `add_type`: The add type.
+    /// - `enroll_options`: Information for the course that you want to enroll in.
+    ///
+    /// # Returns
+    /// `true` if the process succeeded, or a string containing the error message from WebReg if
+    /// something wrong happened.
+    pub async fn add_section_default(
+        &self,
+        add_type: AddType,
+        enroll_options: EnrollWaitAdd<'_>,
+    ) -> types::Result<bool> {
+        let validate = self.raw.info.always_validate_enroll();
+        self.add_section(add_type, enroll_options, validate).await
+    }
+
+    /// Enrolls in, or waitlists, several sections that must be taken together (e.g., a lecture
+    /// and a lab that are listed under different course numbers). The sections are added in
+    /// the given order, stopping at the first section that couldn't be added.
+    ///
+    /// Sections that were already added are not dropped if a later section fails, so make sure
+    /// to check the result and act accordingly.
+    ///
+    /// # Parameters
+    /// - `sections`: The sections to add, along with how each section should be added.
+    /// - `validate`: Whether to validate your enrollment of each section beforehand. See
+    ///   `add_section` for more information.
+    ///
+    /// # Returns
+    /// The sections that were added and, if any, the section that couldn't be added.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::{AddType, EnrollWaitAdd};
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let lecture = EnrollWaitAdd::builder()
+    ///     .with_section_id("260737")
+    ///     .try_build()
+    ///     .unwrap();
+    /// let lab = EnrollWaitAdd::builder()
+    ///     .with_section_id("260740")
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// let res = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .add_linked_sections(
+    ///         vec![(AddType::Enroll, lecture), (AddType::Enroll, lab)],
+    ///         true,
+    ///     )
+    ///     .await;
+    ///
+    /// match res.failed {
+    ///     None => println!("Added all sections."),
+    ///     Some((id, e)) => eprintln!("Added {:?}, but not {id}: {e}", res.added),
+    /// }
+    /// # }
+    /// ```
+    pub async fn add_linked_sections(
+        &self,
+        sections: Vec<(AddType, EnrollWaitAdd<'_>)>,
+        validate: bool,
+    ) -> LinkedAddResult {
+        let mut added = vec![];
+        for (add_type, enroll_options) in sections {
+            let section_id = enroll_options.section_id.to_string();
+            let err = match self.add_section(add_type, enroll_options, validate).await {
+                Ok(true) => {
+                    added.push(section_id);
+                    continue;
+                }
+                // WebReg didn't say that the section was added, so don't count it as added.
+                Ok(false) => ValidationOutcome {
+                    ok: false,
+                    reason: None,
+                }
+                .into_error(),
+                Err(e) => e,
+            };
+
+            return LinkedAddResult {
+                added,
+                failed: Some((section_id, err)),
+            };
+        }
+
+        LinkedAddResult {
+            added,
+            failed: None,
+        }
+    }
+
     /// Drops a section.
     ///
     /// # Parameters
@@ -1420,6 +3476,7 @@ impl<'a> WrapperTermRequest<'a> {
         };
 
         process_post_response(
+            &self.raw.info,
             self.raw
                 .info
                 .req(ReqType::Post(base_reg_url))
@@ -1437,6 +3494,194 @@ impl<'a> WrapperTermRequest<'a> {
         .await
     }
 
+    /// Swaps a section that you are enrolled in for another one (e.g., switching to a different
+    /// discussion section of the same course). You are always enrolled in the new section, not
+    /// waitlisted for it; use `add_section` to waitlist a section. The new section is validated
+    /// first, and the
+    /// old section is only dropped if WebReg says that the new section can be added, or if
+    /// the only problem is a time conflict with the old section (that is, WebReg's message
+    /// mentions the old section's course or section ID). Then, the new section is added. If
+    /// the new section couldn't be added, the old section is added back with the same grading
+    /// option and unit count, so that you don't lose your seat.
+    ///
+    /// Keep in mind that this isn't truly atomic; someone else could take your seat in the
+    /// old section before it's added back. Sections that you are waitlisted for can't be
+    /// swapped, since adding one back would put you at the end of its waitlist.
+    ///
+    /// # Parameters
+    /// - `old_section_id`: The section ID corresponding to the section that you want to drop.
+    ///   You must be enrolled in this section.
+    /// - `new_enroll_options`: Information for the section that you want to enroll in.
+    ///
+    /// # Returns
+    /// What happened to the new section (and, if needed, the old section), or an error if the
+    /// old section couldn't be found or dropped, or if WebReg said that the new section can't
+    /// be added. In that case, your schedule wasn't changed.
+    ///
+    /// # Example
+    /// Here, we will swap the section with section ID `079912` for the section with section
+    /// ID `079913`.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::types::SwapOutcome;
+    /// use webweg::wrapper::input_types::EnrollWaitAdd;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let new_section = EnrollWaitAdd::builder()
+    ///     .with_section_id("079913")
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// match wrapper.req("FA23").parsed().swap_section("079912", new_section).await {
+    ///     Ok(SwapOutcome::Swapped) => println!("Swapped sections."),
+    ///     Ok(SwapOutcome::RolledBack(e)) => eprintln!("Kept the old section: {e}"),
+    ///     Ok(SwapOutcome::RollbackFailed(e, _)) => eprintln!("Lost both sections: {e}"),
+    ///     Err(e) => eprintln!("Unable to drop the old section: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn swap_section(
+        &self,
+        old_section_id: &str,
+        new_enroll_options: EnrollWaitAdd<'_>,
+    ) -> types::Result<SwapOutcome> {
+        let old = self.find_scheduled_section(old_section_id).await?;
+        if old.enrolled_status != EnrollmentStatus::Enrolled {
+            return Err(WrapperError::InputError(
+                "old_section_id",
+                "must be a section that you are enrolled in",
+            ));
+        }
+
+        let validation = self
+            .validate_add_section(AddType::Enroll, &new_enroll_options)
+            .await?;
+        match validation.reason {
+            None if validation.ok => {}
+            Some(EnrollmentFailure::TimeConflict(reason)) if mentions_section(&reason, &old) => {}
+            _ => return Err(validation.into_error()),
+        }
+
+        self.drop_section(ExplicitAddType::Enroll, old_section_id)
+            .await?;
+
+        // WebReg requires each add to be validated beforehand.
+        let add_err = match self
+            .add_section(AddType::Enroll, new_enroll_options, true)
+            .await
+        {
+            Ok(_) => return Ok(SwapOutcome::Swapped),
+            Err(e) => e,
+        };
+
+        let rollback = EnrollWaitAdd {
+            section_id: Cow::Borrowed(old_section_id),
+            grading_option: old.grade_option,
+            unit_count: u8::try_from(old.units).ok(),
+        };
+
+        Ok(
+            match self.add_section(AddType::Enroll, rollback, true).await {
+                Ok(_) => SwapOutcome::RolledBack(add_err),
+                Err(e) => SwapOutcome::RollbackFailed(add_err, e),
+            },
+        )
+    }
+
+    /// Drops the waitlisted sections that a policy says you no longer need, like you might do
+    /// by hand at the end of the add period. Your default schedule is checked, and each
+    /// waitlisted section that any of the policy's rules apply to is dropped (unless the
+    /// policy is a dry run). Enrolled and planned sections are never dropped.
+    ///
+    /// If an attempt sink is set (see the builder's `with_attempt_sink` function), each drop
+    /// is recorded there.
+    ///
+    /// # Parameters
+    /// - `policy`: The policy.
+    ///
+    /// # Returns
+    /// A report of what was done for each section that the policy applied to, or an error if
+    /// your schedule couldn't be retrieved. Errors from dropping a section are part of the
+    /// report instead.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::WaitlistDropPolicy;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let policy = WaitlistDropPolicy::new()
+    ///     .drop_if_enrolled_in(("CSE", "100"), ("CSE", "101"))
+    ///     .drop_below_position(10, None);
+    ///
+    /// let report = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .apply_drop_policy(&policy)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// for attempt in &report.attempts {
+    ///     println!("{}: {}", attempt.section.section_id, attempt.outcome.name());
+    /// }
+    /// # }
+    /// ```
+    pub async fn apply_drop_policy(
+        &self,
+        policy: &WaitlistDropPolicy,
+    ) -> types::Result<WaitlistDropReport> {
+        let schedule = self.get_schedule(None).await?;
+        let now = self.raw.info.get_clock().now();
+
+        let mut attempts = vec![];
+        for (sec, rule) in policy.evaluate(&schedule, now) {
+            let outcome = if policy.dry_run {
+                WaitlistDropOutcome::WouldDrop
+            } else {
+                match self
+                    .drop_section(ExplicitAddType::Waitlist, sec.section_id.as_str())
+                    .await
+                {
+                    Ok(_) => WaitlistDropOutcome::Dropped,
+                    Err(e) => WaitlistDropOutcome::Failed(e),
+                }
+            };
+
+            if let Some(sink) = self.raw.info.get_attempt_sink() {
+                sink.record(&AttemptRecord {
+                    at: self.raw.info.get_clock().now(),
+                    term: self.raw.term.to_uppercase(),
+                    section_id: sec.section_id.clone(),
+                    section_code: sec.section_code.clone(),
+                    course: sec.course().to_string(),
+                    outcome: outcome.name().to_owned(),
+                    reason: match &outcome {
+                        WaitlistDropOutcome::Failed(e) => {
+                            Some(e.webreg_reason().unwrap_or_else(|| e.to_string()))
+                        }
+                        _ => None,
+                    },
+                });
+            }
+
+            attempts.push(WaitlistDropAttempt {
+                section: sec.clone(),
+                rule: rule.clone(),
+                outcome,
+            });
+        }
+
+        Ok(WaitlistDropReport { attempts })
+    }
+
     /// Renames a schedule to the specified name. You cannot rename the default
     /// `My Schedule` schedule.
     ///
@@ -1493,6 +3738,7 @@ impl<'a> WrapperTermRequest<'a> {
         }
 
         process_post_response(
+            &self.raw.info,
             self.raw
                 .info
                 .req(ReqType::Post(RENAME_SCHEDULE))
@@ -1553,6 +3799,7 @@ impl<'a> WrapperTermRequest<'a> {
         }
 
         process_post_response(
+            &self.raw.info,
             self.raw
                 .info
                 .req(ReqType::Post(REMOVE_SCHEDULE))
@@ -1566,6 +3813,74 @@ impl<'a> WrapperTermRequest<'a> {
         .await
     }
 
+    /// Makes sure that a schedule with the given name exists, creating it if needed. This is
+    /// useful as a first step before importing or copying courses into a schedule.
+    ///
+    /// WebReg doesn't have a way to create an empty schedule; a schedule is only created
+    /// when a course is planned into it. So, if the schedule doesn't exist, the `seed` course
+    /// will be planned into the new schedule (any schedule name in `seed` is ignored).
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The name of the schedule.
+    /// - `seed`: The course to plan if the schedule needs to be created.
+    /// - `validate`: Whether to validate your planning of the seed course beforehand.
+    ///
+    /// # Returns
+    /// `true` if the schedule was created, and `false` if it already existed. An error is
+    /// returned if something went wrong.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::PlanAdd;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let seed = PlanAdd::builder()
+    ///     .with_subject_code("CSE")
+    ///     .with_course_code("100")
+    ///     .with_section_id("079911")
+    ///     .with_section_code("A01")
+    ///     .with_unit_count(4)
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// match wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .ensure_schedule_exists("Test Schedule", seed, true)
+    ///     .await
+    /// {
+    ///     Ok(created) => println!("Created? {created}"),
+    ///     Err(e) => eprintln!("Unable to check schedule: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn ensure_schedule_exists(
+        &self,
+        schedule_name: impl AsRef<str>,
+        mut seed: PlanAdd<'_>,
+        validate: bool,
+    ) -> types::Result<bool> {
+        let schedule_name = schedule_name.as_ref();
+        if schedule_name == DEFAULT_SCHEDULE_NAME
+            || self
+                .get_schedule_list()
+                .await?
+                .iter()
+                .any(|s| s == schedule_name)
+        {
+            return Ok(false);
+        }
+
+        seed.schedule_name = Some(Cow::Owned(schedule_name.to_owned()));
+        self.add_to_plan(seed, validate).await?;
+        Ok(true)
+    }
+
     /// Adds an event to your WebReg calendar, or edits an existing event.
     ///
     /// Keep in mind that if you edit an event, WebReg effectively just removes
@@ -1720,6 +4035,7 @@ impl<'a> WrapperTermRequest<'a> {
         }
 
         process_post_response(
+            &self.raw.info,
             self.raw
                 .info
                 .req(ReqType::Post(match et {
@@ -1765,6 +4081,7 @@ impl<'a> WrapperTermRequest<'a> {
     /// ```
     pub async fn remove_event(&self, event_timestamp: impl AsRef<str>) -> types::Result<bool> {
         process_post_response(
+            &self.raw.info,
             self.raw
                 .info
                 .req(ReqType::Post(EVENT_REMOVE))
@@ -1778,6 +4095,78 @@ impl<'a> WrapperTermRequest<'a> {
         .await
     }
 
+    /// Exports all of your events so that they can be added again later (e.g., in another
+    /// term) using `import_events`. The resulting events can be serialized to JSON; see
+    /// `EventAdd` for the format.
+    ///
+    /// # Returns
+    /// All of your events, or an error if one occurred.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let events = wrapper.req("FA23").parsed().export_events().await.unwrap();
+    /// println!("{}", serde_json::to_string_pretty(&events).unwrap());
+    /// # }
+    /// ```
+    pub async fn export_events(&self) -> types::Result<Vec<EventAdd<'static>>> {
+        Ok(self
+            .get_events()
+            .await?
+            .iter()
+            .map(EventAdd::from)
+            .collect())
+    }
+
+    /// Imports the given events, skipping any event that you already have. An event is
+    /// considered to already exist if there's an event with the same name, times, and days.
+    ///
+    /// # Parameters
+    /// - `events`: The events to add.
+    ///
+    /// # Returns
+    /// The number of events that were added, or an error if one occurred. Note that, if an
+    /// error occurs partway through, any events before the failing one will have been added.
+    ///
+    /// # Example
+    /// Copying all events from one term to another.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let events = wrapper.req("FA23").parsed().export_events().await.unwrap();
+    /// match wrapper.req("WI24").parsed().import_events(events).await {
+    ///     Ok(ct) => println!("Added {ct} events."),
+    ///     Err(e) => eprintln!("Unable to import events: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn import_events(&self, events: Vec<EventAdd<'_>>) -> types::Result<usize> {
+        let mut existing: Vec<EventAdd<'_>> = self.export_events().await?;
+        let mut added = 0;
+        for event in events {
+            if existing.iter().any(|e| e.is_same_event(&event)) {
+                continue;
+            }
+
+            existing.push(event.clone());
+            self.add_or_edit_event(event, None).await?;
+            added += 1;
+        }
+
+        Ok(added)
+    }
+
     /// Associates the term bound by this request to the cookies that are provided
     /// as part of this overridden request.
     ///
@@ -1788,3 +4177,45 @@ impl<'a> WrapperTermRequest<'a> {
         associate_term_helper(&self.raw.info, self.raw.term).await
     }
 }
+
+/// Checks whether a message from WebReg mentions a section in your schedule, either by its
+/// course (e.g., `CSE 100`) or by its section ID. Used by `swap_section`.
+///
+/// # Parameters
+/// - `message`: The message from WebReg.
+/// - `section`: The section.
+///
+/// # Returns
+/// Whether the message mentions the section.
+fn mentions_section(message: &str, section: &ScheduledSection) -> bool {
+    let message = message
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase();
+    let course = format!("{} {}", section.subject_code, section.course_code).to_uppercase();
+    let section_id = section.section_id.trim_start_matches('0');
+    message.contains(&course) || (!section_id.is_empty() && message.contains(section_id))
+}
+
+/// Turns the response from one of WebReg's validation endpoints into a validation outcome.
+/// If WebReg rejected the request, the reason is classified; any other error is returned
+/// as is.
+///
+/// # Parameters
+/// - `res`: The processed response.
+///
+/// # Returns
+/// The validation outcome, or an error if the request itself failed.
+fn to_validation_outcome(res: types::Result<bool>) -> types::Result<ValidationOutcome> {
+    match res {
+        Ok(_) => Ok(ValidationOutcome {
+            ok: true,
+            reason: None,
+        }),
+        Err(e) => match e.webreg_reason() {
+            Some(reason) => Ok(ValidationOutcome::rejected(reason)),
+            None => Err(e),
+        },
+    }
+}
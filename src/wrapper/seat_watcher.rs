@@ -0,0 +1,381 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use tokio::time::MissedTickBehavior;
+
+use crate::types;
+use crate::types::{CourseCode, CourseSection, SeatEvent, WrapperError};
+use crate::wrapper::WebRegWrapper;
+
+/// The number of events that can be waiting for the slowest subscriber before it starts
+/// missing events.
+const SEAT_EVENT_CAPACITY: usize = 256;
+
+/// The most that a course's volatility can be. A course is set to this whenever one of its
+/// sections changes, and the volatility is halved on every check where nothing changes.
+const MAX_VOLATILITY: u64 = 8;
+
+/// A section with at most this many available seats is considered to be close to filling up
+/// (or opening up), so its course is checked more often.
+const NEAR_ZERO_SEATS: i64 = 3;
+
+/// When polling adaptively, every course is checked at least once per this many intervals,
+/// no matter how stable it is.
+const MAX_STALENESS_INTERVALS: u64 = 4;
+
+/// The shortest time between checks when polling adaptively. An adaptive period that rounds
+/// down to zero (which `tokio::time::interval` doesn't allow) is raised to this.
+const MIN_POLL_PERIOD: Duration = Duration::from_millis(1);
+
+/// Watches a set of sections for changes in availability, and sends a `SeatEvent` to every
+/// subscriber whenever a section opens up, fills up, or its waitlist changes.
+///
+/// Each course is checked once per interval with `get_enrollment_count`, no matter how many
+/// of its sections are being watched. The watcher owns a shared handle to the wrapper, so
+/// `run` can be spawned as a background task (e.g., with `tokio::spawn`). Since the watcher
+/// doesn't spawn anything on its own, you'll need to drive `run` yourself. Every check uses
+/// the wrapper's default settings for the term.
+///
+/// With `should_poll_adaptively`, the watcher instead checks one course per
+/// `interval / courses`, so the total number of requests stays the same, but picks the course
+/// using an `AdaptiveSchedule`. Courses whose sections recently changed, or are close to zero
+/// seats, are checked more often, and stable courses less often.
+///
+/// # Example
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use reqwest::Client;
+/// use webweg::types::SeatEvent;
+/// use webweg::wrapper::seat_watcher::SeatWatcher;
+/// use webweg::wrapper::WebRegWrapper;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let wrapper = Arc::new(WebRegWrapper::new(Client::new(), "my cookies"));
+///
+/// let watcher = SeatWatcher::new(wrapper, "FA23", Duration::from_secs(30))
+///     .unwrap()
+///     .watch(("CSE", "100"), "079911")
+///     .watch(("MATH", "184"), "081234");
+/// let mut events = watcher.subscribe();
+/// tokio::spawn(watcher.run());
+///
+/// while let Ok(event) = events.recv().await {
+///     if let SeatEvent::Opened { section_id, .. } = event {
+///         println!("{section_id} has seats!");
+///     }
+/// }
+/// # }
+/// ```
+pub struct SeatWatcher {
+    wrapper: Arc<WebRegWrapper>,
+    term: String,
+    interval: Duration,
+    adaptive: bool,
+    /// The section IDs (without leading zeros) to watch, keyed by course.
+    sections: BTreeMap<CourseCode, Vec<String>>,
+    sender: broadcast::Sender<SeatEvent>,
+}
+
+impl SeatWatcher {
+    /// Creates a new watcher that isn't watching any sections yet.
+    ///
+    /// # Parameters
+    /// - `wrapper`: The wrapper to check the sections with.
+    /// - `term`: The term that the sections are in. For example, `FA23`.
+    /// - `interval`: How often to check the sections. This must be greater than zero.
+    ///
+    /// # Returns
+    /// The watcher, or an `InputError` if the interval is zero.
+    pub fn new(
+        wrapper: Arc<WebRegWrapper>,
+        term: impl Into<String>,
+        interval: Duration,
+    ) -> types::Result<Self> {
+        if interval.is_zero() {
+            return Err(WrapperError::InputError(
+                "interval",
+                "The interval must be greater than zero.",
+            ));
+        }
+
+        let (sender, _) = broadcast::channel(SEAT_EVENT_CAPACITY);
+        Ok(Self {
+            wrapper,
+            term: term.into(),
+            interval,
+            adaptive: false,
+            sections: BTreeMap::new(),
+            sender,
+        })
+    }
+
+    /// Adds a section to watch.
+    ///
+    /// # Parameters
+    /// - `course`: The section's course. For example, `("CSE", "100")`.
+    /// - `section_id`: The section ID. For example, `079911`.
+    ///
+    /// # Returns
+    /// The watcher.
+    pub fn watch(mut self, course: impl Into<CourseCode>, section_id: impl AsRef<str>) -> Self {
+        self.sections.entry(course.into()).or_default().push(
+            section_id
+                .as_ref()
+                .trim()
+                .trim_start_matches('0')
+                .to_owned(),
+        );
+        self
+    }
+
+    /// Whether to poll adaptively, checking volatile courses more often than stable ones
+    /// without making more requests overall. By default, every course is checked once per
+    /// interval.
+    ///
+    /// # Parameters
+    /// - `adaptive`: Whether to poll adaptively.
+    ///
+    /// # Returns
+    /// The watcher.
+    pub fn should_poll_adaptively(mut self, adaptive: bool) -> Self {
+        self.adaptive = adaptive;
+        self
+    }
+
+    /// Subscribes to the watcher's events. Only events sent after subscribing are received.
+    ///
+    /// # Returns
+    /// The receiving end of the event channel.
+    pub fn subscribe(&self) -> broadcast::Receiver<SeatEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Checks the watched sections once per interval, sending events to the subscribers,
+    /// until every subscriber is gone. The first check is made right away.
+    ///
+    /// Errors don't stop the watcher; they're sent as `SeatEvent::PollFailed` events instead.
+    /// Subscribe (with `subscribe`) before calling this, since the watcher is consumed.
+    pub async fn run(self) {
+        let courses = self.sections.iter().collect::<Vec<_>>();
+        let period = if self.adaptive && !courses.is_empty() {
+            (self.interval / courses.len().try_into().unwrap_or(u32::MAX)).max(MIN_POLL_PERIOD)
+        } else {
+            self.interval
+        };
+
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut schedule = AdaptiveSchedule::new(courses.len());
+        let mut seen = HashMap::new();
+        while self.sender.receiver_count() > 0 {
+            ticker.tick().await;
+            if !self.adaptive {
+                for (course, section_ids) in &courses {
+                    self.poll(course, section_ids, &mut seen).await;
+                }
+            } else if let Some(idx) = schedule.next_course() {
+                let (course, section_ids) = courses[idx];
+                if let Some((changed, sections)) = self.poll(course, section_ids, &mut seen).await {
+                    schedule.record(idx, changed, &sections);
+                }
+            }
+        }
+    }
+
+    /// Checks the watched sections of one course, sending any events to the subscribers.
+    ///
+    /// # Parameters
+    /// - `course`: The course.
+    /// - `section_ids`: The watched section IDs, without leading zeros.
+    /// - `seen`: Each section as of the previous check, keyed by section ID. This is updated
+    ///   with the results of this check.
+    ///
+    /// # Returns
+    /// Whether any event was sent, along with the watched sections, or `None` if the check
+    /// failed.
+    async fn poll(
+        &self,
+        course: &CourseCode,
+        section_ids: &[String],
+        seen: &mut HashMap<String, CourseSection>,
+    ) -> Option<(bool, Vec<CourseSection>)> {
+        let requester = self.wrapper.req(&self.term).parsed();
+        let strategy = requester.seat_strategy();
+        let sections = match requester.get_enrollment_count(course).await {
+            Ok(sections) => sections,
+            Err(e) => {
+                let _ = self.sender.send(SeatEvent::PollFailed {
+                    course: course.to_string(),
+                    error: e.to_string(),
+                });
+                return None;
+            }
+        };
+
+        let watched = sections
+            .into_iter()
+            .filter(|s| {
+                section_ids
+                    .iter()
+                    .any(|id| id == s.section_id.trim_start_matches('0'))
+            })
+            .collect::<Vec<_>>();
+
+        let mut changed = false;
+        for section in &watched {
+            for event in SeatEvent::changes(seen.get(&section.section_id), section, strategy) {
+                changed = true;
+                // This only fails if there are no subscribers, which `run` checks for.
+                let _ = self.sender.send(event);
+            }
+
+            seen.insert(section.section_id.clone(), section.clone());
+        }
+
+        Some((changed, watched))
+    }
+}
+
+/// Decides which course an adaptive `SeatWatcher` should check next. Each call to `next_course`
+/// stands for one request, so the schedule only decides how the requests are split between
+/// courses, not how many are made.
+///
+/// A course's priority grows with the time since it was last checked, weighted by how
+/// volatile it is: courses whose sections changed recently, or that have a section close to
+/// zero seats, are weighted more. Courses that have never been checked go first, and every
+/// course is checked at least once per four rounds (a round being one request per course), so
+/// stable courses are never starved.
+///
+/// # Example
+/// ```rust
+/// use webweg::wrapper::seat_watcher::AdaptiveSchedule;
+///
+/// let mut schedule = AdaptiveSchedule::new(2);
+/// assert_eq!(Some(0), schedule.next_course());
+/// assert_eq!(Some(1), schedule.next_course());
+///
+/// // Something changed in the first course, so it's checked again first.
+/// schedule.record(0, true, &[]);
+/// schedule.record(1, false, &[]);
+/// assert_eq!(Some(0), schedule.next_course());
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdaptiveSchedule {
+    courses: Vec<CourseActivity>,
+}
+
+/// What the schedule knows about one course.
+#[derive(Debug, Clone)]
+struct CourseActivity {
+    checked: bool,
+    since_check: u64,
+    volatility: u64,
+    near_zero: bool,
+}
+
+impl AdaptiveSchedule {
+    /// Creates a schedule for the given number of courses, none of which have been checked.
+    ///
+    /// # Parameters
+    /// - `courses`: The number of courses.
+    ///
+    /// # Returns
+    /// The schedule.
+    pub fn new(courses: usize) -> Self {
+        Self {
+            courses: vec![
+                CourseActivity {
+                    checked: false,
+                    since_check: 0,
+                    volatility: 1,
+                    near_zero: false,
+                };
+                courses
+            ],
+        }
+    }
+
+    /// Picks the course to check next.
+    ///
+    /// # Returns
+    /// The index of the course, or `None` if there are no courses.
+    pub fn next_course(&mut self) -> Option<usize> {
+        for course in &mut self.courses {
+            course.since_check += 1;
+        }
+
+        let max_staleness = MAX_STALENESS_INTERVALS * self.courses.len() as u64;
+        let idx = self
+            .courses
+            .iter()
+            .position(|c| !c.checked)
+            .or_else(|| {
+                self.courses
+                    .iter()
+                    .position(|c| c.since_check > max_staleness)
+            })
+            .or_else(|| {
+                // Ties go to the course that comes first.
+                let mut best: Option<(usize, u64)> = None;
+                for (i, c) in self.courses.iter().enumerate() {
+                    let priority = c.priority();
+                    if best.is_none_or(|(_, p)| priority > p) {
+                        best = Some((i, priority));
+                    }
+                }
+
+                best.map(|(i, _)| i)
+            })?;
+
+        let course = &mut self.courses[idx];
+        course.checked = true;
+        course.since_check = 0;
+        Some(idx)
+    }
+
+    /// Records the result of checking a course. If the check failed, nothing needs to be
+    /// recorded.
+    ///
+    /// # Parameters
+    /// - `course`: The index of the course, as returned by `next_course`.
+    /// - `changed`: Whether any of the course's sections changed since the previous check.
+    /// - `sections`: The course's sections, as of this check.
+    pub fn record(&mut self, course: usize, changed: bool, sections: &[CourseSection]) {
+        let Some(course) = self.courses.get_mut(course) else {
+            return;
+        };
+
+        course.volatility = if changed {
+            MAX_VOLATILITY
+        } else {
+            (course.volatility / 2).max(1)
+        };
+        course.near_zero = sections
+            .iter()
+            .any(|s| s.available_seats <= NEAR_ZERO_SEATS);
+    }
+
+    /// Gets how volatile a course currently is, from `1` (stable) to `8` (just changed).
+    ///
+    /// # Parameters
+    /// - `course`: The index of the course.
+    ///
+    /// # Returns
+    /// The volatility, or `None` if there's no such course.
+    pub fn volatility(&self, course: usize) -> Option<u64> {
+        self.courses.get(course).map(|c| c.volatility)
+    }
+}
+
+impl CourseActivity {
+    /// How urgently the course should be checked.
+    fn priority(&self) -> u64 {
+        let weight = if self.near_zero { 2 } else { 1 };
+        self.since_check * self.volatility * weight
+    }
+}
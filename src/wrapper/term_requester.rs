@@ -0,0 +1,109 @@
+use futures::future::BoxFuture;
+
+use crate::types;
+use crate::types::{CourseCode, Courses, Events, Schedule, SearchResult};
+use crate::wrapper::input_types::SearchType;
+use crate::wrapper::requester_term::WrapperTermRequest;
+
+/// The read-only parts of the parsed requester (`WrapperTermRequest`), as a trait.
+///
+/// This lets you write code that works with any requester, so that you can substitute a fake
+/// requester in your tests without needing WebReg. `MockTermRequester` is a ready-made fake
+/// that serves canned responses (e.g., saved JSON fixtures) from a `MockWebReg`.
+///
+/// # Example
+/// ```rust,no_run
+/// use webweg::types;
+/// use webweg::types::CourseCode;
+/// use webweg::wrapper::term_requester::TermRequester;
+///
+/// async fn count_sections(req: &impl TermRequester) -> types::Result<usize> {
+///     Ok(req.get_course_info(&CourseCode::new("CSE", "100")).await?.len())
+/// }
+/// ```
+pub trait TermRequester: Send + Sync {
+    /// Gets course information for a particular course. See
+    /// `WrapperTermRequest::get_course_info` for more information.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `CSE 100`).
+    ///
+    /// # Returns
+    /// The sections of the course.
+    fn get_course_info<'b>(
+        &'b self,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>>;
+
+    /// Gets enrollment counts for a particular course. See
+    /// `WrapperTermRequest::get_enrollment_count` for more information.
+    ///
+    /// # Parameters
+    /// - `course`: The course (e.g., `CSE 100`).
+    ///
+    /// # Returns
+    /// The sections of the course, with only the enrollment counts filled out.
+    fn get_enrollment_count<'b>(
+        &'b self,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>>;
+
+    /// Gets your current schedule. See `WrapperTermRequest::get_schedule` for more
+    /// information.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The schedule.
+    fn get_schedule<'b>(
+        &'b self,
+        schedule_name: Option<&'b str>,
+    ) -> BoxFuture<'b, types::Result<Schedule>>;
+
+    /// Searches for courses. See `WrapperTermRequest::search_courses` for more information.
+    ///
+    /// # Parameters
+    /// - `filter_by`: The search to make.
+    ///
+    /// # Returns
+    /// The search results.
+    fn search_courses(&self, filter_by: SearchType) -> BoxFuture<'_, types::Result<SearchResult>>;
+
+    /// Gets all of your events. See `WrapperTermRequest::get_events` for more information.
+    ///
+    /// # Returns
+    /// The events.
+    fn get_events(&self) -> BoxFuture<'_, types::Result<Events>>;
+}
+
+impl<'a> TermRequester for WrapperTermRequest<'a> {
+    fn get_course_info<'b>(
+        &'b self,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>> {
+        Box::pin(WrapperTermRequest::get_course_info(self, course))
+    }
+
+    fn get_enrollment_count<'b>(
+        &'b self,
+        course: &'b CourseCode,
+    ) -> BoxFuture<'b, types::Result<Courses>> {
+        Box::pin(WrapperTermRequest::get_enrollment_count(self, course))
+    }
+
+    fn get_schedule<'b>(
+        &'b self,
+        schedule_name: Option<&'b str>,
+    ) -> BoxFuture<'b, types::Result<Schedule>> {
+        Box::pin(WrapperTermRequest::get_schedule(self, schedule_name))
+    }
+
+    fn search_courses(&self, filter_by: SearchType) -> BoxFuture<'_, types::Result<SearchResult>> {
+        Box::pin(WrapperTermRequest::search_courses(self, filter_by))
+    }
+
+    fn get_events(&self) -> BoxFuture<'_, types::Result<Events>> {
+        Box::pin(WrapperTermRequest::get_events(self))
+    }
+}
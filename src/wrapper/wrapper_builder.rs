@@ -1,10 +1,24 @@
 #[cfg(feature = "multi")]
 use parking_lot::lock_api::Mutex;
+#[cfg(feature = "reqwest")]
+use std::net::IpAddr;
+use std::sync::Arc;
 use std::time::Duration;
 
-use crate::constants::MY_USER_AGENT;
-use crate::wrapper::request_data::WebRegWrapperData;
-use reqwest::Client;
+#[cfg(feature = "blocking")]
+use crate::blocking::{thread_sleep, ClientBackend};
+use crate::constants::{DEFAULT_ACTION_LOG_CAPACITY, DEFAULT_CATALOG_CACHE_TTL, MY_USER_AGENT};
+use crate::types::{
+    AttemptSink, BuilderProblem, Clock, CookieProvider, DefaultSeatStrategy, HttpBackend,
+    PacingProfile, ParseWarning, RateLimit, SeatStrategy, SystemClock, UsageQuota,
+};
+use crate::wrapper::request_data::{
+    tokio_sleep, ActionRecorder, CatalogCache, CookieRefresher, ErrorCollector, Pacer,
+    ParseWarningCallback, QuotaGuard, RateLimiter, ScheduleCache, SessionInvalidCallback, Sleeper,
+    StatsTracker, TermTracker, WaitlistTracker, WebRegWrapperData,
+};
+#[cfg(feature = "reqwest")]
+use reqwest::{Certificate, Client};
 
 use crate::wrapper::WebRegWrapper;
 
@@ -17,19 +31,50 @@ use crate::wrapper::WebRegWrapper;
 ///
 /// # fn main() {
 ///  let wrapper = WebRegWrapper::builder()
-///     .with_cookies("abc")
+///     .with_cookies("jlinksessionidx=abc; itscookie=def")
 ///     .with_default_timeout(Duration::from_secs(10))
-///     .try_build_wrapper();
+///     .build_wrapper();
 ///
-///  assert!(wrapper.is_some());
+///  assert!(wrapper.is_ok());
 /// # }
 /// ```
 pub struct WebRegWrapperBuilder {
     cookies: Option<String>,
-    client: Client,
+    #[cfg(feature = "reqwest")]
+    client: Option<Client>,
     user_agent: String,
+    contact_info: Option<String>,
     default_timeout: Duration,
     close_after_request: bool,
+    cookie_overrides: bool,
+    cache_buster: bool,
+    collect_errors: bool,
+    record_actions: bool,
+    action_log_capacity: usize,
+    #[cfg(feature = "compression")]
+    compression: bool,
+    #[cfg(feature = "reqwest")]
+    root_certificates: Vec<Certificate>,
+    #[cfg(feature = "reqwest")]
+    accept_invalid_certs: bool,
+    #[cfg(feature = "reqwest")]
+    local_address: Option<IpAddr>,
+    on_session_invalid: Option<SessionInvalidCallback>,
+    on_parse_warning: Option<ParseWarningCallback>,
+    strict_parsing: bool,
+    always_validate_enroll: bool,
+    always_validate_plan: bool,
+    seat_strategy: Arc<dyn SeatStrategy>,
+    pacing: PacingProfile,
+    clock: Arc<dyn Clock>,
+    catalog_cache_ttl: Duration,
+    quota: UsageQuota,
+    rate_limit: Option<RateLimit>,
+    cookie_provider: Option<Arc<dyn CookieProvider>>,
+    attempt_sink: Option<Arc<dyn AttemptSink>>,
+    backend: Option<Arc<dyn HttpBackend>>,
+    max_response_size: Option<u64>,
+    sleeper: Sleeper,
 }
 
 impl WebRegWrapperBuilder {
@@ -41,10 +86,41 @@ impl WebRegWrapperBuilder {
     pub fn new() -> Self {
         Self {
             cookies: None,
-            client: Client::new(),
+            #[cfg(feature = "reqwest")]
+            client: None,
             user_agent: MY_USER_AGENT.to_owned(),
+            contact_info: None,
             default_timeout: Duration::from_secs(30),
             close_after_request: false,
+            cookie_overrides: false,
+            cache_buster: true,
+            collect_errors: false,
+            record_actions: false,
+            action_log_capacity: DEFAULT_ACTION_LOG_CAPACITY,
+            #[cfg(feature = "compression")]
+            compression: true,
+            #[cfg(feature = "reqwest")]
+            root_certificates: vec![],
+            #[cfg(feature = "reqwest")]
+            accept_invalid_certs: false,
+            #[cfg(feature = "reqwest")]
+            local_address: None,
+            on_session_invalid: None,
+            on_parse_warning: None,
+            strict_parsing: false,
+            always_validate_enroll: true,
+            always_validate_plan: true,
+            seat_strategy: Arc::new(DefaultSeatStrategy),
+            pacing: PacingProfile::default(),
+            clock: Arc::new(SystemClock),
+            catalog_cache_ttl: DEFAULT_CATALOG_CACHE_TTL,
+            quota: UsageQuota::default(),
+            rate_limit: None,
+            cookie_provider: None,
+            attempt_sink: None,
+            backend: None,
+            max_response_size: None,
+            sleeper: tokio_sleep,
         }
     }
 
@@ -67,8 +143,9 @@ impl WebRegWrapperBuilder {
     ///
     /// # Returns
     /// The builder.
+    #[cfg(feature = "reqwest")]
     pub fn with_client(mut self, client: Client) -> Self {
-        self.client = client;
+        self.client = Some(client);
         self
     }
 
@@ -84,6 +161,38 @@ impl WebRegWrapperBuilder {
         self
     }
 
+    /// Sets contact information (e.g., an email address or a link to your project) that will be
+    /// added as a comment to the end of the user agent, regardless of whether the default user
+    /// agent or a custom one is used. This lets campus IT know who to contact if your tool is
+    /// causing problems, instead of having to block it outright.
+    ///
+    /// Since header values must be visible ASCII, any other characters (including parentheses,
+    /// which would end the comment early) are removed from the contact information.
+    ///
+    /// # Parameters
+    /// - `contact`: The contact information.
+    ///
+    /// # Returns
+    /// The builder.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # fn main() {
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("jlinksessionidx=abc; itscookie=def")
+    ///     .with_contact_info("my-tool-admin@ucsd.edu")
+    ///     .try_build_wrapper();
+    ///
+    /// assert!(wrapper.is_some());
+    /// # }
+    /// ```
+    pub fn with_contact_info(mut self, contact: impl Into<String>) -> Self {
+        self.contact_info = Some(contact.into());
+        self
+    }
+
     /// Sets the timeout to the specified timeout.
     ///
     /// # Parameters
@@ -116,30 +225,732 @@ impl WebRegWrapperBuilder {
         self
     }
 
-    /// Attempts to build the wrapper. To successfully build the wrapper, the cookies and term
-    /// must be provided.
+    /// Whether requests made with this wrapper will override the session cookies (see
+    /// `WrapperTermRequestBuilder::try_override_cookies`). This doesn't change how the wrapper
+    /// behaves, but lets `diagnose` and `build_wrapper` report that overriding the cookies
+    /// won't be allowed because `should_close_after_request` isn't set, instead of each
+    /// override failing later. By default, this is `false`.
+    ///
+    /// # Parameters
+    /// - `overrides`: Whether requests will override the session cookies.
+    ///
+    /// # Returns
+    /// The builder.
+    ///
+    /// # Example
+    /// ```rust
+    /// use webweg::types::BuilderProblem;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// let problems = WebRegWrapper::builder()
+    ///     .with_cookies("jlinksessionidx=abc; itscookie=def")
+    ///     .should_override_cookies(true)
+    ///     .diagnose();
+    /// assert_eq!(vec![BuilderProblem::CookieOverrideWithoutClose], problems);
+    /// ```
+    pub fn should_override_cookies(mut self, overrides: bool) -> Self {
+        self.cookie_overrides = overrides;
+        self
+    }
+
+    /// Whether responses from WebReg should be compressed (using gzip or brotli). Course data
+    /// compresses very well, so this can noticeably speed up requests on slow connections. By
+    /// default, this is `true`.
+    ///
+    /// Note that this only applies to the default client; if you provide your own client through
+    /// `with_client`, you are responsible for configuring compression on that client instead.
+    ///
+    /// The wrapper's `bytes_received` statistic and response size limit (see
+    /// `with_max_response_size`) both count the bytes of a response after it's decompressed.
+    ///
+    /// # Parameters
+    /// - `compress`: Whether to ask for compressed responses.
+    ///
+    /// # Returns
+    /// The builder.
+    #[cfg(feature = "compression")]
+    pub fn with_compression(mut self, compress: bool) -> Self {
+        self.compression = compress;
+        self
+    }
+
+    /// Adds a trusted root certificate, which might be needed if you are behind a proxy that
+    /// uses its own certificate (e.g., a corporate proxy).
+    ///
+    /// Note that this only applies to the default client; if you provide your own client through
+    /// `with_client`, this will be ignored.
+    ///
+    /// # Parameters
+    /// - `cert`: The certificate to trust.
+    ///
+    /// # Returns
+    /// The builder.
+    #[cfg(feature = "reqwest")]
+    pub fn with_root_certificate(mut self, cert: Certificate) -> Self {
+        self.root_certificates.push(cert);
+        self
+    }
+
+    /// Whether the client should accept invalid certificates. This can be useful if you are
+    /// debugging requests with a local proxy (e.g., mitmproxy).
+    ///
+    /// **Do not** enable this outside of debugging, as it will make every request vulnerable to
+    /// man-in-the-middle attacks, exposing your WebReg cookies.
+    ///
+    /// Note that this only applies to the default client; if you provide your own client through
+    /// `with_client`, this will be ignored.
+    ///
+    /// # Parameters
+    /// - `accept`: Whether to accept invalid certificates.
+    ///
+    /// # Returns
+    /// The builder.
+    #[cfg(feature = "reqwest")]
+    pub fn should_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Sets the local address that the client should bind to when making requests.
+    ///
+    /// Note that this only applies to the default client; if you provide your own client through
+    /// `with_client`, this will be ignored.
+    ///
+    /// # Parameters
+    /// - `addr`: The local address to use.
+    ///
+    /// # Returns
+    /// The builder.
+    #[cfg(feature = "reqwest")]
+    pub fn with_local_address(mut self, addr: IpAddr) -> Self {
+        self.local_address = Some(addr);
+        self
+    }
+
+    /// Whether the `_` (cache-busting) query parameter should be included in requests.
+    ///
+    /// WebReg's own frontend includes the current time as this parameter so that responses
+    /// are never cached. If you are going through a caching proxy and want responses to be
+    /// cached, consider setting this to `false`. By default, this is `true`.
+    ///
+    /// # Parameters
+    /// - `include`: Whether to include the cache-busting parameter.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn should_include_cache_buster(mut self, include: bool) -> Self {
+        self.cache_buster = include;
+        self
+    }
+
+    /// Whether the wrapper should keep track of every unique error reason that WebReg gives.
+    /// These can be retrieved through the wrapper's `observed_errors` function.
+    ///
+    /// # Parameters
+    /// - `collect`: Whether to collect error reasons.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn should_collect_errors(mut self, collect: bool) -> Self {
+        self.collect_errors = collect;
+        self
+    }
+
+    /// Whether the wrapper should record every mutating request (e.g., enrolling, dropping,
+    /// planning, or changing a grading option), along with the parameters that were sent and
+    /// the response that WebReg gave. These can be retrieved through the wrapper's
+    /// `action_log` function.
+    ///
+    /// Since every response is kept in memory, only the most recent requests are kept (see
+    /// `with_action_log_capacity`).
+    ///
+    /// # Parameters
+    /// - `record`: Whether to record mutating requests.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn should_record_actions(mut self, record: bool) -> Self {
+        self.record_actions = record;
+        self
+    }
+
+    /// Sets how many mutating requests the action log should keep (see
+    /// `should_record_actions`). Once the log is full, the oldest request is removed to make
+    /// room for each new one. By default, the log keeps 1000 requests.
+    ///
+    /// # Parameters
+    /// - `capacity`: The maximum number of requests to keep.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_action_log_capacity(mut self, capacity: usize) -> Self {
+        self.action_log_capacity = capacity;
+        self
+    }
+
+    /// Sets a function that will be called whenever WebReg indicates that the session is no
+    /// longer valid (e.g., when WebReg responds with its login page because your cookies have
+    /// expired). This might be useful if you want to refresh your cookies automatically.
+    ///
+    /// # Parameters
+    /// - `callback`: The function to call.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_session_invalid_callback(
+        mut self,
+        callback: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        self.on_session_invalid = Some(Arc::new(callback));
+        self
+    }
+
+    /// Sets a function that will be called for each data-quality issue found while parsing
+    /// WebReg's data (e.g., a section with a negative number of available seats). The parser
+    /// normalizes or skips this data, so this might be useful if you want to know when the
+    /// parsed data isn't exactly what WebReg gave.
+    ///
+    /// # Parameters
+    /// - `callback`: The function to call.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_parse_warning_callback(
+        mut self,
+        callback: impl Fn(&ParseWarning) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_parse_warning = Some(Arc::new(callback));
+        self
+    }
+
+    /// Whether the wrapper should return an error when WebReg gives a value that isn't known
+    /// (an unknown meeting type, enrollment status, display type, or day code), instead of
+    /// parsing it into a catch-all value and reporting a warning. This is useful if you want
+    /// to find out as soon as possible when WebReg changes its behavior. The error is a
+    /// `WrapperError::UnknownValue` containing the offending value. By default, this is
+    /// `false`.
+    ///
+    /// # Parameters
+    /// - `strict`: Whether to use strict parsing.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn should_use_strict_parsing(mut self, strict: bool) -> Self {
+        self.strict_parsing = strict;
+        self
+    }
+
+    /// Whether `add_section_default` should validate the enrollment beforehand. WebReg
+    /// requires validation before enrolling or waitlisting, so you should only turn this off
+    /// if you validate on your own (see `validate_add_section`). By default, this is `true`.
+    ///
+    /// # Parameters
+    /// - `validate`: Whether to validate by default.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn should_always_validate_enroll(mut self, validate: bool) -> Self {
+        self.always_validate_enroll = validate;
+        self
+    }
+
+    /// Whether `add_to_plan_default` should validate the plan beforehand. Without validation,
+    /// WebReg will let you plan things that you shouldn't be able to (see `add_to_plan`). By
+    /// default, this is `true`.
+    ///
+    /// # Parameters
+    /// - `validate`: Whether to validate by default.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn should_always_validate_plan(mut self, validate: bool) -> Self {
+        self.always_validate_plan = validate;
+        self
+    }
+
+    /// Sets the strategy used to decide whether a section has seats that can be enrolled in
+    /// (e.g., when deciding whether to enroll or waitlist). By default, this is the
+    /// `DefaultSeatStrategy`.
+    ///
+    /// # Parameters
+    /// - `strategy`: The strategy to use.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_seat_strategy(mut self, strategy: impl SeatStrategy + 'static) -> Self {
+        self.seat_strategy = Arc::new(strategy);
+        self
+    }
+
+    /// Sets how quickly the wrapper should make requests to WebReg. This controls the number of
+    /// requests that can be made at once, the delay between requests to the same endpoint, and
+    /// how failed requests are retried. By default, no pacing is done.
+    ///
+    /// If you aren't sure what settings to use, use one of the presets. For example:
+    /// ```rust,no_run
+    /// use webweg::types::PacingPreset;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("my cookies")
+    ///     .with_pacing(PacingPreset::Polite)
+    ///     .try_build_wrapper();
+    /// ```
+    ///
+    /// # Parameters
+    /// - `pacing`: The pacing settings, or a preset.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_pacing(mut self, pacing: impl Into<PacingProfile>) -> Self {
+        self.pacing = pacing.into();
+        self
+    }
+
+    /// Sets the clock that the wrapper should use whenever it needs the current time. By
+    /// default, this is the `SystemClock`.
+    ///
+    /// This is mostly useful for testing; for example, you can use a clock that always gives
+    /// the same time so that requests are deterministic.
+    ///
+    /// # Parameters
+    /// - `clock`: The clock to use.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Sets the cookie provider that the wrapper should ask for fresh cookies when a request
+    /// fails because the session is no longer valid. The failed request is retried once with
+    /// the fresh cookies, which are then used for every request after it (until you call
+    /// `set_cookies`).
+    ///
+    /// Fresh cookies belong to a new session, so you'll need to associate your terms again.
+    /// Requests made with overridden cookies (see `try_override_cookies`) won't use the
+    /// provider.
+    ///
+    /// # Parameters
+    /// - `provider`: The cookie provider.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_cookie_provider(mut self, provider: impl CookieProvider + 'static) -> Self {
+        self.cookie_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Sets where the automated enrollment helpers (e.g., `enroll_from_plan`) should record
+    /// each attempt that they make. By default, attempts aren't recorded anywhere.
+    ///
+    /// # Parameters
+    /// - `sink`: The attempt sink. This can be a closure, or a sink like
+    ///   `util::JsonLinesAttemptSink`.
+    ///
+    /// # Returns
+    /// The builder.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use webweg::util::JsonLinesAttemptSink;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # fn main() {
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("jlinksessionidx=abc; itscookie=def")
+    ///     .with_attempt_sink(JsonLinesAttemptSink::new("attempts.jsonl").unwrap())
+    ///     .try_build_wrapper();
+    ///
+    /// assert!(wrapper.is_some());
+    /// # }
+    /// ```
+    pub fn with_attempt_sink(mut self, sink: impl AttemptSink + 'static) -> Self {
+        self.attempt_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Sets the backend that should send the wrapper's requests in place of the client (e.g.,
+    /// another HTTP library, or a test double like `wrapper::mock::MockWebReg`). The client is
+    /// still used to put the requests together. By default, requests are sent with the client.
+    ///
+    /// # Parameters
+    /// - `backend`: The backend.
+    ///
+    /// # Returns
+    /// The builder.
+    ///
+    /// # Example
+    /// ```rust
+    /// use webweg::wrapper::mock::MockWebReg;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// let mock = MockWebReg::new().with_response("get-current-name", "\"Your name here\"");
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("my cookies")
+    ///     .with_backend(mock)
+    ///     .try_build_wrapper();
+    ///
+    /// assert!(wrapper.is_some());
+    /// ```
+    pub fn with_backend(mut self, backend: impl HttpBackend + 'static) -> Self {
+        self.backend = Some(Arc::new(backend));
+        self
+    }
+
+    /// Sets the largest response, in bytes, that the wrapper will read. If WebReg responds with
+    /// more than this, the request fails with a `ResponseTooLarge` error instead of reading
+    /// the rest of the response. For compressed responses (see `with_compression`), this
+    /// limits the size of the response after it's decompressed. By default, there is no limit.
+    ///
+    /// # Parameters
+    /// - `bytes`: The largest response to read, in bytes.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_max_response_size(mut self, bytes: u64) -> Self {
+        self.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Sets how long this wrapper should cache the subject and department lists for (see
+    /// `get_subjects_wrapper_cached` and `get_departments_wrapper_cached`). By default, this is
+    /// 24 hours.
+    ///
+    /// # Parameters
+    /// - `ttl`: How long a cached list should be used for.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_catalog_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.catalog_cache_ttl = ttl;
+        self
+    }
+
+    /// Sets self-imposed limits on how many requests the wrapper can make. Requests that would
+    /// go over a limit fail with a `QuotaExceeded` error instead of being sent. By default,
+    /// there are no limits.
+    ///
+    /// # Parameters
+    /// - `quota`: The limits.
+    ///
+    /// # Returns
+    /// The builder.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use webweg::types::UsageQuota;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # fn main() {
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("jlinksessionidx=abc; itscookie=def")
+    ///     .with_quota(UsageQuota {
+    ///         max_requests_per_hour: Some(1000),
+    ///         max_mutations_per_day: Some(50),
+    ///     })
+    ///     .try_build_wrapper();
+    ///
+    /// assert!(wrapper.is_some());
+    /// # }
+    /// ```
+    pub fn with_quota(mut self, quota: UsageQuota) -> Self {
+        self.quota = quota;
+        self
+    }
+
+    /// Sets the rate limit for requests made through the wrapper (and any requesters built
+    /// from it). Requests that would go over the limit wait for their turn instead of being
+    /// sent. This is applied on top of the pacing settings (see `with_pacing`). By default,
+    /// there is no rate limit.
+    ///
+    /// # Parameters
+    /// - `limit`: The rate limit. A limit of `0` requests per second means no limit.
+    ///
+    /// # Returns
+    /// The builder.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use webweg::types::{RateLimit, RateLimitScope};
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # fn main() {
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("jlinksessionidx=abc; itscookie=def")
+    ///     .with_rate_limit(
+    ///         RateLimit::new(2)
+    ///             .with_burst(5)
+    ///             .with_scope(RateLimitScope::PerTerm),
+    ///     )
+    ///     .try_build_wrapper();
+    ///
+    /// assert!(wrapper.is_some());
+    /// # }
+    /// ```
+    pub fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = Some(limit);
+        self
+    }
+
+    /// Checks the options given to this builder for problems, without building the wrapper.
+    /// This doesn't check whether the client can be built; `build_wrapper` reports that.
+    ///
+    /// # Returns
+    /// Every problem that was found, which is empty if there are none.
+    ///
+    /// # Example
+    /// ```rust
+    /// use webweg::types::BuilderProblem;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// let problems = WebRegWrapper::builder().with_cookies("  ").diagnose();
+    /// assert_eq!(vec![BuilderProblem::EmptyCookies], problems);
+    /// ```
+    pub fn diagnose(&self) -> Vec<BuilderProblem> {
+        let mut problems = vec![];
+        match self.cookies.as_deref() {
+            None => problems.push(BuilderProblem::MissingCookies),
+            Some(cookies) if cookies.trim().is_empty() => {
+                problems.push(BuilderProblem::EmptyCookies)
+            }
+            Some(cookies) => {
+                if let Some(reason) = find_cookie_problem(cookies) {
+                    problems.push(BuilderProblem::MalformedCookies(reason));
+                }
+            }
+        }
+
+        if self.default_timeout.is_zero() {
+            problems.push(BuilderProblem::ZeroTimeout);
+        }
+
+        if self.cookie_overrides && !self.close_after_request {
+            problems.push(BuilderProblem::CookieOverrideWithoutClose);
+        }
+
+        #[cfg(feature = "reqwest")]
+        if self.client.is_some()
+            && (self.accept_invalid_certs
+                || self.local_address.is_some()
+                || !self.root_certificates.is_empty())
+        {
+            problems.push(BuilderProblem::ClientOptionsIgnored);
+        }
+
+        #[cfg(not(feature = "reqwest"))]
+        if self.backend.is_none() {
+            problems.push(BuilderProblem::MissingBackend);
+        }
+
+        problems
+    }
+
+    /// Attempts to build the wrapper, failing if there are any problems with the options
+    /// given to this builder (see `diagnose`). Unlike `try_build_wrapper`, this also fails on
+    /// problems that wouldn't stop the wrapper from being built (e.g., malformed cookies),
+    /// and tells you what the problems are.
     ///
     /// # Returns
-    /// The `WebRegWrapper` if both the `cookies` and `term` are specified. If any of those
-    /// are not specified, `None` will be returned.
+    /// The `WebRegWrapper`, or every problem that was found.
+    ///
+    /// # Example
+    /// ```rust
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// match WebRegWrapper::builder()
+    ///     .with_cookies("jlinksessionidx=abc; itscookie=def")
+    ///     .build_wrapper()
+    /// {
+    ///     Ok(_) => println!("Built the wrapper."),
+    ///     Err(problems) => problems.iter().for_each(|p| eprintln!("{p}")),
+    /// }
+    /// ```
+    pub fn build_wrapper(self) -> Result<WebRegWrapper, Vec<BuilderProblem>> {
+        let problems = self.diagnose();
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+
+        self.assemble().map_err(|p| vec![p])
+    }
+
+    /// Attempts to build the wrapper. To successfully build the wrapper, the cookies must be
+    /// provided and must not be empty.
+    ///
+    /// This only fails on problems that make building the wrapper impossible (see
+    /// `BuilderProblem::is_fatal`), ignores every other problem that `diagnose` would report
+    /// (e.g., malformed cookies), and doesn't say what went wrong. Prefer `build_wrapper`,
+    /// which fails on every problem and returns the list of problems.
+    ///
+    /// # Returns
+    /// The `WebRegWrapper` if the cookies are specified. If they are not specified or are
+    /// empty, or the default client could not be built with the given options, `None` will
+    /// be returned.
     pub fn try_build_wrapper(self) -> Option<WebRegWrapper> {
+        if self.diagnose().iter().any(|p| p.is_fatal()) {
+            return None;
+        }
+
+        self.assemble().ok()
+    }
+}
+
+impl WebRegWrapperBuilder {
+    /// Prepares the builder for the blocking wrapper (see `blocking::WebRegWrapper`), which
+    /// waits by putting the thread to sleep instead of relying on a `tokio` runtime.
+    ///
+    /// # Parameters
+    /// - `client`: The blocking client to send requests with. If this isn't given, the
+    ///   builder's backend is used if it has one, and a default client is used otherwise.
+    ///
+    /// # Returns
+    /// The builder.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn into_blocking(mut self, client: Option<reqwest::blocking::Client>) -> Self {
+        if client.is_some() || self.backend.is_none() {
+            let client = client.unwrap_or_default();
+            self.backend = Some(Arc::new(ClientBackend::new(client, self.max_response_size)));
+        }
+
+        self.sleeper = thread_sleep;
+        self
+    }
+
+    /// Builds the wrapper, without checking the options for problems first (other than the
+    /// ones that make building the wrapper impossible).
+    ///
+    /// # Returns
+    /// The `WebRegWrapper`, or the problem that stopped it from being built.
+    pub(crate) fn assemble(self) -> Result<WebRegWrapper, BuilderProblem> {
+        #[cfg(feature = "reqwest")]
+        let client = self.build_client()?;
+        #[cfg(not(feature = "reqwest"))]
+        if self.backend.is_none() {
+            return Err(BuilderProblem::MissingBackend);
+        }
+        let user_agent = self.build_user_agent();
         if let Some(cookies) = self.cookies {
-            Some(WebRegWrapper {
+            Ok(WebRegWrapper {
                 data: WebRegWrapperData {
                     #[cfg(feature = "multi")]
                     cookies: Mutex::new(cookies),
                     #[cfg(not(feature = "multi"))]
                     cookies,
-                    client: self.client,
-                    user_agent: self.user_agent,
+                    #[cfg(feature = "reqwest")]
+                    client,
+                    user_agent,
                     timeout: self.default_timeout,
                     close_after_request: self.close_after_request,
+                    cache_buster: self.cache_buster,
+                    stats: StatsTracker::default(),
+                    errors: ErrorCollector::new(self.collect_errors),
+                    actions: ActionRecorder::new(self.record_actions, self.action_log_capacity),
+                    on_session_invalid: self.on_session_invalid,
+                    on_parse_warning: self.on_parse_warning,
+                    strict_parsing: self.strict_parsing,
+                    always_validate_enroll: self.always_validate_enroll,
+                    always_validate_plan: self.always_validate_plan,
+                    seat_strategy: self.seat_strategy,
+                    pacer: Pacer::new(self.pacing),
+                    rate_limiter: self.rate_limit.and_then(RateLimiter::new),
+                    clock: self.clock,
+                    catalog_cache: CatalogCache::new(self.catalog_cache_ttl),
+                    associated_terms: TermTracker::default(),
+                    schedule_cache: ScheduleCache::default(),
+                    quota: QuotaGuard::new(self.quota),
+                    closed_waitlists: WaitlistTracker::default(),
+                    cookie_refresher: self.cookie_provider.map(CookieRefresher::new),
+                    attempt_sink: self.attempt_sink,
+                    backend: self.backend,
+                    max_response_size: self.max_response_size,
+                    sleeper: self.sleeper,
                 },
             })
         } else {
-            None
+            Err(BuilderProblem::MissingCookies)
         }
     }
+
+    /// Gets the user agent that the wrapper should use, with the contact information (if any)
+    /// added as a comment.
+    ///
+    /// # Returns
+    /// The user agent.
+    fn build_user_agent(&self) -> String {
+        let contact = match self.contact_info.as_deref() {
+            Some(contact) => contact
+                .chars()
+                .filter(|c| (c.is_ascii_graphic() || *c == ' ') && *c != '(' && *c != ')')
+                .collect::<String>(),
+            None => return self.user_agent.clone(),
+        };
+
+        let contact = contact.trim();
+        if contact.is_empty() {
+            self.user_agent.clone()
+        } else {
+            format!("{} (contact: {contact})", self.user_agent)
+        }
+    }
+
+    /// Gets the client that the wrapper should use. If no client was provided, this will build
+    /// one using the options given to this builder.
+    ///
+    /// # Returns
+    /// The client, or the problem if the client could not be built.
+    #[cfg(feature = "reqwest")]
+    fn build_client(&self) -> Result<Client, BuilderProblem> {
+        if let Some(client) = &self.client {
+            return Ok(client.clone());
+        }
+
+        let mut builder = Client::builder()
+            .danger_accept_invalid_certs(self.accept_invalid_certs)
+            .local_address(self.local_address);
+        #[cfg(feature = "compression")]
+        {
+            builder = builder.gzip(self.compression).brotli(self.compression);
+        }
+
+        for cert in &self.root_certificates {
+            builder = builder.add_root_certificate(cert.clone());
+        }
+
+        builder
+            .build()
+            .map_err(|e| BuilderProblem::ClientBuildFailed(e.to_string()))
+    }
+}
+
+/// Checks whether the cookies look like a cookie header (i.e., `name=value` pairs separated
+/// by `;`).
+///
+/// # Parameters
+/// - `cookies`: The cookies.
+///
+/// # Returns
+/// What's wrong with the cookies, or `None` if they look fine.
+fn find_cookie_problem(cookies: &str) -> Option<String> {
+    if cookies.chars().any(|c| c.is_control()) {
+        return Some("contains control characters (e.g., a newline)".into());
+    }
+
+    for pair in cookies.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+        match pair.split_once('=') {
+            None => return Some(format!("'{pair}' is not a name=value pair")),
+            Some((name, _)) if name.trim().is_empty() => {
+                return Some(format!("'{pair}' has no name"));
+            }
+            Some((name, _)) if name.trim().contains(char::is_whitespace) => {
+                return Some(format!("'{}' is not a valid cookie name", name.trim()));
+            }
+            _ => {}
+        }
+    }
+
+    None
 }
 
 impl Default for WebRegWrapperBuilder {
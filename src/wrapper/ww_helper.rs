@@ -1,35 +1,67 @@
-use reqwest::{Error, Response};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 use url::Url;
 
-use crate::constants::{ELIGIBILITY, STATUS_START, VERIFY_FAIL_ERR};
-use crate::types::WrapperError;
+use crate::constants::{ELIGIBILITY, STATUS_START, VERIFY_FAIL_ERR, VERIFY_FAIL_MSG};
+use crate::types;
+use crate::types::{HttpResponse, WebRegOpsResponse, WrapperError};
 use crate::util::get_term_seq_id;
-use crate::wrapper::request_data::{ReqType, ReqwestWebRegClientData};
-use crate::{types, util};
+use crate::wrapper::request_data::{get_endpoint_name, ReqType, ReqwestWebRegClientData};
 
-/// Extracts text from the given response, handling the possibility that a bad status code
-/// or a verification error occurs.
+/// Checks whether the given response text is an HTML page (most likely, the login page that
+/// WebReg redirects to when the session has expired) rather than the expected data.
 ///
 /// # Parameters
+/// - `text`: The response text.
+///
+/// # Returns
+/// Whether the text is an HTML page.
+fn is_html_page(text: &str) -> bool {
+    let text = text.trim_start();
+    text.starts_with('<') && text.to_lowercase().contains("<html")
+}
+
+/// Checks whether the given response means that the session is no longer valid, without
+/// reading the body. This is the case if WebReg responded with `401` or `403`, or if the
+/// request was redirected away from WebReg (most likely, to the login page).
+///
+/// # Parameters
+/// - `res`: The response.
+/// - `host`: The host that the request was made to, if known.
+///
+/// # Returns
+/// Whether the session is no longer valid.
+pub(crate) fn is_auth_failure(res: &HttpResponse, host: Option<&str>) -> bool {
+    let url = Url::parse(&res.url).ok();
+    matches!(res.status, 401 | 403)
+        || host.is_some_and(|h| url.as_ref().and_then(Url::host_str) != Some(h))
+}
+
+/// Extracts text from the given response, handling the possibility that a bad status code,
+/// a verification error, or an expired session occurs.
+///
+/// # Parameters
+/// - `obj`: A reference to the object that made the request. If the session has expired, the
+///   session-invalid callback (if any) will be called.
 /// - `res`: The initial response.
 ///
 /// # Returns
 /// The result of processing the response.
-pub(crate) async fn extract_text(res: Result<Response, Error>) -> types::Result<String> {
+pub(crate) async fn extract_text<'a>(
+    obj: &'a impl ReqwestWebRegClientData<'a>,
+    res: types::Result<HttpResponse>,
+) -> types::Result<String> {
     let r = res?;
-    let status_code = r.status();
-    if !status_code.is_success() {
-        let text = r.text().await.ok();
-        return Err(WrapperError::BadStatusCode(status_code.as_u16(), text));
+    if !r.is_success() {
+        return Err(WrapperError::BadStatusCode(r.status, Some(r.text())));
     }
 
-    let text = r.text().await?;
-    if text.contains(VERIFY_FAIL_ERR) {
-        Err(WrapperError::WebRegError(
-            "Verification error: register your term using the `associate_term` function.".into(),
-        ))
+    let text = r.text();
+    if is_html_page(&text) {
+        obj.notify_session_invalid();
+        Err(WrapperError::SessionExpired)
+    } else if text.contains(VERIFY_FAIL_ERR) {
+        Err(WrapperError::WebRegError(VERIFY_FAIL_MSG.into()))
     } else {
         Ok(text)
     }
@@ -51,69 +83,101 @@ pub(crate) fn process_get_text<T: DeserializeOwned>(res: String) -> types::Resul
 /// - `process_get_text`.
 ///
 /// # Parameters
+/// - `obj`: A reference to the object that made the request.
 /// - `res`: The initial response.
 ///
 /// # Returns
 /// The result of processing the response.
-pub(crate) async fn process_get_result<T: DeserializeOwned>(
-    res: Result<Response, Error>,
+pub(crate) async fn process_get_result<'a, T: DeserializeOwned>(
+    obj: &'a impl ReqwestWebRegClientData<'a>,
+    res: types::Result<HttpResponse>,
 ) -> types::Result<T> {
-    let r = extract_text(res).await?;
+    let r = extract_text(obj, res).await?;
     process_get_text(r)
 }
 
-/// Processes a POST response from the resulting JSON, if any.
+/// Extracts WebReg's response to a POST request, handling the possibility that a bad status
+/// code or an expired session occurs. Unlike `process_post_response`, a response saying that
+/// the operation failed is not turned into an error.
 ///
 /// # Parameters
+/// - `obj`: A reference to the object that made the request.
 /// - `res`: The initial response.
 ///
 /// # Returns
-/// Either one of:
-/// - `true` or `false`, depending on what WebReg returns.
-/// - or some error message if an error occurred.
-pub(crate) async fn process_post_response(res: Result<Response, Error>) -> types::Result<bool> {
+/// The endpoint that the request was made to, and WebReg's response.
+pub(crate) async fn extract_ops_response<'a>(
+    obj: &'a impl ReqwestWebRegClientData<'a>,
+    res: types::Result<HttpResponse>,
+) -> types::Result<(String, WebRegOpsResponse)> {
+    // Whether or not the request went through, the schedule may have changed.
+    obj.get_schedule_cache().invalidate();
     let r = res?;
-    let status_code = r.status();
-    if !status_code.is_success() {
-        let text = r.text().await.ok();
-        return Err(WrapperError::BadStatusCode(status_code.as_u16(), text));
+    let endpoint = get_endpoint_name(&r.url);
+    if !r.is_success() {
+        return Err(WrapperError::BadStatusCode(r.status, Some(r.text())));
     }
 
-    let text = r.text().await?;
-    // Unwrap should not be a problem since we should be getting a valid JSON response
-    // every time.
-    let json: Value = serde_json::from_str(&text)?;
-    if json["OPS"].is_string() && json["OPS"].as_str().unwrap() == "SUCCESS" {
-        return Ok(true);
+    let text = r.text();
+    if is_html_page(&text) {
+        obj.notify_session_invalid();
+        return Err(WrapperError::SessionExpired);
     }
 
-    // Purely to handle an error
-    let mut parsed_str = String::new();
-    let mut is_in_brace = false;
-    json["REASON"]
-        .as_str()
-        .unwrap_or("")
-        .trim()
-        .chars()
-        .for_each(|c| {
-            if c == '<' {
-                is_in_brace = true;
-                return;
-            }
-
-            if c == '>' {
-                is_in_brace = false;
-                return;
-            }
+    Ok((endpoint, serde_json::from_str(&text)?))
+}
 
-            if is_in_brace {
-                return;
-            }
+/// Processes a POST response from the resulting JSON, if any.
+///
+/// # Parameters
+/// - `obj`: A reference to the object that made the request. If error collection is enabled,
+///   any error reason from WebReg will be recorded here.
+/// - `res`: The initial response.
+///
+/// # Returns
+/// Either one of:
+/// - `true`, if WebReg says that the operation went through.
+/// - or some error if an error occurred. If WebReg rejected the operation, this will be a
+///   `WebRegRejected` error containing WebReg's full response.
+pub(crate) async fn process_post_response<'a>(
+    obj: &'a impl ReqwestWebRegClientData<'a>,
+    res: types::Result<HttpResponse>,
+) -> types::Result<bool> {
+    let (endpoint, ops) = extract_ops_response(obj, res).await?;
+    if ops.is_success() {
+        return Ok(true);
+    }
 
-            parsed_str.push(c);
-        });
+    obj.get_error_collector()
+        .record(endpoint, &ops.reason_text());
+    Err(WrapperError::WebRegRejected(Box::new(ops)))
+}
 
-    Err(WrapperError::WebRegError(parsed_str))
+/// Classifies an error from planning a section. If WebReg said that the section is already
+/// planned, the error is turned into an `AlreadyPlanned` error. Otherwise, the error is
+/// returned as is.
+///
+/// WebReg doesn't give an error code for this, only a message, so the message is matched
+/// on. This lets callers (e.g., ones that retry `add_to_plan`) tell an already-planned section
+/// apart from other rejections. A planned section can be changed with `edit_plan` instead.
+///
+/// # Parameters
+/// - `err`: The error.
+///
+/// # Returns
+/// The classified error.
+pub(crate) fn classify_plan_error(err: WrapperError) -> WrapperError {
+    match err.webreg_reason() {
+        Some(reason) => {
+            let lower = reason.to_lowercase();
+            if lower.contains("already") && (lower.contains("plan") || lower.contains("schedule")) {
+                WrapperError::AlreadyPlanned(reason)
+            } else {
+                err
+            }
+        }
+        None => err,
+    }
 }
 
 /// Associates a particular term to an instance that implements the `ReqwestClientWrapper`
@@ -130,6 +194,23 @@ pub(crate) async fn associate_term_helper<'a>(
     obj: &'a impl ReqwestWebRegClientData<'a>,
     term: impl AsRef<str>,
 ) -> types::Result<()> {
+    fetch_term_status(obj, term).await.map(|_| ())
+}
+
+/// Calls the endpoints that associate a particular term to an instance that implements the
+/// `ReqwestClientWrapper` trait (see `associate_term_helper`), keeping what they respond with.
+///
+/// # Parameters
+/// - `obj`: A reference to an object implementing the `ReqwestClientWrapper` trait.
+/// - `term`: The term to associate with your session cookies.
+///
+/// # Returns
+/// The responses from the term status and eligibility endpoints, in that order, or an error
+/// if something went wrong.
+pub(crate) async fn fetch_term_status<'a>(
+    obj: &'a impl ReqwestWebRegClientData<'a>,
+    term: impl AsRef<str>,
+) -> types::Result<[Value; 2]> {
     let term = term.as_ref().to_uppercase();
     let seq_id = get_term_seq_id(&term);
     if seq_id == 0 {
@@ -138,28 +219,28 @@ pub(crate) async fn associate_term_helper<'a>(
 
     let seqid_str = seq_id.to_string();
     // Step 1: call get_status_start endpoint
-    let status_start_url = Url::parse_with_params(
+    let status_start_url = obj.build_url(
         STATUS_START,
-        &[
-            ("termcode", term.as_str()),
-            ("seqid", seqid_str.as_str()),
-            ("_", util::get_epoch_time().to_string().as_str()),
-        ],
+        &[("termcode", term.as_str()), ("seqid", seqid_str.as_str())],
     )?;
 
-    process_get_result::<Value>(obj.req(ReqType::Get(status_start_url)).send().await).await?;
+    let status =
+        process_get_result::<Value>(obj, obj.req(ReqType::Get(status_start_url)).send().await)
+            .await?;
 
     // Step 2: call eligibility endpoint
-    let eligibility_url = Url::parse_with_params(
+    let eligibility_url = obj.build_url(
         ELIGIBILITY,
         &[
             ("termcode", term.as_str()),
             ("seqid", seqid_str.as_str()),
             ("logged", "true"),
-            ("_", util::get_epoch_time().to_string().as_str()),
         ],
     )?;
 
-    process_get_result::<Value>(obj.req(ReqType::Get(eligibility_url)).send().await).await?;
-    Ok(())
+    let eligibility =
+        process_get_result::<Value>(obj, obj.req(ReqType::Get(eligibility_url)).send().await)
+            .await?;
+    obj.get_term_tracker().insert(&term);
+    Ok([status, eligibility])
 }
@@ -1,20 +1,71 @@
 use std::cmp::max;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::SystemTime;
 
+use serde_json::value::RawValue;
 use url::Url;
 
 use crate::constants::*;
 use crate::raw_types::{
-    RawCoursePrerequisite, RawEvent, RawPrerequisite, RawScheduledMeeting, RawWebRegMeeting,
+    RawCoursePrerequisite, RawEvent, RawPrerequisite, RawScheduledMeeting, RawSeatCount,
+    RawWebRegMeeting, RawWebRegMeetingRef, RawWebRegSearchResultItem,
 };
 use crate::types::{
-    CoursePrerequisite, CourseSection, Courses, EnrollmentStatus, Event, Events, Meeting,
-    MeetingDay, PrerequisiteInfo, Schedule, ScheduledSection, TimeType, WrapperError,
+    CoursePrerequisite, CourseSection, Courses, EnrollmentPhases, EnrollmentStatus, Event, Events,
+    Meeting, MeetingDay, MinimumGrade, ParseWarning, PrerequisiteInfo, Schedule, ScheduledSection,
+    SearchResult, SearchResultItem, SeatCount, SectionFamily, SpecialMeetingCode, TimeType,
+    WrapperError,
 };
 use crate::util::parse_binary_days;
-use crate::wrapper::input_types::SearchType;
+use crate::wrapper::input_types::{GradeOption, SearchType};
 use crate::{types, util};
 
+/// Deserializes the raw meetings returned by WebReg's course information endpoint. Unlike
+/// deserializing the entire list at once, a meeting that can't be deserialized (e.g., because
+/// WebReg gave a malformed row) is skipped and reported as a warning, so that one bad meeting
+/// doesn't prevent the rest of the course from being parsed.
+///
+/// # Parameters
+/// - `text`: The response from WebReg, which should be a JSON array of meetings.
+///
+/// # Returns
+/// The meetings that could be deserialized along with a warning for each meeting that
+/// couldn't, or an error if the response isn't a JSON array at all.
+pub fn parse_raw_meetings(text: &str) -> types::Result<(Vec<RawWebRegMeeting>, Vec<ParseWarning>)> {
+    parse_raw_meetings_from_slice(text.as_bytes())
+}
+
+/// Deserializes the raw meetings returned by WebReg's course information endpoint, directly
+/// from the response bytes. See `parse_raw_meetings` for how malformed meetings are handled.
+///
+/// Each meeting is first deserialized as a `RawWebRegMeetingRef` that borrows its strings from
+/// `bytes`, so large responses don't need an intermediate copy of every value.
+///
+/// # Parameters
+/// - `bytes`: The response from WebReg, which should be a JSON array of meetings.
+///
+/// # Returns
+/// The meetings that could be deserialized along with a warning for each meeting that
+/// couldn't, or an error if the response isn't a JSON array at all.
+pub fn parse_raw_meetings_from_slice(
+    bytes: &[u8],
+) -> types::Result<(Vec<RawWebRegMeeting>, Vec<ParseWarning>)> {
+    let rows = serde_json::from_slice::<Vec<&RawValue>>(bytes)?;
+    let mut meetings = Vec::with_capacity(rows.len());
+    let mut warnings = vec![];
+    for (index, row) in rows.into_iter().enumerate() {
+        match serde_json::from_str::<RawWebRegMeetingRef>(row.get()) {
+            Ok(meeting) => meetings.push(meeting.into_owned()),
+            Err(e) => warnings.push(ParseWarning::MalformedMeeting {
+                index,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    Ok((meetings, warnings))
+}
+
 /// Processes the vector containing raw prerequisites information.
 ///
 /// # Parameters
@@ -36,9 +87,7 @@ pub fn parse_prerequisites(res: Vec<RawPrerequisite>) -> types::Result<Prerequis
     for r in &res {
         match r {
             RawPrerequisite::Course(c) => req_map.entry(&c.prereq_seq_id).or_insert(vec![]).push(c),
-            RawPrerequisite::Test(t) => all_reqs
-                .exam_prerequisites
-                .push(t.test_title.trim().to_string()),
+            RawPrerequisite::Test(t) => all_reqs.exam_prerequisites.push(t.test_title.clone()),
         }
     }
 
@@ -46,8 +95,9 @@ pub fn parse_prerequisites(res: Vec<RawPrerequisite>) -> types::Result<Prerequis
         let mut cleaned_reqs: Vec<CoursePrerequisite> = vec![];
         for req in reqs {
             cleaned_reqs.push(CoursePrerequisite {
-                subj_course_id: format!("{} {}", req.subject_code.trim(), req.course_code.trim()),
-                course_title: req.course_title.trim().to_string(),
+                subj_course_id: format!("{} {}", req.subject_code, req.course_code),
+                course_title: req.course_title.clone(),
+                min_grade: req.grade_seq_id.parse().ok().map(MinimumGrade::from_code),
             });
         }
 
@@ -57,6 +107,46 @@ pub fn parse_prerequisites(res: Vec<RawPrerequisite>) -> types::Result<Prerequis
     Ok(all_reqs)
 }
 
+/// Processes the vector containing the raw scheduled meeting objects, keeping track of any
+/// data-quality issues that were found along the way.
+///
+/// # Parameters
+/// - `res`: The vector of raw scheduled meeting objects.
+///
+/// # Returns
+/// Either the parsed schedule information along with any warnings, or an error.
+pub fn parse_schedule_with_warnings(
+    res: Vec<RawScheduledMeeting>,
+) -> types::Result<(Schedule, Vec<ParseWarning>)> {
+    let mut warnings = vec![];
+    let mut seen_status = HashSet::new();
+    let mut seen_grade = HashSet::new();
+    for meeting in &res {
+        let status = meeting.enroll_status.as_str();
+        if ![STATUS_ENROLL, STATUS_WAITLIST, STATUS_PLANNED].contains(&status)
+            && seen_status.insert(meeting.section_id)
+        {
+            warnings.push(ParseWarning::UnknownEnrollStatus {
+                section_id: meeting.section_id.to_string(),
+                enroll_status: status.to_string(),
+            });
+        }
+
+        let grade = meeting.grade_option.trim();
+        if !grade.is_empty()
+            && grade.parse::<GradeOption>().is_err()
+            && seen_grade.insert(meeting.section_id)
+        {
+            warnings.push(ParseWarning::UnknownGradeOption {
+                section_id: meeting.section_id.to_string(),
+                grade_option: grade.to_string(),
+            });
+        }
+    }
+
+    Ok((parse_schedule(res)?, warnings))
+}
+
 /// Processes the vector containing the raw scheduled meeting objects.
 ///
 /// # Parameters
@@ -79,9 +169,14 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
             continue;
         }
 
-        if s_meeting.sect_code.as_bytes()[0].is_ascii_digit() {
+        if s_meeting
+            .sect_code
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_digit())
+        {
             special_classes
-                .entry(s_meeting.course_title.trim())
+                .entry(s_meeting.course_title.as_str())
                 .or_insert_with(Vec::new)
                 .push(s_meeting);
 
@@ -89,7 +184,7 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
         }
 
         base_group_secs
-            .entry(s_meeting.course_title.trim())
+            .entry(s_meeting.course_title.as_str())
             .or_insert_with(Vec::new)
             .push(s_meeting);
     }
@@ -134,10 +229,10 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
         for main in all_main {
             all_meetings.push(Meeting {
                 meeting_type: main.meeting_type.to_string(),
-                meeting_days: if main.day_code.trim().is_empty() {
+                meeting_days: if main.day_code.is_empty() {
                     MeetingDay::None
                 } else {
-                    MeetingDay::Repeated(util::parse_day_code(main.day_code.trim()))
+                    MeetingDay::Repeated(util::parse_day_code(&main.day_code))
                 },
                 start_min: TimeType::try_from(main.start_time_min)
                     .map_err(|_| WrapperError::BadTimeError)?,
@@ -147,8 +242,8 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
                     .map_err(|_| WrapperError::BadTimeError)?,
                 end_hr: TimeType::try_from(main.end_time_hr)
                     .map_err(|_| WrapperError::BadTimeError)?,
-                building: main.bldg_code.trim().to_string(),
-                room: main.room_code.trim().to_string(),
+                building: main.bldg_code.clone(),
+                room: main.room_code.clone(),
                 instructors: util::get_instructor_names(&main.person_full_name),
             });
         }
@@ -173,8 +268,8 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
                         .map_err(|_| WrapperError::BadTimeError)?,
                     end_hr: TimeType::try_from(x.end_time_hr)
                         .map_err(|_| WrapperError::BadTimeError)?,
-                    building: x.bldg_code.trim().to_string(),
-                    room: x.room_code.trim().to_string(),
+                    building: x.bldg_code.clone(),
+                    room: x.room_code.clone(),
                     instructors: util::get_instructor_names(&x.person_full_name),
                 })
             })
@@ -198,8 +293,8 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
                         .map_err(|_| WrapperError::BadTimeError)?,
                     end_hr: TimeType::try_from(x.end_time_hr)
                         .map_err(|_| WrapperError::BadTimeError)?,
-                    building: x.bldg_code.trim().to_string(),
-                    room: x.room_code.trim().to_string(),
+                    building: x.bldg_code.clone(),
+                    room: x.room_code.clone(),
                     instructors: util::get_instructor_names(&x.person_full_name),
                 })
             })
@@ -237,9 +332,9 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
                 schedule.push(ScheduledSection {
                     section_id: data.section_id.to_string(),
                     all_instructors: instructors.clone(),
-                    subject_code: data.subj_code.trim().to_string(),
-                    course_code: data.course_code.trim().to_string(),
-                    course_title: data.course_title.trim().to_string(),
+                    subject_code: data.subj_code.clone(),
+                    course_code: data.course_code.clone(),
+                    course_title: data.course_title.clone(),
                     section_code: match sch_meetings.iter().find(|x| !x.sect_code.ends_with("00")) {
                         Some(r) => r.sect_code.to_string(),
                         None => data.sect_code.to_string(),
@@ -247,7 +342,8 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
                     section_capacity,
                     enrolled_count,
                     available_seats: max(section_capacity - enrolled_count, 0),
-                    grade_option: data.grade_option.to_string(),
+                    grade_option: parse_grade_option(&data.grade_option),
+                    grade_option_locked: is_grade_option_locked(data),
                     units: data.sect_credit_hrs.trunc() as i64,
                     enrolled_status: match data.enroll_status.as_str() {
                         STATUS_ENROLL => EnrollmentStatus::Enrolled,
@@ -269,7 +365,7 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
     for (_, sch_meetings) in special_classes {
         let day_code = sch_meetings
             .iter()
-            .map(|x| x.day_code.trim())
+            .map(|x| x.day_code.as_str())
             .collect::<Vec<_>>()
             .join("");
 
@@ -289,14 +385,15 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
                     .iter()
                     .flat_map(|x| util::get_instructor_names(&x.person_full_name)),
             ),
-            subject_code: sch_meetings[0].subj_code.trim().to_string(),
-            course_code: sch_meetings[0].course_code.trim().to_string(),
-            course_title: sch_meetings[0].course_title.trim().to_string(),
+            subject_code: sch_meetings[0].subj_code.clone(),
+            course_code: sch_meetings[0].course_code.clone(),
+            course_title: sch_meetings[0].course_title.clone(),
             section_code: sch_meetings[0].sect_code.to_string(),
             section_capacity,
             enrolled_count,
             available_seats: max(section_capacity - enrolled_count, 0),
-            grade_option: sch_meetings[0].grade_option.trim().to_string(),
+            grade_option: parse_grade_option(&sch_meetings[0].grade_option),
+            grade_option_locked: is_grade_option_locked(sch_meetings[0]),
             units: sch_meetings[0].sect_credit_hrs.trunc() as i64,
             enrolled_status: match sch_meetings[0].enroll_status.as_str() {
                 STATUS_ENROLL => EnrollmentStatus::Enrolled,
@@ -318,8 +415,8 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
                     .map_err(|_| WrapperError::BadTimeError)?,
                 end_hr: TimeType::try_from(sch_meetings[0].start_time_hr)
                     .map_err(|_| WrapperError::BadTimeError)?,
-                building: sch_meetings[0].bldg_code.trim().to_string(),
-                room: sch_meetings[0].room_code.trim().to_string(),
+                building: sch_meetings[0].bldg_code.clone(),
+                room: sch_meetings[0].room_code.clone(),
                 instructors: util::get_instructor_names(&sch_meetings[0].person_full_name),
             }],
         });
@@ -343,8 +440,27 @@ pub fn parse_enrollment_count(
     meetings: Vec<RawWebRegMeeting>,
     subj_num: String,
 ) -> types::Result<Courses> {
+    parse_enrollment_count_with_warnings(meetings, subj_num).map(|(sections, _)| sections)
+}
+
+/// Processes the vector containing raw meeting information into enrollment count
+/// information, keeping track of any data-quality issues that were found along
+/// the way.
+///
+/// # Parameters
+/// - `meetings`: The vector of meetings.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+///
+/// # Returns
+/// Either the parsed enrollment count information along with any warnings, or
+/// an error.
+pub fn parse_enrollment_count_with_warnings(
+    meetings: Vec<RawWebRegMeeting>,
+    subj_num: String,
+) -> types::Result<(Courses, Vec<ParseWarning>)> {
+    let mut warnings = vec![];
     if meetings.is_empty() {
-        return Ok(vec![]);
+        return Ok((vec![], warnings));
     }
 
     // First, remove any duplicate meetings. For example, some courses may only have sections
@@ -368,23 +484,57 @@ pub fn parse_enrollment_count(
         meetings_to_parse.push(meeting);
     }
 
-    Ok(meetings_to_parse
+    let sections = meetings_to_parse
         .into_iter()
         // Only want available sections, AC = displayed
         .filter(|x| x.display_type == "AC")
         .map(|x| CourseSection {
             is_visible: x.is_visible(),
             subj_course_id: subj_num.to_owned(),
-            section_id: x.section_id.trim().to_string(),
-            section_code: x.sect_code.trim().to_string(),
-            all_instructors: util::get_instructor_names(&x.person_full_name),
-            available_seats: max(x.avail_seat, 0),
+            section_id: x.section_id.clone(),
+            section_code: x.sect_code.clone(),
+            all_instructors: util::get_all_instructors(
+                util::get_instructor_names(&x.person_full_name).into_iter(),
+            ),
+            available_seats: clamp_available_seats(x, &mut warnings),
+            available_seats_raw: x.avail_seat,
+            instructor_info: BTreeMap::new(),
+            section_notes: vec![],
+            waitlist_open: true,
             enrolled_ct: x.enrolled_count,
             total_seats: x.section_capacity,
             waitlist_ct: x.count_on_waitlist,
             meetings: vec![],
         })
-        .collect())
+        .collect();
+
+    Ok((sections, warnings))
+}
+
+/// Processes the vector containing the raw seat counts. This is the fast path of
+/// `parse_enrollment_count`, for when only the seat counts are needed.
+///
+/// # Parameters
+/// - `meetings`: The vector of seat counts.
+///
+/// # Returns
+/// The seat counts for each section that can be enrolled in.
+pub fn parse_seat_counts(meetings: Vec<RawSeatCount>) -> Vec<SeatCount> {
+    // Like with `parse_enrollment_count`, meetings with the same section code (e.g., the
+    // lecture and final exam for section A00) have the same counts.
+    let mut seen: HashSet<String> = HashSet::new();
+    meetings
+        .into_iter()
+        .filter(|x| seen.insert(x.sect_code.clone()))
+        // Only want available sections, AC = displayed
+        .filter(|x| x.display_type == "AC")
+        .map(|x| SeatCount {
+            section_code: x.sect_code.clone(),
+            enrolled_ct: x.enrolled_count,
+            available_seats: max(x.avail_seat, 0),
+            waitlist_ct: x.count_on_waitlist,
+        })
+        .collect()
 }
 
 pub enum CourseInfoType {
@@ -427,7 +577,75 @@ pub fn parse_course_info(
     parsed: Vec<RawWebRegMeeting>,
     subj_num: String,
 ) -> types::Result<Courses> {
-    let mut sections: Courses = vec![];
+    parse_course_info_with_warnings(parsed, subj_num).map(|(sections, _)| sections)
+}
+
+/// Processes the vector containing raw meeting information into parsed
+/// course information, keeping track of any data-quality issues that were
+/// found along the way.
+///
+/// # Parameters
+/// - `meetings`: The vector of meetings.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+///
+/// # Returns
+/// Either the parsed course information along with any warnings, or an error.
+pub fn parse_course_info_with_warnings(
+    parsed: Vec<RawWebRegMeeting>,
+    subj_num: String,
+) -> types::Result<(Courses, Vec<ParseWarning>)> {
+    let (groups, warnings) = group_course_info(parsed, subj_num)?;
+    Ok((
+        groups
+            .into_iter()
+            .flat_map(CourseGroup::into_sections)
+            .collect(),
+        warnings,
+    ))
+}
+
+/// Sections of a course that share the same meetings, as grouped by `group_course_info`.
+pub(crate) struct CourseGroup {
+    /// The section family (e.g., `A`). For sections that don't belong to a family (e.g.,
+    /// `001`), this is the section code.
+    pub family: String,
+    /// The meetings shared by every section in the group (e.g., the lecture and final exam).
+    pub shared: Vec<Meeting>,
+    /// The sections in the group, each with only the meetings that are specific to it (e.g.,
+    /// its discussion).
+    pub sections: Vec<CourseSection>,
+}
+
+impl CourseGroup {
+    /// Gives each section in the group all of its meetings, shared meetings first.
+    ///
+    /// # Returns
+    /// The sections.
+    pub(crate) fn into_sections(self) -> impl Iterator<Item = CourseSection> {
+        let shared = self.shared;
+        self.sections.into_iter().map(move |mut section| {
+            section.meetings.splice(0..0, shared.iter().cloned());
+            section
+        })
+    }
+}
+
+/// Processes the vector containing raw meeting information into groups of sections that share
+/// the same meetings (see `parse_course_info_with_warnings`).
+///
+/// # Parameters
+/// - `meetings`: The vector of meetings.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+///
+/// # Returns
+/// Either the groups, in the order that `parse_course_info` gives the sections, along with any
+/// warnings, or an error.
+pub(crate) fn group_course_info(
+    parsed: Vec<RawWebRegMeeting>,
+    subj_num: String,
+) -> types::Result<(Vec<CourseGroup>, Vec<ParseWarning>)> {
+    let mut groups: Vec<CourseGroup> = vec![];
+    let mut warnings: Vec<ParseWarning> = vec![];
     let mut unprocessed_meetings: Vec<RawWebRegMeeting> = vec![];
 
     // First, let's determine which meetings only have numerical section codes (e.g., 001).
@@ -437,26 +655,39 @@ pub fn parse_course_info(
     for meeting in parsed {
         // If the meeting is canceled, then we do not need to check anything else.
         // Likewise, if the section code doesn't exist, then we can't process it.
-        if meeting.display_type == "CA" || meeting.sect_code.trim().is_empty() {
+        if meeting.display_type == "CA" || meeting.sect_code.is_empty() {
             continue;
         }
 
+        check_day_code(&meeting, &mut warnings);
+        check_special_meeting(&meeting, &mut warnings);
+
         // Next, we check to see if the meeting is a special meeting. To do so, we can just
         // check to make sure the first character in the section code is a digit (e.g. *0*01)
         if meeting.sect_code.as_bytes()[0].is_ascii_digit() {
             let (m_type, m_days) = util::parse_meeting_type_date(&meeting);
-            sections.push(CourseSection {
+            let section = CourseSection {
                 is_visible: meeting.is_visible(),
                 subj_course_id: subj_num.to_owned(),
-                section_id: meeting.section_id.trim().to_string(),
-                section_code: meeting.sect_code.trim().to_string(),
-                all_instructors: util::get_instructor_names(&meeting.person_full_name),
+                section_id: meeting.section_id.clone(),
+                section_code: meeting.sect_code.clone(),
+                all_instructors: util::get_all_instructors(
+                    util::get_instructor_names(&meeting.person_full_name).into_iter(),
+                ),
                 // Because it turns out that you can have negative available seats.
-                available_seats: max(meeting.avail_seat, 0),
+                available_seats: clamp_available_seats(&meeting, &mut warnings),
+                available_seats_raw: meeting.avail_seat,
+                instructor_info: BTreeMap::new(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: meeting.enrolled_count,
                 total_seats: meeting.section_capacity,
                 waitlist_ct: meeting.count_on_waitlist,
-                meetings: vec![Meeting {
+                meetings: vec![],
+            };
+            groups.push(CourseGroup {
+                family: meeting.sect_code.clone(),
+                shared: vec![Meeting {
                     start_hr: TimeType::try_from(meeting.start_time_hr)
                         .map_err(|_| WrapperError::BadTimeError)?,
                     start_min: TimeType::try_from(meeting.start_time_min)
@@ -467,10 +698,11 @@ pub fn parse_course_info(
                         .map_err(|_| WrapperError::BadTimeError)?,
                     meeting_type: m_type.to_string(),
                     meeting_days: m_days,
-                    building: meeting.bldg_code.trim().to_string(),
-                    room: meeting.room_code.trim().to_string(),
+                    building: meeting.bldg_code.clone(),
+                    room: meeting.room_code.clone(),
                     instructors: util::get_instructor_names(&meeting.person_full_name),
                 }],
+                sections: vec![section],
             });
 
             continue;
@@ -482,7 +714,7 @@ pub fn parse_course_info(
 
     // If there is nothing left to process, then we're done!
     if unprocessed_meetings.is_empty() {
-        return Ok(sections);
+        return Ok((groups, warnings));
     }
 
     struct GroupedSection<'a, T> {
@@ -539,7 +771,10 @@ pub fn parse_course_info(
             // and related, these discussion sections will have section codes like A01, A02,
             // and so on.
             "NC" => entry.general_meetings.push(meeting),
-            _ => continue,
+            _ => warnings.push(ParseWarning::UnknownDisplayType {
+                section_code: meeting.sect_code.clone(),
+                display_type: meeting.display_type.clone(),
+            }),
         };
     }
 
@@ -559,7 +794,7 @@ pub fn parse_course_info(
         let entry = &map[key];
         if entry.general_meetings.is_empty() {
             // This should never hit, but sanity check nonetheless.
-            dbg!(&subj_num);
+            warnings.push(ParseWarning::MissingGeneralMeeting { family: *key });
             continue;
         }
 
@@ -584,8 +819,8 @@ pub fn parse_course_info(
                     to.push(Meeting {
                         meeting_type: m_m_type.to_string(),
                         meeting_days: m_days,
-                        building: meeting.bldg_code.trim().to_string(),
-                        room: meeting.room_code.trim().to_string(),
+                        building: meeting.bldg_code.clone(),
+                        room: meeting.room_code.clone(),
                         start_hr: TimeType::try_from(meeting.start_time_hr)
                             .map_err(|_| WrapperError::BadTimeError)?,
                         start_min: TimeType::try_from(meeting.start_time_min)
@@ -604,6 +839,9 @@ pub fn parse_course_info(
                 Ok(())
             };
 
+        let mut shared = vec![];
+        process_meetings(&entry.general_meetings, &mut shared)?;
+
         // If there are no child meetings, then this means we only have lecture + exams.
         if entry.child_meetings.is_empty() {
             // Note that the general meetings vector will contain a lecture (and maybe a
@@ -612,35 +850,42 @@ pub fn parse_course_info(
             // this means that information like enrolled count, waitlist count, and so on will
             // be reflected across both structures accurately, so there's no need to search
             // for one particular meeting.
-            let mut section = CourseSection {
+            let section = CourseSection {
                 is_visible: entry.general_meetings[0].is_visible(),
                 subj_course_id: subj_num.to_owned(),
                 section_id: entry.general_meetings[0].section_id.to_owned(),
                 section_code: entry.general_meetings[0].sect_code.to_owned(),
-                all_instructors: util::get_instructor_names(
-                    &entry.general_meetings[0].person_full_name,
-                ),
-                available_seats: max(entry.general_meetings[0].avail_seat, 0),
+                all_instructors: base_instructors.clone(),
+                available_seats: clamp_available_seats(entry.general_meetings[0], &mut warnings),
+                available_seats_raw: entry.general_meetings[0].avail_seat,
+                instructor_info: BTreeMap::new(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: entry.general_meetings[0].enrolled_count,
                 total_seats: entry.general_meetings[0].section_capacity,
                 waitlist_ct: entry.general_meetings[0].count_on_waitlist,
                 meetings: vec![],
             };
 
-            // Then, iterate through the rest of the general meetings.
-            process_meetings(&entry.general_meetings, &mut section.meetings)?;
-            // Finally, add it to the sections.
-            sections.push(section);
+            // The section's meetings are all shared by the group.
+            groups.push(CourseGroup {
+                family: key.to_string(),
+                shared,
+                sections: vec![section],
+            });
             continue;
         }
 
         // Otherwise, we essentially repeat the same process above. The only difference is that
-        // we clone 'section' for each child meeting.
+        // we create a section for each child meeting.
+        let mut sections = vec![];
         for c_meeting in &entry.child_meetings {
-            let mut instructors = base_instructors.clone();
-            instructors.append(&mut util::get_instructor_names(&c_meeting.person_full_name));
-            instructors.sort();
-            instructors.dedup();
+            let instructors = util::get_all_instructors(
+                base_instructors
+                    .iter()
+                    .cloned()
+                    .chain(util::get_instructor_names(&c_meeting.person_full_name)),
+            );
 
             // Process the general section info.
             let mut section = CourseSection {
@@ -649,22 +894,215 @@ pub fn parse_course_info(
                 section_id: c_meeting.section_id.to_owned(),
                 section_code: c_meeting.sect_code.to_owned(),
                 all_instructors: instructors,
-                available_seats: max(c_meeting.avail_seat, 0),
+                available_seats: clamp_available_seats(c_meeting, &mut warnings),
+                available_seats_raw: c_meeting.avail_seat,
+                instructor_info: BTreeMap::new(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: c_meeting.enrolled_count,
                 total_seats: c_meeting.section_capacity,
                 waitlist_ct: c_meeting.count_on_waitlist,
                 meetings: vec![],
             };
 
-            // Iterate through the general and child meetings.
-            process_meetings(&entry.general_meetings, &mut section.meetings)?;
+            // Only the child meeting is specific to this section.
             process_meetings(&[c_meeting], &mut section.meetings)?;
-            // Finally, add it to the sections as usual.
             sections.push(section);
         }
+
+        groups.push(CourseGroup {
+            family: key.to_string(),
+            shared,
+            sections,
+        });
     }
 
-    Ok(sections)
+    Ok((groups, warnings))
+}
+
+/// Processes the vector containing raw meeting information into section families, separating
+/// the meetings that are shared by the whole family (e.g., the lecture and final exam) from the
+/// meetings that are specific to each section (e.g., the discussion).
+///
+/// # Parameters
+/// - `meetings`: The vector of meetings.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+///
+/// # Returns
+/// Either the section families, sorted by family, or an error.
+pub fn parse_section_families(
+    parsed: Vec<RawWebRegMeeting>,
+    subj_num: String,
+) -> types::Result<Vec<SectionFamily>> {
+    let (groups, _) = group_course_info(parsed, subj_num)?;
+    Ok(into_section_families(groups))
+}
+
+/// Turns the groups from `group_course_info` into section families.
+///
+/// # Parameters
+/// - `groups`: The groups.
+///
+/// # Returns
+/// The section families, sorted by family.
+pub(crate) fn into_section_families(groups: Vec<CourseGroup>) -> Vec<SectionFamily> {
+    let mut families: BTreeMap<String, SectionFamily> = BTreeMap::new();
+    for group in groups {
+        let entry = families.entry(group.family.clone()).or_insert_with(|| {
+            let (exams, lecture) = group
+                .shared
+                .into_iter()
+                .partition(|m| matches!(m.meeting_days, MeetingDay::OneTime(_)));
+            SectionFamily {
+                family: group.family,
+                lecture,
+                discussions: vec![],
+                exams,
+            }
+        });
+
+        entry.discussions.extend(group.sections);
+    }
+
+    families.into_values().collect()
+}
+
+/// Checks whether WebReg doesn't allow the grading option of a scheduled section to be
+/// changed. WebReg marks sections whose grading option can be changed with `+`, so a blank
+/// marker means that the grading option is locked. If the marker is missing entirely, the
+/// grading option is assumed to be changeable.
+///
+/// # Parameters
+/// - `meeting`: The meeting.
+///
+/// # Returns
+/// Whether the grading option is locked.
+fn is_grade_option_locked(meeting: &RawScheduledMeeting) -> bool {
+    meeting
+        .grade_option_plus
+        .as_deref()
+        .is_some_and(|p| p.trim().is_empty())
+}
+
+/// Parses a scheduled section's grading option. WebReg gives a blank code when the section
+/// doesn't have a grading option, which is parsed as `None`. An unknown code is also parsed
+/// as `None`; `parse_schedule_with_warnings` records a warning for these.
+///
+/// # Parameters
+/// - `code`: The grading option code given by WebReg.
+///
+/// # Returns
+/// The grading option, if there is one.
+fn parse_grade_option(code: &str) -> Option<GradeOption> {
+    let code = code.trim();
+    if code.is_empty() {
+        return None;
+    }
+
+    code.parse().ok()
+}
+
+/// Gets the number of available seats for a meeting, clamped to `0`. If the number of
+/// available seats is negative (i.e., the section is over-enrolled), a warning is recorded.
+///
+/// # Parameters
+/// - `meeting`: The meeting.
+/// - `warnings`: Where any warnings should be recorded.
+///
+/// # Returns
+/// The number of available seats.
+fn clamp_available_seats(meeting: &RawWebRegMeeting, warnings: &mut Vec<ParseWarning>) -> i64 {
+    if meeting.avail_seat < 0 {
+        warnings.push(ParseWarning::NegativeSeats {
+            section_id: meeting.section_id.clone(),
+            available_seats: meeting.avail_seat,
+        });
+    }
+
+    max(meeting.avail_seat, 0)
+}
+
+/// Checks that a meeting's day code only consists of known days (`0` through `6`), recording
+/// a warning if it doesn't.
+///
+/// # Parameters
+/// - `meeting`: The meeting.
+/// - `warnings`: Where any warnings should be recorded.
+fn check_day_code(meeting: &RawWebRegMeeting, warnings: &mut Vec<ParseWarning>) {
+    let day_code = meeting.day_code.as_str();
+    if !day_code.chars().all(|c| ('0'..='6').contains(&c)) {
+        warnings.push(ParseWarning::UnknownDayCode {
+            section_code: meeting.sect_code.clone(),
+            day_code: day_code.to_string(),
+        });
+    }
+}
+
+/// Checks that a meeting's special meeting code (if any) is known, recording a warning if it
+/// isn't.
+///
+/// # Parameters
+/// - `meeting`: The meeting.
+/// - `warnings`: Where any warnings should be recorded.
+fn check_special_meeting(meeting: &RawWebRegMeeting, warnings: &mut Vec<ParseWarning>) {
+    let code = meeting.special_meeting.as_str();
+    if code.is_empty() || code == "TBA" {
+        return;
+    }
+
+    if let SpecialMeetingCode::Unknown(code) = SpecialMeetingCode::from_code(code) {
+        warnings.push(ParseWarning::UnknownMeetingType {
+            section_code: meeting.sect_code.clone(),
+            meeting_type: code,
+        });
+    }
+}
+
+/// Processes the vector containing raw meeting information into parsed course information
+/// for just one section. Only the meetings that belong to the section's family (e.g., for
+/// section `A01`, any meeting whose section code starts with `A`) are parsed.
+///
+/// # Parameters
+/// - `parsed`: The vector of meetings.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+/// - `section_id`: The section ID. Leading zeros are ignored.
+///
+/// # Returns
+/// Either the parsed section (or `None` if the section wasn't found), or an error.
+pub fn parse_section_info(
+    parsed: Vec<RawWebRegMeeting>,
+    subj_num: String,
+    section_id: &str,
+) -> types::Result<Option<CourseSection>> {
+    let target_id = section_id.trim().trim_start_matches('0');
+    let is_target = |id: &str| id.trim().trim_start_matches('0') == target_id;
+    let Some(sect_code) = parsed
+        .iter()
+        .find(|m| is_target(&m.section_id))
+        .map(|m| m.sect_code.clone())
+    else {
+        return Ok(None);
+    };
+
+    let family = sect_code.chars().next();
+    let is_special = match family {
+        Some(c) => c.is_ascii_digit(),
+        None => true,
+    };
+    let related = parsed
+        .into_iter()
+        .filter(|m| {
+            if is_special {
+                is_target(&m.section_id)
+            } else {
+                m.sect_code.chars().next() == family
+            }
+        })
+        .collect();
+
+    Ok(parse_course_info(related, subj_num)?
+        .into_iter()
+        .find(|sec| is_target(&sec.section_id)))
 }
 
 /// Builds the URL that can be used to search courses on WebReg.
@@ -672,10 +1110,16 @@ pub fn parse_course_info(
 /// # Parameters
 /// - `filter_by`: The search type.
 /// - `term`: The term.
+/// - `cache_buster`: The time to use for the `_` (cache-busting) query parameter, or `None`
+///   if the parameter shouldn't be included.
 ///
 /// # Returns
 /// The resulting URL that can be used to search for courses.
-pub(crate) fn build_search_course_url(filter_by: SearchType, term: &str) -> types::Result<Url> {
+pub(crate) fn build_search_course_url(
+    filter_by: SearchType,
+    term: &str,
+    cache_buster: Option<SystemTime>,
+) -> types::Result<Url> {
     Ok(match filter_by {
         SearchType::BySection(section) => Url::parse_with_params(
             WEBREG_SEARCH_SEC,
@@ -761,35 +1205,115 @@ pub(crate) fn build_search_course_url(filter_by: SearchType, term: &str) -> type
                 }
             };
 
+            add_cache_buster(
+                Url::parse_with_params(
+                    WEBREG_SEARCH,
+                    &[
+                        ("subjcode", subject_code.as_str()),
+                        ("crsecode", course_code.as_str()),
+                        ("department", department.as_str()),
+                        ("professor", professor.as_str()),
+                        ("title", title.as_str()),
+                        ("levels", levels.as_str()),
+                        ("days", days.as_str()),
+                        ("timestr", time_str.as_str()),
+                        (
+                            "opensection",
+                            if request_filter.only_open {
+                                "true"
+                            } else {
+                                "false"
+                            },
+                        ),
+                        ("isbasic", "true"),
+                        ("basicsearchvalue", ""),
+                        ("termcode", term),
+                    ],
+                )?,
+                cache_buster,
+            )
+        }
+        SearchType::FreeText(query) => add_cache_buster(
             Url::parse_with_params(
                 WEBREG_SEARCH,
                 &[
-                    ("subjcode", subject_code.as_str()),
-                    ("crsecode", course_code.as_str()),
-                    ("department", department.as_str()),
-                    ("professor", professor.as_str()),
-                    ("title", title.as_str()),
-                    ("levels", levels.as_str()),
-                    ("days", days.as_str()),
-                    ("timestr", time_str.as_str()),
-                    (
-                        "opensection",
-                        if request_filter.only_open {
-                            "true"
-                        } else {
-                            "false"
-                        },
-                    ),
+                    ("subjcode", ""),
+                    ("crsecode", ""),
+                    ("department", ""),
+                    ("professor", ""),
+                    ("title", ""),
+                    ("levels", ""),
+                    ("days", ""),
+                    ("timestr", ""),
+                    ("opensection", "false"),
                     ("isbasic", "true"),
-                    ("basicsearchvalue", ""),
+                    ("basicsearchvalue", query.trim()),
                     ("termcode", term),
-                    ("_", util::get_epoch_time().to_string().as_str()),
                 ],
-            )?
-        }
+            )?,
+            cache_buster,
+        ),
     })
 }
 
+/// Adds the `_` (cache-busting) query parameter to the given URL.
+///
+/// # Parameters
+/// - `url`: The URL.
+/// - `cache_buster`: The time to use for the parameter, or `None` if the parameter shouldn't
+///   be included.
+///
+/// # Returns
+/// The URL.
+fn add_cache_buster(mut url: Url, cache_buster: Option<SystemTime>) -> Url {
+    if let Some(now) = cache_buster {
+        url.query_pairs_mut()
+            .append_pair("_", util::get_epoch_time(now).to_string().as_str());
+    }
+
+    url
+}
+
+/// Parses an event time, given in the form `HHMM`, into its hour and minute parts.
+///
+/// # Parameters
+/// - `time`: The time to parse.
+///
+/// # Returns
+/// The hour and minute. Any part that can't be parsed will be `0`.
+fn parse_event_time(time: &str) -> (TimeType, TimeType) {
+    let digits = time
+        .chars()
+        .map(|c| c.to_digit(10).unwrap_or_default())
+        .collect::<Vec<_>>();
+    if digits.len() < 4 {
+        return (0, 0);
+    }
+
+    (digits[0] * 10 + digits[1], digits[2] * 10 + digits[3])
+}
+
+/// Parses search results from the vector of raw search results.
+///
+/// # Parameters
+/// - `raw_results`: The raw search results.
+///
+/// # Returns
+/// The parsed search results.
+pub fn parse_search_results(raw_results: Vec<RawWebRegSearchResultItem>) -> SearchResult {
+    raw_results
+        .into_iter()
+        .map(|item| SearchResultItem {
+            subj_code: item.subj_code,
+            course_code: item.course_code,
+            course_title: item.course_title,
+            min_units: item.min_units,
+            max_units: item.max_units,
+            unit_increment: item.unit_increment,
+        })
+        .collect()
+}
+
 /// Parses events from the vector of raw events.
 ///
 /// # Parameters
@@ -800,16 +1324,8 @@ pub(crate) fn build_search_course_url(filter_by: SearchType, term: &str) -> type
 pub(crate) fn parse_get_events(raw_events: Vec<RawEvent>) -> types::Result<Events> {
     let mut res = vec![];
     for event in raw_events {
-        let start_chars = event.start_time.chars().collect::<Vec<_>>();
-        let start_hr = start_chars[0].to_digit(10).unwrap_or_default()
-            * start_chars[1].to_digit(10).unwrap_or_default();
-        let start_min = start_chars[2].to_digit(10).unwrap_or_default()
-            * start_chars[3].to_digit(10).unwrap_or_default();
-        let end_chars = event.end_time.chars().collect::<Vec<_>>();
-        let end_hr = end_chars[0].to_digit(10).unwrap_or_default()
-            * end_chars[1].to_digit(10).unwrap_or_default();
-        let end_min = end_chars[2].to_digit(10).unwrap_or_default()
-            * end_chars[3].to_digit(10).unwrap_or_default();
+        let (start_hr, start_min) = parse_event_time(&event.start_time);
+        let (end_hr, end_min) = parse_event_time(&event.end_time);
 
         res.push(Event {
             location: event.location,
@@ -825,3 +1341,97 @@ pub(crate) fn parse_get_events(raw_events: Vec<RawEvent>) -> types::Result<Event
 
     Ok(res)
 }
+
+/// Parses the enrollment periods of a term from the responses of WebReg's term status and
+/// eligibility endpoints. Fields are merged in order, so a field in a later response replaces
+/// one with the same name in an earlier response.
+///
+/// This is best-effort. These endpoints aren't documented, and the field names that are
+/// recognized are guesses that haven't been checked against a real response, so the periods
+/// may not be filled in even if WebReg gave them (in which case, see `extra`). A period is
+/// recognized by the words in a field's name, split on underscores and camel case (e.g., a
+/// field named `FIRST_PASS_START` or `firstPassStart` has the words `FIRST` and `PASS`, but
+/// not `END`, so it's taken to be when first pass starts).
+///
+/// # Parameters
+/// - `responses`: The responses. Each response should be an object, or an array of objects.
+///
+/// # Returns
+/// The enrollment periods, which will have nothing filled in if no field was recognized.
+pub fn parse_enrollment_phases(responses: &[serde_json::Value]) -> EnrollmentPhases {
+    let mut extra = serde_json::Map::new();
+    for res in responses {
+        match res {
+            serde_json::Value::Object(obj) => extra.extend(obj.clone()),
+            serde_json::Value::Array(arr) => {
+                for obj in arr.iter().filter_map(|v| v.as_object()) {
+                    extra.extend(obj.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut phases = EnrollmentPhases::default();
+    for (key, value) in &extra {
+        let value = match value {
+            serde_json::Value::String(s) if !s.trim().is_empty() => s.trim().to_string(),
+            serde_json::Value::Number(n) => n.to_string(),
+            _ => continue,
+        };
+
+        let words = key_words(key);
+        let has = |word: &str| words.iter().any(|w| w == word);
+        let is_deadline = ["DEADLINE", "END", "LAST"].into_iter().any(has);
+        let is_pass = has("PASS") && !is_deadline;
+        let field = if is_pass && has("FIRST") {
+            &mut phases.first_pass
+        } else if is_pass && has("SECOND") {
+            &mut phases.second_pass
+        } else if has("ADD") && is_deadline {
+            &mut phases.add_deadline
+        } else if has("DROP") && is_deadline {
+            &mut phases.drop_deadline
+        } else {
+            continue;
+        };
+
+        field.get_or_insert(value);
+    }
+
+    phases.extra = extra;
+    phases
+}
+
+/// Splits a field name into its words, in uppercase. Words are separated by anything that
+/// isn't a letter or digit (e.g., `_`), and by a lowercase letter followed by an uppercase
+/// one (e.g., `firstPass`). Used by `parse_enrollment_phases`.
+///
+/// # Parameters
+/// - `key`: The field name.
+///
+/// # Returns
+/// The words.
+fn key_words(key: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let mut prev_lower = false;
+    for c in key.chars() {
+        let is_boundary = !c.is_ascii_alphanumeric() || (prev_lower && c.is_ascii_uppercase());
+        if is_boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+
+        if c.is_ascii_alphanumeric() {
+            word.push(c.to_ascii_uppercase());
+        }
+
+        prev_lower = c.is_ascii_lowercase();
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
@@ -1,5 +1,18 @@
+use futures::future::{BoxFuture, FutureExt};
 use reqwest::Client;
-use webweg::wrapper::input_types::{DayOfWeek, EnrollWaitAdd, EventAdd, GradeOption, PlanAdd};
+use std::time::{Duration, SystemTime};
+use webweg::types::{
+    AttemptRecord, BuilderProblem, Clock, CookieProvider, CourseCode, EnrollmentStatus,
+    HttpBackend, HttpBackendError, HttpRequest, HttpResponse, PacingPreset, PacingProfile,
+    QuotaKind, RateLimit, RateLimitScope, ScheduledSection, UnitCapSchedule, UsageQuota,
+    WrapperError,
+};
+use webweg::wrapper::input_types::{
+    AutoEnrollOptions, DayOfWeek, DropRule, EnrollFromPlanOptions, EnrollWaitAdd, EventAdd,
+    GradeOption, PlanAdd, PlanPriority, WaitlistDropPolicy,
+};
+use webweg::wrapper::mock::MockWebReg;
+use webweg::wrapper::seat_watcher::AdaptiveSchedule;
 use webweg::wrapper::wrapper_builder::WebRegWrapperBuilder;
 use webweg::wrapper::WebRegWrapper;
 
@@ -20,6 +33,23 @@ fn success_construct_wrapper() {
 }
 
 #[test]
+fn wrapper_can_be_shared_as_dyn_client() {
+    use std::sync::Arc;
+    use webweg::wrapper::dyn_client::DynWebRegClient;
+
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("abc")
+        .try_build_wrapper()
+        .unwrap();
+
+    // Services should be able to hold the wrapper without knowing its type.
+    let client: Arc<dyn DynWebRegClient> = Arc::new(wrapper);
+    let shared = Arc::clone(&client);
+    assert_eq!(2, Arc::strong_count(&shared));
+}
+
+#[test]
+#[allow(deprecated)]
 fn success_override_cookies() {
     let wrapper = WebRegWrapper::builder()
         .with_cookies("ABC")
@@ -31,8 +61,35 @@ fn success_override_cookies() {
     wrapper.req("FA23").override_cookies("abc").parsed();
 }
 
+#[test]
+fn success_try_override_cookies() {
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("ABC")
+        .should_close_after_request(true)
+        .try_build_wrapper()
+        .unwrap();
+
+    wrapper
+        .req("FA23")
+        .try_override_cookies("abc")
+        .unwrap()
+        .parsed();
+}
+
+#[test]
+fn fail_try_override_cookies() {
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("ABC")
+        .try_build_wrapper()
+        .unwrap();
+
+    let res = wrapper.req("FA23").try_override_cookies("abc");
+    assert!(matches!(res, Err(WrapperError::CookieOverrideNotAllowed)));
+}
+
 #[test]
 #[should_panic]
+#[allow(deprecated)]
 fn fail_override_cookies() {
     let wrapper = WebRegWrapper::builder()
         .with_cookies("ABC")
@@ -145,3 +202,697 @@ fn fail_construct_enroll_wait() {
 
     assert!(enroll_add.is_none());
 }
+
+#[test]
+fn new_wrapper_has_empty_stats() {
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("abc")
+        .try_build_wrapper()
+        .unwrap();
+
+    let stats = wrapper.stats();
+    assert_eq!(stats.total_requests, 0);
+    assert!(stats.requests_by_endpoint.is_empty());
+    assert!(stats.errors_by_class.is_empty());
+    assert_eq!(stats.average_latency(), std::time::Duration::ZERO);
+}
+
+#[test]
+fn new_wrapper_has_no_observed_errors() {
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("abc")
+        .should_collect_errors(true)
+        .try_build_wrapper()
+        .unwrap();
+
+    assert!(wrapper.observed_errors().is_empty());
+}
+
+#[test]
+fn success_construct_section_lookup() {
+    let lookup = EnrollWaitAdd::for_course(("cse", " 100"), "A01")
+        .with_grading_option(GradeOption::L)
+        .with_unit_count(4);
+
+    assert_eq!(lookup.course, CourseCode::new("CSE", "100"));
+    assert_eq!(lookup.course.to_string(), "CSE 100");
+    assert_eq!(lookup.section_code, "A01");
+    assert_eq!(lookup.grading_option, Some(GradeOption::L));
+    assert_eq!(lookup.unit_count, Some(4));
+}
+
+#[test]
+fn event_add_json_round_trip() {
+    let event_add = EventAdd::builder()
+        .with_name("study session")
+        .with_day(DayOfWeek::Tuesday)
+        .with_day(DayOfWeek::Thursday)
+        .with_start_time(14, 0)
+        .with_end_time(15, 30)
+        .try_build()
+        .unwrap();
+
+    let json = serde_json::to_string(&event_add).unwrap();
+    let parsed: EventAdd = serde_json::from_str(&json).unwrap();
+    assert!(parsed.is_same_event(&event_add));
+    assert_eq!(parsed.location, None);
+}
+
+#[test]
+fn event_add_same_event_ignores_day_order() {
+    let a = EventAdd::builder()
+        .with_name("study session")
+        .with_day(DayOfWeek::Tuesday)
+        .with_day(DayOfWeek::Thursday)
+        .with_start_time(14, 0)
+        .with_end_time(15, 30)
+        .try_build()
+        .unwrap();
+    let b = EventAdd::builder()
+        .with_name("study session")
+        .with_location("somewhere")
+        .with_day(DayOfWeek::Thursday)
+        .with_day(DayOfWeek::Tuesday)
+        .with_start_time(14, 0)
+        .with_end_time(15, 30)
+        .try_build()
+        .unwrap();
+    let c = EventAdd::builder()
+        .with_name("study session")
+        .with_day(DayOfWeek::Tuesday)
+        .with_start_time(14, 0)
+        .with_end_time(15, 30)
+        .try_build()
+        .unwrap();
+
+    assert!(a.is_same_event(&b));
+    assert!(!a.is_same_event(&c));
+}
+
+#[test]
+fn success_construct_wrapper_with_client_options() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_local_address("127.0.0.1".parse().unwrap())
+        .should_accept_invalid_certs(true)
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+}
+
+#[test]
+fn success_construct_wrapper_with_session_invalid_callback() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_session_invalid_callback(|| eprintln!("Session expired."))
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+}
+
+#[test]
+fn success_construct_wrapper_with_pacing() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_pacing(PacingPreset::Polite)
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+
+    let profile = PacingProfile::from(PacingPreset::Polite);
+    assert_eq!(1, profile.max_concurrent_requests);
+    assert_eq!(PacingProfile::default().max_retries, 0);
+}
+
+#[test]
+fn success_construct_multi_term_requester() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .try_build_wrapper()
+        .unwrap();
+    let req = wrapper.req_many(&["FA23", "WI24"]);
+    assert_eq!(&["FA23", "WI24"], req.terms());
+}
+
+#[test]
+fn success_construct_wrapper_with_clock() {
+    struct FixedClock(SystemTime);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> SystemTime {
+            self.0
+        }
+    }
+
+    let clock = FixedClock(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+    assert_eq!(clock.0, clock.now());
+
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_clock(clock)
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+}
+
+#[test]
+fn success_construct_wrapper_with_cookie_provider() {
+    struct StaticCookies(&'static str);
+
+    impl CookieProvider for StaticCookies {
+        fn refresh_cookies(&self) -> BoxFuture<'_, webweg::types::Result<String>> {
+            async move { Ok(self.0.to_owned()) }.boxed()
+        }
+    }
+
+    let provider = StaticCookies("new cookies");
+    assert_eq!(
+        "new cookies",
+        futures::executor::block_on(provider.refresh_cookies()).unwrap()
+    );
+
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_cookie_provider(provider)
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+}
+
+#[test]
+fn success_construct_wrapper_with_catalog_cache_ttl() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_catalog_cache_ttl(Duration::from_secs(60 * 60))
+        .try_build_wrapper()
+        .unwrap();
+    // Clearing an empty cache should do nothing.
+    wrapper.clear_catalog_cache();
+}
+
+#[test]
+fn new_wrapper_has_no_associated_terms() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .try_build_wrapper()
+        .unwrap();
+    assert!(wrapper.get_associated_terms().is_empty());
+    assert!(!wrapper.is_term_associated("FA23"));
+}
+
+#[test]
+fn success_construct_enroll_from_plan_options() {
+    let options = EnrollFromPlanOptions::new();
+    assert!(options.priority.is_empty());
+    assert!(!options.waitlist_if_full);
+    assert_eq!(options.max_units, None);
+    assert!(options.validate);
+
+    // The default options are the same as the ones from `new`, so validation stays on.
+    let options = EnrollFromPlanOptions {
+        waitlist_if_full: true,
+        ..Default::default()
+    };
+    assert!(options.waitlist_if_full);
+    assert!(options.validate);
+
+    let options = EnrollFromPlanOptions::new()
+        .with_priority([
+            PlanPriority::from(("cse", "100 ")),
+            PlanPriority::section("079911"),
+        ])
+        .should_waitlist_if_full(true)
+        .with_max_units(20)
+        .should_validate(false);
+    assert_eq!(
+        vec![
+            PlanPriority::Course(CourseCode::new("CSE", "100")),
+            PlanPriority::Section("079911".into())
+        ],
+        options.priority
+    );
+    assert!(options.waitlist_if_full);
+    assert_eq!(options.max_units, Some(20));
+    assert!(!options.validate);
+}
+
+#[test]
+fn success_construct_auto_enroll_options() {
+    let options = AutoEnrollOptions::new();
+    assert!(!options.waitlist_if_full);
+    assert!(options.validate);
+    assert!(options.confirm);
+    assert_eq!(3, options.max_attempts);
+    assert_eq!(None, options.grading_option);
+
+    // The default options are the same as the ones from `new`, so an add is still validated,
+    // retried, and confirmed.
+    let options = AutoEnrollOptions {
+        waitlist_if_full: true,
+        ..Default::default()
+    };
+    assert!(options.waitlist_if_full);
+    assert!(options.validate);
+    assert!(options.confirm);
+    assert_eq!(3, options.max_attempts);
+    assert_eq!(Duration::from_millis(500), options.retry_delay);
+
+    let options = AutoEnrollOptions::new()
+        .with_grading_option(GradeOption::P)
+        .with_unit_count(2)
+        .should_waitlist_if_full(true)
+        .with_retries(5, Duration::from_secs(1))
+        .should_confirm(false);
+    assert_eq!(Some(GradeOption::P), options.grading_option);
+    assert_eq!(Some(2), options.unit_count);
+    assert!(options.waitlist_if_full);
+    assert_eq!(5, options.max_attempts);
+    assert_eq!(Duration::from_secs(1), options.retry_delay);
+    assert!(!options.confirm);
+}
+
+#[test]
+fn enroll_from_plan_options_use_current_unit_cap() {
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let caps = UnitCapSchedule::new()
+        .with_period("Second Pass", start + Duration::from_secs(86_400), 22)
+        .with_period("First Pass", start, 16);
+
+    assert_eq!(None, caps.cap_at(start - Duration::from_secs(1)));
+    assert_eq!(Some(16), caps.cap_at(start));
+    assert_eq!(
+        "Second Pass",
+        caps.period_at(start + Duration::from_secs(90_000))
+            .unwrap()
+            .label
+    );
+
+    let options = EnrollFromPlanOptions::new().with_unit_caps(caps);
+    assert_eq!(Some(16), options.unit_limit_at(start));
+    assert_eq!(None, options.unit_limit_at(SystemTime::UNIX_EPOCH));
+
+    let options = options.with_max_units(20);
+    assert_eq!(Some(16), options.unit_limit_at(start));
+    assert_eq!(
+        Some(20),
+        options.unit_limit_at(start + Duration::from_secs(86_400))
+    );
+}
+
+#[test]
+fn success_construct_wrapper_with_contact_info() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_contact_info("admin@example.com")
+        .with_user_agent("my-tool/1.0")
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+}
+
+#[test]
+fn quota_blocks_requests_without_sending() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_quota(UsageQuota {
+            max_requests_per_hour: Some(0),
+            max_mutations_per_day: None,
+        })
+        .try_build_wrapper()
+        .unwrap();
+
+    let res = futures::executor::block_on(wrapper.req("FA23").raw().get_department_codes());
+    assert!(matches!(
+        res,
+        Err(WrapperError::QuotaExceeded(QuotaKind::RequestsPerHour, 0))
+    ));
+}
+
+#[test]
+fn success_construct_requester_with_section_notes() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .try_build_wrapper()
+        .unwrap();
+
+    // This test should pass if nothing panics
+    wrapper
+        .req("FA23")
+        .should_include_section_notes(true)
+        .parsed();
+}
+
+#[test]
+fn success_construct_wrapper_with_strict_parsing() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .should_use_strict_parsing(true)
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+}
+
+#[test]
+fn success_construct_wrapper_with_attempt_sink() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_attempt_sink(|attempt: &AttemptRecord| println!("{attempt:?}"))
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+}
+
+#[test]
+fn success_construct_wrapper_with_rate_limit() {
+    let limit = RateLimit::new(3).with_scope(RateLimitScope::PerTerm);
+    assert_eq!(1, limit.burst);
+
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_rate_limit(limit.with_burst(10))
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .with_rate_limit(RateLimit::new(0))
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+}
+
+#[test]
+fn success_construct_wrapper_with_validation_defaults() {
+    let wrapper = WebRegWrapperBuilder::new()
+        .with_cookies("my cookies")
+        .should_always_validate_enroll(true)
+        .should_always_validate_plan(false)
+        .try_build_wrapper();
+    assert!(wrapper.is_some());
+}
+
+#[test]
+fn diagnose_wrapper_builder() {
+    assert_eq!(
+        vec![BuilderProblem::MissingCookies],
+        WebRegWrapperBuilder::new().diagnose()
+    );
+    assert!(WebRegWrapperBuilder::new()
+        .with_cookies("   ")
+        .try_build_wrapper()
+        .is_none());
+
+    let problems = WebRegWrapperBuilder::new()
+        .with_cookies("abc")
+        .with_default_timeout(Duration::ZERO)
+        .with_client(Client::new())
+        .should_accept_invalid_certs(true)
+        .diagnose();
+    assert_eq!(3, problems.len());
+    assert!(matches!(problems[0], BuilderProblem::MalformedCookies(_)));
+    assert_eq!(BuilderProblem::ZeroTimeout, problems[1]);
+    assert_eq!(BuilderProblem::ClientOptionsIgnored, problems[2]);
+    assert!(problems.iter().all(|p| !p.is_fatal()));
+
+    assert!(WebRegWrapperBuilder::new()
+        .with_cookies("abc")
+        .build_wrapper()
+        .is_err());
+    assert!(WebRegWrapperBuilder::new()
+        .with_cookies("jlinksessionidx=abc; itscookie=def")
+        .build_wrapper()
+        .is_ok());
+
+    let builder = || {
+        WebRegWrapperBuilder::new()
+            .with_cookies("jlinksessionidx=abc; itscookie=def")
+            .should_override_cookies(true)
+    };
+    assert_eq!(
+        vec![BuilderProblem::CookieOverrideWithoutClose],
+        builder().diagnose()
+    );
+    assert!(builder().build_wrapper().is_err());
+    assert!(builder()
+        .should_close_after_request(true)
+        .build_wrapper()
+        .is_ok());
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn success_construct_blocking_wrapper() {
+    let wrapper =
+        webweg::blocking::WebRegWrapper::new(reqwest::blocking::Client::new(), "my cookies");
+    assert!(!wrapper.inner().is_term_associated("FA23"));
+    assert_eq!(4, wrapper.block_on(async { 2 + 2 }));
+
+    let problems = webweg::blocking::WebRegWrapper::from_builder(WebRegWrapperBuilder::new())
+        .err()
+        .unwrap();
+    assert_eq!(vec![BuilderProblem::MissingCookies], problems);
+}
+
+#[test]
+fn success_evaluate_waitlist_drop_policy() {
+    let section = |id: &str, course: &str, status: EnrollmentStatus| ScheduledSection {
+        section_id: id.into(),
+        subject_code: "CSE".into(),
+        course_code: course.into(),
+        course_title: "".into(),
+        section_code: "A01".into(),
+        section_capacity: 100,
+        enrolled_count: 100,
+        available_seats: 0,
+        grade_option: None,
+        grade_option_locked: false,
+        all_instructors: vec![],
+        units: 4,
+        enrolled_status: status,
+        waitlist_ct: 10,
+        meetings: vec![],
+    };
+    let schedule = vec![
+        section("1", "100", EnrollmentStatus::Waitlist { waitlist_pos: 2 }),
+        section("2", "101", EnrollmentStatus::Enrolled),
+        section("3", "105", EnrollmentStatus::Waitlist { waitlist_pos: 8 }),
+        section("4", "110", EnrollmentStatus::Planned),
+    ];
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1000);
+
+    let policy = WaitlistDropPolicy::new();
+    assert!(policy.evaluate(&schedule, now).is_empty());
+    assert!(!policy.dry_run);
+
+    let policy = WaitlistDropPolicy::new()
+        .drop_if_enrolled_in(("cse", " 100"), ("CSE", "101"))
+        .should_dry_run(true);
+    let drops = policy.evaluate(&schedule, now);
+    assert_eq!(1, drops.len());
+    assert_eq!("1", drops[0].0.section_id);
+    assert!(policy.dry_run);
+
+    // Enrollment in the other course is required, and only waitlists are dropped.
+    let policy = WaitlistDropPolicy::new()
+        .drop_if_enrolled_in(("CSE", "100"), ("CSE", "110"))
+        .drop_if_enrolled_in(("CSE", "101"), ("CSE", "101"));
+    assert!(policy.evaluate(&schedule, now).is_empty());
+
+    let later = Some(now + Duration::from_secs(1));
+    let policy = WaitlistDropPolicy::new().drop_below_position(5, later);
+    assert!(policy.evaluate(&schedule, now).is_empty());
+
+    let policy = WaitlistDropPolicy::new().drop_below_position(5, Some(now));
+    let drops = policy.evaluate(&schedule, now);
+    assert_eq!(1, drops.len());
+    assert_eq!("3", drops[0].0.section_id);
+    assert_eq!(
+        &DropRule::BelowPosition {
+            position: 5,
+            after: Some(now)
+        },
+        drops[0].1
+    );
+}
+
+#[test]
+fn success_serve_mock_responses() {
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .with_backend(
+            MockWebReg::new()
+                .with_response("crsecode=101", include_str!("json/courseinfo1.json"))
+                .with_status("crsecode=110", 403, ""),
+        )
+        .try_build_wrapper()
+        .unwrap();
+
+    let req = wrapper.req("FA23").parsed();
+    let sections = futures::executor::block_on(req.get_course_info(("CSE", "101"))).unwrap();
+    assert!(!sections.is_empty());
+
+    let res = futures::executor::block_on(req.get_course_info(("CSE", "110")));
+    assert!(matches!(res, Err(WrapperError::BadStatusCode(403, _))));
+
+    let res = futures::executor::block_on(req.get_course_info(("CSE", "120")));
+    assert!(matches!(res, Err(WrapperError::BadStatusCode(404, _))));
+}
+
+#[test]
+fn success_adaptive_schedule_favors_volatile_courses() {
+    let section = |available_seats: i64| webweg::types::CourseSection {
+        subj_course_id: "CSE 100".into(),
+        section_id: "079912".into(),
+        section_code: "B01".into(),
+        all_instructors: vec![],
+        available_seats,
+        available_seats_raw: available_seats,
+        instructor_info: Default::default(),
+        section_notes: vec![],
+        waitlist_open: true,
+        enrolled_ct: 0,
+        total_seats: 30,
+        waitlist_ct: 0,
+        meetings: vec![],
+        is_visible: true,
+    };
+
+    // Course 0 always changes and is almost full; courses 1 and 2 never change.
+    let mut schedule = AdaptiveSchedule::new(3);
+    let mut checks = [0; 3];
+    let mut last_check = [0; 3];
+    let mut max_gap = [0; 3];
+    for tick in 1..=120 {
+        let idx = schedule.next_course().unwrap();
+        checks[idx] += 1;
+        max_gap[idx] = max_gap[idx].max(tick - last_check[idx]);
+        last_check[idx] = tick;
+        if idx == 0 {
+            schedule.record(0, true, &[section(1)]);
+        } else {
+            schedule.record(idx, false, &[section(20)]);
+        }
+    }
+
+    assert_eq!(120, checks.iter().sum::<i32>());
+    assert!(checks[0] > checks[1] + checks[2]);
+    assert!(checks[1] > 0 && checks[2] > 0);
+    // Stable courses are still checked at least once every four rounds.
+    assert!(max_gap[1] <= 13 && max_gap[2] <= 13);
+    assert_eq!(Some(8), schedule.volatility(0));
+    assert_eq!(Some(1), schedule.volatility(1));
+    assert_eq!(None, schedule.volatility(3));
+    assert_eq!(None, AdaptiveSchedule::new(0).next_course());
+}
+
+struct OfflineBackend;
+
+impl HttpBackend for OfflineBackend {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, HttpBackendError>> {
+        async move { Err(HttpBackendError::Connect(request.url)) }.boxed()
+    }
+}
+
+#[test]
+fn success_surface_backend_errors() {
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .with_backend(OfflineBackend)
+        .try_build_wrapper()
+        .unwrap();
+
+    let res =
+        futures::executor::block_on(wrapper.req("FA23").parsed().get_course_info(("CSE", "100")));
+    match res {
+        Err(e @ WrapperError::BackendError(HttpBackendError::Connect(_))) => {
+            assert!(e.is_transient())
+        }
+        other => panic!("expected a connection error, got {other:?}"),
+    }
+
+    assert_eq!(1, wrapper.stats().total_requests);
+}
+
+/// A backend that remembers the user agent of the last request it was given.
+#[derive(Clone, Default)]
+struct UserAgentBackend(std::sync::Arc<std::sync::Mutex<Option<String>>>);
+
+impl HttpBackend for UserAgentBackend {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, HttpBackendError>> {
+        *self.0.lock().unwrap() = request
+            .headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("user-agent"))
+            .map(|(_, value)| value.clone());
+        async move { Err(HttpBackendError::Connect(request.url)) }.boxed()
+    }
+}
+
+#[test]
+fn success_drop_non_ascii_contact_info() {
+    let backend = UserAgentBackend::default();
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .with_user_agent("my-tool/1.0")
+        .with_contact_info("Jörg Müller (jm@example.com)\n")
+        .with_backend(backend.clone())
+        .try_build_wrapper()
+        .unwrap();
+
+    let res =
+        futures::executor::block_on(wrapper.req("FA23").parsed().get_course_info(("CSE", "100")));
+    assert!(matches!(res, Err(WrapperError::BackendError(_))));
+    assert_eq!(
+        Some("my-tool/1.0 (contact: Jrg Mller jm@example.com)"),
+        backend.0.lock().unwrap().as_deref()
+    );
+}
+
+/// A backend that remembers the last request it was given.
+#[derive(Clone, Default)]
+struct LastRequestBackend(std::sync::Arc<std::sync::Mutex<Option<HttpRequest>>>);
+
+impl HttpBackend for LastRequestBackend {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, HttpBackendError>> {
+        let url = request.url.clone();
+        *self.0.lock().unwrap() = Some(request);
+        async move { Err(HttpBackendError::Connect(url)) }.boxed()
+    }
+}
+
+#[test]
+fn success_send_form_through_backend() {
+    let backend = LastRequestBackend::default();
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .should_close_after_request(true)
+        .with_backend(backend.clone())
+        .try_build_wrapper()
+        .unwrap();
+
+    let res = futures::executor::block_on(
+        wrapper
+            .req("FA23")
+            .parsed()
+            .send_email_to_self("hello & goodbye"),
+    );
+    assert!(matches!(res, Err(WrapperError::BackendError(_))));
+
+    let request = backend.0.lock().unwrap().take().unwrap();
+    let header = |name: &str| {
+        request
+            .headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    };
+    assert_eq!("POST", request.method);
+    assert_eq!(Some("my cookies"), header("cookie"));
+    assert_eq!(Some("close"), header("connection"));
+    assert_eq!(
+        Some("application/x-www-form-urlencoded"),
+        header("content-type")
+    );
+    assert_eq!(
+        Some(b"actionevent=hello+%26+goodbye&termcode=FA23".to_vec()),
+        request.body
+    );
+    assert_eq!(Some(Duration::from_secs(30)), request.timeout);
+}
+
+#[test]
+fn fail_missing_backend_is_fatal() {
+    assert!(BuilderProblem::MissingBackend.is_fatal());
+    assert!(!BuilderProblem::ClientOptionsIgnored.is_fatal());
+}
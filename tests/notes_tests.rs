@@ -0,0 +1,74 @@
+use webweg::notes::{parse_section_notes, SectionNote};
+
+#[test]
+fn test_parse_section_notes_empty() {
+    assert!(parse_section_notes("").is_empty());
+    assert!(parse_section_notes("     ").is_empty());
+}
+
+#[test]
+fn test_parse_section_notes_cross_listed() {
+    assert_eq!(
+        vec![SectionNote::CrossListed(vec![
+            "COGS 118A".to_string(),
+            "DSC 140A".to_string()
+        ])],
+        parse_section_notes("This course is cross-listed with COGS 118A/DSC 140a.")
+    );
+    assert_eq!(
+        vec![SectionNote::CrossListed(vec![])],
+        parse_section_notes("Crosslisted with another course.")
+    );
+}
+
+#[test]
+fn test_parse_section_notes_approval() {
+    assert_eq!(
+        vec![
+            SectionNote::DepartmentApproval,
+            SectionNote::InstructorApproval
+        ],
+        parse_section_notes(
+            "Department approval required.   Enrollment by consent of instructor only."
+        )
+    );
+}
+
+#[test]
+fn test_parse_section_notes_reserved_and_restricted() {
+    assert_eq!(
+        vec![
+            SectionNote::SeatsReserved {
+                count: Some(15),
+                reserved_for: "Data Science majors".to_string()
+            },
+            SectionNote::SeatsReserved {
+                count: None,
+                reserved_for: "transfer students".to_string()
+            },
+            SectionNote::RestrictedTo("seniors".to_string()),
+        ],
+        parse_section_notes(
+            "15 seats reserved for Data Science majors; Some seats are reserved for transfer \
+             students. Enrollment restricted to seniors."
+        )
+    );
+}
+
+#[test]
+fn test_parse_section_notes_text_fallback() {
+    // WebReg pads each line of a note, and unrecognized sentences are kept together.
+    assert_eq!(
+        vec![
+            SectionNote::Text(
+                "Students are required to attend a discussion section. Bring a laptop.".to_string()
+            ),
+            SectionNote::DepartmentApproval,
+            SectionNote::Text("Lab fee is 1.5 units.".to_string()),
+        ],
+        parse_section_notes(
+            "Students are required to attend a discussion   section.   Bring a laptop. \
+             Department stamp needed. Lab fee is 1.5 units."
+        )
+    );
+}
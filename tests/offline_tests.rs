@@ -0,0 +1,77 @@
+use webweg::offline;
+use webweg::raw_types::RawWebRegMeeting;
+use webweg::types::WrapperError;
+use webweg::ww_parser;
+
+#[test]
+fn test_parse_course_info() {
+    let json = include_str!("json/courseinfo1.json");
+    let raw = serde_json::from_str::<Vec<RawWebRegMeeting>>(json).unwrap();
+    assert_eq!(
+        ww_parser::parse_course_info(raw, "CSE 101".into()).unwrap(),
+        offline::parse_course_info(json, "CSE 101").unwrap()
+    );
+
+    let counts = offline::parse_enrollment_count(json, "CSE 101").unwrap();
+    assert!(!counts.is_empty());
+    assert!(counts.iter().all(|s| s.subj_course_id == "CSE 101"));
+}
+
+#[test]
+fn test_parse_schedule_and_prerequisites() {
+    assert!(
+        !offline::parse_schedule(include_str!("json/schedule1.json"))
+            .unwrap()
+            .is_empty()
+    );
+    assert!(
+        !offline::parse_prerequisites(include_str!("json/prereq4.json"))
+            .unwrap()
+            .course_prerequisites
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_parse_events() {
+    let json = r#"[{
+        "LOCATION": "Geisel  ",
+        "START_TIME": "0930",
+        "END_TIME": "1100",
+        "DESCRIPTION": "Study  ",
+        "DAYS": "1010000",
+        "TIME_STAMP": "2023-09-01 10:00:00.000000"
+    }]"#;
+    let events = offline::parse_events(json).unwrap();
+    assert_eq!(1, events.len());
+    assert_eq!("Geisel", events[0].location);
+    assert_eq!("Study", events[0].name);
+    assert_eq!((9, 30), (events[0].start_hr, events[0].start_min));
+    assert_eq!(vec!["M", "W"], events[0].days);
+}
+
+#[test]
+fn test_parse_search_results() {
+    let json = r#"[{
+        "UNIT_TO": 4.0,
+        "SUBJ_CODE": "CSE ",
+        "CRSE_TITLE": "Advanced Data Structures ",
+        "UNIT_FROM": 4.0,
+        "CRSE_CODE": "100 "
+    }]"#;
+    let results = offline::parse_search_results(json).unwrap();
+    assert_eq!(1, results.len());
+    assert_eq!("CSE", results[0].subj_code);
+    assert_eq!("100", results[0].course_code);
+    assert_eq!("Advanced Data Structures", results[0].course_title);
+    assert_eq!(None, results[0].unit_increment);
+}
+
+#[test]
+fn test_parse_invalid_json() {
+    assert!(matches!(
+        offline::parse_schedule("not json"),
+        Err(WrapperError::SerdeError(_))
+    ));
+    assert!(offline::parse_course_info("{}", "CSE 100").is_err());
+}
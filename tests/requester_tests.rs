@@ -0,0 +1,1271 @@
+use futures::executor::block_on;
+use futures::future::{BoxFuture, FutureExt};
+use futures::StreamExt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use webweg::types::{
+    AutoEnrollStatus, Clock, CookieProvider, HttpBackend, HttpBackendError, HttpRequest,
+    HttpResponse, PacingProfile, PlanEnrollOutcome, RequestErrorClass, RetryBudget,
+    ScheduledSection, SeatEvent, SwapOutcome, WrapperError,
+};
+use webweg::wrapper::input_types::{
+    AddType, AutoEnrollOptions, EnrollFromPlanOptions, EnrollWaitAdd, ExplicitAddType, GradeOption,
+    LagBehavior, PlanAdd, SearchType,
+};
+use webweg::wrapper::mock::MockWebReg;
+use webweg::wrapper::seat_watcher::SeatWatcher;
+use webweg::wrapper::WebRegWrapper;
+
+/// Builds a wrapper that sends its requests to the given mock instead of WebReg.
+fn mock_wrapper(mock: MockWebReg) -> WebRegWrapper {
+    WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .with_backend(mock)
+        .try_build_wrapper()
+        .unwrap()
+}
+
+/// Builds a wrapper that sends its requests to the given mock, retrying failed requests up to
+/// twice.
+fn retrying_mock_wrapper(mock: MockWebReg) -> WebRegWrapper {
+    WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .with_backend(mock)
+        .with_pacing(PacingProfile {
+            max_concurrent_requests: 0,
+            min_request_interval: Duration::ZERO,
+            max_retries: 2,
+            retry_backoff: Duration::from_millis(1),
+        })
+        .try_build_wrapper()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn success_mutations_are_not_retried() {
+    let wrapper = retrying_mock_wrapper(
+        MockWebReg::new()
+            .with_status("drop-enroll", 503, "")
+            .with_status("search-load-group-data", 503, ""),
+    );
+    let req = wrapper.req("FA23").parsed();
+
+    let res = req.drop_section(ExplicitAddType::Enroll, "079911").await;
+    assert!(matches!(res, Err(WrapperError::BadStatusCode(503, _))));
+    assert_eq!(
+        Some(&1),
+        wrapper.stats().requests_by_endpoint.get("drop-enroll")
+    );
+
+    // Requests that don't change anything are still retried.
+    let res = req.get_course_info(("CSE", "100")).await;
+    assert!(matches!(res, Err(WrapperError::BadStatusCode(503, _))));
+    assert_eq!(
+        Some(&3),
+        wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("search-load-group-data")
+    );
+}
+
+#[tokio::test]
+async fn success_auto_enroll_checks_schedule_after_transient_error() {
+    // The enroll request fails, but the section is in the schedule, as if WebReg enrolled
+    // us before the request timed out.
+    let wrapper = mock_wrapper(
+            MockWebReg::new()
+                .with_response(
+                    "search-by-sectionid",
+                    r#"[{"UNIT_TO":4.0,"SUBJ_CODE":"HILA","CRSE_TITLE":"Topics","UNIT_FROM":4.0,"CRSE_CODE":"102"}]"#,
+                )
+                .with_response(
+                    "search-load-group-data",
+                    include_str!("json/courseinfo3.json").replace("142034", "185826"),
+                )
+                .with_status("add-enroll", 503, "")
+                .with_response("get-class", include_str!("json/schedule1.json")),
+        );
+
+    let options = AutoEnrollOptions::new()
+        .should_waitlist_if_full(true)
+        .should_validate(false)
+        .with_retries(3, Duration::from_millis(1));
+    let outcome = wrapper
+        .req("SP23")
+        .parsed()
+        .auto_enroll("185826", options)
+        .await
+        .unwrap();
+
+    assert!(matches!(outcome.status, AutoEnrollStatus::Enrolled));
+    assert!(!outcome.tried_waitlist);
+    assert_eq!(1, outcome.attempts);
+    assert_eq!(
+        Some(&1),
+        wrapper.stats().requests_by_endpoint.get("add-enroll")
+    );
+}
+
+#[test]
+fn success_get_section_info_by_section_id() {
+    let wrapper = mock_wrapper(
+            MockWebReg::new()
+                .with_response(
+                    "search-by-sectionid",
+                    r#"[{"UNIT_TO":4.0,"SUBJ_CODE":"MATH","CRSE_TITLE":"Abstract Algebra III","UNIT_FROM":4.0,"CRSE_CODE":"100C"}]"#,
+                )
+                .with_response(
+                    "search-load-group-data",
+                    include_str!("json/courseinfo3.json"),
+                ),
+        );
+    let req = wrapper.req("SP23").parsed();
+
+    let section = block_on(req.get_section_info("142034")).unwrap();
+    assert_eq!("142034", section.section_id);
+    assert_eq!("A01", section.section_code);
+    assert_eq!("MATH 100C", section.subj_course_id);
+
+    let wrapper = mock_wrapper(MockWebReg::new().with_response("search-by-sectionid", "[]"));
+    let res = block_on(wrapper.req("SP23").parsed().get_section_info("999999"));
+    assert!(matches!(res, Err(WrapperError::SectionIdNotFound(..))));
+}
+
+#[test]
+fn fail_watch_seat_counts_zero_interval() {
+    let wrapper = mock_wrapper(MockWebReg::new());
+    let req = wrapper.req("FA23").parsed();
+    let res = req.watch_seat_counts(("CSE", "101"), Duration::ZERO, LagBehavior::Skip);
+    assert!(matches!(res, Err(WrapperError::InputError("interval", _))));
+}
+
+#[test]
+fn fail_seat_watcher_zero_interval() {
+    let wrapper = Arc::new(mock_wrapper(MockWebReg::new()));
+    let res = SeatWatcher::new(wrapper, "FA23", Duration::ZERO);
+    assert!(matches!(res, Err(WrapperError::InputError("interval", _))));
+}
+
+#[tokio::test(start_paused = true)]
+async fn success_spawn_seat_watcher() {
+    let wrapper = Arc::new(mock_wrapper(MockWebReg::new().with_response(
+        "search-load-group-data",
+        include_str!("json/courseinfo3.json"),
+    )));
+
+    let watcher = SeatWatcher::new(wrapper, "FA23", Duration::from_secs(30))
+        .unwrap()
+        .watch(("CSE", "8B"), "142034");
+    let mut events = watcher.subscribe();
+    let task = tokio::spawn(watcher.run());
+
+    assert_eq!(
+        SeatEvent::Opened {
+            section_id: "142034".into(),
+            available_seats: 9,
+        },
+        events.recv().await.unwrap()
+    );
+
+    // The watcher stops once every subscriber is gone.
+    drop(events);
+    task.await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn success_watch_seat_counts_skips_missed_polls() {
+    let wrapper = mock_wrapper(MockWebReg::new().with_response(
+        "search-load-group-data",
+        include_str!("json/courseinfo1.json"),
+    ));
+    let req = wrapper.req("FA23").parsed();
+    let stream = req
+        .watch_seat_counts(("CSE", "101"), Duration::from_secs(10), LagBehavior::Skip)
+        .unwrap();
+    futures::pin_mut!(stream);
+
+    let start = tokio::time::Instant::now();
+    assert!(stream.next().await.unwrap().is_ok());
+    assert_eq!(Duration::ZERO, start.elapsed());
+
+    // Falling behind by a few polls gives one snapshot right away, and then the stream goes
+    // back to the original schedule rather than catching up on the missed polls.
+    tokio::time::advance(Duration::from_secs(35)).await;
+    assert!(stream.next().await.unwrap().is_ok());
+    assert_eq!(Duration::from_secs(35), start.elapsed());
+    assert!(stream.next().await.unwrap().is_ok());
+    assert_eq!(Duration::from_secs(40), start.elapsed());
+}
+
+#[test]
+fn success_validate_grading_option() {
+    let wrapper = mock_wrapper(
+        MockWebReg::new().with_response("get-class", include_str!("json/schedule2.json")),
+    );
+
+    let req = wrapper.req("SP23").parsed();
+    assert!(block_on(req.validate_grading_option("290181", GradeOption::P)).is_ok());
+
+    let res = block_on(req.validate_grading_option("290181", GradeOption::L));
+    assert!(matches!(
+        res,
+        Err(WrapperError::GradeOptionNotAllowed(GradeOption::L, id)) if id == "290181"
+    ));
+
+    let res = block_on(req.validate_grading_option("079911", GradeOption::L));
+    assert!(matches!(res, Err(WrapperError::SectionIdNotFound(..))));
+
+    // Sections marked with `+` can be changed to any grading option. Rules that depend on the
+    // unit count aren't known, so they aren't checked.
+    let wrapper = mock_wrapper(
+        MockWebReg::new().with_response("get-class", include_str!("json/schedule1.json")),
+    );
+    let req = wrapper.req("SP23").parsed();
+    for option in [GradeOption::L, GradeOption::P] {
+        assert!(block_on(req.validate_grading_option("185826", option)).is_ok());
+    }
+
+    // Without any marker, the grading option is assumed to be changeable.
+    let mut schedule: Vec<serde_json::Value> =
+        serde_json::from_str(include_str!("json/schedule2.json")).unwrap();
+    schedule[0]
+        .as_object_mut()
+        .unwrap()
+        .remove("GRADE_OPTN_CD_PLUS");
+    let wrapper = mock_wrapper(
+        MockWebReg::new().with_response("get-class", serde_json::to_string(&schedule).unwrap()),
+    );
+    let req = wrapper.req("SP23").parsed();
+    assert!(block_on(req.validate_grading_option("290181", GradeOption::L)).is_ok());
+}
+
+#[test]
+fn success_swap_section_rolls_back() {
+    let mock = MockWebReg::new()
+        .with_response("get-class", include_str!("json/schedule1.json"))
+        .with_response("drop-enroll", r#"{"OPS":"SUCCESS"}"#)
+        .with_response("edit-enroll", r#"{"OPS":"SUCCESS"}"#)
+        .with_response(
+            "add-enroll",
+            r#"{"OPS":"FAIL","REASON":"Section is full."}"#,
+        );
+    let wrapper = mock_wrapper(mock);
+
+    let req = wrapper.req("SP23").parsed();
+    let new_section = EnrollWaitAdd::builder()
+        .with_section_id("185827")
+        .try_build()
+        .unwrap();
+    let res = block_on(req.swap_section("185826", new_section)).unwrap();
+    assert!(!res.is_swapped());
+    assert!(matches!(res, SwapOutcome::RollbackFailed(..)));
+    assert_eq!(
+        Some(&1),
+        wrapper.stats().requests_by_endpoint.get("drop-enroll")
+    );
+
+    // Planned sections can't be swapped, and nothing is dropped.
+    let wrapper = mock_wrapper(
+        MockWebReg::new().with_response("get-class", include_str!("json/schedule2.json")),
+    );
+    let new_section = EnrollWaitAdd::builder()
+        .with_section_id("290182")
+        .try_build()
+        .unwrap();
+    let res = block_on(
+        wrapper
+            .req("SP23")
+            .parsed()
+            .swap_section("290181", new_section),
+    );
+    assert!(matches!(res, Err(WrapperError::InputError(..))));
+
+    // Neither are sections that you're waitlisted for, since adding one back would lose your
+    // place on the waitlist.
+    let wrapper = mock_wrapper(
+        MockWebReg::new().with_response("get-class", include_str!("json/schedule1.json")),
+    );
+    let new_section = EnrollWaitAdd::builder()
+        .with_section_id("184960")
+        .try_build()
+        .unwrap();
+    let res = block_on(
+        wrapper
+            .req("SP23")
+            .parsed()
+            .swap_section("184959", new_section),
+    );
+    assert!(matches!(res, Err(WrapperError::InputError(..))));
+    assert_eq!(None, wrapper.stats().requests_by_endpoint.get("drop-wait"));
+}
+
+#[test]
+fn success_swap_section_validates_first() {
+    let swap = |reason: &str| {
+        let wrapper = mock_wrapper(
+            MockWebReg::new()
+                .with_response("get-class", include_str!("json/schedule1.json"))
+                .with_response("drop-enroll", r#"{"OPS":"SUCCESS"}"#)
+                .with_response(
+                    "edit-enroll",
+                    format!(r#"{{"OPS":"FAIL","REASON":"{reason}"}}"#),
+                ),
+        );
+        let new_section = EnrollWaitAdd::builder()
+            .with_section_id("185827")
+            .try_build()
+            .unwrap();
+        let res = block_on(
+            wrapper
+                .req("SP23")
+                .parsed()
+                .swap_section("185826", new_section),
+        );
+        let drops = wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("drop-enroll")
+            .copied()
+            .unwrap_or_default();
+        (res, drops)
+    };
+
+    // The new section can't be added for a reason unrelated to the old section, so the old
+    // section is kept.
+    let (res, drops) = swap("Section is full.");
+    assert!(matches!(res, Err(WrapperError::WebRegRejected(..))));
+    assert_eq!(0, drops);
+
+    let (res, drops) = swap("Time conflict with CSE 100.");
+    assert!(matches!(res, Err(WrapperError::WebRegRejected(..))));
+    assert_eq!(0, drops);
+
+    // WebReg didn't say why, so the old section is kept.
+    let (res, drops) = swap("");
+    match res {
+        Err(e @ WrapperError::WebRegRejected(..)) => {
+            assert!(!e.webreg_reason().unwrap().is_empty())
+        }
+        other => panic!("expected a rejection, got {other:?}"),
+    }
+    assert_eq!(0, drops);
+
+    // The only problem is a time conflict with the old section, so it's dropped.
+    let (res, drops) = swap("Time conflict with HILA  102.");
+    assert!(!res.unwrap().is_swapped());
+    assert_eq!(1, drops);
+}
+
+#[test]
+fn success_reject_add_section_with_one_error() {
+    let add = |validate: bool| {
+        let wrapper = mock_wrapper(
+            MockWebReg::new()
+                .with_response(
+                    "edit-enroll",
+                    r#"{"OPS":"FAIL","REASON":"Section is full."}"#,
+                )
+                .with_response(
+                    "add-enroll",
+                    r#"{"OPS":"FAIL","REASON":"Section is full."}"#,
+                ),
+        );
+        let section = EnrollWaitAdd::builder()
+            .with_section_id("185827")
+            .try_build()
+            .unwrap();
+        block_on(
+            wrapper
+                .req("SP23")
+                .parsed()
+                .add_section(AddType::Enroll, section, validate),
+        )
+    };
+
+    // Whether WebReg rejects the section while validating it or while adding it, the error is
+    // the same.
+    for validate in [true, false] {
+        match add(validate) {
+            Err(e @ WrapperError::WebRegRejected(..)) => {
+                assert_eq!(Some("Section is full."), e.webreg_reason().as_deref())
+            }
+            other => panic!("expected a rejection, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn success_act_on_plan_validation() {
+    let plan = |mock: MockWebReg| {
+        let wrapper = mock_wrapper(mock);
+        let plan = PlanAdd::builder()
+            .with_course(("CSE", "100"))
+            .with_section_id("079911")
+            .with_section_code("A01")
+            .with_unit_count(4)
+            .try_build()
+            .unwrap();
+        let res = block_on(wrapper.req("FA23").parsed().add_to_plan(plan, true));
+        let plans = wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("plan-add")
+            .copied();
+        (res, plans)
+    };
+    let restricted = r#"{"OPS":"FAIL","REASON":"This course is restricted to majors."}"#;
+
+    // If the validation request fails, the course isn't planned.
+    let (res, plans) = plan(MockWebReg::new().with_status("edit-plan", 500, ""));
+    assert!(
+        matches!(res, Err(WrapperError::BadStatusCode(500, _))),
+        "{res:?}"
+    );
+    assert_eq!(None, plans);
+
+    // Restricted courses can still be planned.
+    let (res, plans) = plan(
+        MockWebReg::new()
+            .with_response("edit-plan", restricted)
+            .with_response("plan-add", r#"{"OPS":"SUCCESS"}"#),
+    );
+    assert!(res.unwrap());
+    assert_eq!(Some(1), plans);
+
+    // If planning fails too, the validation's reason is given.
+    let (res, _) = plan(
+        MockWebReg::new()
+            .with_response("edit-plan", restricted)
+            .with_response("plan-add", r#"{"OPS":"FAIL","REASON":"Unable to plan."}"#),
+    );
+    match res {
+        Err(e @ WrapperError::WebRegRejected(..)) => assert_eq!(
+            Some("This course is restricted to majors."),
+            e.webreg_reason().as_deref()
+        ),
+        other => panic!("expected a rejection, got {other:?}"),
+    }
+}
+
+#[test]
+fn success_add_linked_sections() {
+    let wrapper = mock_wrapper(
+        MockWebReg::new()
+            .with_response("plan-remove-all", r#"{"OPS":"SUCCESS"}"#)
+            .with_response("add-enroll", r#"{"OPS":"SUCCESS"}"#)
+            .with_response("add-wait", r#"{"OPS":"FAIL","REASON":"Waitlist is full."}"#),
+    );
+    let section = |id| {
+        EnrollWaitAdd::builder()
+            .with_section_id(id)
+            .try_build()
+            .unwrap()
+    };
+    let res = block_on(wrapper.req("FA23").parsed().add_linked_sections(
+        vec![
+            (AddType::Enroll, section("260737")),
+            (AddType::Waitlist, section("260740")),
+            (AddType::Enroll, section("260741")),
+        ],
+        false,
+    ));
+
+    // Sections after the one that couldn't be added aren't attempted.
+    assert!(!res.is_complete());
+    assert_eq!(vec!["260737"], res.added);
+    match res.failed {
+        Some((id, e @ WrapperError::WebRegRejected(..))) => {
+            assert_eq!("260740", id);
+            assert_eq!(Some("Waitlist is full."), e.webreg_reason().as_deref());
+        }
+        other => panic!("expected a rejection, got {other:?}"),
+    }
+    assert_eq!(
+        Some(1),
+        wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("add-enroll")
+            .copied()
+    );
+}
+
+#[test]
+fn success_classify_plan_errors() {
+    let plan = |reason: &str| {
+        let wrapper = mock_wrapper(MockWebReg::new().with_response(
+            "plan-add",
+            format!(r#"{{"OPS":"FAIL","REASON":"{reason}"}}"#),
+        ));
+        let plan = PlanAdd::builder()
+            .with_course(("CSE", "100"))
+            .with_section_id("079911")
+            .with_section_code("A01")
+            .with_unit_count(4)
+            .try_build()
+            .unwrap();
+        block_on(wrapper.req("FA23").parsed().add_to_plan(plan, false))
+    };
+
+    for reason in [
+        "This section is already planned.",
+        "CSE 100 A01 is already in your schedule.",
+        "You have already planned this course.",
+    ] {
+        let res = plan(reason);
+        assert!(
+            matches!(&res, Err(WrapperError::AlreadyPlanned(r)) if r == reason),
+            "{reason}: {res:?}"
+        );
+    }
+
+    // Being rejected for any other reason, including already being enrolled, isn't
+    // classified as already planned.
+    for reason in [
+        "You are already enrolled in this course.",
+        "Section is full.",
+        "Time conflict with CSE 101 A00.",
+    ] {
+        let res = plan(reason);
+        assert!(
+            matches!(res, Err(WrapperError::WebRegRejected(..))),
+            "{reason}: {res:?}"
+        );
+    }
+}
+
+/// Makes a schedule where each section is a copy of the section in `schedule2.json`, with the
+/// given section ID, subject code, course code, section code, and status. The section code
+/// should be numeric (e.g., `001`), so that each section is parsed on its own.
+fn make_schedule(sections: &[(&str, &str, &str, &str, &str)]) -> String {
+    let template: Vec<serde_json::Value> =
+        serde_json::from_str(include_str!("json/schedule2.json")).unwrap();
+    let schedule = sections
+        .iter()
+        .map(|&(section_id, subj_code, crse_code, sect_code, status)| {
+            let mut sec = template[0].clone();
+            let id: i64 = section_id.parse().unwrap();
+            sec["SECTION_NUMBER"] = id.into();
+            sec["SECTION_HEAD"] = id.into();
+            sec["SUBJ_CODE"] = subj_code.into();
+            sec["CRSE_CODE"] = crse_code.into();
+            sec["SECT_CODE"] = sect_code.into();
+            sec["CRSE_TITLE"] = format!("{subj_code} {crse_code} {sect_code}").into();
+            sec["ENROLL_STATUS"] = status.into();
+            sec
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string(&schedule).unwrap()
+}
+
+#[test]
+fn success_reconcile_plan() {
+    let schedule = make_schedule(&[
+        // MATH 100C's 142034 has seats, and 142099 is no longer offered.
+        ("142034", "MATH", "100C", "001", "PL"),
+        ("142099", "MATH", "100C", "002", "PL"),
+        // CSE 101's 260739 is full.
+        ("260739", "CSE", "101", "001", "PL"),
+        // We're already enrolled in another section of HILA 102.
+        ("185827", "HILA", "102", "002", "PL"),
+        ("185826", "HILA", "102", "001", "EN"),
+    ]);
+    let wrapper = mock_wrapper(
+        MockWebReg::new()
+            .with_response("get-class", schedule)
+            .with_response("crsecode=100C", include_str!("json/courseinfo3.json"))
+            .with_response("crsecode=101", include_str!("json/courseinfo1.json")),
+    );
+
+    let rec = block_on(wrapper.req("FA23").parsed().reconcile_plan(None)).unwrap();
+    let ids = |secs: &[ScheduledSection]| {
+        secs.iter()
+            .map(|sec| sec.section_id.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec!["142034"], ids(&rec.open));
+    assert_eq!(vec!["260739"], ids(&rec.full));
+    assert_eq!(vec!["142099"], ids(&rec.cancelled));
+    assert_eq!(vec!["185827"], ids(&rec.enrolled));
+    assert!(rec.waitlisted.is_empty());
+    assert!(!rec.is_done());
+
+    // Each course is only looked up once.
+    assert_eq!(
+        Some(&2),
+        wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("search-load-group-data")
+    );
+}
+
+#[test]
+fn success_enroll_from_plan() {
+    let enroll = |add_enroll: MockWebReg| {
+        let schedule = make_schedule(&[
+            ("260739", "CSE", "101", "001", "PL"),
+            ("142034", "MATH", "100C", "001", "PL"),
+        ]);
+        let wrapper = mock_wrapper(
+            add_enroll
+                .with_response("get-class", schedule)
+                .with_response("crsecode=100C", include_str!("json/courseinfo3.json"))
+                .with_response("crsecode=101", include_str!("json/courseinfo1.json"))
+                .with_response("plan-remove-all", r#"{"OPS":"SUCCESS"}"#)
+                .with_response("add-wait", r#"{"OPS":"SUCCESS"}"#),
+        );
+        let options = EnrollFromPlanOptions::new()
+            .with_priority([("cse", "101")])
+            .should_waitlist_if_full(true)
+            .should_validate(false);
+        let report =
+            block_on(wrapper.req("FA23").parsed().enroll_from_plan(None, options)).unwrap();
+        let waitlists = wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("add-wait")
+            .copied();
+        (report, waitlists)
+    };
+
+    // CSE 101 is tried first even though it's full. Then, WebReg turns down the enroll for
+    // MATH 100C, so it's waitlisted instead.
+    let (report, waitlists) = enroll(MockWebReg::new().with_response(
+        "add-enroll",
+        r#"{"OPS":"FAIL","REASON":"Section is full."}"#,
+    ));
+    assert_eq!("260739", report.attempts[0].section.section_id);
+    assert!(matches!(
+        report.attempts[1].outcome,
+        PlanEnrollOutcome::Waitlisted
+    ));
+    assert_eq!(Some(2), waitlists);
+
+    // Errors that don't come from WebReg turning down the enroll aren't worth waitlisting for.
+    let (report, waitlists) =
+        enroll(MockWebReg::new().with_status("add-enroll", 500, "Internal Server Error"));
+    assert!(matches!(
+        report.attempts[1].outcome,
+        PlanEnrollOutcome::Failed(WrapperError::BadStatusCode(500, _))
+    ));
+    assert_eq!(Some(1), waitlists);
+}
+
+#[test]
+fn success_free_text_search() {
+    // The mock only answers if the query is sent as the basic search value, with every
+    // structured filter left blank.
+    let wrapper = mock_wrapper(MockWebReg::new().with_response(
+        "search-by-all?subjcode=&crsecode=&department=&professor=&title=&levels=&days=\
+            &timestr=&opensection=false&isbasic=true&basicsearchvalue=cse+101&termcode=FA23",
+        concat!(
+            r#"[{"UNIT_TO":4.0,"SUBJ_CODE":"CSE ","CRSE_TITLE":"Design & Analysis of "#,
+            r#"Algorithm","UNIT_FROM":4.0,"CRSE_CODE":"101 "}]"#,
+        ),
+    ));
+
+    let results = block_on(
+        wrapper
+            .req("FA23")
+            .parsed()
+            .search_courses(SearchType::FreeText("  cse 101 ".into())),
+    )
+    .unwrap();
+    assert_eq!(1, results.len());
+    assert_eq!("CSE", results[0].subj_code);
+    assert_eq!("101", results[0].course_code);
+}
+
+#[test]
+fn success_scan_course_range() {
+    // Only CSE 101 exists; every other course number in the range has no sections.
+    let wrapper = mock_wrapper(
+        MockWebReg::new()
+            .with_response(
+                "subjcode=CSE&crsecode=101&",
+                include_str!("json/courseinfo1.json"),
+            )
+            .with_response("search-load-group-data", "[]"),
+    );
+
+    let scan = block_on(
+        wrapper
+            .req("FA23")
+            .parsed()
+            .scan_course_range("CSE", 95..=110),
+    )
+    .unwrap();
+    assert!(scan.is_complete());
+    assert_eq!(1, scan.found.len());
+    assert_eq!("101", scan.found[0].0);
+    assert!(scan.found[0].1.iter().any(|sec| sec.section_id == "260739"));
+
+    // Every course number in the range is probed, across more than one batch.
+    assert_eq!(
+        Some(&16),
+        wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("search-load-group-data")
+    );
+}
+
+#[test]
+fn success_scan_course_range_keeps_going_after_errors() {
+    // CSE 100 is restricted and errors, but the scan still finds CSE 101.
+    let wrapper = mock_wrapper(
+        MockWebReg::new()
+            .with_status("subjcode=CSE&crsecode=100&", 404, "")
+            .with_response(
+                "subjcode=CSE&crsecode=101&",
+                include_str!("json/courseinfo1.json"),
+            )
+            .with_response("search-load-group-data", "[]"),
+    );
+
+    let scan = block_on(
+        wrapper
+            .req("FA23")
+            .parsed()
+            .scan_course_range("CSE", 99..=102),
+    )
+    .unwrap();
+    assert!(!scan.is_complete());
+    assert_eq!(1, scan.found.len());
+    assert_eq!("101", scan.found[0].0);
+    assert_eq!(1, scan.errors.len());
+    assert_eq!("100", scan.errors[0].0);
+    assert!(matches!(
+        scan.errors[0].1,
+        WrapperError::BadStatusCode(404, _)
+    ));
+
+    // A session error stops the scan, since every other course number would fail too.
+    let wrapper = mock_wrapper(MockWebReg::new().with_status("search-load-group-data", 401, ""));
+    let res = block_on(
+        wrapper
+            .req("FA23")
+            .parsed()
+            .scan_course_range("CSE", 99..=102),
+    );
+    assert!(matches!(res, Err(e) if e.is_session_error()));
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn success_blocking_wrapper_mirrors_requests() {
+    let builder = WebRegWrapper::builder()
+        .with_cookies("jlinksessionidx=abc")
+        .with_backend(
+            MockWebReg::new()
+                .with_response(
+                    "subjcode=CSE&crsecode=101&",
+                    include_str!("json/courseinfo1.json"),
+                )
+                .with_response("search-load-group-data", "[]"),
+        );
+    let wrapper = webweg::blocking::WebRegWrapper::from_builder(builder).unwrap();
+
+    let req = wrapper.req("FA23");
+    let courses = req.get_course_info(("CSE", "101")).unwrap();
+    assert!(courses.iter().any(|sec| sec.section_id == "260739"));
+
+    let scan = req.scan_course_range("CSE", 100..=101).unwrap();
+    assert_eq!(1, scan.found.len());
+    assert_eq!(
+        Some(&3),
+        wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("search-load-group-data")
+    );
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn success_blocking_wrapper_retries_without_runtime() {
+    let builder = WebRegWrapper::builder()
+        .with_cookies("jlinksessionidx=abc")
+        .with_backend(MockWebReg::new().with_status("search-load-group-data", 503, ""))
+        .with_pacing(PacingProfile {
+            max_concurrent_requests: 0,
+            min_request_interval: Duration::from_millis(1),
+            max_retries: 2,
+            retry_backoff: Duration::from_millis(1),
+        });
+    let wrapper = webweg::blocking::WebRegWrapper::from_builder(builder).unwrap();
+
+    let res = wrapper.req("FA23").get_course_info(("CSE", "101"));
+    assert!(matches!(res, Err(WrapperError::BadStatusCode(503, _))));
+    assert_eq!(3, wrapper.stats().total_requests);
+}
+
+#[test]
+fn success_search_courses_detailed() {
+    let wrapper = mock_wrapper(
+        MockWebReg::new()
+            .with_response(
+                "search-by-all",
+                concat!(
+                    r#"[{"UNIT_TO":4.0,"SUBJ_CODE":"MATH","CRSE_TITLE":"Abstract Algebra III","#,
+                    r#""UNIT_FROM":4.0,"CRSE_CODE":"100C"},{"UNIT_TO":4.0,"SUBJ_CODE":"CSE","#,
+                    r#""CRSE_TITLE":"Design & Analysis of Algorithm","UNIT_FROM":4.0,"#,
+                    r#""CRSE_CODE":"101"}]"#,
+                ),
+            )
+            .with_response("crsecode=100C", include_str!("json/courseinfo3.json"))
+            .with_response("crsecode=101", include_str!("json/courseinfo1.json")),
+    );
+
+    let courses = block_on(
+        wrapper
+            .req("FA23")
+            .parsed()
+            .search_courses_detailed(SearchType::FreeText("algebra".into()), 2),
+    )
+    .unwrap();
+
+    // The courses are in the order that the search gave them, each with its own sections.
+    assert_eq!(2, courses.len());
+    assert_eq!("100C", courses[0].0.course_code);
+    assert!(courses[0].1.iter().any(|sec| sec.section_id == "142034"));
+    assert_eq!("101", courses[1].0.course_code);
+    assert!(courses[1].1.iter().any(|sec| sec.section_id == "260739"));
+    assert_eq!(
+        Some(&2),
+        wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("search-load-group-data")
+    );
+}
+
+#[test]
+fn success_bulk_plan_operations() {
+    let mock = MockWebReg::new()
+        .with_response("plan-add", r#"{"OPS":"FAIL","REASON":"Course is full."}"#)
+        .with_response("plan-remove", r#"{"OPS":"SUCCESS"}"#);
+    let wrapper = mock_wrapper(mock);
+    let req = wrapper.req("FA23").parsed();
+
+    let plans = ["079911", "079913"]
+        .into_iter()
+        .map(|section_id| {
+            PlanAdd::builder()
+                .with_course(("CSE", "100"))
+                .with_section_id(section_id)
+                .with_section_code("A01")
+                .with_unit_count(4)
+                .try_build()
+                .unwrap()
+        })
+        .collect();
+    let report = block_on(req.add_many_to_plan(plans, 2));
+    assert!(!report.is_complete());
+    assert_eq!(
+        vec!["079911", "079913"],
+        report.failures().map(|(id, _)| id).collect::<Vec<_>>()
+    );
+    assert!(report.to_string().starts_with("0 of 2 sections succeeded"));
+
+    let report = block_on(req.remove_many_from_plan(vec!["079911", "079913", "079915"], None, 2));
+    assert!(report.is_complete());
+    assert_eq!(
+        vec!["079911", "079913", "079915"],
+        report.succeeded().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn success_record_mutating_actions() {
+    let mock = || {
+        MockWebReg::new()
+            .with_response("get-class", include_str!("json/schedule1.json"))
+            .with_response("change-enroll", r#"{"OPS":"SUCCESS"}"#)
+    };
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .with_backend(mock())
+        .should_record_actions(true)
+        .try_build_wrapper()
+        .unwrap();
+
+    let req = wrapper.req("SP23").parsed();
+    assert!(block_on(req.change_grading_option("185826", GradeOption::L)).unwrap());
+
+    // Only the grading option change is recorded, not the schedule request before it.
+    let log = wrapper.action_log();
+    assert_eq!(1, log.actions.len());
+    let action = &log.actions[0];
+    assert_eq!("change-enroll", action.endpoint);
+    assert_eq!(Some("SP23"), action.term.as_deref());
+    assert!(action.params.contains(&("section".into(), "185826".into())));
+    assert!(action.params.contains(&("grade".into(), "L".into())));
+    assert_eq!(Some(200), action.status);
+    assert_eq!(Some(r#"{"OPS":"SUCCESS"}"#), action.response.as_deref());
+    assert!(log.to_json().unwrap().contains("change-enroll"));
+
+    wrapper.clear_action_log();
+    assert!(wrapper.action_log().actions.is_empty());
+
+    let wrapper = mock_wrapper(mock());
+    let req = wrapper.req("SP23").parsed();
+    assert!(block_on(req.change_grading_option("185826", GradeOption::L)).unwrap());
+    assert!(wrapper.action_log().actions.is_empty());
+}
+
+/// A clock that only moves when the test moves it.
+struct ManualClock(Arc<Mutex<SystemTime>>);
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.0.lock().unwrap()
+    }
+}
+
+#[test]
+fn success_cache_schedule_per_session() {
+    let now = Arc::new(Mutex::new(
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+    ));
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .with_backend(
+            MockWebReg::new().with_response("get-class", include_str!("json/schedule1.json")),
+        )
+        .with_clock(ManualClock(now.clone()))
+        .should_close_after_request(true)
+        .try_build_wrapper()
+        .unwrap();
+    let fetches = || {
+        wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("get-class")
+            .copied()
+    };
+
+    // The section isn't in the schedule, so nothing is changed (and the cache is kept).
+    let req = wrapper.req("FA23").parsed();
+    for _ in 0..2 {
+        let res = block_on(req.change_grading_option("999999", GradeOption::L));
+        assert!(matches!(res, Err(WrapperError::SectionIdNotFound(..))));
+    }
+    assert_eq!(Some(1), fetches());
+
+    // Another session never sees this session's schedule.
+    let other = wrapper
+        .req("FA23")
+        .try_override_cookies("other cookies")
+        .unwrap()
+        .parsed();
+    let res = block_on(other.change_grading_option("999999", GradeOption::L));
+    assert!(matches!(res, Err(WrapperError::SectionIdNotFound(..))));
+    assert_eq!(Some(2), fetches());
+
+    // The cached schedule expires according to the wrapper's clock.
+    *now.lock().unwrap() += Duration::from_secs(6);
+    let res = block_on(req.change_grading_option("999999", GradeOption::L));
+    assert!(matches!(res, Err(WrapperError::SectionIdNotFound(..))));
+    assert_eq!(Some(3), fetches());
+}
+
+#[test]
+fn success_keep_only_recent_actions() {
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let now = Arc::new(Mutex::new(start));
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .with_backend(
+            MockWebReg::new()
+                .with_response("get-class", include_str!("json/schedule1.json"))
+                .with_response("change-enroll", r#"{"OPS":"SUCCESS"}"#),
+        )
+        .with_clock(ManualClock(now.clone()))
+        .should_record_actions(true)
+        .with_action_log_capacity(2)
+        .try_build_wrapper()
+        .unwrap();
+
+    let req = wrapper.req("SP23").parsed();
+    for _ in 0..3 {
+        *now.lock().unwrap() += Duration::from_secs(10);
+        assert!(block_on(req.change_grading_option("185826", GradeOption::L)).unwrap());
+    }
+
+    // Only the two most recent requests are kept.
+    let times = wrapper
+        .action_log()
+        .actions
+        .iter()
+        .map(|a| a.at)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            start + Duration::from_secs(20),
+            start + Duration::from_secs(30)
+        ],
+        times
+    );
+}
+
+#[test]
+fn success_limit_response_size() {
+    let schedule = include_str!("json/schedule1.json");
+    let wrapper = |limit: u64| {
+        WebRegWrapper::builder()
+            .with_cookies("my cookies")
+            .with_backend(MockWebReg::new().with_response("get-class", schedule))
+            .with_max_response_size(limit)
+            .try_build_wrapper()
+            .unwrap()
+    };
+
+    let small = wrapper(100);
+    let res = block_on(small.req("FA23").parsed().get_schedule(None));
+    assert!(
+        matches!(res, Err(WrapperError::ResponseTooLarge(100))),
+        "{res:?}"
+    );
+    assert_eq!(
+        Some(&1),
+        small.stats().errors_by_class.get(&RequestErrorClass::Other)
+    );
+
+    // The bytes that were actually read are counted.
+    let large = wrapper(schedule.len() as u64);
+    assert!(block_on(large.req("FA23").parsed().get_schedule(None)).is_ok());
+    assert_eq!(schedule.len() as u64, large.stats().bytes_received);
+}
+
+#[test]
+fn success_cache_subjects_by_clock() {
+    let now = Arc::new(Mutex::new(
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+    ));
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("my cookies")
+        .with_backend(MockWebReg::new().with_response(
+            "search-load-subject",
+            r#"[{"LONG_DESC": "Mathematics", "SUBJECT_CODE": "MATH "}]"#,
+        ))
+        .with_clock(ManualClock(now.clone()))
+        .with_catalog_cache_ttl(Duration::from_secs(60))
+        .should_close_after_request(true)
+        .try_build_wrapper()
+        .unwrap();
+    let fetches = || {
+        wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("search-load-subject")
+            .copied()
+    };
+
+    // Requesters for other terms share the wrapper's list.
+    for term in ["FA23", "WI24"] {
+        let subjects = block_on(wrapper.req(term).parsed().get_subjects_wrapper_cached()).unwrap();
+        assert_eq!(vec!["MATH".to_string()], subjects);
+    }
+    assert_eq!(Some(1), fetches());
+
+    // The cached list expires according to the wrapper's clock.
+    *now.lock().unwrap() += Duration::from_secs(61);
+    block_on(wrapper.req("FA23").parsed().get_subjects_wrapper_cached()).unwrap();
+    assert_eq!(Some(2), fetches());
+}
+
+/// Gives the same cookies every time.
+struct StaticCookies(&'static str);
+
+impl CookieProvider for StaticCookies {
+    fn refresh_cookies(&self) -> BoxFuture<'_, webweg::types::Result<String>> {
+        async move { Ok(self.0.to_owned()) }.boxed()
+    }
+}
+
+/// A WebReg that only accepts `fresh cookies`, and only gives course data once the term has
+/// been associated with that session.
+#[derive(Default)]
+struct FreshSessionBackend {
+    associated: AtomicBool,
+}
+
+impl HttpBackend for FreshSessionBackend {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, HttpBackendError>> {
+        let fresh = request
+            .headers
+            .iter()
+            .any(|(name, value)| name.eq_ignore_ascii_case("cookie") && value == "fresh cookies");
+        let (status, body) = if !fresh {
+            (401, "")
+        } else if request.url.contains("check-eligibility") && request.url.contains("FA23") {
+            self.associated.store(true, Ordering::SeqCst);
+            (200, "{}")
+        } else if request.url.contains("get-status-start") {
+            (200, "{}")
+        } else if self.associated.load(Ordering::SeqCst) {
+            (200, "[]")
+        } else {
+            (200, r#"[{"VERIFY":"FAIL"}]"#)
+        };
+
+        let response = HttpResponse {
+            status,
+            url: request.url,
+            headers: vec![],
+            body: body.into(),
+        };
+        async move { Ok(response) }.boxed()
+    }
+}
+
+#[test]
+fn success_associate_term_after_refreshing_cookies() {
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("old cookies")
+        .with_cookie_provider(StaticCookies("fresh cookies"))
+        .with_backend(FreshSessionBackend::default())
+        .try_build_wrapper()
+        .unwrap();
+
+    let sections = block_on(wrapper.req("FA23").parsed().get_course_info(("CSE", "100"))).unwrap();
+    assert!(sections.is_empty());
+    assert_eq!(vec!["FA23"], wrapper.get_associated_terms());
+
+    let endpoints = wrapper.stats().requests_by_endpoint;
+    assert_eq!(Some(&2), endpoints.get("search-load-group-data"));
+    assert_eq!(Some(&1), endpoints.get("get-status-start"));
+    assert_eq!(Some(&1), endpoints.get("check-eligibility"));
+}
+
+#[test]
+fn success_get_all_term_details() {
+    let wrapper = mock_wrapper(
+        MockWebReg::new()
+            .with_response(
+                "get-term",
+                concat!(
+                    r#"[{"termDesc":"Fall 2023","seqId":5320,"termCode":"FA23"},"#,
+                    r#"{"termDesc":"Winter 2024","seqId":5330,"termCode":"WI24"}]"#
+                ),
+            )
+            .with_response(
+                "get-status-start?termcode=WI24",
+                r#"{"FIRST_PASS_START":"2023-11-20"}"#,
+            )
+            .with_response("get-status-start", "{}")
+            .with_response("check-eligibility", "{}"),
+    );
+
+    let terms = block_on(wrapper.get_all_term_details(4)).unwrap();
+    assert_eq!(
+        vec!["FA23", "WI24"],
+        terms
+            .iter()
+            .map(|t| t.term_code.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        None,
+        terms[0].enrollment.as_ref().unwrap().first_pass.as_deref()
+    );
+    assert_eq!(
+        Some("2023-11-20"),
+        terms[1].enrollment.as_ref().unwrap().first_pass.as_deref()
+    );
+
+    // Getting the enrollment periods associates each term.
+    assert_eq!(vec!["FA23", "WI24"], wrapper.get_associated_terms());
+}
+
+#[test]
+fn success_verify_associated_terms() {
+    let wrapper = mock_wrapper(
+        MockWebReg::new()
+            .with_response("get-status-start", "{}")
+            .with_response("check-eligibility", "{}")
+            .with_response("termcode=FA23", r#"[{"VERIFY":"FAIL"}]"#)
+            .with_response("termcode=WI24", r#"["My Schedule"]"#),
+    );
+    for term in ["FA23", "SP23", "WI24"] {
+        block_on(wrapper.associate_term(term)).unwrap();
+    }
+
+    // WebReg has forgotten about FA23, so it's removed. SP23 couldn't be checked, since the
+    // mock has no response for it, so it's kept.
+    assert_eq!(
+        vec!["SP23", "WI24"],
+        block_on(wrapper.verify_associated_terms())
+    );
+    assert_eq!(vec!["SP23", "WI24"], wrapper.get_associated_terms());
+    assert_eq!(
+        Some(&3),
+        wrapper
+            .stats()
+            .requests_by_endpoint
+            .get("sched-get-schednames")
+    );
+}
+
+#[tokio::test]
+async fn success_wrap_only_errors_stopped_by_retry_budget() {
+    let wrapper = retrying_mock_wrapper(
+        MockWebReg::new()
+            .with_status("termcode=FA23", 503, "")
+            .with_status("termcode=WI24", 404, "")
+            .with_response("termcode=SP24", include_str!("json/courseinfo1.json")),
+    );
+    let budget = RetryBudget::new(1);
+    let results = wrapper
+        .req_many(&["FA23", "WI24", "SP24"])
+        .with_retry_budget(&budget)
+        .get_course_info_across_terms(("CSE", "101"))
+        .await;
+
+    // FA23 used up the budget, but WI24 failed for a reason that retrying wouldn't fix.
+    assert!(budget.is_exhausted());
+    assert!(matches!(
+        results["FA23"],
+        Err(WrapperError::RetryBudgetExhausted(ref e)) if matches!(**e, WrapperError::BadStatusCode(503, _))
+    ));
+    assert!(matches!(
+        results["WI24"],
+        Err(WrapperError::BadStatusCode(404, _))
+    ));
+    assert!(results["SP24"].is_ok());
+}
+
+#[tokio::test]
+async fn success_search_courses_detailed_with_retry_budget() {
+    let wrapper = retrying_mock_wrapper(
+        MockWebReg::new()
+            .with_response(
+                "search-by-all",
+                concat!(
+                    r#"[{"UNIT_TO":4.0,"SUBJ_CODE":"MATH","CRSE_TITLE":"Abstract Algebra III","#,
+                    r#""UNIT_FROM":4.0,"CRSE_CODE":"100C"},{"UNIT_TO":4.0,"SUBJ_CODE":"CSE","#,
+                    r#""CRSE_TITLE":"Design & Analysis of Algorithm","UNIT_FROM":4.0,"#,
+                    r#""CRSE_CODE":"101"}]"#,
+                ),
+            )
+            .with_status("crsecode=100C", 503, "")
+            .with_response("crsecode=101", include_str!("json/courseinfo1.json")),
+    );
+    let budget = RetryBudget::new(0);
+    let courses = wrapper
+        .req("FA23")
+        .with_retry_budget(&budget)
+        .parsed()
+        .search_courses_detailed(SearchType::FreeText("algebra".into()), 2)
+        .await
+        .unwrap();
+
+    // MATH 100C couldn't be retried, so only the courses that were retrieved are given.
+    assert!(budget.is_exhausted());
+    assert_eq!(1, courses.len());
+    assert_eq!("101", courses[0].0.course_code);
+}
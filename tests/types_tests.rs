@@ -0,0 +1,1107 @@
+use webweg::types::{Meeting, MeetingDay, TimeType};
+
+/// Creates a meeting with the specified days and times. Everything else is left blank.
+fn meeting(days: MeetingDay, start: (TimeType, TimeType), end: (TimeType, TimeType)) -> Meeting {
+    Meeting {
+        meeting_type: "LE".into(),
+        meeting_days: days,
+        start_hr: start.0,
+        start_min: start.1,
+        end_hr: end.0,
+        end_min: end.1,
+        building: "".into(),
+        room: "".into(),
+        instructors: vec![],
+    }
+}
+
+fn repeated(days: &[&str]) -> MeetingDay {
+    MeetingDay::Repeated(days.iter().map(|d| d.to_string()).collect())
+}
+
+#[test]
+fn test_meeting_conflict_overlapping() {
+    let a = meeting(repeated(&["M", "W", "F"]), (10, 0), (10, 50));
+    let b = meeting(repeated(&["W"]), (10, 30), (11, 20));
+    assert!(a.conflicts_with(&b));
+    assert!(b.conflicts_with(&a));
+}
+
+#[test]
+fn test_meeting_conflict_back_to_back() {
+    let a = meeting(repeated(&["Tu", "Th"]), (9, 30), (10, 50));
+    let b = meeting(repeated(&["Tu", "Th"]), (10, 50), (12, 10));
+    assert!(!a.conflicts_with(&b));
+}
+
+#[test]
+fn test_meeting_conflict_different_days() {
+    let a = meeting(repeated(&["M", "W", "F"]), (10, 0), (10, 50));
+    let b = meeting(repeated(&["Tu", "Th"]), (10, 0), (10, 50));
+    assert!(!a.conflicts_with(&b));
+}
+
+#[test]
+fn test_meeting_conflict_one_time() {
+    let a = meeting(MeetingDay::OneTime("2023-12-09".into()), (8, 0), (10, 59));
+    let b = meeting(MeetingDay::OneTime("2023-12-09".into()), (10, 0), (12, 0));
+    let c = meeting(MeetingDay::OneTime("2023-12-10".into()), (10, 0), (12, 0));
+    let d = meeting(repeated(&["Sa"]), (8, 0), (10, 59));
+    assert!(a.conflicts_with(&b));
+    assert!(!a.conflicts_with(&c));
+    assert!(!a.conflicts_with(&d));
+    assert!(!a.conflicts_with(&meeting(MeetingDay::None, (8, 0), (10, 59))));
+}
+
+#[cfg(feature = "chrono")]
+mod term_dates_tests {
+    use super::{meeting, repeated};
+    use chrono::NaiveDate;
+    use webweg::types::{EnrollmentStatus, MeetingDay, ScheduledSection, TermDates, TermWeek};
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, m, d).unwrap()
+    }
+
+    /// Fall 2023 starts on a Thursday, with finals starting on Saturday, December 9.
+    fn fall_term() -> TermDates {
+        TermDates::new(date(9, 28), date(12, 9), date(12, 16))
+    }
+
+    #[test]
+    fn test_week_of() {
+        let term = fall_term();
+        assert_eq!(None, term.week_of(date(9, 27)));
+        assert_eq!(Some(TermWeek::Instruction(1)), term.week_of(date(9, 28)));
+        assert_eq!(Some(TermWeek::Instruction(1)), term.week_of(date(10, 1)));
+        assert_eq!(Some(TermWeek::Instruction(2)), term.week_of(date(10, 2)));
+        assert_eq!(Some(TermWeek::Finals), term.week_of(date(12, 11)));
+        assert_eq!(None, term.week_of(date(12, 17)));
+    }
+
+    #[test]
+    fn test_meetings_on() {
+        let section = ScheduledSection {
+            section_id: "79903".into(),
+            subject_code: "CSE".into(),
+            course_code: "100".into(),
+            course_title: "Advanced Data Structure".into(),
+            section_code: "A01".into(),
+            section_capacity: 100,
+            enrolled_count: 100,
+            available_seats: 0,
+            grade_option: None,
+            grade_option_locked: false,
+            all_instructors: vec![],
+            units: 4,
+            enrolled_status: EnrollmentStatus::Enrolled,
+            waitlist_ct: 0,
+            meetings: vec![
+                meeting(repeated(&["M", "W", "F"]), (11, 0), (11, 50)),
+                meeting(repeated(&["M"]), (9, 0), (9, 50)),
+                meeting(MeetingDay::OneTime("2023-12-11".into()), (8, 0), (10, 59)),
+            ],
+        };
+        let schedule = vec![section];
+        let term = fall_term();
+
+        let monday = term.meetings_on(date(10, 2), &schedule);
+        assert_eq!(2, monday.len());
+        assert_eq!(9, monday[0].1.start_hr);
+        assert_eq!(11, monday[1].1.start_hr);
+
+        assert!(term.meetings_on(date(10, 3), &schedule).is_empty());
+
+        // Only the final should show up during finals week.
+        let finals = term.meetings_on(date(12, 11), &schedule);
+        assert_eq!(1, finals.len());
+        assert_eq!(8, finals[0].1.start_hr);
+    }
+}
+
+mod seat_strategy_tests {
+    use webweg::types::{
+        AvailableSeatStrategy, CourseSection, DefaultSeatStrategy, SeatCount, SeatEvent,
+        SeatStrategy,
+    };
+
+    fn section(available_seats: i64, waitlist_ct: i64) -> CourseSection {
+        CourseSection {
+            subj_course_id: "CSE 100".into(),
+            section_id: "079912".into(),
+            section_code: "B01".into(),
+            all_instructors: vec![],
+            available_seats,
+            available_seats_raw: available_seats,
+            instructor_info: Default::default(),
+            section_notes: vec![],
+            waitlist_open: true,
+            enrolled_ct: 0,
+            total_seats: 30,
+            waitlist_ct,
+            meetings: vec![],
+            is_visible: true,
+        }
+    }
+
+    struct NeverOpen;
+
+    impl SeatStrategy for NeverOpen {
+        fn has_seats(&self, _: &CourseSection) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn test_default_seat_strategy() {
+        assert!(section(5, 0).has_seats());
+        assert!(!section(5, 2).has_seats());
+        assert!(!section(0, 0).has_seats());
+    }
+
+    #[test]
+    fn test_custom_seat_strategy() {
+        assert!(section(5, 2).has_seats_with(&AvailableSeatStrategy));
+        assert!(!section(0, 2).has_seats_with(&AvailableSeatStrategy));
+        assert!(!section(5, 0).has_seats_with(&NeverOpen));
+    }
+
+    #[test]
+    fn test_seat_count_agrees_with_section() {
+        for (available_seats, waitlist_ct) in [(5, 0), (5, 2), (0, 0), (0, 2)] {
+            let count = SeatCount {
+                section_code: "B01".into(),
+                enrolled_ct: 0,
+                available_seats,
+                waitlist_ct,
+            };
+            let sec = section(available_seats, waitlist_ct);
+
+            assert_eq!(sec.has_seats(), count.has_seats());
+            for strategy in [&AvailableSeatStrategy as &dyn SeatStrategy, &NeverOpen] {
+                assert_eq!(sec.has_seats_with(strategy), count.has_seats_with(strategy));
+            }
+        }
+    }
+
+    #[test]
+    fn test_seat_event_first_check() {
+        assert_eq!(
+            vec![SeatEvent::Opened {
+                section_id: "079912".into(),
+                available_seats: 5,
+            }],
+            SeatEvent::changes(None, &section(5, 0), &DefaultSeatStrategy)
+        );
+        assert!(SeatEvent::changes(None, &section(0, 3), &DefaultSeatStrategy).is_empty());
+    }
+
+    #[test]
+    fn test_seat_event_transitions() {
+        let open = section(2, 0);
+        let full = section(0, 0);
+        let waitlisted = section(0, 4);
+
+        assert_eq!(
+            vec![SeatEvent::Closed {
+                section_id: "079912".into()
+            }],
+            SeatEvent::changes(Some(&open), &full, &DefaultSeatStrategy)
+        );
+        assert_eq!(
+            vec![SeatEvent::WaitlistChanged {
+                section_id: "079912".into(),
+                old: 0,
+                new: 4,
+            }],
+            SeatEvent::changes(Some(&full), &waitlisted, &DefaultSeatStrategy)
+        );
+        assert!(SeatEvent::changes(Some(&open), &open, &DefaultSeatStrategy).is_empty());
+    }
+}
+
+mod coscheduled_tests {
+    use super::{meeting, repeated};
+    use webweg::types::{CourseSection, Meeting};
+
+    fn section(course: &str, meetings: Vec<Meeting>) -> CourseSection {
+        CourseSection {
+            subj_course_id: course.into(),
+            section_id: "000000".into(),
+            section_code: "A00".into(),
+            all_instructors: vec![],
+            available_seats: 0,
+            available_seats_raw: 0,
+            instructor_info: Default::default(),
+            section_notes: vec![],
+            waitlist_open: true,
+            enrolled_ct: 0,
+            total_seats: 0,
+            waitlist_ct: 0,
+            meetings,
+            is_visible: true,
+        }
+    }
+
+    fn in_room(mut m: Meeting, building: &str, room: &str) -> Meeting {
+        m.building = building.into();
+        m.room = room.into();
+        m
+    }
+
+    #[test]
+    fn test_coscheduled_same_room_and_time() {
+        let a = section(
+            "CSE 190",
+            vec![in_room(
+                meeting(repeated(&["Tu", "Th"]), (11, 0), (12, 20)),
+                "CENTR",
+                "115",
+            )],
+        );
+        let b = section(
+            "CSE 291",
+            vec![in_room(
+                meeting(repeated(&["Tu", "Th"]), (11, 0), (12, 20)),
+                "CENTR",
+                "115",
+            )],
+        );
+        assert!(a.is_coscheduled_with(&b));
+        assert!(b.is_coscheduled_with(&a));
+    }
+
+    #[test]
+    fn test_not_coscheduled_different_room() {
+        let a = section(
+            "CSE 190",
+            vec![in_room(
+                meeting(repeated(&["Tu", "Th"]), (11, 0), (12, 20)),
+                "CENTR",
+                "115",
+            )],
+        );
+        let b = section(
+            "CSE 291",
+            vec![in_room(
+                meeting(repeated(&["Tu", "Th"]), (11, 0), (12, 20)),
+                "WLH",
+                "2001",
+            )],
+        );
+        assert!(!a.is_coscheduled_with(&b));
+    }
+
+    #[test]
+    fn test_not_coscheduled_same_course() {
+        let m = in_room(meeting(repeated(&["M"]), (9, 0), (9, 50)), "CENTR", "115");
+        let a = section("CSE 190", vec![m.clone()]);
+        let b = section("CSE 190", vec![m]);
+        assert!(!a.is_coscheduled_with(&b));
+    }
+}
+
+mod fingerprint_tests {
+    use super::{meeting, repeated};
+    use webweg::types::{CourseSection, MeetingDay};
+
+    fn section() -> CourseSection {
+        CourseSection {
+            subj_course_id: "CSE 100".into(),
+            section_id: "079911".into(),
+            section_code: "A01".into(),
+            all_instructors: vec!["Smith, John".into(), "Doe, Jane".into()],
+            available_seats: 10,
+            available_seats_raw: 10,
+            instructor_info: Default::default(),
+            section_notes: vec![],
+            waitlist_open: true,
+            enrolled_ct: 20,
+            total_seats: 30,
+            waitlist_ct: 0,
+            meetings: vec![
+                meeting(repeated(&["M", "W", "F"]), (10, 0), (10, 50)),
+                meeting(MeetingDay::OneTime("2023-12-12".into()), (8, 0), (10, 59)),
+            ],
+            is_visible: true,
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_counts_and_order() {
+        let original = section();
+        assert_eq!(original.fingerprint(), section().fingerprint());
+
+        let mut changed = section();
+        changed.available_seats = 0;
+        changed.enrolled_ct = 30;
+        changed.waitlist_ct = 4;
+        changed.meetings.reverse();
+        changed.all_instructors.reverse();
+        assert_eq!(original.fingerprint(), changed.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_detects_changes() {
+        let original = section().fingerprint();
+
+        let mut changed = section();
+        changed.meetings[0].start_hr = 11;
+        assert_ne!(original, changed.fingerprint());
+
+        let mut changed = section();
+        changed.all_instructors.pop();
+        assert_ne!(original, changed.fingerprint());
+
+        let mut changed = section();
+        changed.total_seats = 40;
+        assert_ne!(original, changed.fingerprint());
+    }
+}
+
+mod validation_outcome_tests {
+    use webweg::types::{EnrollmentFailure, ValidationOutcome, WrapperError};
+
+    #[test]
+    fn test_classify_reasons() {
+        assert!(matches!(
+            EnrollmentFailure::from_reason("This section conflicts with CSE 100 A01."),
+            EnrollmentFailure::TimeConflict(_)
+        ));
+        assert!(matches!(
+            EnrollmentFailure::from_reason("Your enrollment appointment has not started yet."),
+            EnrollmentFailure::AppointmentTime(_)
+        ));
+        assert!(matches!(
+            EnrollmentFailure::from_reason("Adding this course would exceed your unit limit."),
+            EnrollmentFailure::UnitLimit(_)
+        ));
+        assert!(matches!(
+            EnrollmentFailure::from_reason("This course is restricted to CS majors."),
+            EnrollmentFailure::Restriction(_)
+        ));
+        assert!(matches!(
+            EnrollmentFailure::from_reason("The waitlist for this section is full."),
+            EnrollmentFailure::WaitlistFull(_)
+        ));
+        assert!(matches!(
+            EnrollmentFailure::from_reason("Wait list is closed for this section."),
+            EnrollmentFailure::WaitlistFull(_)
+        ));
+        assert!(matches!(
+            EnrollmentFailure::from_reason("Something unexpected happened."),
+            EnrollmentFailure::Other(_)
+        ));
+    }
+
+    #[test]
+    fn test_rejected_keeps_message() {
+        let outcome = ValidationOutcome::rejected("You are already enrolled in this course.");
+        assert!(!outcome.ok);
+        let reason = outcome.reason.unwrap();
+        assert!(matches!(reason, EnrollmentFailure::AlreadyAdded(_)));
+        assert_eq!("You are already enrolled in this course.", reason.message());
+    }
+
+    #[test]
+    fn test_classify_wrapper_errors() {
+        let err =
+            WrapperError::WebRegError("Adding this course would exceed your unit limit.".into());
+        assert!(matches!(
+            err.rejection(),
+            Some(EnrollmentFailure::UnitLimit(_))
+        ));
+
+        let err = WrapperError::AlreadyPlanned("Already planned.".into());
+        assert_eq!(
+            Some(EnrollmentFailure::AlreadyAdded("Already planned.".into())),
+            err.rejection()
+        );
+
+        assert_eq!(None, WrapperError::SessionNotValid.rejection());
+    }
+
+    #[test]
+    fn test_transient_errors() {
+        assert!(WrapperError::BadStatusCode(503, None).is_transient());
+        assert!(WrapperError::BadStatusCode(429, None).is_transient());
+        assert!(!WrapperError::BadStatusCode(404, None).is_transient());
+        assert!(
+            WrapperError::RetryBudgetExhausted(Box::new(WrapperError::BadStatusCode(502, None)))
+                .is_transient()
+        );
+        assert!(!WrapperError::WebRegError("The waitlist is full.".into()).is_transient());
+        assert!(!WrapperError::SessionExpired.is_transient());
+    }
+}
+
+mod health_report_tests {
+    use std::time::{Duration, SystemTime};
+    use webweg::types::{HealthCheck, HealthReport};
+
+    fn check(name: &'static str, ok: bool) -> HealthCheck {
+        HealthCheck {
+            name,
+            ok,
+            latency: Duration::from_millis(50),
+            error: (!ok).then(|| "failed".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_is_healthy() {
+        let mut report = HealthReport {
+            checks: vec![check("ping", true), check("term", true)],
+            account_name: Some("Your name here".into()),
+            checked_at: SystemTime::now(),
+        };
+        assert!(report.is_healthy());
+
+        report.checks.push(check("account_name", false));
+        assert!(!report.is_healthy());
+    }
+}
+
+#[test]
+fn test_retry_budget_new() {
+    use webweg::types::RetryBudget;
+
+    let budget = RetryBudget::new(5);
+    let shared = budget.clone();
+    assert_eq!(5, shared.remaining());
+    assert!(!shared.is_exhausted());
+    assert!(!RetryBudget::new(0).is_exhausted());
+}
+
+#[test]
+fn test_special_meeting_codes() {
+    use webweg::types::{Meeting, MeetingDay, SpecialMeetingCode};
+
+    for code in ["FI", "MI", "RE", "PB", "MU", "OT", "XY"] {
+        assert_eq!(code, SpecialMeetingCode::from_code(code).code());
+    }
+
+    assert_eq!(
+        "Review Session",
+        SpecialMeetingCode::from_code("RE").to_string()
+    );
+    assert_eq!(
+        SpecialMeetingCode::Unknown("XY".into()),
+        SpecialMeetingCode::from_code("XY")
+    );
+
+    let mut meeting = Meeting {
+        meeting_type: "FI".into(),
+        meeting_days: MeetingDay::OneTime("2023-06-14".into()),
+        start_hr: 11,
+        start_min: 30,
+        end_hr: 14,
+        end_min: 29,
+        building: "CENTR".into(),
+        room: "115".into(),
+        instructors: vec![],
+    };
+    assert_eq!(
+        Some(SpecialMeetingCode::FinalExam),
+        meeting.special_meeting()
+    );
+
+    meeting.meeting_type = "LE".into();
+    meeting.meeting_days = MeetingDay::Repeated(vec!["M".into()]);
+    assert_eq!(None, meeting.special_meeting());
+}
+
+mod term_requester_tests {
+    use futures::future::BoxFuture;
+    use webweg::types;
+    use webweg::types::{CourseCode, Courses, Events, Schedule, SearchResult};
+    use webweg::wrapper::input_types::SearchType;
+    use webweg::wrapper::mock::{MockTermRequester, MockWebReg};
+    use webweg::wrapper::term_requester::TermRequester;
+
+    /// A requester that doesn't have any courses, and whose schedule is always empty.
+    struct EmptyRequester;
+
+    impl TermRequester for EmptyRequester {
+        fn get_course_info<'b>(
+            &'b self,
+            _: &'b CourseCode,
+        ) -> BoxFuture<'b, types::Result<Courses>> {
+            Box::pin(async { Ok(vec![]) })
+        }
+
+        fn get_enrollment_count<'b>(
+            &'b self,
+            _: &'b CourseCode,
+        ) -> BoxFuture<'b, types::Result<Courses>> {
+            Box::pin(async { Ok(vec![]) })
+        }
+
+        fn get_schedule<'b>(
+            &'b self,
+            _: Option<&'b str>,
+        ) -> BoxFuture<'b, types::Result<Schedule>> {
+            Box::pin(async { Ok(vec![]) })
+        }
+
+        fn search_courses(&self, _: SearchType) -> BoxFuture<'_, types::Result<SearchResult>> {
+            Box::pin(async { Ok(vec![]) })
+        }
+
+        fn get_events(&self) -> BoxFuture<'_, types::Result<Events>> {
+            Box::pin(async { Ok(vec![]) })
+        }
+    }
+
+    async fn count_sections(req: &impl TermRequester, course: &CourseCode) -> usize {
+        req.get_course_info(course)
+            .await
+            .map(|c| c.len())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_generic_over_requester() {
+        let req = EmptyRequester;
+        assert_eq!(
+            0,
+            futures::executor::block_on(count_sections(&req, &CourseCode::new("CSE", "100")))
+        );
+        assert!(futures::executor::block_on(req.get_schedule(None))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_mock_term_requester() {
+        let req = MockTermRequester::new(
+            MockWebReg::new().with_response(
+                "subjcode=CSE&crsecode=101&",
+                include_str!("json/courseinfo1.json"),
+            ),
+            "FA23",
+        );
+
+        let course = CourseCode::new("CSE", "101");
+        assert!(futures::executor::block_on(count_sections(&req, &course)) > 0);
+        assert!(req.mock().requests()[0].url.contains("termcode=FA23"));
+
+        // Anything without a canned response fails, like it would with the mock backend.
+        assert!(futures::executor::block_on(req.get_events()).is_err());
+    }
+}
+
+mod enrollment_series_tests {
+    use std::time::{Duration, SystemTime};
+    use webweg::types::{EnrollmentPoint, EnrollmentSeries};
+
+    fn point(mins: u64, enrolled_ct: i64, waitlist_ct: i64) -> EnrollmentPoint {
+        EnrollmentPoint {
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(mins * 60),
+            enrolled_ct,
+            available_seats: 100 - enrolled_ct,
+            waitlist_ct,
+        }
+    }
+
+    #[test]
+    fn test_series_is_bounded() {
+        let mut series = EnrollmentSeries::new(3);
+        assert!(series.is_empty());
+        assert!(series.rate_of_change(Duration::MAX).is_none());
+
+        for i in 0..5 {
+            series.push(point(i * 10, i as i64, 0));
+        }
+
+        assert_eq!(3, series.len());
+        assert_eq!(
+            vec![2, 3, 4],
+            series.points().map(|p| p.enrolled_ct).collect::<Vec<_>>()
+        );
+        assert_eq!(4, series.latest().unwrap().enrolled_ct);
+    }
+
+    #[test]
+    fn test_rate_of_change() {
+        let mut series = EnrollmentSeries::new(10);
+        series.push(point(0, 0, 0));
+        series.push(point(30, 50, 0));
+        series.push(point(60, 90, 6));
+
+        let rate = series.rate_of_change(Duration::MAX).unwrap();
+        assert_eq!(90.0, rate.enrolled_per_hour);
+        assert_eq!(-90.0, rate.available_per_hour);
+        assert_eq!(6.0, rate.waitlist_per_hour);
+
+        // Only the last 30 minutes.
+        let rate = series.rate_of_change(Duration::from_secs(30 * 60)).unwrap();
+        assert_eq!(80.0, rate.enrolled_per_hour);
+        assert_eq!(12.0, rate.waitlist_per_hour);
+
+        // Not enough points in the window.
+        assert!(series.rate_of_change(Duration::from_secs(60)).is_none());
+    }
+
+    #[test]
+    fn test_series_round_trip() {
+        let mut series = EnrollmentSeries::new(2);
+        series.push(point(0, 1, 0));
+        series.push(point(5, 2, 0));
+
+        let json = serde_json::to_string(&series).unwrap();
+        assert_eq!(series, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn test_series_checks_capacity_when_deserialized() {
+        let mut series = EnrollmentSeries::new(3);
+        for i in 0..3 {
+            series.push(point(i, i as i64, 0));
+        }
+
+        let mut json = serde_json::to_value(&series).unwrap();
+        json["capacity"] = 0.into();
+        assert!(serde_json::from_value::<EnrollmentSeries>(json.clone()).is_err());
+
+        // Only the most recent points fit.
+        json["capacity"] = 2.into();
+        let mut series = serde_json::from_value::<EnrollmentSeries>(json).unwrap();
+        assert_eq!(2, series.capacity());
+        assert_eq!(
+            vec![1, 2],
+            series.points().map(|p| p.enrolled_ct).collect::<Vec<_>>()
+        );
+
+        series.push(point(3, 3, 0));
+        assert_eq!(2, series.len());
+    }
+}
+
+mod ops_response_tests {
+    use webweg::types::{WebRegOpsResponse, WrapperError};
+
+    #[test]
+    fn parse_failed_response() {
+        let res: WebRegOpsResponse = serde_json::from_str(
+            r#"{"OPS":"FAIL","REASON":"<b>Section is full.</b> Try again later.","SECTNUM":"079911"}"#,
+        )
+        .unwrap();
+
+        assert!(!res.is_success());
+        assert_eq!("Section is full. Try again later.", res.reason_text());
+        assert_eq!(Some("079911"), res.extra["SECTNUM"].as_str());
+
+        let err = WrapperError::WebRegRejected(Box::new(res));
+        assert_eq!(
+            Some("Section is full. Try again later."),
+            err.webreg_reason().as_deref()
+        );
+        assert_eq!(
+            "Error from WebReg: Section is full. Try again later.",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_successful_response() {
+        let res: WebRegOpsResponse = serde_json::from_str(r#"{"OPS":"SUCCESS"}"#).unwrap();
+        assert!(res.is_success());
+        assert_eq!(None, res.reason);
+        assert!(res.extra.is_empty());
+        assert_eq!("", res.reason_text());
+    }
+}
+
+mod instructor_tests {
+    use super::{meeting, repeated};
+    use webweg::types::{CourseSection, Meeting, MeetingDay};
+
+    fn taught_by(mut m: Meeting, instructors: &[&str]) -> Meeting {
+        m.instructors = instructors.iter().map(|i| i.to_string()).collect();
+        m
+    }
+
+    #[test]
+    fn test_section_and_additional_instructors() {
+        let lecture = taught_by(
+            meeting(repeated(&["M", "W", "F"]), (10, 0), (10, 50)),
+            &["Smith, Jane", "Doe, John"],
+        );
+        let discussion = taught_by(
+            meeting(repeated(&["Tu"]), (17, 0), (17, 50)),
+            &["Doe, John", "Smith, Jane", "Lee, TA"],
+        );
+        let exam = meeting(MeetingDay::OneTime("2023-12-09".into()), (8, 0), (10, 59));
+
+        let section = CourseSection {
+            subj_course_id: "CSE 100".into(),
+            section_id: "079912".into(),
+            section_code: "A01".into(),
+            all_instructors: vec!["Doe, John".into(), "Lee, TA".into(), "Smith, Jane".into()],
+            available_seats: 0,
+            available_seats_raw: 0,
+            instructor_info: Default::default(),
+            section_notes: vec![],
+            waitlist_open: true,
+            enrolled_ct: 0,
+            total_seats: 0,
+            waitlist_ct: 0,
+            meetings: vec![lecture, discussion, exam],
+            is_visible: true,
+        };
+
+        let base = section.section_instructors();
+        assert_eq!(vec!["Doe, John", "Smith, Jane"], base);
+        assert!(section.meetings[0].additional_instructors(&base).is_empty());
+        assert_eq!(
+            vec!["Lee, TA"],
+            section.meetings[1].additional_instructors(&base)
+        );
+        assert_eq!(
+            section.all_instructors,
+            section.meetings[1].instructors_with(&base)
+        );
+        // A meeting without any listed instructors falls back to the section's.
+        assert_eq!(base, section.meetings[2].instructors_with(&base));
+    }
+}
+
+mod room_index_tests {
+    use super::{meeting, repeated};
+    use webweg::types::{CourseSection, Meeting, MeetingDay, RoomIndex};
+
+    fn section(course: &str, code: &str, meetings: Vec<Meeting>) -> CourseSection {
+        CourseSection {
+            subj_course_id: course.into(),
+            section_id: "000000".into(),
+            section_code: code.into(),
+            all_instructors: vec![],
+            available_seats: 0,
+            available_seats_raw: 0,
+            instructor_info: Default::default(),
+            section_notes: vec![],
+            waitlist_open: true,
+            enrolled_ct: 0,
+            total_seats: 0,
+            waitlist_ct: 0,
+            meetings,
+            is_visible: true,
+        }
+    }
+
+    fn in_room(mut m: Meeting, building: &str, room: &str) -> Meeting {
+        m.building = building.into();
+        m.room = room.into();
+        m
+    }
+
+    #[test]
+    fn test_room_index_queries() {
+        let lecture = in_room(
+            meeting(repeated(&["M", "W", "F"]), (10, 0), (10, 50)),
+            "CENTR",
+            "115",
+        );
+        let sections = vec![
+            section("CSE 100", "A01", vec![lecture.clone()]),
+            section("CSE 100", "A02", vec![lecture]),
+            section(
+                "MATH 18",
+                "B01",
+                vec![
+                    in_room(meeting(repeated(&["Tu"]), (10, 0), (10, 50)), "WLH", "2001"),
+                    in_room(meeting(repeated(&["Th"]), (10, 0), (10, 50)), "TBA", "TBA"),
+                ],
+            ),
+        ];
+
+        let index = RoomIndex::from_sections(&sections);
+        assert_eq!(
+            vec![("CENTR", "115"), ("WLH", "2001")],
+            index.rooms().collect::<Vec<_>>()
+        );
+        // The shared lecture is only indexed once.
+        assert_eq!(1, index.bookings("centr", "115").len());
+
+        let mwf = repeated(&["M", "W", "F"]);
+        assert!(!index.is_free("CENTR", "115", &mwf, (10, 30), (11, 0)));
+        assert!(index.is_free("CENTR", "115", &mwf, (11, 0), (12, 0)));
+        assert!(index.is_free("CENTR", "115", &repeated(&["Tu"]), (10, 0), (11, 0)));
+        assert!(index.is_free(
+            "CENTR",
+            "115",
+            &MeetingDay::OneTime("2023-12-09".into()),
+            (10, 0),
+            (11, 0)
+        ));
+        assert_eq!(
+            vec![("CENTR", "115")],
+            index.free_rooms(&repeated(&["Tu"]), (10, 0), (11, 0))
+        );
+    }
+}
+
+mod instructor_index_tests {
+    use webweg::types::InstructorIndex;
+    use webweg::ww_parser::parse_course_info;
+
+    #[test]
+    fn test_instructor_index_queries() {
+        let mut sections = parse_course_info(
+            serde_json::from_str(include_str!("json/courseinfo4.json")).unwrap(),
+            "WCWP 10A".into(),
+        )
+        .unwrap();
+        sections.extend(
+            parse_course_info(
+                serde_json::from_str(include_str!("json/courseinfo2.json")).unwrap(),
+                "CSE 30".into(),
+            )
+            .unwrap(),
+        );
+
+        let index = InstructorIndex::from_sections(&sections);
+        assert!(index.instructors().all(|name| name != "staff"));
+
+        let load = index.get("Jeffrey Gagnon").unwrap();
+        assert_eq!(vec!["Gagnon, Jeffrey C"], load.names);
+        assert_eq!(
+            sections
+                .iter()
+                .filter(|s| s.all_instructors.contains(&"Gagnon, Jeffrey C".to_string()))
+                .count(),
+            load.sections.len()
+        );
+        assert_eq!(vec!["WCWP 10A"], index.courses_taught_by("Gagnon, Jeffrey"));
+        assert!(index.courses_taught_by("Nobody, Here").is_empty());
+    }
+}
+
+mod search_result_units_tests {
+    use webweg::types::SearchResultItem;
+
+    fn item(min_units: f32, max_units: f32, unit_increment: Option<f32>) -> SearchResultItem {
+        SearchResultItem {
+            subj_code: "CSE".into(),
+            course_code: "199".into(),
+            course_title: "Independent Study".into(),
+            min_units,
+            max_units,
+            unit_increment,
+        }
+    }
+
+    #[test]
+    fn test_unit_options() {
+        assert_eq!(vec![4.0], item(4.0, 4.0, None).unit_options());
+        assert_eq!(
+            vec![1.0, 2.0, 3.0, 4.0],
+            item(1.0, 4.0, None).unit_options()
+        );
+        assert_eq!(vec![2.0, 4.0], item(2.0, 4.0, Some(2.0)).unit_options());
+
+        let variable = item(2.0, 4.0, Some(2.0));
+        assert!(variable.allows_units(4.0));
+        assert!(!variable.allows_units(3.0));
+        assert!(!variable.allows_units(6.0));
+    }
+
+    #[test]
+    fn test_parse_raw_search_result() {
+        let raw: webweg::raw_types::RawWebRegSearchResultItem = serde_json::from_str(
+            r#"{"UNIT_TO":4.0,"SUBJ_CODE":"CSE ","CRSE_TITLE":"Independent Study","UNIT_FROM":1.0,"CRSE_CODE":"199 "}"#,
+        )
+        .unwrap();
+        assert_eq!(1.0, raw.min_units);
+        assert_eq!(4.0, raw.max_units);
+        assert_eq!(None, raw.unit_increment);
+    }
+}
+
+mod ping_response_tests {
+    use webweg::types::PingResponse;
+
+    #[test]
+    fn parse_ping_with_extra_fields() {
+        let ping: PingResponse =
+            serde_json::from_str(r#"{"SESSION_OK":true,"TIMEOUT":"1800","ELAPSED":12}"#).unwrap();
+        assert!(ping.session_ok);
+        assert_eq!(Some(1800.0), ping.get_number("TIMEOUT"));
+        assert_eq!(Some(12.0), ping.get_number("ELAPSED"));
+        assert_eq!(None, ping.get_number("SESSION_OK"));
+        assert_eq!(None, ping.get_number("MISSING"));
+    }
+
+    #[test]
+    fn parse_ping_without_session_field() {
+        let ping: PingResponse = serde_json::from_str("{}").unwrap();
+        assert!(!ping.session_ok);
+        assert!(ping.extra.is_empty());
+    }
+}
+
+mod compatibility_tests {
+    use std::time::SystemTime;
+    use webweg::types::{
+        Compatibility, CompatibilityReport, EndpointCompatibility, Result, WrapperError,
+    };
+
+    #[test]
+    fn classify_endpoint_results() {
+        let ok: Result<()> = Ok(());
+        let bad_shape: Result<()> = Err(serde_json::from_str::<u32>("{}").unwrap_err().into());
+        let expired: Result<()> = Err(WrapperError::SessionExpired);
+
+        let ok = EndpointCompatibility::from_result("get-class", &ok);
+        let bad_shape = EndpointCompatibility::from_result("get-class", &bad_shape);
+        let expired = EndpointCompatibility::from_result("get-class", &expired);
+        assert_eq!(Compatibility::Compatible, ok.compatibility);
+        assert!(ok.error.is_none());
+        assert_eq!(Compatibility::Incompatible, bad_shape.compatibility);
+        assert!(bad_shape.error.is_some());
+        assert_eq!(Compatibility::Unavailable, expired.compatibility);
+
+        let mut report = CompatibilityReport {
+            wrapper_version: "0.0.0",
+            endpoints: vec![ok, expired],
+            checked_at: SystemTime::UNIX_EPOCH,
+        };
+        assert!(report.is_compatible());
+
+        report.endpoints.push(bad_shape);
+        assert!(!report.is_compatible());
+        assert_eq!(1, report.incompatible().count());
+    }
+}
+
+mod plan_matrix_tests {
+    use webweg::types::{PlanMatrix, PlanUpsertOutcome, WrapperError};
+
+    #[test]
+    fn test_plan_matrix_lookup() {
+        let matrix = PlanMatrix {
+            schedules: vec!["Backup A".into(), "Backup B".into()],
+            section_ids: vec!["079911".into(), "079912".into()],
+            outcomes: vec![
+                vec![
+                    Ok(PlanUpsertOutcome::Added),
+                    Ok(PlanUpsertOutcome::Unchanged),
+                ],
+                vec![
+                    Ok(PlanUpsertOutcome::Added),
+                    Err(WrapperError::AlreadyPlanned("Already planned.".into())),
+                ],
+            ],
+        };
+
+        assert!(matches!(
+            matrix.get("Backup A", "079912"),
+            Some(Ok(PlanUpsertOutcome::Unchanged))
+        ));
+        assert!(matrix.get("Backup C", "079911").is_none());
+
+        let failures = matrix.failures().collect::<Vec<_>>();
+        assert_eq!(1, failures.len());
+        assert_eq!(("Backup B", "079912"), (failures[0].0, failures[0].1));
+        assert!(!matrix.is_complete());
+    }
+}
+
+mod course_index_tests {
+    use webweg::types::{CourseIndex, SearchResultItem};
+
+    fn course(subj_code: &str, course_code: &str, course_title: &str) -> SearchResultItem {
+        SearchResultItem {
+            subj_code: subj_code.into(),
+            course_code: course_code.into(),
+            course_title: course_title.into(),
+            min_units: 4.0,
+            max_units: 4.0,
+            unit_increment: None,
+        }
+    }
+
+    fn index() -> CourseIndex {
+        CourseIndex::build(&[
+            course("CSE", "100", "Advanced Data Structures"),
+            course("CSE", "8A", "Introduction to Programming"),
+            course("CSE", "101", "Design and Analysis of Algorithm"),
+            course("MATH", "100A", "Abstract Algebra I"),
+            course("MATH", "100B", "Abstract Algebra II"),
+            course("CSE", "100", "Advanced Data Structures"),
+        ])
+    }
+
+    fn codes(results: Vec<&SearchResultItem>) -> Vec<String> {
+        results
+            .into_iter()
+            .map(|c| format!("{} {}", c.subj_code, c.course_code))
+            .collect()
+    }
+
+    #[test]
+    fn test_build_dedupes() {
+        assert_eq!(5, index().len());
+        assert!(CourseIndex::build(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_search_by_code() {
+        let index = index();
+        assert_eq!(vec!["CSE 100"], codes(index.search("cse 100", 1)));
+        assert_eq!(vec!["CSE 100", "CSE 101"], codes(index.search("CSE10", 10)));
+        assert_eq!(
+            vec!["MATH 100A", "MATH 100B"],
+            codes(index.search("math100", 10))
+        );
+        assert_eq!(
+            vec!["CSE 8A", "CSE 100", "CSE 101"],
+            codes(index.search("cse", 10))
+        );
+    }
+
+    #[test]
+    fn test_search_by_title() {
+        let index = index();
+        assert_eq!(vec!["CSE 100"], codes(index.search("data struct", 10)));
+        assert_eq!(
+            vec!["MATH 100A", "MATH 100B"],
+            codes(index.search("abstract alg", 10))
+        );
+        // Typos in longer words are allowed.
+        assert_eq!(vec!["CSE 101"], codes(index.search("algoritm", 10)));
+        assert_eq!(vec!["CSE 8A"], codes(index.search("programing", 10)));
+        // Every word has to match.
+        assert!(index.search("data algebra", 10).is_empty());
+        assert!(index.search("   ", 10).is_empty());
+    }
+}
+
+mod course_code_tests {
+    use webweg::types::CourseCode;
+
+    #[test]
+    fn test_course_code_normalization() {
+        let course = CourseCode::new(" cse", "100a ");
+        assert_eq!("CSE", course.subject);
+        assert_eq!("100A", course.number);
+        assert_eq!("CSE 100A", course.to_string());
+        assert_eq!(course, CourseCode::from(("CSE", "100A")));
+        assert_eq!(course, CourseCode::from(&course));
+    }
+
+    #[test]
+    fn test_course_code_parse() {
+        assert_eq!(
+            CourseCode::new("MATH", "20D"),
+            "math-20d".parse::<CourseCode>().unwrap()
+        );
+        assert_eq!(
+            CourseCode::new("CSE", "8B"),
+            "CSE 8B".parse::<CourseCode>().unwrap()
+        );
+        assert!("100 CSE".parse::<CourseCode>().is_err());
+        assert!("".parse::<CourseCode>().is_err());
+    }
+}
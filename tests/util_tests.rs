@@ -1,6 +1,7 @@
 extern crate core;
 
 use webweg::util;
+use webweg::wrapper::input_types::GradeOption;
 
 #[test]
 fn test_parse_day_code_simple() {
@@ -164,3 +165,562 @@ fn test_format_multiple_courses_mixed() {
         util::format_multiple_courses(&["math 20", "cse95", "cogs100", "math10"])
     )
 }
+
+#[test]
+fn test_parse_grade_option() {
+    assert_eq!(Some(GradeOption::L), "L".parse().ok());
+    assert_eq!(Some(GradeOption::P), "P".parse().ok());
+    assert_eq!(Some(GradeOption::P), "P/NP".parse().ok());
+    assert_eq!(Some(GradeOption::S), "s/u ".parse().ok());
+    assert!("".parse::<GradeOption>().is_err());
+    assert!("X".parse::<GradeOption>().is_err());
+}
+
+#[test]
+fn test_grade_option_round_trip() {
+    for opt in [GradeOption::L, GradeOption::P, GradeOption::S] {
+        assert_eq!(opt, opt.to_string().parse().unwrap());
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_webreg_datetime_around_dst() {
+    use chrono::{NaiveDate, Timelike};
+
+    // The clocks go forward at 2:00 AM on March 12, 2023, so 2:30 AM doesn't exist.
+    let date = NaiveDate::from_ymd_opt(2023, 3, 12).unwrap();
+    let dt = util::to_webreg_datetime(date, 2, 30).unwrap();
+    assert_eq!(3, dt.hour());
+    assert_eq!(30, dt.minute());
+
+    // The clocks go back at 2:00 AM on November 5, 2023, so 1:30 AM happens twice.
+    let date = NaiveDate::from_ymd_opt(2023, 11, 5).unwrap();
+    let dt = util::to_webreg_datetime(date, 1, 30).unwrap();
+    assert_eq!("2023-11-05T01:30:00-07:00", dt.to_rfc3339());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_meeting_next_start_after() {
+    use chrono::{TimeZone, Utc};
+    use webweg::types::{Meeting, MeetingDay};
+
+    let meeting = Meeting {
+        meeting_type: "LE".into(),
+        meeting_days: MeetingDay::Repeated(vec!["Tu".into(), "Th".into()]),
+        start_hr: 9,
+        start_min: 30,
+        end_hr: 10,
+        end_min: 50,
+        building: "CENTR".into(),
+        room: "115".into(),
+        instructors: vec![],
+    };
+
+    // Tuesday, October 3, 2023 at 10:00 AM Pacific time, which is after Tuesday's lecture.
+    let after = Utc.with_ymd_and_hms(2023, 10, 3, 17, 0, 0).unwrap();
+    let next = meeting.next_start_after(&after).unwrap();
+    assert_eq!("2023-10-05T09:30:00-07:00", next.to_rfc3339());
+}
+
+#[test]
+fn test_minimum_grade_codes() {
+    use webweg::types::MinimumGrade;
+
+    assert_eq!(MinimumGrade::CMinus, MinimumGrade::from_code(450));
+    assert_eq!(MinimumGrade::DMinus, MinimumGrade::from_code(600));
+    assert_eq!(MinimumGrade::APlus, MinimumGrade::from_code(50));
+    assert_eq!(MinimumGrade::Other(650), MinimumGrade::from_code(650));
+    assert_eq!(MinimumGrade::Other(0), MinimumGrade::from_code(0));
+    assert_eq!(450, MinimumGrade::CMinus.code());
+    assert_eq!("C-", MinimumGrade::CMinus.to_string());
+}
+
+#[test]
+fn test_render_schedule_html() {
+    use webweg::raw_types::RawScheduledMeeting;
+    use webweg::ww_parser::parse_schedule;
+
+    let raw_schedule =
+        serde_json::from_str::<Vec<RawScheduledMeeting>>(include_str!("json/schedule1.json"))
+            .unwrap();
+    let schedule = parse_schedule(raw_schedule).unwrap();
+
+    let html = util::render_schedule_html("My Schedule <FA23>", &schedule);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>My Schedule &lt;FA23&gt;</title>"));
+    assert!(html.contains("Latin America/Twentieth Centry"));
+    // Every meeting gets its own row, plus the header row.
+    let num_meetings: usize = schedule.iter().map(|s| s.meetings.len().max(1)).sum();
+    assert_eq!(num_meetings + 1, html.matches("<tr>").count());
+}
+
+#[test]
+fn test_get_final_exams() {
+    use webweg::raw_types::RawScheduledMeeting;
+    use webweg::types::EnrollmentStatus;
+    use webweg::ww_parser::parse_schedule;
+
+    let raw_schedule =
+        serde_json::from_str::<Vec<RawScheduledMeeting>>(include_str!("json/schedule1.json"))
+            .unwrap();
+    let mut schedule = parse_schedule(raw_schedule).unwrap();
+    // The order of the schedule shouldn't matter.
+    schedule.reverse();
+
+    let exams = util::get_final_exams(&schedule);
+    assert_eq!(2, exams.len());
+    assert_eq!(
+        vec!["185826", "184959"],
+        exams
+            .iter()
+            .map(|e| e.section_id.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!("2023-09-08", exams[0].date);
+    assert_eq!(
+        (11, 30, 14, 29),
+        (
+            exams[0].start_hr,
+            exams[0].start_min,
+            exams[0].end_hr,
+            exams[0].end_min
+        )
+    );
+    assert_eq!(
+        ("YORK", "4050B"),
+        (exams[0].building.as_str(), exams[0].room.as_str())
+    );
+    assert_eq!(EnrollmentStatus::Enrolled, exams[0].enrolled_status);
+    assert_eq!(
+        "COGS 118B (A01): 2023-09-08 at 19:00 - 21:59 in RCLAS R01",
+        exams[1].to_string()
+    );
+
+    let raw_schedule =
+        serde_json::from_str::<Vec<RawScheduledMeeting>>(include_str!("json/schedule2.json"))
+            .unwrap();
+    assert!(util::get_final_exams(&parse_schedule(raw_schedule).unwrap()).is_empty());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_to_ical() {
+    use chrono::NaiveDate;
+    use std::time::{Duration, SystemTime};
+    use webweg::raw_types::RawScheduledMeeting;
+    use webweg::types::{Clock, Event, MeetingDay, TermDates};
+    use webweg::ww_parser::parse_schedule;
+
+    struct FixedClock;
+    impl Clock for FixedClock {
+        fn now(&self) -> SystemTime {
+            // 2023-09-01 12:00:00 UTC
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_693_569_600)
+        }
+    }
+
+    let raw_schedule =
+        serde_json::from_str::<Vec<RawScheduledMeeting>>(include_str!("json/schedule1.json"))
+            .unwrap();
+    let schedule = parse_schedule(raw_schedule).unwrap();
+    let event = Event {
+        location: "Geisel".into(),
+        start_hr: 15,
+        start_min: 0,
+        end_hr: 16,
+        end_min: 30,
+        name: "Study, group".into(),
+        days: vec!["M".into(), "W".into()],
+        timestamp: "2023-09-01 10:00:00.000000".into(),
+    };
+    let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+    let term = TermDates::new(date(9, 28), date(12, 9), date(12, 16));
+
+    let ical = util::to_ical(&schedule, &[event], &term, &FixedClock);
+    assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ical.ends_with("END:VCALENDAR\r\n"));
+    assert!(ical.split("\r\n").all(|line| line.len() <= 75));
+
+    let num_meetings = schedule
+        .iter()
+        .flat_map(|s| &s.meetings)
+        .filter(|m| !matches!(m.meeting_days, MeetingDay::None))
+        .count();
+    assert_eq!(num_meetings + 1, ical.matches("BEGIN:VEVENT").count());
+
+    // The event repeats from the first Monday of the term until the end of the term.
+    assert!(ical.contains("SUMMARY:Study\\, group\r\n"));
+    assert!(ical.contains("DTSTART;TZID=America/Los_Angeles:20231002T150000\r\n"));
+    assert!(ical.contains("RRULE:FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20231217T075900Z\r\n"));
+    assert!(ical.contains("DTSTAMP:20230901T120000Z\r\n"));
+    assert!(!ical.contains("UNTIL=\r\n"));
+}
+
+#[test]
+fn test_normalize_instructor_name() {
+    assert_eq!(
+        "john smith",
+        util::normalize_instructor_name("Smith, John A.")
+    );
+    assert_eq!("john smith", util::normalize_instructor_name("John Smith"));
+    assert_eq!(
+        "mary-jane o'neil",
+        util::normalize_instructor_name("O'Neil, Mary-Jane")
+    );
+    assert_eq!("staff", util::normalize_instructor_name("Staff"));
+    assert_eq!("", util::normalize_instructor_name("  "));
+}
+
+#[test]
+fn test_enrich_sections() {
+    use std::cell::Cell;
+    use webweg::types::{InstructorInfo, InstructorInfoProvider};
+    use webweg::ww_parser::parse_course_info;
+
+    struct Directory {
+        lookups: Cell<usize>,
+    }
+
+    impl InstructorInfoProvider for Directory {
+        fn lookup(&self, normalized_name: &str) -> Option<InstructorInfo> {
+            self.lookups.set(self.lookups.get() + 1);
+            Some(InstructorInfo {
+                email: Some(format!("{}@ucsd.edu", normalized_name.replace(' ', "."))),
+                ..Default::default()
+            })
+        }
+    }
+
+    let raw = serde_json::from_str(include_str!("json/courseinfo1.json")).unwrap();
+    let mut sections = parse_course_info(raw, "CSE 100".to_string()).unwrap();
+    let directory = Directory {
+        lookups: Cell::new(0),
+    };
+
+    let num_instructors: usize = sections.iter().map(|s| s.all_instructors.len()).sum();
+    assert_eq!(
+        num_instructors,
+        util::enrich_sections(&mut sections, &directory)
+    );
+    let unique = sections
+        .iter()
+        .flat_map(|s| s.all_instructors.iter())
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    assert_eq!(unique, directory.lookups.get());
+
+    for section in &sections {
+        for instructor in &section.all_instructors {
+            let info = &section.instructor_info[instructor];
+            assert!(info.email.as_ref().unwrap().ends_with("@ucsd.edu"));
+        }
+    }
+}
+
+#[test]
+fn test_summarize_families() {
+    use webweg::ww_parser::parse_course_info;
+
+    let raw = serde_json::from_str(include_str!("json/courseinfo3.json")).unwrap();
+    let sections = parse_course_info(raw, "MATH 100C".into()).unwrap();
+    let families = util::summarize_families(&sections);
+    assert_eq!(1, families.len());
+    assert_eq!("A", families[0].family);
+    assert_eq!(2, families[0].section_codes.len());
+    assert_eq!(52, families[0].total_seats);
+    assert_eq!(31, families[0].enrolled_ct);
+    assert_eq!(21, families[0].available_seats);
+    assert_eq!(2, families[0].sections_with_seats);
+    assert!(families[0].has_seats());
+
+    let raw = serde_json::from_str(include_str!("json/courseinfo2.json")).unwrap();
+    let sections = parse_course_info(raw, "CSE 30".into()).unwrap();
+    let families = util::summarize_families(&sections);
+    assert_eq!(
+        vec!["A", "B"],
+        families
+            .iter()
+            .map(|f| f.family.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(53, families[0].waitlist_ct);
+    assert_eq!(0, families[0].available_seats);
+    assert!(!families[0].has_seats());
+    assert!(!families[1].has_seats());
+}
+
+#[test]
+fn test_parse_course_identifier() {
+    let expected = ("CSE".to_string(), "100".to_string());
+    for input in ["cse100", "CSE 100", "cse-100", "  Cse   100 ", "CSE_100"] {
+        assert_eq!(expected, util::parse_course_identifier(input).unwrap());
+    }
+
+    assert_eq!(
+        ("MATH".to_string(), "20D".to_string()),
+        util::parse_course_identifier("Math 20D").unwrap()
+    );
+    assert_eq!(
+        ("CSE".to_string(), "8A".to_string()),
+        util::parse_course_identifier("cse8a").unwrap()
+    );
+
+    for input in [
+        "",
+        "CSE",
+        "100",
+        "100 CSE",
+        "CSE 1000",
+        "ABCDE 100",
+        "CSE 100ABC",
+        "CSE 1A1",
+    ] {
+        assert!(util::parse_course_identifier(input).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_canonical_json_is_order_independent() {
+    use webweg::ww_parser::parse_course_info;
+
+    let raw = serde_json::from_str(include_str!("json/courseinfo2.json")).unwrap();
+    let sections = parse_course_info(raw, "CSE 30".into()).unwrap();
+
+    let mut shuffled = sections.clone();
+    shuffled.reverse();
+    for section in &mut shuffled {
+        section.meetings.reverse();
+    }
+
+    let json = util::to_canonical_json(&sections).unwrap();
+    assert_eq!(json, util::to_canonical_json(&shuffled).unwrap());
+
+    let mut canonical = shuffled;
+    util::canonicalize_sections(&mut canonical);
+    assert!(canonical
+        .windows(2)
+        .all(|w| (&w[0].subj_course_id, &w[0].section_code)
+            <= (&w[1].subj_course_id, &w[1].section_code)));
+}
+
+#[test]
+fn test_export_selected_fields() {
+    use webweg::types::ExportFields;
+    use webweg::ww_parser::parse_course_info;
+
+    let raw = serde_json::from_str(include_str!("json/courseinfo3.json")).unwrap();
+    let sections = parse_course_info(raw, "MATH 100C".into()).unwrap();
+    let fields = ExportFields::default()
+        .with_meetings(false)
+        .with_instructors(true)
+        .with_seats(false);
+
+    let value = util::project_section(&sections[0], &fields).unwrap();
+    let obj = value.as_object().unwrap();
+    // The keys are the serialized field names, which depend on the `camel_case` feature.
+    let (section_id, all_instructors, enrolled_ct) = if cfg!(feature = "camel_case") {
+        ("sectionId", "allInstructors", "enrolledCt")
+    } else {
+        ("section_id", "all_instructors", "enrolled_ct")
+    };
+    assert!(obj.contains_key(section_id));
+    assert!(obj.contains_key(all_instructors));
+    assert!(!obj.contains_key("meetings"));
+    assert!(!obj.contains_key(enrolled_ct));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&util::sections_to_json(&sections, &fields).unwrap()).unwrap();
+    assert_eq!(sections.len(), json.as_array().unwrap().len());
+
+    let csv = util::sections_to_csv(&sections, &ExportFields::none().with_visibility(true));
+    let mut lines = csv.lines();
+    let header = if cfg!(feature = "camel_case") {
+        "subjCourseId,sectionId,sectionCode,isVisible"
+    } else {
+        "subj_course_id,section_id,section_code,is_visible"
+    };
+    assert_eq!(Some(header), lines.next());
+    assert_eq!(sections.len(), lines.count());
+}
+
+#[test]
+fn test_write_json_lines_rotates() {
+    use futures::stream;
+    use webweg::types::Result;
+    use webweg::util::JsonLinesWriter;
+
+    let dir = std::env::temp_dir().join(format!("webweg-jsonl-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let items: Vec<Result<Vec<u32>>> = vec![Ok(vec![1, 2]), Ok(vec![3]), Ok(vec![4, 5])];
+    let mut writer = JsonLinesWriter::new(dir.join("items.jsonl"), Some(4)).unwrap();
+    let count =
+        futures::executor::block_on(util::write_json_lines(stream::iter(items), &mut writer))
+            .unwrap();
+    assert_eq!(5, count);
+
+    let files = writer.finish().unwrap();
+    assert_eq!(3, files.len());
+    assert_eq!(dir.join("items.jsonl"), files[0]);
+    assert_eq!(dir.join("items.1.jsonl"), files[1]);
+    assert_eq!("1\n2\n", std::fs::read_to_string(&files[0]).unwrap());
+    assert_eq!("5\n", std::fs::read_to_string(&files[2]).unwrap());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_json_lines_attempt_sink() {
+    use std::time::SystemTime;
+    use webweg::types::{AttemptRecord, AttemptSink};
+    use webweg::util::JsonLinesAttemptSink;
+
+    let path = std::env::temp_dir().join(format!("webweg-attempts-{}.jsonl", std::process::id()));
+    let sink = JsonLinesAttemptSink::new(&path).unwrap();
+    let attempt = AttemptRecord {
+        at: SystemTime::UNIX_EPOCH,
+        term: "FA23".into(),
+        section_id: "079911".into(),
+        section_code: "A01".into(),
+        course: "CSE 100".into(),
+        outcome: "failed".into(),
+        reason: Some("This section conflicts with MATH 184 A01.".into()),
+    };
+    sink.record(&attempt);
+    sink.record(&attempt);
+
+    // Each attempt should be on disk without having to drop the sink.
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let lines = contents.lines().collect::<Vec<_>>();
+    assert_eq!(2, lines.len());
+    assert_eq!(
+        attempt,
+        serde_json::from_str::<AttemptRecord>(lines[0]).unwrap()
+    );
+
+    drop(sink);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_diff_against_baseline() {
+    use webweg::types::SectionChange;
+    use webweg::util::JsonLinesWriter;
+    use webweg::ww_parser::parse_course_info;
+
+    let raw = serde_json::from_str(include_str!("json/courseinfo2.json")).unwrap();
+    let baseline = parse_course_info(raw, "CSE 30".into()).unwrap();
+    assert_eq!(2, baseline.len());
+
+    let path = std::env::temp_dir().join(format!("webweg-baseline-{}.jsonl", std::process::id()));
+    let mut writer = JsonLinesWriter::new(&path, None).unwrap();
+    for section in &baseline {
+        writer.write(section).unwrap();
+    }
+    writer.finish().unwrap();
+
+    assert!(util::diff_against_baseline(&path, &baseline)
+        .unwrap()
+        .is_empty());
+
+    // Drop the first section, change the second section's seats, and add a new section.
+    let mut current = baseline[1..].to_vec();
+    current[0].available_seats += 5;
+    let mut added = baseline[0].clone();
+    added.section_id = "999999".into();
+    added.section_code = "C01".into();
+    current.push(added);
+    let changes = util::diff_against_baseline(&path, &current).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(3, changes.len());
+    assert!(changes.iter().any(|c| matches!(
+        c,
+        SectionChange::Added(sec) if sec.section_id == "999999"
+    )));
+    assert!(changes.iter().any(|c| matches!(
+        c,
+        SectionChange::Removed(sec) if sec.section_id == baseline[0].section_id
+    )));
+
+    let modified = changes
+        .iter()
+        .find_map(|c| match c {
+            SectionChange::Modified { section_id, fields } => Some((section_id, fields)),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(&baseline[1].section_id, modified.0);
+    assert_eq!(1, modified.1.len());
+    assert_eq!(
+        serde_json::json!(baseline[1].available_seats + 5),
+        modified.1[0].new
+    );
+}
+
+#[test]
+fn test_anonymize_response() {
+    use webweg::offline;
+
+    let json = include_str!("json/courseinfo1.json");
+    let anonymized = util::anonymize_response(json).unwrap();
+    assert!(json.contains("A93603904"));
+    assert!(!anonymized.contains("A93603904"));
+    assert_eq!(
+        offline::parse_course_info(json, "CSE 101").unwrap(),
+        offline::parse_course_info(&anonymized, "CSE 101").unwrap()
+    );
+
+    let json = include_str!("json/schedule1.json");
+    let anonymized = util::anonymize_response(json).unwrap();
+    assert!(!anonymized.contains("A16666958"));
+    assert!(!anonymized.contains("2090586"));
+    // The order of the sections in a parsed schedule isn't guaranteed.
+    let sorted = |json: &str| {
+        let mut schedule = offline::parse_schedule(json).unwrap();
+        schedule.sort_by(|a, b| a.section_id.cmp(&b.section_id));
+        schedule
+    };
+    assert_eq!(sorted(json), sorted(&anonymized));
+
+    let json = r#"[{
+        "DESCRIPTION": "Dentist",
+        "LOCATION": "Home",
+        "START_TIME": "0930",
+        "END_TIME": "1030",
+        "DAYS": "1000000",
+        "TIME_STAMP": "2023-09-01 10:00:00.000000"
+    }]"#;
+    let events = offline::parse_events(&util::anonymize_response(json).unwrap()).unwrap();
+    assert_eq!("Event 1", events[0].name);
+    assert_eq!("Location 1", events[0].location);
+
+    assert!(util::anonymize_response("not json").is_err());
+}
+
+#[test]
+fn test_build_url() {
+    use std::time::{Duration, SystemTime};
+
+    let base = "https://act.ucsd.edu/webreg2/svc/wradapter/secure/search-by-all?";
+    let url = util::build_url(base, &[("subjcode", "CSE"), ("crsecode", "  8A")], None).unwrap();
+    assert_eq!(
+        url::Url::parse_with_params(base, &[("subjcode", "CSE"), ("crsecode", "  8A")]).unwrap(),
+        url
+    );
+
+    // The base is reused, so a second URL from the same base shouldn't keep the old query.
+    let now = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+    let url = util::build_url(base, &[("termcode", "FA23")], Some(now)).unwrap();
+    assert_eq!(Some("termcode=FA23&_=1700000000000"), url.query());
+
+    let url = util::build_url("https://example.com/a?b=c", &[], Some(now)).unwrap();
+    assert_eq!(Some("b=c&_=1700000000000"), url.query());
+
+    let url = util::build_url("https://example.com/a", &[], None).unwrap();
+    assert_eq!("https://example.com/a?", url.as_str());
+
+    assert!(util::build_url("not a url", &[], None).is_err());
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod prerequisites_tests {
     use webweg::raw_types::RawPrerequisite;
-    use webweg::types::{CoursePrerequisite, PrerequisiteInfo};
+    use webweg::types::{CoursePrerequisite, MinimumGrade, PrerequisiteInfo};
     use webweg::ww_parser::parse_prerequisites;
 
     /// Sorts the prerequisite objects so that we can check equality without needing to use
@@ -34,8 +34,10 @@ mod prerequisites_tests {
         let mut res = parse_prerequisites(raw_prereqs).unwrap();
         let mut expected = PrerequisiteInfo {
             course_prerequisites: vec![vec![
-                CoursePrerequisite::new("CSE 8B", "Intro to Programming 2"),
-                CoursePrerequisite::new("CSE 11", "Accel. Intro to Programming"),
+                CoursePrerequisite::new("CSE 8B", "Intro to Programming 2")
+                    .with_min_grade(MinimumGrade::DMinus),
+                CoursePrerequisite::new("CSE 11", "Accel. Intro to Programming")
+                    .with_min_grade(MinimumGrade::DMinus),
             ]],
             exam_prerequisites: vec![],
         };
@@ -53,14 +55,14 @@ mod prerequisites_tests {
         let mut res = parse_prerequisites(raw_prereqs).unwrap();
         let mut expected = PrerequisiteInfo {
             course_prerequisites: vec![
-                vec![CoursePrerequisite::new(
-                    "CSE 12",
-                    "Basic Data Struct & OO Design",
-                )],
-                vec![CoursePrerequisite::new(
-                    "CSE 15L",
-                    "Software Tools&Techniques Lab",
-                )],
+                vec![
+                    CoursePrerequisite::new("CSE 12", "Basic Data Struct & OO Design")
+                        .with_min_grade(MinimumGrade::DMinus),
+                ],
+                vec![
+                    CoursePrerequisite::new("CSE 15L", "Software Tools&Techniques Lab")
+                        .with_min_grade(MinimumGrade::DMinus),
+                ],
             ],
             exam_prerequisites: vec![],
         };
@@ -79,23 +81,30 @@ mod prerequisites_tests {
         let mut expected = PrerequisiteInfo {
             course_prerequisites: vec![
                 vec![
-                    CoursePrerequisite::new("CSE 21", "Math/Algorithm&Systems Analys"),
-                    CoursePrerequisite::new("MATH 154", "Discrete Math & Graph Theory"),
-                    CoursePrerequisite::new("MATH 158", "Extremal Combinatorics/Graph"),
-                    CoursePrerequisite::new("MATH 184", "Enumerative Combinatorics"),
-                    CoursePrerequisite::new("MATH 188", "Algebraic Combinatorics"),
+                    CoursePrerequisite::new("CSE 21", "Math/Algorithm&Systems Analys")
+                        .with_min_grade(MinimumGrade::DMinus),
+                    CoursePrerequisite::new("MATH 154", "Discrete Math & Graph Theory")
+                        .with_min_grade(MinimumGrade::DMinus),
+                    CoursePrerequisite::new("MATH 158", "Extremal Combinatorics/Graph")
+                        .with_min_grade(MinimumGrade::DMinus),
+                    CoursePrerequisite::new("MATH 184", "Enumerative Combinatorics")
+                        .with_min_grade(MinimumGrade::DMinus),
+                    CoursePrerequisite::new("MATH 188", "Algebraic Combinatorics")
+                        .with_min_grade(MinimumGrade::DMinus),
                 ],
-                vec![CoursePrerequisite::new(
-                    "CSE 12",
-                    "Basic Data Struct & OO Design",
-                )],
-                vec![CoursePrerequisite::new(
-                    "CSE 15L",
-                    "Software Tools&Techniques Lab",
-                )],
                 vec![
-                    CoursePrerequisite::new("CSE 30", "Computer Organiz&Systms Progrm"),
-                    CoursePrerequisite::new("ECE 15", "Engineering Computation"),
+                    CoursePrerequisite::new("CSE 12", "Basic Data Struct & OO Design")
+                        .with_min_grade(MinimumGrade::DMinus),
+                ],
+                vec![
+                    CoursePrerequisite::new("CSE 15L", "Software Tools&Techniques Lab")
+                        .with_min_grade(MinimumGrade::DMinus),
+                ],
+                vec![
+                    CoursePrerequisite::new("CSE 30", "Computer Organiz&Systms Progrm")
+                        .with_min_grade(MinimumGrade::DMinus),
+                    CoursePrerequisite::new("ECE 15", "Engineering Computation")
+                        .with_min_grade(MinimumGrade::DMinus),
                 ],
             ],
             exam_prerequisites: vec![],
@@ -116,7 +125,8 @@ mod prerequisites_tests {
             course_prerequisites: vec![vec![CoursePrerequisite::new(
                 "MATH 20B",
                 "Calculus/Science & Engineering",
-            )]],
+            )
+            .with_min_grade(MinimumGrade::CMinus)]],
             exam_prerequisites: vec!["AP-Math BC".into()],
         };
 
@@ -150,6 +160,7 @@ mod prerequisites_tests {
 mod schedule_tests {
     use webweg::raw_types::RawScheduledMeeting;
     use webweg::types::{EnrollmentStatus, Meeting, MeetingDay, ScheduledSection};
+    use webweg::wrapper::input_types::GradeOption;
     use webweg::ww_parser::parse_schedule;
 
     /// Sorts the schedule objects so that we can check equality without needing to use
@@ -183,7 +194,8 @@ mod schedule_tests {
             section_capacity: 9999,
             enrolled_count: 1,
             available_seats: 9998,
-            grade_option: "P".into(),
+            grade_option: Some(GradeOption::P),
+            grade_option_locked: true,
             all_instructors: vec!["Sahoo, Debashis".into()],
             units: 2,
             enrolled_status: EnrollmentStatus::Planned,
@@ -220,7 +232,8 @@ mod schedule_tests {
                 section_capacity: 20,
                 enrolled_count: 7,
                 available_seats: 13,
-                grade_option: "P".into(),
+                grade_option: Some(GradeOption::P),
+                grade_option_locked: false,
                 all_instructors: vec!["Staff".into()],
                 units: 4,
                 enrolled_status: EnrollmentStatus::Enrolled,
@@ -292,7 +305,8 @@ mod schedule_tests {
                 section_capacity: 90,
                 enrolled_count: 90,
                 available_seats: 0,
-                grade_option: "L".into(),
+                grade_option: Some(GradeOption::L),
+                grade_option_locked: false,
                 all_instructors: vec!["Gupta, Anjum".into()],
                 units: 4,
                 enrolled_status: EnrollmentStatus::Waitlist { waitlist_pos: 26 },
@@ -397,6 +411,10 @@ mod course_info_tests {
             section_code: "A01".into(),
             all_instructors: vec!["Bach, Quang Tran".into()],
             available_seats: 0,
+            available_seats_raw: -84,
+            instructor_info: Default::default(),
+            section_notes: vec![],
+            waitlist_open: true,
             enrolled_ct: 329,
             total_seats: 245,
             waitlist_ct: 125,
@@ -478,6 +496,10 @@ mod course_info_tests {
                 section_code: "A01".into(),
                 all_instructors: vec!["Chin, Bryan W.".into()],
                 available_seats: 0,
+                available_seats_raw: -52,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 152,
                 total_seats: 100,
                 waitlist_ct: 53,
@@ -535,6 +557,10 @@ mod course_info_tests {
                 section_code: "B01".into(),
                 all_instructors: vec!["Cao, Yingjun".into()],
                 available_seats: 0,
+                available_seats_raw: -27,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 127,
                 total_seats: 100,
                 waitlist_ct: 29,
@@ -606,6 +632,10 @@ mod course_info_tests {
                 section_code: "A01".into(),
                 all_instructors: vec!["Pollack, Aaron".into()],
                 available_seats: 9,
+                available_seats_raw: 9,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 18,
                 total_seats: 27,
                 waitlist_ct: 0,
@@ -656,6 +686,10 @@ mod course_info_tests {
                 section_code: "A03".into(),
                 all_instructors: vec!["Pollack, Aaron".into()],
                 available_seats: 12,
+                available_seats_raw: 12,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 13,
                 total_seats: 25,
                 waitlist_ct: 0,
@@ -720,6 +754,10 @@ mod course_info_tests {
                 section_code: "001".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
+                available_seats_raw: 0,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 15,
                 total_seats: 15,
                 waitlist_ct: 0,
@@ -742,6 +780,10 @@ mod course_info_tests {
                 section_code: "002".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
+                available_seats_raw: 0,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 15,
                 total_seats: 15,
                 waitlist_ct: 2,
@@ -764,6 +806,10 @@ mod course_info_tests {
                 section_code: "003".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
+                available_seats_raw: 0,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 15,
                 total_seats: 15,
                 waitlist_ct: 1,
@@ -786,6 +832,10 @@ mod course_info_tests {
                 section_code: "004".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
+                available_seats_raw: 0,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 15,
                 total_seats: 15,
                 waitlist_ct: 2,
@@ -808,6 +858,10 @@ mod course_info_tests {
                 section_code: "005".into(),
                 all_instructors: vec!["Susi, Natalie".into()],
                 available_seats: 1,
+                available_seats_raw: 1,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 19,
                 total_seats: 20,
                 waitlist_ct: 0,
@@ -830,6 +884,10 @@ mod course_info_tests {
                 section_code: "006".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
+                available_seats_raw: 0,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 20,
                 total_seats: 20,
                 waitlist_ct: 1,
@@ -852,6 +910,10 @@ mod course_info_tests {
                 section_code: "007".into(),
                 all_instructors: vec!["Ornelas, Tricia".into()],
                 available_seats: 0,
+                available_seats_raw: 0,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 20,
                 total_seats: 20,
                 waitlist_ct: 1,
@@ -874,6 +936,10 @@ mod course_info_tests {
                 section_code: "008".into(),
                 all_instructors: vec!["Ornelas, Tricia".into()],
                 available_seats: 0,
+                available_seats_raw: 0,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 20,
                 total_seats: 20,
                 waitlist_ct: 0,
@@ -896,6 +962,10 @@ mod course_info_tests {
                 section_code: "009".into(),
                 all_instructors: vec!["Ornelas, Tricia".into()],
                 available_seats: 0,
+                available_seats_raw: 0,
+                instructor_info: Default::default(),
+                section_notes: vec![],
+                waitlist_open: true,
                 enrolled_ct: 20,
                 total_seats: 20,
                 waitlist_ct: 1,
@@ -919,3 +989,501 @@ mod course_info_tests {
         assert_eq!(expected, res);
     }
 }
+
+#[cfg(test)]
+mod section_info_tests {
+    use webweg::raw_types::RawWebRegMeeting;
+    use webweg::ww_parser::{parse_course_info, parse_section_info};
+
+    /// Checks that parsing each section on its own gives the same result as parsing the
+    /// entire course and then picking out the section.
+    fn check_all_sections(json: &str, subj_num: &str) {
+        let raw = || serde_json::from_str::<Vec<RawWebRegMeeting>>(json).unwrap();
+        let all_sections = parse_course_info(raw(), subj_num.into()).unwrap();
+        assert!(!all_sections.is_empty());
+        for section in all_sections {
+            let res = parse_section_info(raw(), subj_num.into(), &section.section_id)
+                .unwrap()
+                .unwrap();
+            assert_eq!(section, res);
+        }
+    }
+
+    #[test]
+    pub fn test_section_info_matches_course_info() {
+        check_all_sections(include_str!("json/courseinfo1.json"), "CSE 101");
+        check_all_sections(include_str!("json/courseinfo2.json"), "CSE 30");
+        check_all_sections(include_str!("json/courseinfo3.json"), "MATH 100C");
+        check_all_sections(include_str!("json/courseinfo4.json"), "WCWP 10A");
+    }
+
+    #[test]
+    pub fn test_section_info_not_found() {
+        let raw =
+            serde_json::from_str::<Vec<RawWebRegMeeting>>(include_str!("json/courseinfo1.json"))
+                .unwrap();
+        assert!(parse_section_info(raw, "CSE 101".into(), "999999")
+            .unwrap()
+            .is_none());
+    }
+}
+
+mod section_family_tests {
+    use webweg::raw_types::RawWebRegMeeting;
+    use webweg::types::MeetingDay;
+    use webweg::ww_parser::{parse_course_info, parse_section_families};
+
+    #[test]
+    pub fn test_families_with_discussions() {
+        let raw =
+            serde_json::from_str::<Vec<RawWebRegMeeting>>(include_str!("json/courseinfo3.json"))
+                .unwrap();
+        let families = parse_section_families(raw, "MATH 100C".into()).unwrap();
+
+        assert_eq!(1, families.len());
+        let family = &families[0];
+        assert_eq!("A", family.family);
+        assert_eq!(1, family.lecture.len());
+        assert_eq!("LE", family.lecture[0].meeting_type);
+        assert_eq!(1, family.exams.len());
+        assert_eq!("FI", family.exams[0].meeting_type);
+        assert!(matches!(
+            family.exams[0].meeting_days,
+            MeetingDay::OneTime(_)
+        ));
+
+        let mut codes = family
+            .discussions
+            .iter()
+            .map(|s| s.section_code.as_str())
+            .collect::<Vec<_>>();
+        codes.sort();
+        assert_eq!(vec!["A01", "A03"], codes);
+        for discussion in &family.discussions {
+            assert_eq!(1, discussion.meetings.len());
+            assert_eq!("DI", discussion.meetings[0].meeting_type);
+        }
+    }
+
+    #[test]
+    pub fn test_families_without_letters() {
+        let raw =
+            serde_json::from_str::<Vec<RawWebRegMeeting>>(include_str!("json/courseinfo4.json"))
+                .unwrap();
+        let num_sections = parse_course_info(raw, "WCWP 10A".into()).unwrap().len();
+        let raw =
+            serde_json::from_str::<Vec<RawWebRegMeeting>>(include_str!("json/courseinfo4.json"))
+                .unwrap();
+        let families = parse_section_families(raw, "WCWP 10A".into()).unwrap();
+
+        // Each numbered section is its own family.
+        assert_eq!(num_sections, families.len());
+        for family in &families {
+            assert_eq!(1, family.discussions.len());
+            assert_eq!(family.family, family.discussions[0].section_code);
+            assert!(family.discussions[0].meetings.is_empty());
+            assert_eq!(1, family.lecture.len());
+        }
+    }
+
+    fn check_matches_course_info(json: &str) {
+        let raw = || serde_json::from_str::<Vec<RawWebRegMeeting>>(json).unwrap();
+        let sections = parse_course_info(raw(), "CSE 100".into()).unwrap();
+        let families = parse_section_families(raw(), "CSE 100".into()).unwrap();
+
+        assert_eq!(
+            sections.len(),
+            families.iter().map(|f| f.discussions.len()).sum::<usize>()
+        );
+        for family in &families {
+            for discussion in &family.discussions {
+                let section = sections
+                    .iter()
+                    .find(|s| s.section_id == discussion.section_id)
+                    .unwrap();
+                // Every meeting of the section is either shared by the family or specific to
+                // the section, but never both.
+                assert_eq!(
+                    section.meetings.len(),
+                    family.lecture.len() + family.exams.len() + discussion.meetings.len()
+                );
+                for meeting in family.lecture.iter().chain(&family.exams) {
+                    assert!(section.meetings.contains(meeting));
+                }
+                for meeting in &discussion.meetings {
+                    assert!(section.meetings.contains(meeting));
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_families_match_course_info() {
+        check_matches_course_info(include_str!("json/courseinfo1.json"));
+        check_matches_course_info(include_str!("json/courseinfo2.json"));
+        check_matches_course_info(include_str!("json/courseinfo3.json"));
+        check_matches_course_info(include_str!("json/courseinfo4.json"));
+    }
+}
+
+mod seat_count_tests {
+    use webweg::raw_types::{RawSeatCount, RawWebRegMeeting};
+    use webweg::ww_parser::{parse_enrollment_count, parse_seat_counts};
+
+    fn check_matches_enrollment_count(json: &str) {
+        let full = parse_enrollment_count(
+            serde_json::from_str::<Vec<RawWebRegMeeting>>(json).unwrap(),
+            "CSE 100".into(),
+        )
+        .unwrap();
+        let fast = parse_seat_counts(serde_json::from_str::<Vec<RawSeatCount>>(json).unwrap());
+
+        assert_eq!(full.len(), fast.len());
+        for (f, s) in full.iter().zip(fast.iter()) {
+            assert_eq!(f.section_code, s.section_code);
+            assert_eq!(f.enrolled_ct, s.enrolled_ct);
+            assert_eq!(f.available_seats, s.available_seats);
+            assert_eq!(f.waitlist_ct, s.waitlist_ct);
+            assert_eq!(f.has_seats(), s.has_seats());
+        }
+    }
+
+    #[test]
+    pub fn test_seat_counts_match_enrollment_count() {
+        check_matches_enrollment_count(include_str!("json/courseinfo1.json"));
+        check_matches_enrollment_count(include_str!("json/courseinfo2.json"));
+        check_matches_enrollment_count(include_str!("json/courseinfo3.json"));
+        check_matches_enrollment_count(include_str!("json/courseinfo4.json"));
+    }
+}
+
+mod parse_warning_tests {
+    use webweg::raw_types::{RawScheduledMeeting, RawWebRegMeeting};
+    use webweg::types::{EnrollmentStatus, ParseWarning};
+    use webweg::ww_parser::{
+        parse_course_info_with_warnings, parse_enrollment_count_with_warnings,
+        parse_schedule_with_warnings,
+    };
+
+    #[test]
+    pub fn test_negative_seats_warning() {
+        let raw = || {
+            serde_json::from_str::<Vec<RawWebRegMeeting>>(include_str!("json/courseinfo2.json"))
+                .unwrap()
+        };
+
+        let expected = vec![
+            ParseWarning::NegativeSeats {
+                section_id: "260735".into(),
+                available_seats: -52,
+            },
+            ParseWarning::NegativeSeats {
+                section_id: "249208".into(),
+                available_seats: -27,
+            },
+        ];
+
+        let (sections, warnings) = parse_course_info_with_warnings(raw(), "CSE 30".into()).unwrap();
+        assert!(sections.iter().all(|s| s.available_seats >= 0));
+        let over_enrolled = sections.iter().find(|s| s.section_id == "260735").unwrap();
+        assert_eq!(-52, over_enrolled.available_seats_raw);
+        assert_eq!(52, over_enrolled.over_enrolled_by());
+        assert_eq!(expected, warnings);
+
+        let (_, warnings) = parse_enrollment_count_with_warnings(raw(), "CSE 30".into()).unwrap();
+        assert_eq!(expected, warnings);
+    }
+
+    #[test]
+    pub fn test_no_warnings() {
+        let raw =
+            serde_json::from_str::<Vec<RawWebRegMeeting>>(include_str!("json/courseinfo4.json"))
+                .unwrap();
+        let (_, warnings) = parse_course_info_with_warnings(raw, "WCWP 10A".into()).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    pub fn test_unknown_value_warnings() {
+        let mut raw =
+            serde_json::from_str::<Vec<serde_json::Value>>(include_str!("json/schedule2.json"))
+                .unwrap();
+        raw[0]["ENROLL_STATUS"] = "XX".into();
+        let raw = serde_json::from_value::<Vec<RawScheduledMeeting>>(raw.into()).unwrap();
+
+        let (schedule, warnings) = parse_schedule_with_warnings(raw).unwrap();
+        assert_eq!(EnrollmentStatus::Unknown, schedule[0].enrolled_status);
+        assert_eq!(
+            vec![ParseWarning::UnknownEnrollStatus {
+                section_id: "290181".into(),
+                enroll_status: "XX".into(),
+            }],
+            warnings
+        );
+        assert!(warnings[0].is_unknown_value());
+        assert!(!ParseWarning::MissingGeneralMeeting { family: 'A' }.is_unknown_value());
+    }
+
+    #[test]
+    pub fn test_unknown_grade_option_warning() {
+        let mut raw =
+            serde_json::from_str::<Vec<serde_json::Value>>(include_str!("json/schedule2.json"))
+                .unwrap();
+        for meeting in raw.iter_mut() {
+            meeting["GRADE_OPTION"] = "X".into();
+        }
+        let raw = serde_json::from_value::<Vec<RawScheduledMeeting>>(raw.into()).unwrap();
+
+        let (schedule, warnings) = parse_schedule_with_warnings(raw).unwrap();
+        assert!(schedule.iter().all(|s| s.grade_option.is_none()));
+        assert!(warnings.contains(&ParseWarning::UnknownGradeOption {
+            section_id: "290181".into(),
+            grade_option: "X".into(),
+        }));
+        assert!(warnings.iter().all(|w| w.is_unknown_value()));
+    }
+
+    #[test]
+    pub fn test_blank_grade_option_no_warning() {
+        let mut raw =
+            serde_json::from_str::<Vec<serde_json::Value>>(include_str!("json/schedule2.json"))
+                .unwrap();
+        for meeting in raw.iter_mut() {
+            meeting["GRADE_OPTION"] = " ".into();
+        }
+        let raw = serde_json::from_value::<Vec<RawScheduledMeeting>>(raw.into()).unwrap();
+
+        let (schedule, warnings) = parse_schedule_with_warnings(raw).unwrap();
+        assert!(schedule.iter().all(|s| s.grade_option.is_none()));
+        assert!(warnings.is_empty());
+    }
+}
+
+mod serialization_tests {
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use std::fmt::Debug;
+    use webweg::raw_types::{RawPrerequisite, RawScheduledMeeting, RawWebRegMeeting};
+    use webweg::types::{
+        CourseSection, ExportFields, PrerequisiteInfo, ScheduledSection, SeatEvent, SectionChange,
+    };
+    use webweg::ww_parser::{parse_course_info, parse_prerequisites, parse_schedule};
+
+    /// Checks that serializing and then deserializing the value gives back the same value.
+    fn check_round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+        let json = serde_json::to_string(value).unwrap();
+        let back: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, &back);
+    }
+
+    fn schedule() -> Vec<ScheduledSection> {
+        let raw =
+            serde_json::from_str::<Vec<RawScheduledMeeting>>(include_str!("json/schedule1.json"))
+                .unwrap();
+        parse_schedule(raw).unwrap()
+    }
+
+    fn seat_event() -> serde_json::Value {
+        serde_json::to_value(SeatEvent::Opened {
+            section_id: "079911".into(),
+            available_seats: 3,
+        })
+        .unwrap()
+    }
+
+    fn section_change() -> serde_json::Value {
+        serde_json::to_value(SectionChange::Modified {
+            section_id: "079911".into(),
+            fields: vec![],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    pub fn test_round_trip() {
+        check_round_trip(&schedule());
+
+        for (json, subj_num) in [
+            (include_str!("json/courseinfo1.json"), "CSE 101"),
+            (include_str!("json/courseinfo3.json"), "MATH 100C"),
+        ] {
+            let raw = serde_json::from_str::<Vec<RawWebRegMeeting>>(json).unwrap();
+            let sections: Vec<CourseSection> = parse_course_info(raw, subj_num.into()).unwrap();
+            check_round_trip(&sections);
+        }
+
+        let raw = serde_json::from_str::<Vec<RawPrerequisite>>(include_str!("json/prereq4.json"))
+            .unwrap();
+        let prereqs: PrerequisiteInfo = parse_prerequisites(raw).unwrap();
+        check_round_trip(&prereqs);
+    }
+
+    #[test]
+    #[cfg(not(feature = "camel_case"))]
+    pub fn test_snake_case_names() {
+        let json = serde_json::to_value(schedule()).unwrap();
+        let section = &json[0];
+        assert!(section.get("section_id").is_some());
+        assert!(section.get("enrolled_status").is_some());
+        assert!(section["enrolled_status"].get("enroll_status").is_some());
+        assert!(section["meetings"][0].get("meeting_days").is_some());
+        assert!(seat_event()["Opened"].get("available_seats").is_some());
+        assert!(section_change()["Modified"].get("section_id").is_some());
+
+        let fields = serde_json::to_value(ExportFields::default()).unwrap();
+        assert!(fields.get("section_notes").is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "camel_case")]
+    pub fn test_camel_case_names() {
+        let json = serde_json::to_value(schedule()).unwrap();
+        let section = &json[0];
+        assert!(section.get("sectionId").is_some());
+        assert!(section.get("enrolledStatus").is_some());
+        assert!(section["enrolledStatus"].get("enrollStatus").is_some());
+        assert!(section["meetings"][0].get("meetingDays").is_some());
+
+        // Enum variants aren't renamed, but their fields are.
+        assert!(seat_event()["Opened"].get("availableSeats").is_some());
+        assert!(section_change()["Modified"].get("sectionId").is_some());
+
+        let fields = serde_json::to_value(ExportFields::default()).unwrap();
+        assert!(fields.get("sectionNotes").is_some());
+    }
+}
+
+mod raw_meetings_tests {
+    use webweg::raw_types::{RawWebRegMeeting, RawWebRegMeetingRef};
+    use webweg::types::ParseWarning;
+    use webweg::ww_parser::{parse_course_info, parse_raw_meetings, parse_raw_meetings_from_slice};
+
+    #[test]
+    pub fn test_malformed_meeting_is_skipped() {
+        let text = include_str!("json/courseinfo3.json");
+        let (all, warnings) = parse_raw_meetings(text).unwrap();
+        assert!(warnings.is_empty());
+
+        let mut rows = serde_json::from_str::<Vec<serde_json::Value>>(text).unwrap();
+        rows.push(serde_json::json!({ "SECTION_NUMBER": 12345 }));
+        let (meetings, warnings) =
+            parse_raw_meetings(&serde_json::to_string(&rows).unwrap()).unwrap();
+        assert_eq!(all.len(), meetings.len());
+        assert_eq!(1, warnings.len());
+        assert!(matches!(
+            warnings[0],
+            ParseWarning::MalformedMeeting { index, .. } if index == all.len()
+        ));
+
+        // The rest of the course is still parsed.
+        assert_eq!(
+            parse_course_info(all, "MATH 100C".into()).unwrap(),
+            parse_course_info(meetings, "MATH 100C".into()).unwrap()
+        );
+
+        assert!(parse_raw_meetings("{}").is_err());
+    }
+
+    #[test]
+    pub fn test_borrowed_meetings_match_owned() {
+        let text = include_str!("json/courseinfo2.json");
+        let owned = serde_json::from_str::<Vec<RawWebRegMeeting>>(text).unwrap();
+        let (borrowed, warnings) = parse_raw_meetings_from_slice(text.as_bytes()).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(
+            parse_course_info(owned, "CSE 30".into()).unwrap(),
+            parse_course_info(borrowed, "CSE 30".into()).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_borrowed_meeting_trims_escaped_strings() {
+        let row = serde_json::json!({
+            "END_HH_TIME": 10, "END_MM_TIME": 50, "SCTN_CPCTY_QTY": 30, "SCTN_ENRLT_QTY": 0,
+            "SECTION_NUMBER": "079911", "COUNT_ON_WAITLIST": 0, "ROOM_CODE": "115  ",
+            "BEGIN_MM_TIME": 0, "BEGIN_HH_TIME": 10, "DAY_CODE": "135",
+            "PERSON_FULL_NAME": " Smith, Pat \"PJ\"  ", "FK_SPM_SPCL_MTG_CD": "  ",
+            "BLDG_CODE": "CENTR", "FK_CDI_INSTR_TYPE": "LE", "SECT_CODE": "A00",
+            "AVAIL_SEAT": 30, "START_DATE": "2023-09-28", "SECTION_START_DATE": "2023-09-28",
+            "FK_SST_SCTN_STATCD": "AC", "PRINT_FLAG": " "
+        })
+        .to_string();
+
+        let meeting = serde_json::from_str::<RawWebRegMeetingRef>(&row).unwrap();
+        assert_eq!("115", meeting.room_code);
+        assert_eq!("", meeting.special_meeting);
+
+        let meeting = meeting.into_owned();
+        assert_eq!("Smith, Pat \"PJ\"", meeting.person_full_name);
+        assert!(meeting.is_visible());
+    }
+}
+
+mod trimming_tests {
+    use webweg::raw_types::{RawSeatCount, RawWebRegSearchResultItem};
+
+    #[test]
+    fn raw_strings_are_trimmed() {
+        let raw: RawWebRegSearchResultItem = serde_json::from_str(
+            r#"{"UNIT_TO":4.0,"SUBJ_CODE":"CSE ","CRSE_TITLE":"Data Structures  ","UNIT_FROM":4.0,"CRSE_CODE":"  100"}"#,
+        )
+        .unwrap();
+        assert_eq!("CSE", raw.subj_code);
+        assert_eq!("100", raw.course_code);
+        assert_eq!("Data Structures", raw.course_title);
+
+        let raw: RawSeatCount = serde_json::from_str(
+            r#"{"SECT_CODE":"A01","SCTN_ENRLT_QTY":1,"AVAIL_SEAT":2,"COUNT_ON_WAITLIST":0,"FK_SST_SCTN_STATCD":"  "}"#,
+        )
+        .unwrap();
+        assert_eq!("A01", raw.sect_code);
+        assert!(raw.display_type.is_empty());
+    }
+}
+
+mod enrollment_phases_tests {
+    use serde_json::json;
+    use webweg::ww_parser::parse_enrollment_phases;
+
+    // These field names are guesses, not taken from a real response.
+    #[test]
+    fn test_phases_are_recognized_by_name() {
+        let status = json!({"SUCCESS": "YES", "FIRST_PASS_START": "2023-05-20 08:00"});
+        let eligibility = json!([{
+            "SECOND_PASS_START": "2023-05-27 08:00",
+            "SECOND_PASS_END": "2023-06-02",
+            "ADD_DEADLINE": "2023-10-13",
+            "LAST_DROP_DATE": " 2023-11-03 ",
+            "ENROLL_OK": " "
+        }]);
+
+        let phases = parse_enrollment_phases(&[status, eligibility]);
+        assert_eq!(Some("2023-05-20 08:00"), phases.first_pass.as_deref());
+        assert_eq!(Some("2023-05-27 08:00"), phases.second_pass.as_deref());
+        assert_eq!(Some("2023-10-13"), phases.add_deadline.as_deref());
+        assert_eq!(Some("2023-11-03"), phases.drop_deadline.as_deref());
+        assert_eq!(7, phases.extra.len());
+    }
+
+    #[test]
+    fn test_phases_are_recognized_by_word() {
+        let phases = parse_enrollment_phases(&[json!({
+            // "ATTEND" and "ADDRESS" only contain "END" and "ADD", so they don't count.
+            "ATTEND_FIRST_PASS": "2023-05-20 08:00",
+            "ADDRESS_DEADLINE": "2023-10-13",
+            "secondPassStart": "2023-05-27 08:00",
+            "dropEndDate": "2023-11-03",
+        })]);
+        assert_eq!(Some("2023-05-20 08:00"), phases.first_pass.as_deref());
+        assert_eq!(Some("2023-05-27 08:00"), phases.second_pass.as_deref());
+        assert!(phases.add_deadline.is_none());
+        assert_eq!(Some("2023-11-03"), phases.drop_deadline.as_deref());
+    }
+
+    #[test]
+    fn test_nothing_recognized() {
+        let phases = parse_enrollment_phases(&[json!({"SUCCESS": "YES"}), json!(null)]);
+        assert!(phases.first_pass.is_none() && phases.drop_deadline.is_none());
+        assert_eq!(1, phases.extra.len());
+    }
+}